@@ -31,7 +31,7 @@ def daisy_constant(path):
                     if "_ALT" in pad:
                         pad = pad[:-5]  # _ALTx
                     daisy = int(values.find("./value").text, 16)
-                    constant = f"pub const DAISY_{name}_{pad}: Daisy = Daisy::new({address:#010x} as *mut u32, {daisy});"
+                    constant = f"pub const DAISY_{name}_{pad}: Daisy = Daisy::new({address:#010x}, {daisy});"
                     print(constant)
 
 
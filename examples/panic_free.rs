@@ -0,0 +1,235 @@
+//! Link test for the panic-free guarantee
+//!
+//! Build this example with the `panic-free` profile:
+//!
+//! ```text
+//! cargo build --profile panic-free --example panic_free --features panic-free-test
+//! ```
+//!
+//! In that profile, this is a `no_std` binary whose panic handler calls a function
+//! that doesn't exist. If the optimizer can't remove every panic path from the code
+//! below, the binary fails to link. In debug builds, this is an ordinary program that
+//! runs the same code, so that `cargo test` and `cargo clippy` cover it.
+//!
+//! When you add a run-time API, call it from [`exercise()`].
+
+#![cfg_attr(not(debug_assertions), no_std, no_main)]
+
+use core::convert::TryFrom;
+use core::hint::black_box;
+use imxrt_iomuxc::{
+    consts::*,
+    imxrt1060::{gpio_ad_b0::*, gpio_b1::*},
+    lpuart,
+    pad::{Map, Set},
+    xbar, Config, DriveStrength, ErasedPad, OpenDrain, Pad, PadId, PadLookup, PadRef, Peripheral,
+    Plan, PreparedWrites, PullKeeper, Reservation, Routing, SionRequirement, Speed,
+};
+
+/// A pad group backed by ordinary memory
+struct Memory;
+
+static mut MUX: [u32; 4] = [0; 4];
+static mut PAD: [u32; 4] = [0; 4];
+
+unsafe impl imxrt_iomuxc::Base for Memory {
+    fn mux_base() -> *mut u32 {
+        core::ptr::addr_of_mut!(MUX) as *mut u32
+    }
+    fn pad_base() -> *mut u32 {
+        core::ptr::addr_of_mut!(PAD) as *mut u32
+    }
+}
+
+/// Memory pads that stand in for the i.MX RT 1060 pads in [`BOARD_PADS`]
+struct Board {
+    pads: [ErasedPad; 2],
+}
+
+/// LPUART1 TX and RX, which don't have daisy registers
+const BOARD_PADS: [PadId; 2] = [GPIO_AD_B0_12::ID, GPIO_AD_B0_13::ID];
+
+impl PadLookup for Board {
+    fn find_mut(&mut self, id: PadId) -> Option<&mut ErasedPad> {
+        let idx = BOARD_PADS.iter().position(|&pad| pad == id)?;
+        self.pads.get_mut(idx)
+    }
+}
+
+impl lpuart::PinTable for Board {
+    fn pins(&self) -> &[lpuart::TableEntry] {
+        const PINS: [lpuart::TableEntry; 2] = [
+            lpuart::TableEntry::new::<U1, GPIO_AD_B0_12>(),
+            lpuart::TableEntry::new::<U1, GPIO_AD_B0_13>(),
+        ];
+        &PINS
+    }
+}
+
+impl xbar::PinTable for Board {
+    fn pins(&self) -> &[xbar::TableEntry] {
+        const PINS: [xbar::TableEntry; 1] = [xbar::TableEntry::new::<GPIO_B1_00>()];
+        &PINS
+    }
+    fn line(&self, line: u8) -> Option<xbar::Line> {
+        match line {
+            0..=1 => Some(xbar::Line::Hardwired),
+            14 => Some(xbar::Line::Selectable),
+            _ => None,
+        }
+    }
+}
+
+/// Call each run-time API with inputs that the optimizer can't see
+fn exercise() {
+    let mut pads: [ErasedPad; 4] = unsafe {
+        [
+            Pad::<Memory, U0>::new().erase(),
+            Pad::<Memory, U1>::new().erase(),
+            Pad::<Memory, U2>::new().erase(),
+            Pad::<Memory, U3>::new().erase(),
+        ]
+    };
+
+    // Pad configuration
+    let config = Config::zero()
+        .set_speed(black_box(Speed::Fast))
+        .set_drive_strength(black_box(DriveStrength::R0_6))
+        .set_pull_keeper(black_box(Some(PullKeeper::Pullup22k)))
+        .set_open_drain(black_box(OpenDrain::Enabled));
+    if config.validate().is_ok() {
+        imxrt_iomuxc::configure(&mut pads[0], config);
+    }
+    let _ = black_box(Speed::try_from(black_box(4u32)));
+    let _ = black_box(DriveStrength::try_from(black_box(7u32)));
+
+    // Mux registers
+    imxrt_iomuxc::alternate(&mut pads[1], black_box(5));
+    imxrt_iomuxc::set_sion(&mut pads[1]);
+    imxrt_iomuxc::clear_sion(&mut pads[1]);
+
+    // Pad maps
+    let mut map: Map<u32, 2> = Map::new();
+    let mut set: Set<2> = Set::new();
+    for pad in pads.iter() {
+        let _ = black_box(map.insert(pad.id(), black_box(pad.offset() as u32)));
+        let _ = black_box(set.insert(pad.id()));
+    }
+    if let Some(value) = map.get_mut(pads[0].id()) {
+        *value += 1;
+    }
+    let _ = black_box(map.remove(black_box(pads[1].id())));
+    let _ = black_box(set.remove(black_box(pads[0].id())));
+    black_box(map.iter().count() + set.iter().count());
+
+    // Run-time routing
+    let routings = [
+        Routing {
+            pad: pads[2].id(),
+            alt: black_box(3),
+            sion: SionRequirement::Set,
+            daisy: None,
+            config,
+            peripheral: black_box(Some(Peripheral::Mqs)),
+            function: None,
+        },
+        Routing {
+            pad: pads[3].id(),
+            alt: black_box(3),
+            sion: SionRequirement::DontCare,
+            daisy: None,
+            config: Config::floating(),
+            peripheral: black_box(Some(Peripheral::Sai(1))),
+            function: None,
+        },
+    ];
+    if let Ok(plan) = imxrt_iomuxc::plan(black_box(&routings)) {
+        let _ = black_box(unsafe { plan.apply(&mut pads) });
+        let reservations = [Reservation {
+            peripheral: black_box(Peripheral::Sai(1)),
+            pads: black_box(&[]),
+        }];
+        let _ = black_box(plan.check_reservations(&reservations));
+
+        // Plan encoding
+        let mut sector = [0u8; 64];
+        let len = black_box(plan.encode(black_box(&mut sector)).unwrap_or(0));
+        let mut storage = routings;
+        let decoded = Plan::decode(black_box(&sector[..len.min(sector.len())]), &mut storage);
+        let _ = black_box(decoded.map(|plan| plan.routings().len()));
+    }
+
+    // Daisy conflicts
+    let prepared = [PreparedWrites {
+        mux_addr: pads[0].id().mux_addr(),
+        mux_val: black_box(0x13),
+        sion: SionRequirement::Set,
+        pad_addr: 0,
+        pad_val: black_box(0x10B0),
+        daisy: black_box(Some((0x401F_852C, 1))),
+        peripheral: None,
+    }; 3];
+    black_box(imxrt_iomuxc::detect_daisy_conflicts(black_box(&prepared)).count());
+
+    // Register reads
+    let view = PadRef::from(black_box(&pads[0]));
+    black_box((view.mux_value(), view.read_config().pull_keeper()));
+    black_box(view.verify(black_box(&prepared[0])));
+
+    // Adopted pads
+    let adopted = unsafe { Pad::<Memory, U1>::new().assume_configured() };
+    black_box(adopted.verify(black_box(&prepared[1])));
+
+    // Erased pads back to typed pads
+    let [p0, _, p2, p3] = pads;
+    let _ = black_box(Pad::<Memory, U0>::try_from(p0));
+
+    // Run-time preparation from pin tables
+    let mut board = Board { pads: [p2, p3] };
+    let _ = black_box(lpuart::prepare_erased(
+        &mut board,
+        black_box(1),
+        black_box(BOARD_PADS[0]),
+        black_box(BOARD_PADS[1]),
+    ));
+    // GPIO_B1_00 selects its pad with a daisy register, which the host doesn't have,
+    // so the pad isn't on the board, and the call fails. Every path still links.
+    let _ = black_box(xbar::prepare_erased(
+        &mut board,
+        black_box(14),
+        black_box(BOARD_PADS[0]),
+    ));
+}
+
+#[cfg(debug_assertions)]
+fn main() {
+    exercise();
+}
+
+#[cfg(not(debug_assertions))]
+#[no_mangle]
+extern "C" fn main(_argc: isize, _argv: *const *const u8) -> isize {
+    exercise();
+    0
+}
+
+// Link the C runtime, which calls main()
+#[cfg(not(debug_assertions))]
+#[link(name = "c")]
+extern "C" {}
+
+// The precompiled core library refers to the personality routine, even
+// though panics abort.
+#[cfg(not(debug_assertions))]
+#[no_mangle]
+extern "C" fn rust_eh_personality() {}
+
+#[cfg(not(debug_assertions))]
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    extern "C" {
+        /// Undefined, so that a reachable panic fails to link
+        fn imxrt_iomuxc_may_panic() -> !;
+    }
+    unsafe { imxrt_iomuxc_may_panic() }
+}
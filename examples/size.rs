@@ -0,0 +1,133 @@
+//! Code size of the error messages
+//!
+//! This example formats every error, and a pad's `Debug` output. Build it in the
+//! `size` profile, with and without the `min-size` feature, and compare the sizes:
+//!
+//! ```text
+//! cargo build --profile size --example size --features size-test
+//! size target/size/examples/size
+//! cargo build --profile size --example size --features size-test,min-size
+//! size target/size/examples/size
+//! ```
+//!
+//! In that profile, this is a `no_std` binary, so the `text` section holds only
+//! this crate's code, `core`'s formatting machinery, and the C runtime. In debug
+//! builds, this is an ordinary program that runs the same code.
+//!
+//! Sizes of the `text` section, measured on an x86_64 Linux host with Rust 1.95:
+//!
+//! | Features                | `text` (bytes) |
+//! | ----------------------- | -------------- |
+//! | `size-test`             | 12068          |
+//! | `size-test`, `min-size` | 3154           |
+//! | Difference              | -8914          |
+//!
+//! Update the table when you change an error message, or add an error.
+
+#![cfg_attr(not(debug_assertions), no_std, no_main)]
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::hint::black_box;
+use imxrt_iomuxc::{
+    consts::*, flexspi::FcbError, Config, DaisyConflict, Error, OpenDrain, Pad, Peripheral,
+    PlanError, PullKeeper, RoutingError, Speed, WrongPadError,
+};
+
+/// A pad group backed by ordinary memory
+struct Memory;
+
+static mut MUX: [u32; 2] = [0; 2];
+static mut PAD: [u32; 2] = [0; 2];
+
+unsafe impl imxrt_iomuxc::Base for Memory {
+    fn mux_base() -> *mut u32 {
+        core::ptr::addr_of_mut!(MUX) as *mut u32
+    }
+    fn pad_base() -> *mut u32 {
+        core::ptr::addr_of_mut!(PAD) as *mut u32
+    }
+}
+
+/// Discards the formatted text, without letting the optimizer see that
+struct Sink;
+
+impl Write for Sink {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        black_box(s);
+        Ok(())
+    }
+}
+
+/// Format one error of each kind
+fn format_errors() {
+    let pad = unsafe { Pad::<Memory, U1>::new() };
+    let _ = write!(Sink, "{:?}", pad);
+
+    let erased = pad.erase();
+    let id = erased.id();
+    let config = Config::zero()
+        .set_open_drain(black_box(OpenDrain::Enabled))
+        .set_pull_keeper(black_box(Some(PullKeeper::Keeper)));
+
+    let mut errors = [
+        Speed::try_from(black_box(4u32))
+            .map(drop)
+            .map_err(Error::from),
+        config.validate().map_err(Error::from),
+        Err(Error::from(black_box(FcbError::MissingTag))),
+        Err(Error::from(RoutingError::UnknownPad(black_box(id)))),
+        Err(Error::from(PlanError::DuplicatePad {
+            pad: id,
+            earlier: black_box(0),
+            later: black_box(1),
+        })),
+        Err(Error::from(PlanError::DaisyConflict(DaisyConflict {
+            reg: black_box(0x401F_852C),
+            earlier: 0,
+            earlier_value: 1,
+            later: 1,
+            later_value: 0,
+        }))),
+        Err(Error::from(PlanError::BorrowConflict {
+            peripheral: black_box(Peripheral::Sai(3)),
+            borrower: 1,
+            user: 0,
+        })),
+        Err(Error::from(WrongPadError(erased))),
+    ];
+    for result in black_box(&mut errors).iter() {
+        if let Err(err) = result {
+            let _ = write!(Sink, "{}", err);
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+fn main() {
+    format_errors();
+}
+
+#[cfg(not(debug_assertions))]
+#[no_mangle]
+extern "C" fn main(_argc: isize, _argv: *const *const u8) -> isize {
+    format_errors();
+    0
+}
+
+// Link the C runtime, which calls main()
+#[cfg(not(debug_assertions))]
+#[link(name = "c")]
+extern "C" {}
+
+// The precompiled core library refers to the personality routine, even
+// though panics abort.
+#[cfg(not(debug_assertions))]
+#[no_mangle]
+extern "C" fn rust_eh_personality() {}
+
+#[cfg(not(debug_assertions))]
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,135 @@
+//! Candidate tables generated from pin tables
+//!
+//! A chip's candidate table lists every peripheral pin, along with the pin's
+//! function and prepared writes. The chip's `candidates()` and `resolve()`
+//! search it at run time.
+
+use crate::{PadTable, Table};
+use std::io::{self, Write};
+
+/// Write the chip's candidates module for the pin `tables`
+///
+/// `source` names the data files in the generated module, like
+/// `data/imxrt1060/*.csv`. `pads` is the chip's pad table, which supplies the pad
+/// groups. The module defines one constant, `CANDIDATES`, that pairs each pin's
+/// `function()` with its `prepared()` writes, in table order. Each table's module
+/// is named after its data file, like `lpi2c` for `data/imxrt1060/lpi2c.csv`.
+///
+/// The entries don't fit rustfmt's line width. Declare the module with
+/// `#[rustfmt::skip]`.
+pub fn write_candidates<W: Write>(
+    out: &mut W,
+    source: &str,
+    pads: &PadTable,
+    tables: &[Table],
+) -> io::Result<()> {
+    let entries: Vec<(&str, Vec<(String, String)>)> = tables
+        .iter()
+        .map(|table| (table.module(), table.candidates()))
+        .collect();
+    let count: usize = entries.iter().map(|(_, pins)| pins.len()).sum();
+    let typenums = entries
+        .iter()
+        .flat_map(|(_, pins)| pins.iter())
+        .any(|(function, _)| function.contains("<U"));
+
+    writeln!(
+        out,
+        "// Generated by imxrt-iomuxc-build from {}. Do not edit.",
+        source
+    )?;
+    writeln!(out, "//! Candidate pads for each function")?;
+    writeln!(out)?;
+    let groups: Vec<String> = pads
+        .ranges()
+        .iter()
+        .map(|range| format!("{}::*", range.base.to_lowercase()))
+        .collect();
+    writeln!(out, "use super::pads::{{{}}};", groups.join(", "))?;
+    let mut modules: Vec<&str> = entries.iter().map(|(module, _)| *module).collect();
+    if typenums {
+        modules.push("consts::*");
+    }
+    modules.sort_unstable();
+    writeln!(
+        out,
+        "use crate::{{{}, function::Candidates, Config}};",
+        modules.join(", ")
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "/// Every pin that the chip implements, except for GPIOs"
+    )?;
+    writeln!(out, "///")?;
+    writeln!(
+        out,
+        "/// Each pin is paired with the writes of its module's `prepared()`, which name"
+    )?;
+    writeln!(out, "/// the pin's pad, alternate, and daisy.")?;
+    writeln!(
+        out,
+        "pub(super) const CANDIDATES: Candidates<{}> = Candidates::new([",
+        count
+    )?;
+    for (idx, (module, pins)) in entries.iter().enumerate() {
+        if idx > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "    // {}", module.to_uppercase())?;
+        for (function, prepared) in pins {
+            writeln!(out, "    ({}, {}),", function, prepared)?;
+        }
+    }
+    writeln!(out, "]);")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_candidates;
+    use crate::{PadTable, Table};
+
+    const PADS: &str = "[groups]\nGPIO_AD_B1,16\n[alternates]\nGPIO_AD_B1,0,16,0-9\n";
+
+    #[test]
+    fn test_write_candidates() {
+        let i2c = Table::parse(
+            "data/chip/lpi2c.csv",
+            "[pins i2c]\nmodule,alt,pad,signal,daisy!\nU1,3,GPIO_AD_B1_00,Scl,SCL\n\
+             [daisies]\nSCL_GPIO_AD_B1_00,0x401f84cc,1\n",
+        )
+        .unwrap();
+        let adc = Table::parse(
+            "data/chip/adc.csv",
+            "[pins adc]\nmodule,pad,input\nAdc1,GPIO_AD_B1_02,7\n",
+        )
+        .unwrap();
+        let flexio = Table::parse(
+            "data/chip/flexio.csv",
+            "[pins flexio]\nmodule,alt,pad,index\nfor U3,9,GPIO_AD_B1_03,U16\n",
+        )
+        .unwrap();
+        let pads = PadTable::parse(PADS).unwrap();
+
+        let mut out = Vec::new();
+        write_candidates(&mut out, "data/chip/*.csv", &pads, &[adc, flexio, i2c]).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(
+            "use super::pads::{gpio_ad_b1::*};\n\
+             use crate::{adc, consts::*, flexio, lpi2c, function::Candidates, Config};\n"
+        ));
+        assert!(out.contains("Candidates<3> = "));
+        assert!(out.contains(
+            "    (adc::function::<adc::Adc1, GPIO_AD_B1_02>(), \
+             adc::prepared::<adc::Adc1, GPIO_AD_B1_02>(Config::zero())),\n"
+        ));
+        assert!(out.contains(
+            "    (flexio::function_for::<U3, GPIO_AD_B1_03>(), \
+             flexio::prepared_for::<U3, GPIO_AD_B1_03>(Config::zero())),\n"
+        ));
+        assert!(out.contains(
+            "    (lpi2c::function::<GPIO_AD_B1_00>(), \
+             lpi2c::prepared::<GPIO_AD_B1_00>(Config::zero())),\n"
+        ));
+    }
+}
@@ -78,6 +78,33 @@
 //!     ],
 //! ).unwrap();
 //! ```
+//!
+//! # Generate pin tables
+//!
+//! Chip-specific pin implementations, like the i.MX RT 1060 XBAR pins, are
+//! generated from data files. Use [`Table`] to parse a data file, and
+//! [`write_table()`] to generate the module. See the [`Table`] documentation
+//! for the data file format.
+//!
+//! # Generate pad tables
+//!
+//! The chip's pad groups, dedicated balls, and GPIO routings are also described
+//! by a data file. Use [`PadTable`] to parse the data file, and
+//! [`write_pad_table()`] to generate the `pads` module and the GPIO `Pin`
+//! implementations.
+//!
+//! # Generate candidate tables
+//!
+//! [`write_candidates()`] generates a chip's reverse lookup table, which lists
+//! every pin of the chip's pin tables along with its function.
+
+mod candidates;
+mod pad_table;
+mod table;
+
+pub use candidates::write_candidates;
+pub use pad_table::{write_pad_table, PadTable};
+pub use table::{write_table, Table, TableError};
 
 use std::io::{self, Write};
 use std::ops::Range;
@@ -134,8 +161,37 @@ impl PadRange {
 /// On success, `out` will contain a Rust module, `pads`, that has
 /// public submodules. The submodules are named after the `PadRange` tags
 /// (lower-case). The submodules will contain public type aliases that match
-/// the processor pads.
+/// the processor pads. Each submodule's `Pads` struct implements `PadGroup`,
+/// which describes the number of pads and their offsets. Each submodule's
+/// `INFO` constant describes the group at run time, and the `groups::GROUPS`
+/// array lists every group's `INFO`.
+///
+/// The generated `ErasedPads` implements `PadLookup`. It checks alternates with
+/// the chip's `supports_alternate(PadId, u8) -> bool`, and names functions with the
+/// chip's `function_at(PadId, u8) -> Option<Function>`. The parent of the `pads`
+/// module must define both.
 pub fn write_pads<'a, W, I>(out: &mut W, ranges: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = &'a PadRange> + Clone,
+{
+    write_pads_with_dedicated(out, ranges, &[])
+}
+
+/// Write types for all pad `ranges`, and for the processor's `dedicated` balls
+///
+/// Dedicated balls, like the crystal oscillator and USB PHY balls, are not
+/// controlled by the IOMUXC. Each name in `dedicated` becomes a marker type in the
+/// `pads::dedicated` module. The types do not implement `Iomuxc`, so they cannot
+/// be configured. The `Pads` and `ErasedPads` aggregates own all of the dedicated
+/// balls in their `dedicated` member.
+///
+/// If `dedicated` is empty, this is the same as [`write_pads()`](fn.write_pads.html).
+pub fn write_pads_with_dedicated<'a, W, I>(
+    out: &mut W,
+    ranges: I,
+    dedicated: &[&str],
+) -> io::Result<()>
 where
     W: Write,
     I: IntoIterator<Item = &'a PadRange> + Clone,
@@ -178,7 +234,10 @@ where
         let name = quote::format_ident!("{}", base);
         let doc = format!("Pads with the prefix '{}'", range.base);
         let len = range.range.end - range.range.start;
+        let offsets = range.range.clone().map(|n| n as u8);
         let erased_doc = format!("Erased pads with the prefix '{}'", range.base);
+        let group_name = &range.base;
+        let first = quote::format_ident!("{}_{:02}", range.base, range.range.start);
         quote::quote! {
             #[doc = #doc]
             pub mod #name {
@@ -186,6 +245,13 @@ where
                 use super::super::bases::*;
                 #(#types)*
 
+                /// Describes this pad group
+                pub const INFO: crate::GroupInfo = crate::GroupInfo {
+                    name: #group_name,
+                    pad_count: #len,
+                    base_id: #first::ID,
+                };
+
                 #[doc = #doc]
                 pub struct Pads {
                     #(#pad_members),*
@@ -225,6 +291,15 @@ where
                         ]
                     }
                 }
+
+                impl crate::PadGroup for Pads {
+                    const PAD_COUNT: usize = #len;
+                    const OFFSETS: &'static [u8] = &[#(#offsets),*];
+                    type ErasedIter = ::core::array::IntoIter<ErasedPad, #len>;
+                    fn erased_iter(self) -> Self::ErasedIter {
+                        ::core::iter::IntoIterator::into_iter(self.erase())
+                    }
+                }
             }
         }
     });
@@ -247,11 +322,85 @@ where
             pub #name: #name::ErasedPads
         }
     });
-    let module_pads_erase = module_names.into_iter().map(|name| {
+    let module_pads_erase = module_names.clone().into_iter().map(|name| {
         quote::quote! {
             #name: self.#name.erase()
         }
     });
+    let group_count = module_names.len();
+    let (first_module, other_modules) = module_names
+        .split_first()
+        .expect("write_pads requires at least one pad range");
+
+    let has_dedicated = !dedicated.is_empty();
+    let types = dedicated.iter().map(|ball| {
+        let name = quote::format_ident!("{}", ball);
+        let doc = format!("The dedicated `{}` ball", ball);
+        quote::quote! {
+            #[doc = #doc]
+            pub struct #name {
+                _private: (),
+            }
+
+            impl #name {
+                /// Take the ball
+                ///
+                /// # Safety
+                ///
+                /// Creates a handle to a resource that may be owned elsewhere.
+                pub const unsafe fn new() -> Self {
+                    #name { _private: () }
+                }
+            }
+        }
+    });
+    let members = dedicated.iter().map(|ball| {
+        let name = quote::format_ident!("{}", ball);
+        let member = quote::format_ident!("{}", ball.to_lowercase());
+        quote::quote! { pub #member: #name }
+    });
+    let init = dedicated.iter().map(|ball| {
+        let name = quote::format_ident!("{}", ball);
+        let member = quote::format_ident!("{}", ball.to_lowercase());
+        quote::quote! { #member: <#name>::new() }
+    });
+    let dedicated_module = has_dedicated.then(|| {
+        quote::quote! {
+            /// Dedicated balls that are not controlled by the IOMUXC
+            ///
+            /// The types represent ownership of the balls. They do not implement
+            /// `Iomuxc`, so you cannot configure them.
+            pub mod dedicated {
+                #(#types)*
+
+                /// All of the dedicated balls
+                pub struct Pads {
+                    #(#members),*
+                }
+
+                impl Pads {
+                    /// Take all of the dedicated balls
+                    ///
+                    /// # Safety
+                    ///
+                    /// You may safely call this once to acquire all of the balls. Subsequent calls
+                    /// may return balls that are owned elsewhere.
+                    pub const unsafe fn new() -> Pads {
+                        Pads {
+                            #(#init),*
+                        }
+                    }
+                }
+            }
+        }
+    });
+    let dedicated_member = has_dedicated.then(|| quote::quote! { pub dedicated: dedicated::Pads });
+    let module_pad_members = module_pad_members.chain(dedicated_member.clone());
+    let module_pads_erase_members = module_pads_erase_members.chain(dedicated_member);
+    let module_pads_init = module_pads_init
+        .chain(has_dedicated.then(|| quote::quote! { dedicated: <dedicated::Pads>::new() }));
+    let module_pads_erase =
+        module_pads_erase.chain(has_dedicated.then(|| quote::quote! { dedicated: self.dedicated }));
     let module = quote::quote! {
         /// Contains all of the pads
         ///
@@ -260,6 +409,15 @@ where
         mod pads {
             #![allow(non_camel_case_types)] // Conform with reference manual
             #(#modules)*
+            #dedicated_module
+
+            /// Describes all of the pad groups
+            pub mod groups {
+                /// All of the pad groups, in the order of the `Pads` members
+                pub const GROUPS: [crate::GroupInfo; #group_count] = [
+                    #(super::#module_names::INFO),*
+                ];
+            }
 
             /// All of the pads
             ///
@@ -307,6 +465,22 @@ where
                     }
                 }
             }
+
+            impl crate::PadLookup for ErasedPads {
+                fn find_mut(&mut self, id: crate::PadId) -> Option<&mut crate::ErasedPad> {
+                    self.#first_module.iter_mut()
+                        #(.chain(self.#other_modules.iter_mut()))*
+                        .find(|pad| pad.id() == id)
+                }
+
+                fn supports_alternate(&self, id: crate::PadId, alt: u8) -> bool {
+                    super::supports_alternate(id, alt)
+                }
+
+                fn function_at(&self, id: crate::PadId, alt: u8) -> Option<crate::Function> {
+                    super::function_at(id, alt)
+                }
+            }
         }
     };
 
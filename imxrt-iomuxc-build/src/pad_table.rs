@@ -0,0 +1,468 @@
+//! Pad tables generated from data files
+//!
+//! A pad table describes all of a chip's pads. The build script uses it to
+//! generate the `pads` module, the GPIO `Pin` implementations, and the pads'
+//! alternates and GPIOs.
+
+use crate::{table::TableError, GpioRange, ImplGpioPin, PadRange};
+use std::io::{self, Write};
+
+fn error<T>(line: usize, message: impl Into<String>) -> Result<T, TableError> {
+    Err(TableError {
+        line,
+        message: message.into(),
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    None,
+    Groups,
+    Dedicated,
+    Gpio,
+    Alternates,
+}
+
+/// A chip's pad table, parsed from a data file
+///
+/// The data file has four sections:
+///
+/// - `[groups]` lists the pad groups as `GROUP,count`, in the order of the `Pads`
+///   members. `GPIO_EMC,42` describes `GPIO_EMC_00` through `GPIO_EMC_41`.
+/// - `[dedicated]` lists the names of the balls that the IOMUXC does not control,
+///   one per line.
+/// - `[gpio]` lists the GPIO routings as `GROUP,first,count,module,offset,alt`.
+///   `GPIO_EMC,32,10,3,18,5` routes `GPIO_EMC_32` through `GPIO_EMC_41` to
+///   `GPIO3_IO18` through `GPIO3_IO27` on ALT5.
+/// - `[alternates]` lists the alternates that each pad implements as
+///   `GROUP,first,count,alts`. `alts` is a space-separated list of alternates and
+///   inclusive ranges. `GPIO_B1,0,12,0-6 8 9` says that `GPIO_B1_00` through
+///   `GPIO_B1_11` implement ALT0 through ALT6, ALT8, and ALT9. Every pad must have
+///   exactly one row, and each pad's GPIO alternate must be in its row.
+///
+/// Lines that start with `#` are comments, and the parser ignores them.
+///
+/// ```text
+/// [groups]
+/// GPIO_AD,15
+/// GPIO_SD,15
+/// [dedicated]
+/// XTALI
+/// [gpio]
+/// GPIO_AD,0,15,1,0,5
+/// [alternates]
+/// GPIO_AD,0,15,0-5
+/// ```
+///
+/// Use [`parse()`](PadTable::parse) to parse the data file, and
+/// [`write_pad_table()`] to generate the module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PadTable {
+    ranges: Vec<PadRange>,
+    dedicated: Vec<String>,
+    gpio: Vec<(PadRange, GpioRange)>,
+    alternates: Vec<(PadRange, u16)>,
+}
+
+impl PadTable {
+    /// Parse the pad table data file `source`
+    ///
+    /// Returns an error if the data file is malformed, if a GPIO routing names
+    /// a pad that is not in a group, or if two routings share a pad or a GPIO.
+    /// Also returns an error if a pad doesn't have exactly one alternates row, or if
+    /// its row doesn't include its GPIO alternate.
+    pub fn parse(source: &str) -> Result<Self, TableError> {
+        let mut table = PadTable {
+            ranges: Vec::new(),
+            dedicated: Vec::new(),
+            gpio: Vec::new(),
+            alternates: Vec::new(),
+        };
+        let mut section = Section::None;
+        for (idx, raw) in source.lines().enumerate() {
+            let line = idx + 1;
+            let trimmed = raw.trim();
+            match trimmed {
+                "[groups]" => section = Section::Groups,
+                "[dedicated]" => section = Section::Dedicated,
+                "[gpio]" => section = Section::Gpio,
+                "[alternates]" => section = Section::Alternates,
+                _ if trimmed.is_empty() || trimmed.starts_with('#') => {}
+                _ => match section {
+                    Section::None => return error(line, "expected a section"),
+                    Section::Groups => table.parse_group(line, trimmed)?,
+                    Section::Dedicated => table.parse_dedicated(line, trimmed)?,
+                    Section::Gpio => table.parse_gpio(line, trimmed)?,
+                    Section::Alternates => table.parse_alternates(line, trimmed)?,
+                },
+            }
+        }
+        if table.ranges.is_empty() {
+            return error(source.lines().count(), "missing the groups section");
+        }
+        table.check_alternates(source.lines().count())?;
+        Ok(table)
+    }
+
+    fn parse_group(&mut self, line: usize, trimmed: &str) -> Result<(), TableError> {
+        let cells: Vec<&str> = trimmed.split(',').map(str::trim).collect();
+        let (base, count) = match cells.as_slice() {
+            [base, count] => (*base, *count),
+            _ => return error(line, "expected GROUP,count"),
+        };
+        if !is_ident(base) {
+            return error(line, format!("invalid group '{}'", base));
+        }
+        if self.ranges.iter().any(|range| range.base == base) {
+            return error(line, format!("duplicate group '{}'", base));
+        }
+        match count.parse::<usize>() {
+            Ok(count) if (1..=100).contains(&count) => {
+                self.ranges.push(PadRange::new(base, 0..count));
+                Ok(())
+            }
+            _ => error(line, format!("invalid pad count '{}'", count)),
+        }
+    }
+
+    fn parse_dedicated(&mut self, line: usize, trimmed: &str) -> Result<(), TableError> {
+        if !is_ident(trimmed) {
+            return error(line, format!("invalid ball '{}'", trimmed));
+        }
+        if self.dedicated.iter().any(|ball| ball == trimmed) {
+            return error(line, format!("duplicate ball '{}'", trimmed));
+        }
+        self.dedicated.push(String::from(trimmed));
+        Ok(())
+    }
+
+    fn parse_gpio(&mut self, line: usize, trimmed: &str) -> Result<(), TableError> {
+        let cells: Vec<&str> = trimmed.split(',').map(str::trim).collect();
+        let (base, numbers) = match cells.as_slice() {
+            [base, numbers @ ..] if numbers.len() == 5 => (*base, numbers),
+            _ => return error(line, "expected GROUP,first,count,module,offset,alt"),
+        };
+        let mut values = [0; 5];
+        for (value, cell) in values.iter_mut().zip(numbers) {
+            *value = match cell.parse::<u32>() {
+                Ok(value) => value,
+                Err(_) => return error(line, format!("invalid number '{}'", cell)),
+            };
+        }
+        let [first, count, module, offset, alt] = values;
+        let (first, count) = (first as usize, count as usize);
+
+        let group = match self.ranges.iter().find(|range| range.base == base) {
+            Some(group) => group,
+            None => return error(line, format!("unknown group '{}'", base)),
+        };
+        if count == 0 || first + count > group.range.end {
+            return error(line, format!("{} does not have those pads", base));
+        }
+        if alt > 9 {
+            return error(line, format!("invalid alt '{}'", alt));
+        }
+        if module == 0 || offset + count as u32 > 32 {
+            return error(line, format!("GPIO{} does not have those pins", module));
+        }
+
+        let range = PadRange::new(base, first..first + count);
+        let gpio = GpioRange {
+            module,
+            offset,
+            alt,
+        };
+        for (other, other_gpio) in &self.gpio {
+            let pads_overlap = other.base == range.base
+                && other.range.start < range.range.end
+                && range.range.start < other.range.end;
+            let other_pins = other_gpio.offset..other_gpio.offset + other.range.len() as u32;
+            let pins_overlap = other_gpio.module == module
+                && other_pins.start < offset + count as u32
+                && offset < other_pins.end;
+            if pads_overlap || pins_overlap {
+                return error(line, "overlaps another GPIO routing");
+            }
+        }
+        self.gpio.push((range, gpio));
+        Ok(())
+    }
+
+    fn parse_alternates(&mut self, line: usize, trimmed: &str) -> Result<(), TableError> {
+        let cells: Vec<&str> = trimmed.split(',').map(str::trim).collect();
+        let (base, first, count, alts) = match cells.as_slice() {
+            [base, first, count, alts] => (*base, *first, *count, *alts),
+            _ => return error(line, "expected GROUP,first,count,alts"),
+        };
+        let (first, count) = match (first.parse::<usize>(), count.parse::<usize>()) {
+            (Ok(first), Ok(count)) => (first, count),
+            _ => return error(line, "invalid pad range"),
+        };
+        let group = match self.ranges.iter().find(|range| range.base == base) {
+            Some(group) => group,
+            None => return error(line, format!("unknown group '{}'", base)),
+        };
+        if count == 0 || first + count > group.range.end {
+            return error(line, format!("{} does not have those pads", base));
+        }
+
+        let mut mask = 0u16;
+        for alt in alts.split_whitespace() {
+            let (start, end) = match alt.split_once('-') {
+                Some((start, end)) => (start, end),
+                None => (alt, alt),
+            };
+            match (start.parse::<u32>(), end.parse::<u32>()) {
+                (Ok(start), Ok(end)) if start <= end && end <= 9 => {
+                    mask |= (start..=end).fold(0, |mask, alt| mask | 1 << alt);
+                }
+                _ => return error(line, format!("invalid alternates '{}'", alt)),
+            }
+        }
+        if mask == 0 {
+            return error(line, "expected at least one alternate");
+        }
+
+        let range = PadRange::new(base, first..first + count);
+        let overlaps = self.alternates.iter().any(|(other, _)| {
+            other.base == range.base
+                && other.range.start < range.range.end
+                && range.range.start < other.range.end
+        });
+        if overlaps {
+            return error(line, "overlaps another alternates row");
+        }
+        self.alternates.push((range, mask));
+        Ok(())
+    }
+
+    fn check_alternates(&self, line: usize) -> Result<(), TableError> {
+        for group in &self.ranges {
+            for pad in group.range.clone() {
+                let mask = match self.mask(&group.base, pad) {
+                    Some(mask) => mask,
+                    None => {
+                        let message = format!("{}_{:02} has no alternates", group.base, pad);
+                        return error(line, message);
+                    }
+                };
+                let gpio = self.gpio.iter().find_map(|(range, gpio)| {
+                    (range.base == group.base && range.range.contains(&pad)).then_some(gpio.alt)
+                });
+                if let Some(alt) = gpio.filter(|alt| mask & 1 << alt == 0) {
+                    let message =
+                        format!("{}_{:02} is missing its GPIO ALT{}", group.base, pad, alt);
+                    return error(line, message);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn mask(&self, base: &str, pad: usize) -> Option<u16> {
+        self.alternates
+            .iter()
+            .find(|(range, _)| range.base == base && range.range.contains(&pad))
+            .map(|(_, mask)| *mask)
+    }
+
+    /// Returns the alternates mask of each pad, by group
+    ///
+    /// Bit `n` of a mask is set if the pad implements ALT`n`. The groups are in the
+    /// order of [`ranges()`](PadTable::ranges), and each group's masks are in pad order.
+    pub fn alternates(&self) -> Vec<Vec<u16>> {
+        self.ranges
+            .iter()
+            .map(|group| {
+                group
+                    .range
+                    .clone()
+                    .map(|pad| self.mask(&group.base, pad).unwrap_or(0))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the GPIO module and offset of each pad, by group
+    ///
+    /// A pad that isn't a GPIO is `None`. The groups are in the order of
+    /// [`ranges()`](PadTable::ranges), and each group's GPIOs are in pad order.
+    pub fn gpios(&self) -> Vec<Vec<Option<(u32, u32)>>> {
+        self.ranges
+            .iter()
+            .map(|group| {
+                group
+                    .range
+                    .clone()
+                    .map(|pad| {
+                        self.gpio.iter().find_map(|(range, gpio)| {
+                            (range.base == group.base && range.range.contains(&pad)).then(|| {
+                                (gpio.module, gpio.offset + (pad - range.range.start) as u32)
+                            })
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The pad groups, in the order of the `Pads` members
+    pub fn ranges(&self) -> &[PadRange] {
+        &self.ranges
+    }
+}
+
+fn is_ident(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Write the `pads` module, the GPIO `Pin` implementations, and the alternates for the pad `table`
+///
+/// This combines [`write_pads_with_dedicated()`](crate::write_pads_with_dedicated)
+/// and [`write_impl_gpio_pins()`](crate::write_impl_gpio_pins). It also writes
+/// `ALTERNATES`, which holds each group's [`alternates()`](PadTable::alternates)
+/// masks in the order of `pads::groups::GROUPS`, and `GPIOS`, which holds each
+/// group's [`gpios()`](PadTable::gpios) as `(module, offset)`. For tests,
+/// `GPIO_PINS` lists every GPIO pin's `PadId`, module, `ICR_INDEX`, and `ICR_SHIFT`.
+pub fn write_pad_table<W: Write>(out: &mut W, table: &PadTable) -> io::Result<()> {
+    let dedicated: Vec<&str> = table.dedicated.iter().map(String::as_str).collect();
+    crate::write_pads_with_dedicated(out, &table.ranges, &dedicated)?;
+    crate::write_impl_gpio_pins(
+        out,
+        table
+            .gpio
+            .iter()
+            .map(|(range, gpio)| ImplGpioPin::from_range(range, *gpio)),
+    )?;
+
+    let alternates = table.alternates();
+    let count = alternates.len();
+    let groups = alternates
+        .iter()
+        .map(|masks| quote::quote! { &[#(#masks),*] });
+    let alternates = quote::quote! {
+        /// The alternates that each pad implements, by pad group
+        ///
+        /// Bit `n` of a pad's mask is set if the pad implements ALT`n`. The groups are in
+        /// the order of `pads::groups::GROUPS`, and each group's masks are in pad order.
+        const ALTERNATES: [&[u16]; #count] = [#(#groups),*];
+    };
+    write!(out, "{}", alternates)?;
+
+    let gpios = table.gpios();
+    let count = gpios.len();
+    let groups = gpios.iter().map(|pins| {
+        let pins = pins.iter().map(|pin| {
+            let (module, offset) = pin.unwrap_or((0, 0));
+            let (module, offset) = (module as u8, offset as u8);
+            quote::quote! { (#module, #offset) }
+        });
+        quote::quote! { &[#(#pins),*] }
+    });
+    let pins = table.gpio.iter().flat_map(|(range, _)| {
+        let module = quote::format_ident!("{}", range.base.to_lowercase());
+        range.range.clone().map(move |idx| {
+            let name = quote::format_ident!("{}_{:02}", range.base, idx);
+            quote::quote! {
+                (
+                    pads::#module::#name::ID,
+                    <<pads::#module::#name as crate::gpio::Pin>::Module as crate::consts::Unsigned>::U8,
+                    <pads::#module::#name as crate::gpio::Pin>::ICR_INDEX,
+                    <pads::#module::#name as crate::gpio::Pin>::ICR_SHIFT,
+                )
+            }
+        })
+    });
+    let gpios = quote::quote! {
+        /// The GPIO module and offset of each pad, by pad group
+        ///
+        /// A pad that isn't a GPIO is `(0, 0)`. The groups are in the order of
+        /// `pads::groups::GROUPS`, and each group's GPIOs are in pad order.
+        const GPIOS: [&[(u8, u8)]; #count] = [#(#groups),*];
+
+        /// Every GPIO pin's module and interrupt configuration field, from its `gpio::Pin`
+        #[cfg(test)]
+        const GPIO_PINS: &[(crate::PadId, u8, usize, u32)] = &[#(#pins),*];
+    };
+    write!(out, "{}", gpios)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PadTable;
+    use crate::PadRange;
+
+    const TABLE: &str = "\
+[groups]
+GPIO_AD,15
+GPIO_SD,15
+[dedicated]
+XTALI
+[gpio]
+GPIO_AD,0,15,1,0,5
+GPIO_SD,0,15,2,0,5
+[alternates]
+GPIO_AD,0,10,0-5
+GPIO_AD,10,5,0 2 4-7
+GPIO_SD,0,15,5
+";
+
+    #[test]
+    fn test_parse() {
+        let table = PadTable::parse(TABLE).unwrap();
+        assert_eq!(
+            table.ranges(),
+            &[
+                PadRange::new("GPIO_AD", 0..15),
+                PadRange::new("GPIO_SD", 0..15)
+            ]
+        );
+        assert_eq!(table.dedicated, ["XTALI"]);
+        assert_eq!(table.gpio.len(), 2);
+        let alternates = table.alternates();
+        assert_eq!(alternates[0][..10], [0x3F; 10]);
+        assert_eq!(alternates[0][10..], [0xF5; 5]);
+        assert_eq!(alternates[1], [0x20; 15]);
+
+        let gpios = table.gpios();
+        assert_eq!(gpios[0][0], Some((1, 0)));
+        assert_eq!(gpios[0][14], Some((1, 14)));
+        assert_eq!(gpios[1][3], Some((2, 3)));
+
+        let partial =
+            PadTable::parse(&TABLE.replace("GPIO_SD,0,15,2,0,5", "GPIO_SD,4,11,2,7,5")).unwrap();
+        let gpios = partial.gpios();
+        assert_eq!(gpios[1][..4], [None; 4]);
+        assert_eq!(gpios[1][4], Some((2, 7)));
+        assert_eq!(gpios[1][14], Some((2, 17)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            (TABLE.replace("GPIO_SD,15\n", "GPIO_AD,15\n"), 3),
+            (TABLE.replace("XTALI", "XTAL I"), 5),
+            (TABLE.replace("GPIO_AD,0,15,1", "GPIO_AD,1,15,1"), 7),
+            (TABLE.replace("GPIO_SD,0,15,2", "GPIO_B0,0,15,2"), 8),
+            (TABLE.replace("GPIO_SD,0,15,2", "GPIO_SD,0,15,1"), 8),
+            (TABLE.replace("2,0,5", "2,0,10"), 8),
+            (
+                TABLE.replace("[alternates]", "GPIO_AD,3,1,3,0,5\n[alternates]"),
+                9,
+            ),
+            (TABLE.replace("[groups]\n", ""), 1),
+            (TABLE.replace("GPIO_AD,10,5,0 2", "GPIO_AD,9,5,0 2"), 11),
+            (TABLE.replace("0 2 4-7", "0 2 7-4"), 11),
+            (TABLE.replace("0 2 4-7", "0 2 4-10"), 11),
+            (TABLE.replace("0 2 4-7", ""), 11),
+            (TABLE.replace("GPIO_SD,0,15,5", "GPIO_SD,0,14,5"), 12),
+            (TABLE.replace("GPIO_SD,0,15,5", "GPIO_SD,0,15,4"), 12),
+        ];
+        for (source, line) in cases.iter() {
+            let err = PadTable::parse(source).unwrap_err();
+            assert_eq!(err.line, *line, "{}", err);
+        }
+    }
+}
@@ -0,0 +1,681 @@
+//! Pin tables generated from data files
+//!
+//! A pin table describes one chip-specific peripheral module, like the i.MX RT 1060
+//! XBAR pins. The table has these sections:
+//!
+//! - `[header]` is copied verbatim to the top of the module. It contains the module
+//!   documentation and the `use` statements.
+//! - `[pins <macro>]` names the peripheral macro, then lists the macro arguments in
+//!   a header row. Each following row is one macro invocation.
+//! - `[daisies]` lists the daisy constants as `NAME,register,value`. The generator
+//!   adds the `DAISY_` prefix.
+//! - `[footer]` is optional. It's copied verbatim to the bottom of the module, after
+//!   the daisy constants. Use it for lookup tables and tests.
+//!
+//! Outside of the header and footer, lines that start with `#` are comments, and the
+//! generator ignores them.
+//!
+//! ```text
+//! [header]
+//! //! I2C pin implementations
+//!
+//! use super::pads::gpio_ad_b1::*;
+//! use crate::{consts::*, lpi2c::{Pin, Scl, Sda}, Daisy};
+//! [pins i2c]
+//! module,alt,pad,signal,daisy!
+//! == I2C1
+//! U1,3,GPIO_AD_B1_00,Scl,LPI2C1_SCL
+//! U1,3,GPIO_AD_B1_01,Sda,LPI2C1_SDA
+//! [daisies]
+//! LPI2C1_SCL_GPIO_AD_B1_00,0x401f84cc,1
+//! LPI2C1_SDA_GPIO_AD_B1_01,0x401f84d0,1
+//! ```
+//!
+//! # Pin rows
+//!
+//! Most cells are copied into the macro invocation. These columns are special:
+//!
+//! - `pad` must name a pad, like `GPIO_AD_B1_00`.
+//! - `alt` must be an alternate value, 0 through 9.
+//! - `daisy` names the pad's select input, like `LPI2C1_SCL`, or `-` if the pad
+//!   does not have a select input. The generator emits `Some(DAISY_<input>_<pad>)`
+//!   or `None`.
+//! - `daisy!` is a `daisy` column for macros that require a daisy. The generator
+//!   emits `DAISY_<input>_<pad>` for the `daisy` argument.
+//!
+//! A column named like `reset?` is optional. A `-` cell omits the argument from
+//! the invocation.
+//!
+//! A row that starts with `for ` emits a `for` invocation, like
+//! `flexio!(for module: U3, ...)`. Use it to route a pad to another module when
+//! an earlier row already routes the pad to the macro's `Pin`.
+//!
+//! In the pins section, a line like `== I2C1` emits a banner comment, and lines
+//! that start with `//` are copied verbatim. Blank lines are preserved.
+
+use crate::PadRange;
+use std::{
+    collections::HashSet,
+    fmt,
+    io::{self, Write},
+};
+
+/// An error in a pin table data file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableError {
+    /// The one-based line number in the data file
+    pub line: usize,
+    /// Describes the error
+    pub message: String,
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for TableError {}
+
+fn error<T>(line: usize, message: impl Into<String>) -> Result<T, TableError> {
+    Err(TableError {
+        line,
+        message: message.into(),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Column {
+    /// Copied into the invocation
+    Plain(String),
+    /// Copied into the invocation, unless the cell is `-`
+    Optional(String),
+    Pad,
+    Alt,
+    /// `true` if the daisy is required
+    Daisy(bool),
+}
+
+impl Column {
+    fn name(&self) -> &str {
+        match self {
+            Column::Plain(name) | Column::Optional(name) => name,
+            Column::Pad => "pad",
+            Column::Alt => "alt",
+            Column::Daisy(_) => "daisy",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Body {
+    Row {
+        line: usize,
+        cells: Vec<String>,
+        /// `true` for a `for` row
+        other_module: bool,
+    },
+    Banner(String),
+    Comment(String),
+    Blank,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DaisyDef {
+    name: String,
+    reg: u32,
+    value: u32,
+}
+
+/// A pin table, parsed from a data file
+///
+/// Use [`parse()`](Table::parse) to parse the data file, and [`write_table()`]
+/// to generate the module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    name: String,
+    header: Vec<String>,
+    macro_name: String,
+    columns: Vec<Column>,
+    body: Vec<Body>,
+    daisies: Vec<DaisyDef>,
+    footer: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    None,
+    Header,
+    Pins,
+    Daisies,
+    Footer,
+}
+
+impl Table {
+    /// Parse the pin table data file `source`
+    ///
+    /// `name` identifies the data file in the generated module, like
+    /// `data/imxrt1060/xbar.csv`. Returns an error if the data file is
+    /// malformed, or if a pin refers to a daisy that the table does not define.
+    pub fn parse(name: &str, source: &str) -> Result<Self, TableError> {
+        let mut table = Table {
+            name: String::from(name),
+            header: Vec::new(),
+            macro_name: String::new(),
+            columns: Vec::new(),
+            body: Vec::new(),
+            daisies: Vec::new(),
+            footer: Vec::new(),
+        };
+        let mut section = Section::None;
+        for (idx, raw) in source.lines().enumerate() {
+            let line = idx + 1;
+            let trimmed = raw.trim();
+            if trimmed == "[header]" {
+                section = Section::Header;
+                continue;
+            } else if let Some(macro_name) = trimmed
+                .strip_prefix("[pins ")
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                if !table.macro_name.is_empty() {
+                    return error(line, "a table has only one pins section");
+                }
+                table.macro_name = String::from(macro_name.trim());
+                section = Section::Pins;
+                continue;
+            } else if trimmed == "[daisies]" {
+                section = Section::Daisies;
+                continue;
+            } else if trimmed == "[footer]" {
+                section = Section::Footer;
+                continue;
+            }
+
+            match section {
+                Section::Header => table.header.push(String::from(raw.trim_end())),
+                Section::Footer => table.footer.push(String::from(raw.trim_end())),
+                _ if trimmed.starts_with('#') => {}
+                Section::None if trimmed.is_empty() => {}
+                Section::None => return error(line, "expected a section"),
+                Section::Pins => table.parse_pins_line(line, trimmed)?,
+                Section::Daisies if trimmed.is_empty() => {}
+                Section::Daisies => table.parse_daisy(line, trimmed)?,
+            }
+        }
+
+        while table.header.last().is_some_and(String::is_empty) {
+            table.header.pop();
+        }
+        while table.footer.first().is_some_and(String::is_empty) {
+            table.footer.remove(0);
+        }
+        while table.footer.last().is_some_and(String::is_empty) {
+            table.footer.pop();
+        }
+        if table.macro_name.is_empty() {
+            return error(source.lines().count(), "missing the pins section");
+        }
+        table.check_daisies()?;
+        Ok(table)
+    }
+
+    fn parse_pins_line(&mut self, line: usize, trimmed: &str) -> Result<(), TableError> {
+        if self.columns.is_empty() {
+            if trimmed.is_empty() {
+                return Ok(());
+            }
+            return self.parse_columns(line, trimmed);
+        }
+        let body = if trimmed.is_empty() {
+            Body::Blank
+        } else if let Some(title) = trimmed.strip_prefix("==") {
+            Body::Banner(String::from(title.trim()))
+        } else if trimmed.starts_with("//") {
+            Body::Comment(String::from(trimmed))
+        } else {
+            let (other_module, row) = match trimmed.strip_prefix("for ") {
+                Some(row) => (true, row),
+                None => (false, trimmed),
+            };
+            let cells: Vec<String> = row
+                .split(',')
+                .map(|cell| String::from(cell.trim()))
+                .collect();
+            self.check_row(line, &cells)?;
+            Body::Row {
+                line,
+                cells,
+                other_module,
+            }
+        };
+        self.body.push(body);
+        Ok(())
+    }
+
+    fn parse_columns(&mut self, line: usize, trimmed: &str) -> Result<(), TableError> {
+        for name in trimmed.split(',').map(str::trim) {
+            let column = match name {
+                "pad" => Column::Pad,
+                "alt" => Column::Alt,
+                "daisy" => Column::Daisy(false),
+                "daisy!" => Column::Daisy(true),
+                name if is_ident(name) => Column::Plain(String::from(name)),
+                name if name.strip_suffix('?').is_some_and(is_ident) => {
+                    Column::Optional(String::from(&name[..name.len() - 1]))
+                }
+                name => return error(line, format!("invalid column '{}'", name)),
+            };
+            if self.columns.iter().any(|c| c.name() == column.name()) {
+                return error(line, format!("duplicate column '{}'", column.name()));
+            }
+            self.columns.push(column);
+        }
+        if !self.columns.contains(&Column::Pad) {
+            return error(line, "missing the 'pad' column");
+        }
+        Ok(())
+    }
+
+    fn check_row(&self, line: usize, cells: &[String]) -> Result<(), TableError> {
+        if cells.len() != self.columns.len() {
+            return error(
+                line,
+                format!(
+                    "expected {} cells, found {}",
+                    self.columns.len(),
+                    cells.len()
+                ),
+            );
+        }
+        for (column, cell) in self.columns.iter().zip(cells) {
+            match column {
+                Column::Pad if pad_group(cell).is_none() => {
+                    return error(line, format!("invalid pad '{}'", cell));
+                }
+                Column::Alt if !matches!(cell.parse::<u32>(), Ok(alt) if alt <= 9) => {
+                    return error(line, format!("invalid alt '{}'", cell));
+                }
+                Column::Daisy(true) if cell == "-" => {
+                    return error(line, "this macro requires a daisy");
+                }
+                Column::Daisy(_) if cell != "-" && !is_ident(cell) => {
+                    return error(line, format!("invalid select input '{}'", cell));
+                }
+                Column::Plain(_) | Column::Optional(_) if cell.is_empty() => {
+                    return error(line, "empty cell");
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_daisy(&mut self, line: usize, trimmed: &str) -> Result<(), TableError> {
+        let cells: Vec<&str> = trimmed.split(',').map(str::trim).collect();
+        let (name, reg, value) = match cells.as_slice() {
+            [name, reg, value] => (*name, *reg, *value),
+            _ => return error(line, "expected NAME,register,value"),
+        };
+        if !is_ident(name) {
+            return error(line, format!("invalid daisy name '{}'", name));
+        }
+        let reg = match reg
+            .strip_prefix("0x")
+            .map(|hex| u32::from_str_radix(hex, 16))
+        {
+            Some(Ok(reg)) if reg % 4 == 0 => reg,
+            _ => return error(line, format!("invalid daisy register '{}'", reg)),
+        };
+        let value = match value.parse::<u32>() {
+            Ok(value) if value <= 7 => value,
+            _ => return error(line, format!("invalid daisy value '{}'", value)),
+        };
+        for daisy in &self.daisies {
+            if daisy.name == name {
+                return error(line, format!("duplicate daisy '{}'", name));
+            }
+            if (daisy.reg, daisy.value) == (reg, value) {
+                return error(
+                    line,
+                    format!(
+                        "'{}' has the same register and value as '{}'",
+                        name, daisy.name
+                    ),
+                );
+            }
+        }
+        self.daisies.push(DaisyDef {
+            name: String::from(name),
+            reg,
+            value,
+        });
+        Ok(())
+    }
+
+    /// Every pin's daisy must be defined
+    fn check_daisies(&self) -> Result<(), TableError> {
+        for (line, cells) in self.rows() {
+            if let Some(name) = self.daisy_name(cells) {
+                if !self.daisies.iter().any(|daisy| daisy.name == name) {
+                    return error(line, format!("undefined daisy 'DAISY_{}'", name));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every pin in the table names a pad in `ranges`
+    ///
+    /// Returns the first pin whose pad is not in any range.
+    pub fn check_pads<'a, I>(&self, ranges: I) -> Result<(), TableError>
+    where
+        I: IntoIterator<Item = &'a PadRange> + Clone,
+    {
+        let pads: HashSet<String> = ranges
+            .into_iter()
+            .flat_map(|range| {
+                range
+                    .range
+                    .clone()
+                    .map(move |n| format!("{}_{:02}", range.base, n))
+            })
+            .collect();
+        for (line, cells) in self.rows() {
+            let pad = self.cell(cells, &Column::Pad);
+            if !pads.contains(pad) {
+                return error(line, format!("unknown pad '{}'", pad));
+            }
+        }
+        Ok(())
+    }
+
+    fn rows(&self) -> impl Iterator<Item = (usize, &[String])> {
+        self.body.iter().filter_map(|body| match body {
+            Body::Row { line, cells, .. } => Some((*line, cells.as_slice())),
+            _ => None,
+        })
+    }
+
+    fn cell<'a>(&self, cells: &'a [String], column: &Column) -> &'a str {
+        let idx = self.columns.iter().position(|c| c == column).unwrap();
+        &cells[idx]
+    }
+
+    /// The daisy name for a row, without the `DAISY_` prefix
+    fn daisy_name(&self, cells: &[String]) -> Option<String> {
+        let idx = self
+            .columns
+            .iter()
+            .position(|c| matches!(c, Column::Daisy(_)))?;
+        let input = &cells[idx];
+        if input == "-" {
+            None
+        } else {
+            Some(format!("{}_{}", input, self.cell(cells, &Column::Pad)))
+        }
+    }
+
+    /// The crate module that defines the table's traits; `lpi2c` for
+    /// `data/imxrt1060/lpi2c.csv`
+    pub(crate) fn module(&self) -> &str {
+        let file = self.name.rsplit('/').next().unwrap_or(&self.name);
+        file.strip_suffix(".csv").unwrap_or(file)
+    }
+
+    /// Each pin's `(function, prepared)` expressions, in table order
+    ///
+    /// A pin's functions are `<module>::function::<P>()` and `<module>::prepared::<P>()`.
+    /// The `adc` and `sai` traits take the peripheral module as their first parameter,
+    /// and so does a `for` row, which uses `function_for()` and `prepared_for()`.
+    pub(crate) fn candidates(&self) -> Vec<(String, String)> {
+        let module = self.module();
+        self.body
+            .iter()
+            .filter_map(|body| match body {
+                Body::Row {
+                    cells,
+                    other_module,
+                    ..
+                } => Some((cells, *other_module)),
+                _ => None,
+            })
+            .map(|(cells, other_module)| {
+                let pad = self.cell(cells, &Column::Pad);
+                let peripheral = self
+                    .columns
+                    .iter()
+                    .position(|c| *c == Column::Plain(String::from("module")))
+                    .map(|idx| cells[idx].as_str());
+                let (suffix, generics) = match peripheral {
+                    Some(peripheral) if other_module || module == "adc" || module == "sai" => {
+                        let typenum = peripheral.strip_prefix('U').is_some_and(|n| {
+                            !n.is_empty() && n.chars().all(|c| c.is_ascii_digit())
+                        });
+                        let peripheral = if typenum {
+                            String::from(peripheral)
+                        } else {
+                            format!("{}::{}", module, peripheral)
+                        };
+                        let suffix = if other_module { "_for" } else { "" };
+                        (suffix, format!("{}, {}", peripheral, pad))
+                    }
+                    _ => ("", String::from(pad)),
+                };
+                (
+                    format!("{}::function{}::<{}>()", module, suffix, generics),
+                    format!(
+                        "{}::prepared{}::<{}>(Config::zero())",
+                        module, suffix, generics
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn invocation(&self, cells: &[String], other_module: bool) -> String {
+        let args: Vec<String> = self
+            .columns
+            .iter()
+            .zip(cells)
+            .filter(|(column, cell)| !matches!(column, Column::Optional(_)) || *cell != "-")
+            .map(|(column, cell)| {
+                let value = match (column, self.daisy_name(cells)) {
+                    (Column::Daisy(true), Some(name)) => format!("DAISY_{}", name),
+                    (Column::Daisy(false), Some(name)) => format!("Some(DAISY_{})", name),
+                    (Column::Daisy(_), None) => String::from("None"),
+                    _ => cell.clone(),
+                };
+                format!("{}: {}", column.name(), value)
+            })
+            .collect();
+        let prefix = if other_module { "for " } else { "" };
+        format!("{}!({}{});", self.macro_name, prefix, args.join(", "))
+    }
+}
+
+fn is_ident(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns the pad group for a pad name; `GPIO_AD_B1` for `GPIO_AD_B1_00`
+fn pad_group(pad: &str) -> Option<&str> {
+    let (group, number) = pad.rsplit_once('_')?;
+    let valid = is_ident(group) && number.len() == 2 && number.chars().all(|c| c.is_ascii_digit());
+    if valid {
+        Some(group)
+    } else {
+        None
+    }
+}
+
+/// Write the chip-specific module for the pin `table`
+///
+/// The module contains the table's header, one macro invocation per pin, a
+/// `daisy` module with the daisy constants, and the table's footer. If the table
+/// has no daisies, the module omits the `daisy` module.
+pub fn write_table<W: Write>(out: &mut W, table: &Table) -> io::Result<()> {
+    writeln!(
+        out,
+        "// Generated by imxrt-iomuxc-build from {}. Do not edit.",
+        table.name
+    )?;
+    for line in &table.header {
+        writeln!(out, "{}", line)?;
+    }
+
+    // Blank lines are deferred, so that the body never starts or ends with one.
+    let mut blank = true;
+    let mut started = false;
+    for body in &table.body {
+        let lines = match body {
+            Body::Blank => {
+                blank = true;
+                continue;
+            }
+            Body::Banner(title) => {
+                blank = true;
+                vec![
+                    String::from("//"),
+                    format!("// {}", title),
+                    String::from("//"),
+                ]
+            }
+            Body::Comment(comment) => vec![comment.clone()],
+            Body::Row {
+                cells,
+                other_module,
+                ..
+            } => vec![table.invocation(cells, *other_module)],
+        };
+        if blank || !started {
+            writeln!(out)?;
+        }
+        blank = false;
+        started = true;
+        for line in lines {
+            writeln!(out, "{}", line)?;
+        }
+    }
+
+    if !table.daisies.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "/// Auto-generated Daisy constants")?;
+        writeln!(out, "mod daisy {{")?;
+        writeln!(out, "    #![allow(unused)]")?;
+        writeln!(out, "    use super::Daisy;")?;
+        writeln!(out)?;
+        for daisy in &table.daisies {
+            writeln!(
+                out,
+                "    pub const DAISY_{}: Daisy = Daisy::new({:#010x}, {});",
+                daisy.name, daisy.reg, daisy.value
+            )?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out, "use daisy::*;")?;
+    }
+
+    if !table.footer.is_empty() {
+        writeln!(out)?;
+        for line in &table.footer {
+            writeln!(out, "{}", line)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pad_group, write_table, Table};
+
+    const TABLE: &str = "\
+[header]
+//! Test pins
+[pins test]
+module,alt,pad,daisy
+U1,3,GPIO_AD_B1_00,TEST_IN
+[daisies]
+TEST_IN_GPIO_AD_B1_00,0x401f84cc,1
+";
+
+    #[test]
+    fn test_pad_group() {
+        assert_eq!(pad_group("GPIO_AD_B1_00"), Some("GPIO_AD_B1"));
+        assert_eq!(pad_group("GPIO_00"), Some("GPIO"));
+        assert_eq!(pad_group("GPIO_AD_B1_0"), None);
+        assert_eq!(pad_group("GPIO"), None);
+    }
+
+    #[test]
+    fn test_write_for_row() {
+        let source = TABLE.replace("[daisies]", "for U2,4,GPIO_AD_B1_00,-\n[daisies]");
+        let table = Table::parse("test.csv", &source).unwrap();
+        let mut out = Vec::new();
+        write_table(&mut out, &table).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(
+            "test!(module: U1, alt: 3, pad: GPIO_AD_B1_00, daisy: Some(DAISY_TEST_IN_GPIO_AD_B1_00));\n\
+             test!(for module: U2, alt: 4, pad: GPIO_AD_B1_00, daisy: None);\n"
+        ));
+    }
+
+    #[test]
+    fn test_write_optional_column() {
+        let source = TABLE
+            .replace("module,alt,pad,daisy", "module,alt,pad,daisy,reset?")
+            .replace("TEST_IN\n", "TEST_IN,5\nU2,4,GPIO_AD_B1_01,-,-\n");
+        let table = Table::parse("test.csv", &source).unwrap();
+        let mut out = Vec::new();
+        write_table(&mut out, &table).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(
+            "test!(module: U1, alt: 3, pad: GPIO_AD_B1_00, daisy: Some(DAISY_TEST_IN_GPIO_AD_B1_00), reset: 5);\n\
+             test!(module: U2, alt: 4, pad: GPIO_AD_B1_01, daisy: None);\n"
+        ));
+    }
+
+    #[test]
+    fn test_write_footer() {
+        let source = format!("{}[footer]\n\n#[cfg(test)]\nmod tests {{}}\n\n", TABLE);
+        let table = Table::parse("test.csv", &source).unwrap();
+        let mut out = Vec::new();
+        write_table(&mut out, &table).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.ends_with("use daisy::*;\n\n#[cfg(test)]\nmod tests {}\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            (TABLE.replace("U1,3,", "U1,10,"), 5),
+            (TABLE.replace("U1,3,", "U1,"), 5),
+            (TABLE.replace("GPIO_AD_B1_00,TEST", "GPIO_AD_B1_0,TEST"), 5),
+            (TABLE.replace("0x401f84cc", "0x401f84cd"), 7),
+            (TABLE.replace("TEST_IN_GPIO", "OTHER_IN_GPIO"), 5),
+            (TABLE.replace("module,alt,pad", "module,alt,pin"), 4),
+            (format!("{}OTHER,0x401f84cc,1\n", TABLE), 8),
+            (TABLE.replace("[pins test]", ""), 7),
+        ];
+        for (source, line) in cases.iter() {
+            let err = Table::parse("test.csv", source).unwrap_err();
+            assert_eq!(err.line, *line, "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_check_pads() {
+        use crate::PadRange;
+
+        let table = Table::parse("test.csv", TABLE).unwrap();
+        table
+            .check_pads(&[PadRange::new("GPIO_AD_B1", 0..16)])
+            .unwrap();
+        let err = table
+            .check_pads(&[PadRange::new("GPIO_AD_B0", 0..16)])
+            .unwrap_err();
+        assert_eq!(err.line, 5);
+    }
+}
@@ -0,0 +1,186 @@
+//! Checks that the committed pin tables match their data files
+//!
+//! Set `IMXRT_IOMUXC_REGENERATE=1` to rewrite the committed modules from the
+//! data files, then review the changes.
+
+use imxrt_iomuxc_build::{write_candidates, write_pad_table, write_table, PadTable, Table};
+use std::{env, fs, path::Path};
+
+fn root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// Parse `data/<chip>/pads.csv`
+fn pads(chip: &str) -> PadTable {
+    let name = format!("data/{}/pads.csv", chip);
+    let source = fs::read_to_string(root().join(&name)).unwrap();
+    PadTable::parse(&source).unwrap_or_else(|err| panic!("{}: {}", name, err))
+}
+
+/// Parse `data/<chip>/<table>.csv`, and check its pads
+fn table(chip: &str, table: &str) -> Table {
+    let name = format!("data/{}/{}.csv", chip, table);
+    let source = fs::read_to_string(root().join(&name)).unwrap();
+    let table = Table::parse(&name, &source).unwrap_or_else(|err| panic!("{}: {}", name, err));
+    table
+        .check_pads(pads(chip).ranges())
+        .unwrap_or_else(|err| panic!("{}: {}", name, err));
+    table
+}
+
+/// Compare `generated` with the committed module `src/<chip>/<module>.rs`
+fn compare(chip: &str, module: &str, generated: Vec<u8>, name: &str) {
+    let generated = String::from_utf8(generated).unwrap();
+    let module = root().join(format!("src/{}/{}.rs", chip, module));
+    if env::var_os("IMXRT_IOMUXC_REGENERATE").is_some() {
+        fs::write(&module, &generated).unwrap();
+    } else {
+        let committed = fs::read_to_string(&module).unwrap();
+        assert!(
+            committed == generated,
+            "{} does not match {}; regenerate with IMXRT_IOMUXC_REGENERATE=1",
+            module.display(),
+            name
+        );
+    }
+}
+
+/// Generate the module for `data/<chip>/<table>.csv`, and compare it with
+/// `src/<chip>/<table>.rs`
+fn regenerate(chip: &str, name: &str) {
+    let mut generated = Vec::new();
+    write_table(&mut generated, &table(chip, name)).unwrap();
+    compare(
+        chip,
+        name,
+        generated,
+        &format!("data/{}/{}.csv", chip, name),
+    );
+}
+
+/// Generate the candidates module from every pin table in `data/<chip>`, and
+/// compare it with `src/<chip>/candidates.rs`
+fn candidates(chip: &str) {
+    let mut names: Vec<String> = fs::read_dir(root().join("data").join(chip))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter_map(|file| file.strip_suffix(".csv").map(String::from))
+        .filter(|name| name != "pads")
+        .collect();
+    names.sort();
+    let tables: Vec<Table> = names.iter().map(|name| table(chip, name)).collect();
+
+    let source = format!("data/{}/*.csv", chip);
+    let mut generated = Vec::new();
+    write_candidates(&mut generated, &source, &pads(chip), &tables).unwrap();
+    compare(chip, "candidates", generated, &source);
+}
+
+#[test]
+fn pad_tables() {
+    for chip in ["imxrt1010", "imxrt1060"].iter() {
+        let mut generated = Vec::new();
+        write_pad_table(&mut generated, &pads(chip)).unwrap();
+        assert!(!generated.is_empty());
+    }
+}
+
+#[test]
+fn imxrt1010_candidates() {
+    candidates("imxrt1010");
+}
+
+#[test]
+fn imxrt1010_flexio() {
+    regenerate("imxrt1010", "flexio");
+}
+
+#[test]
+fn imxrt1010_flexspi() {
+    regenerate("imxrt1010", "flexspi");
+}
+
+#[test]
+fn imxrt1010_lpi2c() {
+    regenerate("imxrt1010", "lpi2c");
+}
+
+#[test]
+fn imxrt1010_lpspi() {
+    regenerate("imxrt1010", "lpspi");
+}
+
+#[test]
+fn imxrt1010_lpuart() {
+    regenerate("imxrt1010", "lpuart");
+}
+
+#[test]
+fn imxrt1060_adc() {
+    regenerate("imxrt1060", "adc");
+}
+
+#[test]
+fn imxrt1060_candidates() {
+    candidates("imxrt1060");
+}
+
+#[test]
+fn imxrt1060_csi() {
+    regenerate("imxrt1060", "csi");
+}
+
+#[test]
+fn imxrt1060_flexio() {
+    regenerate("imxrt1060", "flexio");
+}
+
+#[test]
+fn imxrt1060_flexpwm() {
+    regenerate("imxrt1060", "flexpwm");
+}
+
+#[test]
+fn imxrt1060_lcdif() {
+    regenerate("imxrt1060", "lcdif");
+}
+
+#[test]
+fn imxrt1060_lpi2c() {
+    regenerate("imxrt1060", "lpi2c");
+}
+
+#[test]
+fn imxrt1060_lpspi() {
+    regenerate("imxrt1060", "lpspi");
+}
+
+#[test]
+fn imxrt1060_lpuart() {
+    regenerate("imxrt1060", "lpuart");
+}
+
+#[test]
+fn imxrt1060_mqs() {
+    regenerate("imxrt1060", "mqs");
+}
+
+#[test]
+fn imxrt1060_qtimer() {
+    regenerate("imxrt1060", "qtimer");
+}
+
+#[test]
+fn imxrt1060_sai() {
+    regenerate("imxrt1060", "sai");
+}
+
+#[test]
+fn imxrt1060_src() {
+    regenerate("imxrt1060", "src");
+}
+
+#[test]
+fn imxrt1060_xbar() {
+    regenerate("imxrt1060", "xbar");
+}
@@ -1,4 +1,4 @@
-use imxrt_iomuxc_build::{write_pads, PadRange};
+use imxrt_iomuxc_build::{write_pads, write_pads_with_dedicated, PadRange};
 
 #[test]
 fn test_write_pads() {
@@ -18,6 +18,13 @@ fn test_write_pads() {
                 pub type FOO_02 = Pad<FOO, U2>;
                 pub type FOO_03 = Pad<FOO, U3>;
 
+                /// Describes this pad group
+                pub const INFO: crate::GroupInfo = crate::GroupInfo {
+                    name: "FOO",
+                    pad_count: 2usize,
+                    base_id: FOO_02::ID,
+                };
+
                 #[doc = "Pads with the prefix 'FOO'"]
                 pub struct Pads {
                     pub p02: FOO_02,
@@ -60,6 +67,15 @@ fn test_write_pads() {
                         ]
                     }
                 }
+
+                impl crate::PadGroup for Pads {
+                    const PAD_COUNT: usize = 2usize;
+                    const OFFSETS: &'static [u8] = &[2u8, 3u8];
+                    type ErasedIter = ::core::array::IntoIter<ErasedPad, 2usize>;
+                    fn erased_iter(self) -> Self::ErasedIter {
+                        ::core::iter::IntoIterator::into_iter(self.erase())
+                    }
+                }
             }
 
             #[doc = "Pads with the prefix 'BAR'"]
@@ -70,6 +86,13 @@ fn test_write_pads() {
                 pub type BAR_37 = Pad<BAR, U37>;
                 pub type BAR_38 = Pad<BAR, U38>;
 
+                /// Describes this pad group
+                pub const INFO: crate::GroupInfo = crate::GroupInfo {
+                    name: "BAR",
+                    pad_count: 2usize,
+                    base_id: BAR_37::ID,
+                };
+
                 #[doc = "Pads with the prefix 'BAR'"]
                 pub struct Pads {
                     pub p37: BAR_37,
@@ -112,6 +135,24 @@ fn test_write_pads() {
                         ]
                     }
                 }
+
+                impl crate::PadGroup for Pads {
+                    const PAD_COUNT: usize = 2usize;
+                    const OFFSETS: &'static [u8] = &[37u8, 38u8];
+                    type ErasedIter = ::core::array::IntoIter<ErasedPad, 2usize>;
+                    fn erased_iter(self) -> Self::ErasedIter {
+                        ::core::iter::IntoIterator::into_iter(self.erase())
+                    }
+                }
+            }
+
+            /// Describes all of the pad groups
+            pub mod groups {
+                /// All of the pad groups, in the order of the `Pads` members
+                pub const GROUPS: [crate::GroupInfo; 2usize] = [
+                    super::foo::INFO,
+                    super::bar::INFO
+                ];
             }
 
             /// All of the pads
@@ -164,6 +205,22 @@ fn test_write_pads() {
                     }
                 }
             }
+
+            impl crate::PadLookup for ErasedPads {
+                fn find_mut(&mut self, id: crate::PadId) -> Option<&mut crate::ErasedPad> {
+                    self.foo.iter_mut()
+                        .chain(self.bar.iter_mut())
+                        .find(|pad| pad.id() == id)
+                }
+
+                fn supports_alternate(&self, id: crate::PadId, alt: u8) -> bool {
+                    super::supports_alternate(id, alt)
+                }
+
+                fn function_at(&self, id: crate::PadId, alt: u8) -> Option<crate::Function> {
+                    super::function_at(id, alt)
+                }
+            }
         }
     };
     let expected = expected_tokens.to_string();
@@ -175,3 +232,63 @@ fn test_write_pads() {
     .unwrap();
     assert_eq!(std::str::from_utf8(&actual).unwrap(), expected);
 }
+
+#[test]
+fn test_write_pads_with_dedicated() {
+    let dedicated = quote::quote! {
+        /// Dedicated balls that are not controlled by the IOMUXC
+        ///
+        /// The types represent ownership of the balls. They do not implement
+        /// `Iomuxc`, so you cannot configure them.
+        pub mod dedicated {
+            #[doc = "The dedicated `XTALI` ball"]
+            pub struct XTALI {
+                _private: (),
+            }
+
+            impl XTALI {
+                /// Take the ball
+                ///
+                /// # Safety
+                ///
+                /// Creates a handle to a resource that may be owned elsewhere.
+                pub const unsafe fn new() -> Self {
+                    XTALI { _private: () }
+                }
+            }
+
+            /// All of the dedicated balls
+            pub struct Pads {
+                pub xtali: XTALI
+            }
+
+            impl Pads {
+                /// Take all of the dedicated balls
+                ///
+                /// # Safety
+                ///
+                /// You may safely call this once to acquire all of the balls. Subsequent calls
+                /// may return balls that are owned elsewhere.
+                pub const unsafe fn new() -> Pads {
+                    Pads {
+                        xtali: <XTALI>::new()
+                    }
+                }
+            }
+        }
+    };
+    let mut actual = Vec::new();
+    write_pads_with_dedicated(&mut actual, vec![&PadRange::new("FOO", 2..4)], &["XTALI"]).unwrap();
+    let actual = String::from_utf8(actual).unwrap();
+
+    assert!(actual.contains(&dedicated.to_string()));
+    for member in &[
+        quote::quote! { pub dedicated: dedicated::Pads },
+        quote::quote! { dedicated: <dedicated::Pads>::new() },
+        quote::quote! { dedicated: self.dedicated },
+    ] {
+        assert!(actual.contains(&member.to_string()), "{}", member);
+    }
+    // Dedicated balls aren't erased pads.
+    assert!(actual.contains(&quote::quote! { self.foo.iter_mut().find }.to_string()));
+}
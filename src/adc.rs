@@ -1,11 +1,49 @@
 //! ADC pad configuration
+//!
+//! # PWM-synchronized conversions
+//!
+//! For motor control, a FlexPWM submodule usually triggers the ADC. The trigger
+//! travels from the PWM, through the crossbar (XBAR), to the ADC external trigger
+//! control (ADC_ETC). None of those internal connections use pads. However, you can
+//! also route the trigger to a bidirectional XBAR pad, then observe the trigger on a
+//! scope.
+//!
+//! The example below prepares all of the pads for the i.MX RT 1060. Your HAL then
+//!
+//! - routes `PWM1_OUT_TRIG0` to `ADC_ETC_TRIG00` through the crossbar,
+//! - routes the same trigger to the XBAR output that drives the scope pad,
+//! - selects the output direction for that XBAR line in `IOMUXC_GPR_GPR6`,
+//! - and configures the ADC_ETC chain for the ADC input.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::imxrt1060::{gpio_ad_b1::*, gpio_b1::*, gpio_emc::*};
+//! use imxrt_iomuxc::{adc, flexpwm, xbar, DriveStrength};
+//!
+//! let mut pwm = unsafe { GPIO_EMC_23::new() }; // PWM1_SM0 A
+//! let mut scope = unsafe { GPIO_B1_00::new() }; // XBAR1_INOUT14
+//! let mut sense = unsafe { GPIO_AD_B1_11::new() }; // ADC1 input 0
+//!
+//! flexpwm::prepare(&mut pwm);
+//! let trigger_output = xbar::prepare_output(&mut scope, DriveStrength::R0_6);
+//! adc::prepare::<adc::Adc1, _>(&mut sense);
+//!
+//! // Route the PWM trigger to this crossbar output
+//! assert_eq!(trigger_output.index(), 14);
+//! // Sample this ADC input in the ADC_ETC chain
+//! assert_eq!(<GPIO_AD_B1_11 as adc::Pin<adc::Adc1>>::INPUT, 0);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
 
 use crate::consts::Unsigned;
 
-/// Type number for ADC1
-pub type Adc1 = crate::consts::U1;
-/// Type number for ADC2
-pub type Adc2 = crate::consts::U2;
+module_aliases!("ADC":
+    1 => Adc1 = U1,
+    2 => Adc2 = U2,
+);
+
+pub use module::{Adc1, Adc2};
 
 /// Describes an ADC input pin
 ///
@@ -17,6 +55,10 @@ pub trait Pin<U: Unsigned>: super::gpio::Pin {
     ///
     /// Starts at `0`, and increments up.
     const INPUT: u32;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+    const SION: super::SionRequirement = super::SionRequirement::DontCare;
 }
 
 /// Prepare an ADC pin
@@ -24,12 +66,59 @@ pub trait Pin<U: Unsigned>: super::gpio::Pin {
 /// Due to a requirement in the ADC module, `prepare` will disable the pull/keeper
 /// on the pin. The configuration change will not affect any other settings.
 pub fn prepare<U: Unsigned, P: Pin<U>>(pin: &mut P) {
+    trace!("adc::prepare mux={:#010x}", pin.mux() as usize);
     // See the note in the ADC section of the reference manual
     // (using iMXRT1060, rev 2). ADC input signals connect to
     // GPIO, and we need to disable the keeper to prevent signal
     // jumps.
-    super::alternate(pin, <P as super::gpio::Pin>::ALT);
-    super::configure(pin, super::Config::modify().set_pull_keeper(None));
+    super::prepare_pin_configured(
+        pin,
+        super::Config::modify().set_pull_keeper(None),
+        <P as super::gpio::Pin>::ALT,
+        <P as Pin<U>>::SION,
+        None,
+    );
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<U: Unsigned, P: Pin<U>>(pin: &mut P) -> bool {
+    trace!("adc::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_configured_idempotent(
+        pin,
+        super::Config::modify().set_pull_keeper(None),
+        <P as super::gpio::Pin>::ALT,
+        <P as Pin<U>>::SION,
+        None,
+    )
+}
+
+/// Compute the register writes that prepare an ADC pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+///
+/// Like `prepare()`, the pull / keeper is disabled, regardless of `config`.
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<U: Unsigned, P: Pin<U> + super::ConstIomuxc>(
+    config: super::Config,
+) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(
+        <P as super::gpio::Pin>::ALT,
+        <P as Pin<U>>::SION,
+        config.set_pull_keeper(None),
+        None,
+    )
+}
+
+/// Returns the [`Function`](crate::Function) of an ADC pin
+pub const fn function<U: Unsigned, P: Pin<U>>() -> super::Function {
+    super::Function::Adc {
+        module: U::U8,
+        input: P::INPUT as u8,
+    }
 }
 
 #[allow(unused)] // Used in chip-specific modules...
@@ -40,3 +129,47 @@ macro_rules! adc {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{prepare, Adc1, Pin};
+    use crate::{consts::*, Iomuxc, PullKeeper};
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    impl crate::gpio::Pin for FakePad {
+        const ALT: u32 = 5;
+        type Module = U1;
+        type Offset = U12;
+    }
+
+    impl Pin<Adc1> for FakePad {
+        const INPUT: u32 = 1;
+    }
+
+    #[test]
+    fn disables_keeper_before_mux() {
+        let mut pad = FakePad {
+            mux: 2,
+            pad: PullKeeper::Keeper as u32 | 1,
+        };
+        prepare::<Adc1, _>(&mut pad);
+        assert_eq!(pad.mux, 5);
+        // prepare_pin_configured() orders the writes; see prepare_writes_mux_last
+        assert_eq!(pad.pad, 1);
+    }
+}
@@ -1,7 +1,7 @@
 //! Pad configuration
 
 use crate::Iomuxc;
-use core::ptr;
+use core::{convert::TryFrom, ptr};
 
 /// Applies the configuration `config` for the supplied pad
 ///
@@ -13,7 +13,8 @@ use core::ptr;
 ///
 /// ```no_run
 /// use imxrt_iomuxc::{configure, Config, OpenDrain, PullKeeper};
-/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_03;
+/// # struct GPIO_AD_B0; unsafe impl imxrt_iomuxc::Base for GPIO_AD_B0 { fn mux_base() -> *mut u32 { 0 as *mut u32 } fn pad_base() -> *mut u32 { 0 as *mut u32 } }
+/// # type GPIO_AD_B0_03 = imxrt_iomuxc::Pad<GPIO_AD_B0, imxrt_iomuxc::consts::U3>;
 ///
 /// const CONFIG: Config = Config::zero()
 ///     .set_open_drain(OpenDrain::Enabled)
@@ -23,14 +24,110 @@ use core::ptr;
 ///
 /// configure(&mut pad, CONFIG);
 /// ```
+///
 #[inline(always)]
 pub fn configure<I: Iomuxc>(pad: &mut I, config: Config) {
+    debug_assert_eq!(config.validate(), Ok(()), "Invalid pad configuration");
+    debug_assert!(
+        !config.pulls_with_keeper(),
+        "Pad configuration selects a pull resistance with the keeper"
+    );
+    // Safety: same justification as set_sion.
+    unsafe { configure_raw(pad.pad(), config.mask, config.value) }
+}
+
+/// Change one field of a pad's configuration
+///
+/// `modify_field()` reads the pad's configuration register, replaces `field`, and
+/// writes the register back. It leaves every other field untouched. It's the same
+/// as [`configure()`] with a [`Config::modify()`] configuration that sets `field`.
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::gpio_sd_b0::GPIO_SD_B0_01, modify_field, DriveStrength, Field};
+///
+/// let mut clk = unsafe { GPIO_SD_B0_01::new() };
+/// modify_field(&mut clk, Field::DriveStrength(DriveStrength::R0_4));
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+#[inline(always)]
+pub fn modify_field<I: Iomuxc>(pad: &mut I, field: Field) {
+    configure(pad, field.apply(Config::modify()));
+}
+
+/// Change several fields of a pad's configuration, with one register write
+///
+/// Like [`modify_field()`], `modify_fields()` leaves the fields that aren't in
+/// `fields` untouched. If `fields` has the same field more than once, the last one
+/// wins.
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::gpio_sd_b0::GPIO_SD_B0_00, modify_fields};
+/// use imxrt_iomuxc::{DriveStrength, Field, SlewRate};
+///
+/// let mut cmd = unsafe { GPIO_SD_B0_00::new() };
+/// modify_fields(
+///     &mut cmd,
+///     &[
+///         Field::DriveStrength(DriveStrength::R0_6),
+///         Field::SlewRate(SlewRate::Fast),
+///     ],
+/// );
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+#[inline(always)]
+pub fn modify_fields<I: Iomuxc>(pad: &mut I, fields: &[Field]) {
+    let config = fields
+        .iter()
+        .fold(Config::modify(), |config, field| field.apply(config));
+    configure(pad, config);
+}
+
+/// Like [`configure()`], but skip the write if the register already holds `config`
+///
+/// Returns `true` if it wrote the register.
+#[inline(always)]
+pub(crate) fn configure_idempotent<I: Iomuxc>(pad: &mut I, config: Config) -> bool {
+    debug_assert_eq!(config.validate(), Ok(()), "Invalid pad configuration");
+    debug_assert!(
+        !config.pulls_with_keeper(),
+        "Pad configuration selects a pull resistance with the keeper"
+    );
     // Safety: same justification as set_sion.
-    unsafe {
-        let cfg = ptr::read_volatile(pad.pad());
-        let cfg = (cfg & !config.mask) | config.value;
-        ptr::write_volatile(pad.pad(), cfg);
+    unsafe { configure_idempotent_raw(pad.pad(), config.mask, config.value) }
+}
+
+/// The shared body of [`configure_idempotent()`]
+#[inline(never)]
+unsafe fn configure_idempotent_raw(pad_reg: *mut u32, mask: u32, value: u32) -> bool {
+    let current = ptr::read_volatile(pad_reg);
+    let cfg = (current & !mask) | value;
+    if cfg == current {
+        return false;
     }
+    trace!(
+        "configure pad={:#010x} value={:#010x}",
+        pad_reg as usize,
+        cfg
+    );
+    ptr::write_volatile(pad_reg, cfg);
+    true
+}
+
+/// The shared body of [`configure()`]
+#[inline(never)]
+pub(crate) unsafe fn configure_raw(pad_reg: *mut u32, mask: u32, value: u32) {
+    let cfg = ptr::read_volatile(pad_reg);
+    let cfg = (cfg & !mask) | value;
+    trace!(
+        "configure pad={:#010x} value={:#010x}",
+        pad_reg as usize,
+        cfg
+    );
+    ptr::write_volatile(pad_reg, cfg);
 }
 
 const HYSTERESIS_SHIFT: u32 = 16;
@@ -108,6 +205,25 @@ const fn pull_keeper(select: PullKeepSelect, pull: Option<PullUpDown>) -> u32 {
 const PULL_KEEPER_MASK: u32 = PULLKEEP_MASK | PULLUPDOWN_MASK | PULL_KEEP_SELECT_MASK;
 
 /// The pull up, pull down, or keeper configuration.
+///
+/// `PullKeeper` replaces the deprecated `PullKeep`, `PullKeepSelect`, and `PullUpDown`
+/// fields. Use [`from_deprecated()`](PullKeeper::from_deprecated) to migrate a
+/// combination of the deprecated fields.
+///
+/// Each configuration writes the pull / keeper enable (PKE), pull / keeper
+/// select (PUE), and pull up / down (PUS) fields:
+///
+/// | Configuration                  | PKE | PUE | PUS  |
+/// | ------------------------------ | --- | --- | ---- |
+/// | `None`                         | 0   | 0   | 0b00 |
+/// | [`Keeper`](PullKeeper::Keeper) | 1   | 0   | 0b00 |
+/// | [`Pulldown100k`](PullKeeper::Pulldown100k) | 1 | 1 | 0b00 |
+/// | [`Pullup47k`](PullKeeper::Pullup47k)       | 1 | 1 | 0b01 |
+/// | [`Pullup100k`](PullKeeper::Pullup100k)     | 1 | 1 | 0b10 |
+/// | [`Pullup22k`](PullKeeper::Pullup22k)       | 1 | 1 | 0b11 |
+///
+/// When PKE is 0, the hardware ignores PUE and PUS. When PUE is 0, the hardware
+/// ignores PUS. See [`Config::pull_keeper()`] to decode the fields.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(u32)]
 #[allow(deprecated)]
@@ -130,6 +246,69 @@ pub enum PullKeeper {
     Keeper = pull_keeper(PullKeepSelect::Keeper, None),
 }
 
+#[allow(deprecated)]
+impl PullKeeper {
+    /// Convert a combination of the deprecated pull / keeper fields
+    ///
+    /// Returns `None` if `pull_keep` disables the pull / keeper function. When
+    /// `select` chooses the keeper, the hardware ignores `pull`, so any `pull`
+    /// value maps to [`PullKeeper::Keeper`].
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use imxrt_iomuxc::{Config, PullKeep, PullKeeper, PullKeepSelect, PullUpDown};
+    ///
+    /// // Before
+    /// const OLD: Config = Config::zero()
+    ///     .set_pull_keep(PullKeep::Enabled)
+    ///     .set_pull_keep_select(PullKeepSelect::Pull)
+    ///     .set_pullupdown(PullUpDown::Pullup22k);
+    ///
+    /// // After
+    /// const NEW: Config = Config::zero().set_pull_keeper(PullKeeper::from_deprecated(
+    ///     PullKeep::Enabled,
+    ///     PullKeepSelect::Pull,
+    ///     PullUpDown::Pullup22k,
+    /// ));
+    /// # assert_eq!(NEW.set_pull_keeper(Some(PullKeeper::Pullup22k)), NEW);
+    /// ```
+    pub const fn from_deprecated(
+        pull_keep: PullKeep,
+        select: PullKeepSelect,
+        pull: PullUpDown,
+    ) -> Option<Self> {
+        match (pull_keep, select, pull) {
+            (PullKeep::Disabled, _, _) => None,
+            (PullKeep::Enabled, PullKeepSelect::Keeper, _) => Some(PullKeeper::Keeper),
+            (PullKeep::Enabled, PullKeepSelect::Pull, PullUpDown::Pulldown100k) => {
+                Some(PullKeeper::Pulldown100k)
+            }
+            (PullKeep::Enabled, PullKeepSelect::Pull, PullUpDown::Pullup47k) => {
+                Some(PullKeeper::Pullup47k)
+            }
+            (PullKeep::Enabled, PullKeepSelect::Pull, PullUpDown::Pullup100k) => {
+                Some(PullKeeper::Pullup100k)
+            }
+            (PullKeep::Enabled, PullKeepSelect::Pull, PullUpDown::Pullup22k) => {
+                Some(PullKeeper::Pullup22k)
+            }
+        }
+    }
+}
+
+/// Convert an enabled pull / keeper selection
+///
+/// Equivalent to [`PullKeeper::from_deprecated()`] with `PullKeep::Enabled`.
+#[allow(deprecated)]
+impl From<(PullKeepSelect, PullUpDown)> for PullKeeper {
+    fn from((select, pull): (PullKeepSelect, PullUpDown)) -> Self {
+        match PullKeeper::from_deprecated(PullKeep::Enabled, select, pull) {
+            Some(pk) => pk,
+            None => unreachable!(),
+        }
+    }
+}
+
 const OPENDRAIN_SHIFT: u32 = 11;
 const OPENDRAIN_MASK: u32 = 1 << OPENDRAIN_SHIFT;
 
@@ -212,6 +391,142 @@ pub enum SlewRate {
     Slow = 0 << SLEW_RATE_SHIFT,
 }
 
+/// One field of a pad's configuration
+///
+/// Use a `Field` with [`modify_field()`] or [`modify_fields()`] to change a pad's
+/// configuration without touching its other fields. The pull / keeper field covers
+/// the PKE, PUE, and PUS bits, like [`Config::set_pull_keeper()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    /// The hysteresis (HYS) bit
+    Hysteresis(Hysteresis),
+    /// The pull / keeper (PKE, PUE, PUS) bits; `None` disables the pull / keeper
+    PullKeeper(Option<PullKeeper>),
+    /// The open drain (ODE) bit
+    OpenDrain(OpenDrain),
+    /// The speed (SPEED) bits
+    Speed(Speed),
+    /// The drive strength (DSE) bits
+    DriveStrength(DriveStrength),
+    /// The slew rate (SRE) bit
+    SlewRate(SlewRate),
+}
+
+impl Field {
+    /// Set this field in `config`
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{Config, Field, Speed};
+    ///
+    /// let config = Field::Speed(Speed::Fast).apply(Config::modify());
+    /// assert_eq!(config, Config::modify().set_speed(Speed::Fast));
+    /// ```
+    pub const fn apply(self, config: Config) -> Config {
+        match self {
+            Field::Hysteresis(hys) => config.set_hysteresis(hys),
+            Field::PullKeeper(pk) => config.set_pull_keeper(pk),
+            Field::OpenDrain(od) => config.set_open_drain(od),
+            Field::Speed(speed) => config.set_speed(speed),
+            Field::DriveStrength(dse) => config.set_drive_strength(dse),
+            Field::SlewRate(sre) => config.set_slew_rate(sre),
+        }
+    }
+}
+
+/// An error that indicates a field encoding does not map to a configuration
+///
+/// Returned when decoding a configuration enum from its field encoding. The
+/// error wraps the field encoding that failed to convert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InvalidFieldError(pub u32);
+
+/// An error that indicates a [`Config`] combines fields that don't make sense
+///
+/// Returned from [`Config::validate()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConfigError {
+    /// Open drain is enabled, along with the pull-down resistor
+    ///
+    /// An open drain output needs a pull-up to drive the line high.
+    OpenDrainPulldown,
+    /// Open drain is enabled, along with the keeper
+    ///
+    /// The keeper holds the line at its last level, and fights the open drain
+    /// output's pull-up.
+    OpenDrainKeeper,
+    /// The speed is [`Speed::Max`], but the slew rate is [`SlewRate::Slow`]
+    ///
+    /// The slow slew rate cannot support the 200MHz speed setting.
+    MaxSpeedSlowSlew,
+}
+
+/// Implements the field encoding and decoding for a configuration enum
+///
+/// The field encoding is the value of the enum's register field, shifted down
+/// to bit zero. Encodings that don't map to a variant are invalid.
+macro_rules! field {
+    ($ty:ident, $shift:expr, [$($variant:ident),+]) => {
+        #[allow(deprecated)]
+        impl $ty {
+            /// Decode the configuration from its field encoding
+            ///
+            /// Returns an error if the encoding does not map to a configuration.
+            pub const fn from_field(field: u32) -> Result<Self, InvalidFieldError> {
+                $(
+                    if field == ($ty::$variant as u32) >> $shift {
+                        return Ok($ty::$variant);
+                    }
+                )+
+                Err(InvalidFieldError(field))
+            }
+
+            /// Returns the field encoding of this configuration
+            pub const fn field(self) -> u32 {
+                (self as u32) >> $shift
+            }
+        }
+
+        #[allow(deprecated)]
+        impl TryFrom<u32> for $ty {
+            type Error = InvalidFieldError;
+            fn try_from(field: u32) -> Result<Self, Self::Error> {
+                Self::from_field(field)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl From<$ty> for u32 {
+            fn from(config: $ty) -> u32 {
+                config.field()
+            }
+        }
+    };
+}
+
+field!(Hysteresis, HYSTERESIS_SHIFT, [Enabled, Disabled]);
+field!(
+    PullUpDown,
+    PULLUPDOWN_SHIFT,
+    [Pulldown100k, Pullup47k, Pullup100k, Pullup22k]
+);
+field!(PullKeepSelect, PULL_KEEP_SELECT_SHIFT, [Keeper, Pull]);
+field!(PullKeep, PULLKEEP_SHIFT, [Enabled, Disabled]);
+field!(
+    PullKeeper,
+    PULLKEEP_SHIFT,
+    [Pulldown100k, Pullup22k, Pullup47k, Pullup100k, Keeper]
+);
+field!(OpenDrain, OPENDRAIN_SHIFT, [Enabled, Disabled]);
+field!(Speed, SPEED_SHIFT, [Low, Medium, Fast, Max]);
+field!(
+    DriveStrength,
+    DRIVE_STRENGTH_SHIFT,
+    [Disabled, R0, R0_2, R0_3, R0_4, R0_5, R0_6, R0_7]
+);
+field!(SlewRate, SLEW_RATE_SHIFT, [Fast, Slow]);
+
 /// A configuration capable of compile-time, `const` configuration:
 ///
 /// ```
@@ -235,6 +550,12 @@ impl Config {
     /// can use a higher bit to represent if the config is generated
     /// from `zero()`, or if it was generated from `modify()`.
     const ZERO_BIT: u32 = 1 << 31;
+    /// Set in the mask when the pull / keeper fields were set with
+    /// [`set_pull_keeper()`](Config::set_pull_keeper).
+    const PULL_KEEPER_BIT: u32 = 1 << 30;
+    /// Set in the mask when the pull / keeper fields were set with one of the
+    /// deprecated setters.
+    const DEPRECATED_PULL_BIT: u32 = 1 << 29;
 
     /// Create a `Config` that will zero any unspecified field
     ///
@@ -243,7 +564,8 @@ impl Config {
     /// to the register. Those that are not set explicitly set are written as zero.
     ///
     /// ```no_run
-    /// # use imxrt_iomuxc::{Iomuxc, imxrt1060::gpio_ad_b0::GPIO_AD_B0_13};
+    /// # struct GPIO_AD_B0; unsafe impl imxrt_iomuxc::Base for GPIO_AD_B0 { fn mux_base() -> *mut u32 { 0 as *mut u32 } fn pad_base() -> *mut u32 { 0 as *mut u32 } }
+    /// # type GPIO_AD_B0_13 = imxrt_iomuxc::Pad<GPIO_AD_B0, imxrt_iomuxc::consts::U13>;
     /// # let mut gpio_ad_b0_13 = unsafe { GPIO_AD_B0_13::new() };
     /// use imxrt_iomuxc::{
     ///     Config, configure, SlewRate,
@@ -273,7 +595,7 @@ impl Config {
     pub const fn zero() -> Self {
         Config {
             value: 0u32,
-            mask: 0xFFFF_FFFFu32,
+            mask: !(Self::PULL_KEEPER_BIT | Self::DEPRECATED_PULL_BIT),
         }
     }
 
@@ -282,7 +604,8 @@ impl Config {
     /// Any field that is is *not* specified in the configuration will not be touched.
     ///
     /// ```no_run
-    /// # use imxrt_iomuxc::{Iomuxc, imxrt1060::gpio_ad_b0::GPIO_AD_B0_13};
+    /// # struct GPIO_AD_B0; unsafe impl imxrt_iomuxc::Base for GPIO_AD_B0 { fn mux_base() -> *mut u32 { 0 as *mut u32 } fn pad_base() -> *mut u32 { 0 as *mut u32 } }
+    /// # type GPIO_AD_B0_13 = imxrt_iomuxc::Pad<GPIO_AD_B0, imxrt_iomuxc::consts::U13>;
     /// # let mut gpio_ad_b0_13 = unsafe { GPIO_AD_B0_13::new() };
     /// use imxrt_iomuxc::{Config, configure, SlewRate, DriveStrength, Hysteresis};
     ///
@@ -313,6 +636,40 @@ impl Config {
         }
     }
 
+    /// Create a `Config` that disables the pull / keeper, and doesn't modify any
+    /// other field
+    ///
+    /// The pad floats when nothing drives it. This writes PKE, PUE, and PUS to
+    /// zero, and is the same as
+    /// `Config::modify().set_pull_keeper(None)`.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{Config, Hysteresis};
+    ///
+    /// const INPUT: Config = Config::floating().set_hysteresis(Hysteresis::Enabled);
+    /// assert_eq!(INPUT.pull_keeper(), Some(None));
+    /// assert!(!INPUT.is_zero());
+    /// ```
+    pub const fn floating() -> Self {
+        Config::modify().set_pull_keeper(None)
+    }
+
+    /// Create a `Config` that enables the keeper, and doesn't modify any other field
+    ///
+    /// The keeper holds the pad at its last level when nothing drives it. This
+    /// writes PKE to 1, PUE to 0, and PUS to zero, and is the same as
+    /// `Config::modify().set_pull_keeper(Some(PullKeeper::Keeper))`.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{Config, PullKeeper};
+    ///
+    /// const HOLD: Config = Config::keeper();
+    /// assert_eq!(HOLD.pull_keeper(), Some(Some(PullKeeper::Keeper)));
+    /// ```
+    pub const fn keeper() -> Self {
+        Config::modify().set_pull_keeper(Some(PullKeeper::Keeper))
+    }
+
     /// Returns `true` if this `Config` was created using [`zero()`](struct.Config.html#method.zero), meaning that it will
     /// zero any unspecified fields. If `false`, this config was created using [`modify()`](struct.Config.html#method.modify),
     /// which will not touch unspecified fields.
@@ -327,6 +684,147 @@ impl Config {
         self.mask & Self::ZERO_BIT != 0
     }
 
+    /// The field values of this configuration
+    ///
+    /// For a config created using `zero()`, this is the complete register value.
+    pub(crate) const fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Returns `true` if the register value `register` holds every field that this
+    /// configuration specifies
+    pub(crate) const fn matches(&self, register: u32) -> bool {
+        let fields =
+            self.mask & !(Self::ZERO_BIT | Self::PULL_KEEPER_BIT | Self::DEPRECATED_PULL_BIT);
+        (register ^ self.value) & fields == 0
+    }
+
+    /// A `zero()` configuration that writes the complete register value `value`
+    pub(crate) const fn from_register(value: u32) -> Self {
+        Config {
+            value,
+            mask: Self::zero().mask,
+        }
+    }
+
+    /// The value and the mask of this configuration
+    pub(crate) const fn to_raw(self) -> (u32, u32) {
+        (self.value, self.mask)
+    }
+
+    /// A configuration from its [`to_raw()`](Config::to_raw) parts
+    ///
+    /// Returns `None` if `value` sets a bit outside of `mask`, or outside of the pad
+    /// configuration register's fields.
+    pub(crate) const fn from_raw(value: u32, mask: u32) -> Option<Self> {
+        if value & !mask != 0 || value >> 17 != 0 {
+            None
+        } else {
+            Some(Config { value, mask })
+        }
+    }
+
+    /// Returns `true` if this configuration enables open drain
+    pub(crate) const fn enables_open_drain(&self) -> bool {
+        self.value & OPENDRAIN_MASK != 0
+    }
+
+    /// Returns `true` if this configuration specifies all of the fields in `mask`
+    const fn specifies(&self, mask: u32) -> bool {
+        self.mask & mask == mask
+    }
+
+    /// Decode the pull / keeper configuration that the hardware observes
+    ///
+    /// Returns `None` if this configuration doesn't specify all of the PKE, PUE,
+    /// and PUS fields. Otherwise, returns `Some(None)` when PKE is 0, and the
+    /// keeper when PKE is 1 and PUE is 0. In both cases, the hardware ignores the
+    /// other fields, so the decoding ignores them too. Use this to inspect a
+    /// configuration that you read back from a pad, like
+    /// [`PadRef::read_config()`](crate::PadRef::read_config).
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{Config, PullKeeper};
+    ///
+    /// let config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup22k));
+    /// assert_eq!(config.pull_keeper(), Some(Some(PullKeeper::Pullup22k)));
+    /// assert_eq!(Config::zero().pull_keeper(), Some(None));
+    /// assert_eq!(Config::modify().pull_keeper(), None);
+    /// ```
+    #[allow(deprecated)]
+    pub const fn pull_keeper(&self) -> Option<Option<PullKeeper>> {
+        if !self.specifies(PULL_KEEPER_MASK) {
+            return None;
+        }
+        if self.value & PULLKEEP_MASK == 0 {
+            return Some(None);
+        }
+        if self.value & PULL_KEEP_SELECT_MASK == 0 {
+            return Some(Some(PullKeeper::Keeper));
+        }
+        Some(Some(match self.value & PULLUPDOWN_MASK {
+            v if v == PullUpDown::Pulldown100k as u32 => PullKeeper::Pulldown100k,
+            v if v == PullUpDown::Pullup47k as u32 => PullKeeper::Pullup47k,
+            v if v == PullUpDown::Pullup100k as u32 => PullKeeper::Pullup100k,
+            _ => PullKeeper::Pullup22k,
+        }))
+    }
+
+    /// Returns `true` if this configuration selects the keeper, and also selects
+    /// a pull resistance that the keeper ignores
+    ///
+    /// Only the deprecated setters, or a register value, can express this.
+    const fn pulls_with_keeper(&self) -> bool {
+        self.specifies(PULL_KEEPER_MASK)
+            && self.value & (PULLKEEP_MASK | PULL_KEEP_SELECT_MASK) == PULLKEEP_MASK
+            && self.value & PULLUPDOWN_MASK != 0
+    }
+
+    /// Check this configuration for fields that don't make sense together
+    ///
+    /// A rule only applies when the configuration specifies all of the rule's
+    /// fields. A `Config` created with [`zero()`](Config::zero) specifies all
+    /// fields. [`configure()`] checks the configuration with debug assertions.
+    ///
+    /// `validate()` is a `const fn`, so you can check your configurations at
+    /// build time.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{Config, ConfigError, OpenDrain, PullKeeper, SlewRate, Speed};
+    ///
+    /// const I2C: Config = Config::zero()
+    ///     .set_open_drain(OpenDrain::Enabled)
+    ///     .set_pull_keeper(Some(PullKeeper::Pullup22k));
+    /// const _: () = assert!(I2C.validate().is_ok());
+    ///
+    /// const CLOCK: Config = Config::modify()
+    ///     .set_speed(Speed::Max)
+    ///     .set_slew_rate(SlewRate::Slow);
+    /// assert_eq!(CLOCK.validate(), Err(ConfigError::MaxSpeedSlowSlew));
+    /// ```
+    pub const fn validate(&self) -> Result<(), ConfigError> {
+        const PULL_MASK: u32 = PULLKEEP_MASK | PULL_KEEP_SELECT_MASK | PULLUPDOWN_MASK;
+        const PULLDOWN: u32 = PullKeeper::Pulldown100k as u32;
+        const KEEPER: u32 = PullKeeper::Keeper as u32;
+
+        if self.specifies(OPENDRAIN_MASK | PULL_MASK) && self.enables_open_drain() {
+            if self.value & PULL_MASK == PULLDOWN {
+                return Err(ConfigError::OpenDrainPulldown);
+            }
+            let keeper_mask = PULLKEEP_MASK | PULL_KEEP_SELECT_MASK;
+            if self.value & keeper_mask == KEEPER & keeper_mask {
+                return Err(ConfigError::OpenDrainKeeper);
+            }
+        }
+        if self.specifies(SPEED_MASK | SLEW_RATE_MASK)
+            && self.value & SPEED_MASK == Speed::Max as u32
+            && self.value & SLEW_RATE_MASK == SlewRate::Slow as u32
+        {
+            return Err(ConfigError::MaxSpeedSlowSlew);
+        }
+        Ok(())
+    }
+
     /// Set the hysteresis bit
     pub const fn set_hysteresis(mut self, hys: Hysteresis) -> Self {
         self.value = (self.value & !HYSTERESIS_MASK) | (hys as u32);
@@ -337,41 +835,73 @@ impl Config {
     /// Set the pull up / pull down / keeper configuration.
     ///
     /// A `None` value disables the pull / keeper function.
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions, panics if this `Config` was modified by one of the
+    /// deprecated pull / keeper setters. Mixing the two APIs can produce conflicting
+    /// fields.
     pub const fn set_pull_keeper(mut self, pk: Option<PullKeeper>) -> Self {
+        debug_assert!(
+            self.mask & Self::DEPRECATED_PULL_BIT == 0,
+            "Cannot combine set_pull_keeper() with the deprecated pull / keeper setters"
+        );
         let pk = match pk {
             None => 0u32,
             Some(pk) => pk as u32,
         };
-        self.value = (self.value & !PULL_KEEPER_MASK) | (pk as u32);
-        self.mask |= PULL_KEEPER_MASK;
+        self.value = (self.value & !PULL_KEEPER_MASK) | pk;
+        self.mask |= PULL_KEEPER_MASK | Self::PULL_KEEPER_BIT;
+        self
+    }
+
+    /// Check that the deprecated setters are not combined with `set_pull_keeper()`
+    const fn deprecated_pull(mut self, mask: u32) -> Self {
+        debug_assert!(
+            self.mask & Self::PULL_KEEPER_BIT == 0,
+            "Cannot combine the deprecated pull / keeper setters with set_pull_keeper()"
+        );
+        self.mask |= mask | Self::DEPRECATED_PULL_BIT;
         self
     }
 
     /// Set the pull-up / pull-down value
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions, panics if this `Config` was modified by
+    /// [`set_pull_keeper()`](Config::set_pull_keeper).
     #[deprecated(since = "0.2.0", note = "Use PullKeeper and Config::set_pull_keeper")]
     #[allow(deprecated)]
     pub const fn set_pullupdown(mut self, pud: PullUpDown) -> Self {
         self.value = (self.value & !PULLUPDOWN_MASK) | (pud as u32);
-        self.mask |= PULLUPDOWN_MASK;
-        self
+        self.deprecated_pull(PULLUPDOWN_MASK)
     }
 
     /// Set the the pull-up / pull-down or keeper selection bit
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions, panics if this `Config` was modified by
+    /// [`set_pull_keeper()`](Config::set_pull_keeper).
     #[deprecated(since = "0.2.0", note = "Use PullKeeper and Config::set_pull_keeper")]
     #[allow(deprecated)]
     pub const fn set_pull_keep_select(mut self, pke: PullKeepSelect) -> Self {
         self.value = (self.value & !PULL_KEEP_SELECT_MASK) | (pke as u32);
-        self.mask |= PULL_KEEP_SELECT_MASK;
-        self
+        self.deprecated_pull(PULL_KEEP_SELECT_MASK)
     }
 
     /// Set the flag that enables the keeper or pull-up / pull-down configuration
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions, panics if this `Config` was modified by
+    /// [`set_pull_keeper()`](Config::set_pull_keeper).
     #[deprecated(since = "0.2.0", note = "Use PullKeeper and Config::set_pull_keeper")]
     #[allow(deprecated)]
     pub const fn set_pull_keep(mut self, pk: PullKeep) -> Self {
         self.value = (self.value & !PULLKEEP_MASK) | (pk as u32);
-        self.mask |= PULLKEEP_MASK;
-        self
+        self.deprecated_pull(PULLKEEP_MASK)
     }
 
     /// Set the open drain value
@@ -482,6 +1012,80 @@ mod tests {
         assert_eq!(pad.0, 1 << 12);
     }
 
+    #[test]
+    fn floating_and_keeper() {
+        let mut pad = Pad(PAD_BITMASK);
+        configure(&mut pad, Config::floating());
+        assert_eq!(pad.0, PAD_BITMASK & !PULL_KEEPER_MASK);
+
+        let mut pad = Pad(PAD_BITMASK);
+        configure(&mut pad, Config::keeper());
+        assert_eq!(pad.0, PAD_BITMASK & !PULL_KEEPER_MASK | 1 << 12);
+
+        assert_eq!(Config::floating(), Config::modify().set_pull_keeper(None));
+        assert_eq!(
+            Config::keeper(),
+            Config::modify().set_pull_keeper(Some(PullKeeper::Keeper))
+        );
+    }
+
+    /// Every combination of PKE, PUE, and PUS decodes to the configuration that
+    /// the hardware observes
+    #[test]
+    fn pull_keeper_truth_table() {
+        for pke in 0..2u32 {
+            for pue in 0..2u32 {
+                for pus in 0..4u32 {
+                    let value = pke << 12 | pue << 13 | pus << 14;
+                    let config = Config::from_register(value);
+                    let expected = match (pke, pue, pus) {
+                        (0, _, _) => None,
+                        (1, 0, _) => Some(PullKeeper::Keeper),
+                        (1, 1, 0b00) => Some(PullKeeper::Pulldown100k),
+                        (1, 1, 0b01) => Some(PullKeeper::Pullup47k),
+                        (1, 1, 0b10) => Some(PullKeeper::Pullup100k),
+                        (1, 1, 0b11) => Some(PullKeeper::Pullup22k),
+                        _ => unreachable!(),
+                    };
+                    assert_eq!(config.pull_keeper(), Some(expected), "{:#x}", value);
+                    assert_eq!(
+                        config.pulls_with_keeper(),
+                        pke == 1 && pue == 0 && pus != 0,
+                        "{:#x}",
+                        value
+                    );
+
+                    // Encoding the decoded configuration produces the same
+                    // observed fields
+                    let encoded = Config::zero().set_pull_keeper(expected);
+                    assert_eq!(encoded.pull_keeper(), Some(expected));
+                    assert!(!encoded.pulls_with_keeper());
+                }
+            }
+        }
+        assert_eq!(Config::modify().pull_keeper(), None);
+        assert_eq!(
+            Config::modify()
+                .set_open_drain(OpenDrain::Enabled)
+                .pull_keeper(),
+            None
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn pull_with_keeper_panics() {
+        let mut pad = Pad(0);
+        configure(
+            &mut pad,
+            Config::zero()
+                .set_pull_keep(PullKeep::Enabled)
+                .set_pull_keep_select(PullKeepSelect::Keeper)
+                .set_pullupdown(PullUpDown::Pullup22k),
+        );
+    }
+
     #[test]
     fn pull_keeper_pullupdown() {
         struct ConfigToField {
@@ -514,6 +1118,379 @@ mod tests {
             assert_eq!(pad.0, 1 << 12 | 1 << 13 | test.value);
         }
     }
+    /// Checks that every encoding below `limit` decodes to a configuration, and
+    /// round trips, and that the next encodings are invalid
+    fn exhaustive<T>(limit: u32)
+    where
+        T: TryFrom<u32, Error = InvalidFieldError> + Into<u32> + Copy,
+    {
+        for field in 0..limit {
+            let config = T::try_from(field).unwrap();
+            assert_eq!(config.into(), field);
+        }
+        for field in limit..limit * 2 {
+            assert_eq!(T::try_from(field).err(), Some(InvalidFieldError(field)));
+        }
+        assert_eq!(
+            T::try_from(u32::MAX).err(),
+            Some(InvalidFieldError(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn field_hysteresis() {
+        exhaustive::<Hysteresis>(2);
+    }
+
+    #[test]
+    fn field_open_drain() {
+        exhaustive::<OpenDrain>(2);
+    }
+
+    #[test]
+    fn field_speed() {
+        exhaustive::<Speed>(4);
+    }
+
+    #[test]
+    fn field_drive_strength() {
+        exhaustive::<DriveStrength>(8);
+    }
+
+    #[test]
+    fn field_slew_rate() {
+        exhaustive::<SlewRate>(2);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn field_deprecated() {
+        exhaustive::<PullUpDown>(4);
+        exhaustive::<PullKeepSelect>(2);
+        exhaustive::<PullKeep>(2);
+    }
+
+    #[test]
+    fn field_pull_keeper() {
+        const VALID: [(u32, PullKeeper); 5] = [
+            (0b0001, PullKeeper::Keeper),
+            (0b0011, PullKeeper::Pulldown100k),
+            (0b0111, PullKeeper::Pullup47k),
+            (0b1011, PullKeeper::Pullup100k),
+            (0b1111, PullKeeper::Pullup22k),
+        ];
+        for field in 0..32 {
+            match VALID.iter().find(|(valid, _)| *valid == field) {
+                Some((_, pk)) => {
+                    assert_eq!(PullKeeper::try_from(field), Ok(*pk));
+                    assert_eq!(u32::from(*pk), field);
+                }
+                None => assert_eq!(PullKeeper::try_from(field), Err(InvalidFieldError(field))),
+            }
+        }
+    }
+
+    /// Every enabled combination of the deprecated fields decodes to the
+    /// equivalent `PullKeeper`, unless it's a keeper with a pull selection
+    #[test]
+    #[allow(deprecated)]
+    fn field_deprecated_pull_keeper() {
+        for pud in 0..4 {
+            for pke in 0..2 {
+                let pud = PullUpDown::try_from(pud).unwrap();
+                let pke = PullKeepSelect::try_from(pke).unwrap();
+                let config = Config::zero()
+                    .set_pull_keep(PullKeep::Enabled)
+                    .set_pull_keep_select(pke)
+                    .set_pullupdown(pud);
+                let decoded = PullKeeper::try_from(config.value() >> PULLKEEP_SHIFT);
+
+                let expected = match (pke, pud) {
+                    (PullKeepSelect::Keeper, PullUpDown::Pulldown100k) => Ok(PullKeeper::Keeper),
+                    (PullKeepSelect::Keeper, _) => {
+                        Err(InvalidFieldError(config.value() >> PULLKEEP_SHIFT))
+                    }
+                    (PullKeepSelect::Pull, PullUpDown::Pulldown100k) => {
+                        Ok(PullKeeper::Pulldown100k)
+                    }
+                    (PullKeepSelect::Pull, PullUpDown::Pullup47k) => Ok(PullKeeper::Pullup47k),
+                    (PullKeepSelect::Pull, PullUpDown::Pullup100k) => Ok(PullKeeper::Pullup100k),
+                    (PullKeepSelect::Pull, PullUpDown::Pullup22k) => Ok(PullKeeper::Pullup22k),
+                };
+                assert_eq!(decoded, expected);
+            }
+        }
+
+        let disabled = Config::zero().set_pull_keep(PullKeep::Disabled);
+        assert!(PullKeeper::try_from(disabled.value() >> PULLKEEP_SHIFT).is_err());
+    }
+
+    #[test]
+    fn field_const() {
+        const SPEED: Result<Speed, InvalidFieldError> = Speed::from_field(2);
+        const DSE: u32 = DriveStrength::R0_6.field();
+        assert_eq!(SPEED, Ok(Speed::Fast));
+        assert_eq!(DSE, 6);
+    }
+    /// The deprecated combinations, and the pull / keeper fields that the
+    /// hardware observes for each: `(PKE, PUE, PUS)`
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_matrix() {
+        for pk in 0..2 {
+            for pke in 0..2 {
+                for pud in 0..4 {
+                    let pk = PullKeep::try_from(pk).unwrap();
+                    let pke = PullKeepSelect::try_from(pke).unwrap();
+                    let pud = PullUpDown::try_from(pud).unwrap();
+
+                    // configure() rejects a keeper with a pull resistance, so
+                    // take the register value directly
+                    let old = Pad(Config::zero()
+                        .set_pull_keep(pk)
+                        .set_pull_keep_select(pke)
+                        .set_pullupdown(pud)
+                        .value());
+                    let mut new = Pad(0);
+                    let converted = PullKeeper::from_deprecated(pk, pke, pud);
+                    configure(&mut new, Config::zero().set_pull_keeper(converted));
+
+                    match (pk, pke) {
+                        // Nothing else is observed
+                        (PullKeep::Disabled, _) => {
+                            assert_eq!(converted, None);
+                            assert_eq!(new.0 & PULLKEEP_MASK, old.0 & PULLKEEP_MASK);
+                        }
+                        // Pull / keeper select and enable are observed
+                        (PullKeep::Enabled, PullKeepSelect::Keeper) => {
+                            assert_eq!(converted, Some(PullKeeper::Keeper));
+                            assert_eq!(PullKeeper::from((pke, pud)), PullKeeper::Keeper);
+                            let observed = PULLKEEP_MASK | PULL_KEEP_SELECT_MASK;
+                            assert_eq!(new.0 & observed, old.0 & observed);
+                        }
+                        // Everything is observed
+                        (PullKeep::Enabled, PullKeepSelect::Pull) => {
+                            assert_eq!(PullKeeper::from((pke, pud)), converted.unwrap());
+                            assert_eq!(new.0, old.0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn mix_deprecated_then_pull_keeper() {
+        Config::zero()
+            .set_pull_keep(PullKeep::Enabled)
+            .set_pull_keeper(Some(PullKeeper::Pullup22k));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn mix_pull_keeper_then_deprecated() {
+        Config::modify()
+            .set_pull_keeper(None)
+            .set_pullupdown(PullUpDown::Pullup22k);
+    }
+
+    #[test]
+    fn repeat_pull_keeper() {
+        let mut pad = Pad(0);
+        configure(
+            &mut pad,
+            Config::zero()
+                .set_pull_keeper(Some(PullKeeper::Pullup22k))
+                .set_pull_keeper(Some(PullKeeper::Keeper)),
+        );
+        assert_eq!(pad.0, PullKeeper::Keeper as u32);
+    }
+
+    #[test]
+    fn validate_open_drain_pulldown() {
+        const CONFIG: Config = Config::zero()
+            .set_open_drain(OpenDrain::Enabled)
+            .set_pull_keeper(Some(PullKeeper::Pulldown100k));
+        assert_eq!(CONFIG.validate(), Err(ConfigError::OpenDrainPulldown));
+        assert_eq!(
+            CONFIG.set_open_drain(OpenDrain::Disabled).validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn validate_open_drain_keeper() {
+        const CONFIG: Config = Config::modify()
+            .set_open_drain(OpenDrain::Enabled)
+            .set_pull_keeper(Some(PullKeeper::Keeper));
+        assert_eq!(CONFIG.validate(), Err(ConfigError::OpenDrainKeeper));
+
+        // The keeper ignores the pull-down selection
+        let deprecated = Config::zero()
+            .set_open_drain(OpenDrain::Enabled)
+            .set_pull_keep(PullKeep::Enabled)
+            .set_pull_keep_select(PullKeepSelect::Keeper)
+            .set_pullupdown(PullUpDown::Pulldown100k);
+        assert_eq!(deprecated.validate(), Err(ConfigError::OpenDrainKeeper));
+    }
+
+    #[test]
+    fn validate_open_drain_pullup() {
+        for pk in [
+            None,
+            Some(PullKeeper::Pullup22k),
+            Some(PullKeeper::Pullup100k),
+        ] {
+            let config = Config::zero()
+                .set_open_drain(OpenDrain::Enabled)
+                .set_pull_keeper(pk);
+            assert_eq!(config.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn validate_max_speed_slow_slew() {
+        const CONFIG: Config = Config::zero()
+            .set_speed(Speed::Max)
+            .set_slew_rate(SlewRate::Slow);
+        assert_eq!(CONFIG.validate(), Err(ConfigError::MaxSpeedSlowSlew));
+        assert_eq!(CONFIG.set_slew_rate(SlewRate::Fast).validate(), Ok(()));
+        assert_eq!(CONFIG.set_speed(Speed::Fast).validate(), Ok(()));
+    }
+
+    /// A rule doesn't apply unless the config specifies all of its fields
+    #[test]
+    fn validate_unspecified_fields() {
+        let speed = Config::modify().set_speed(Speed::Max);
+        assert_eq!(speed.validate(), Ok(()));
+        let open_drain = Config::modify().set_open_drain(OpenDrain::Enabled);
+        assert_eq!(open_drain.validate(), Ok(()));
+    }
+
+    /// Checks that `field` writes `encoding` to the register bits in `mask`, and
+    /// leaves every other bit untouched
+    fn check_field(field: Field, mask: u32, encoding: u32) {
+        for start in [0, PAD_BITMASK, PAD_ALL_HIGH.0] {
+            let mut pad = Pad(start);
+            modify_field(&mut pad, field);
+            assert_eq!(pad.0 & !mask, start & !mask, "{:?}", field);
+            assert_eq!(pad.0 & mask, encoding, "{:?}", field);
+        }
+    }
+
+    #[test]
+    fn modify_field_isolated() {
+        for field in 0..2 {
+            let hys = Hysteresis::from_field(field).unwrap();
+            check_field(
+                Field::Hysteresis(hys),
+                HYSTERESIS_MASK,
+                field << HYSTERESIS_SHIFT,
+            );
+            let od = OpenDrain::from_field(field).unwrap();
+            check_field(
+                Field::OpenDrain(od),
+                OPENDRAIN_MASK,
+                field << OPENDRAIN_SHIFT,
+            );
+            let sre = SlewRate::from_field(field).unwrap();
+            check_field(
+                Field::SlewRate(sre),
+                SLEW_RATE_MASK,
+                field << SLEW_RATE_SHIFT,
+            );
+        }
+        for field in 0..4 {
+            let speed = Speed::from_field(field).unwrap();
+            check_field(Field::Speed(speed), SPEED_MASK, field << SPEED_SHIFT);
+        }
+        for field in 0..8 {
+            let dse = DriveStrength::from_field(field).unwrap();
+            check_field(
+                Field::DriveStrength(dse),
+                DRIVE_STRENGTH_MASK,
+                field << DRIVE_STRENGTH_SHIFT,
+            );
+        }
+        check_field(Field::PullKeeper(None), PULL_KEEPER_MASK, 0);
+        for pk in [
+            PullKeeper::Keeper,
+            PullKeeper::Pulldown100k,
+            PullKeeper::Pullup47k,
+            PullKeeper::Pullup100k,
+            PullKeeper::Pullup22k,
+        ] {
+            check_field(
+                Field::PullKeeper(Some(pk)),
+                PULL_KEEPER_MASK,
+                pk.field() << PULLKEEP_SHIFT,
+            );
+            let mut pad = PAD_ALL_HIGH;
+            modify_field(&mut pad, Field::PullKeeper(Some(pk)));
+            assert_eq!(Config::from_register(pad.0).pull_keeper(), Some(Some(pk)));
+        }
+    }
+
+    /// Counts the accesses to its pad register
+    struct CountingPad {
+        pad: Pad,
+        accesses: usize,
+    }
+
+    impl crate::private::Sealed for CountingPad {}
+
+    unsafe impl Iomuxc for CountingPad {
+        fn mux(&mut self) -> *mut u32 {
+            panic!("Nothing calls mux() in these tests");
+        }
+        fn pad(&mut self) -> *mut u32 {
+            self.accesses += 1;
+            self.pad.pad()
+        }
+    }
+
+    #[test]
+    fn modify_fields_one_write() {
+        let mut counting = CountingPad {
+            pad: PAD_ALL_HIGH,
+            accesses: 0,
+        };
+        modify_fields(
+            &mut counting,
+            &[
+                Field::DriveStrength(DriveStrength::R0_2),
+                Field::SlewRate(SlewRate::Slow),
+                Field::DriveStrength(DriveStrength::R0_4),
+            ],
+        );
+        assert_eq!(counting.accesses, 1);
+        let mut pad = counting.pad;
+
+        // The last drive strength wins
+        let mask = DRIVE_STRENGTH_MASK | SLEW_RATE_MASK;
+        assert_eq!(pad.0 & mask, DriveStrength::R0_4 as u32);
+        assert_eq!(pad.0 & !mask, PAD_ALL_HIGH.0 & !mask);
+
+        // No fields, no change
+        modify_fields(&mut pad, &[]);
+        assert_eq!(pad.0 & mask, DriveStrength::R0_4 as u32);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn configure_invalid() {
+        let mut pad = Pad(0);
+        configure(
+            &mut pad,
+            Config::zero()
+                .set_open_drain(OpenDrain::Enabled)
+                .set_pull_keeper(Some(PullKeeper::Pulldown100k)),
+        );
+    }
 }
 
 /// ```rust
@@ -0,0 +1,415 @@
+//! CMOS sensor interface (CSI) pad configuration
+//!
+//! A CSI pin is one of the camera's clock, sync, or data signals. Use [`prepare()`]
+//! to prepare any single CSI pin. The call commits the pin's daisy register.
+//!
+//! # Bus presets
+//!
+//! Camera sensors use an 8-bit bus on `DATA02` through `DATA09`. [`Bus8`]
+//! bundles the pixel clock and sync pins with exactly those data pins, prepares
+//! all of the pins, and commits all of their daisy registers. A single missing daisy shifts the
+//! image, so prefer a bus preset over preparing each pin. Supply the data pins as
+//! a tuple, starting with the lowest data line.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{csi, imxrt1060::gpio_ad_b1::*};
+//!
+//! let bus = csi::Bus8::new(
+//!     unsafe { GPIO_AD_B1_04::new() },
+//!     unsafe { GPIO_AD_B1_06::new() },
+//!     unsafe { GPIO_AD_B1_07::new() },
+//!     unsafe {
+//!         (
+//!             GPIO_AD_B1_15::new(),
+//!             GPIO_AD_B1_14::new(),
+//!             GPIO_AD_B1_13::new(),
+//!             GPIO_AD_B1_12::new(),
+//!             GPIO_AD_B1_11::new(),
+//!             GPIO_AD_B1_10::new(),
+//!             GPIO_AD_B1_09::new(),
+//!             GPIO_AD_B1_08::new(),
+//!         )
+//!     },
+//! );
+//! assert_eq!(bus.width(), 8);
+//!
+//! // The sensor's master clock, if the board doesn't supply one
+//! let mut mclk = unsafe { GPIO_AD_B1_05::new() };
+//! csi::prepare(&mut mclk);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! Each data pin must be in its place. `GPIO_AD_B1_14` is `DATA03`, so it cannot
+//! be `DATA02`:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{csi, imxrt1060::gpio_ad_b1::*};
+//!
+//! let bus = csi::Bus8::new(
+//!     unsafe { GPIO_AD_B1_04::new() },
+//!     unsafe { GPIO_AD_B1_06::new() },
+//!     unsafe { GPIO_AD_B1_07::new() },
+//!     unsafe {
+//!         (
+//!             GPIO_AD_B1_14::new(),
+//!             GPIO_AD_B1_15::new(),
+//!             GPIO_AD_B1_13::new(),
+//!             GPIO_AD_B1_12::new(),
+//!             GPIO_AD_B1_11::new(),
+//!             GPIO_AD_B1_10::new(),
+//!             GPIO_AD_B1_09::new(),
+//!             GPIO_AD_B1_08::new(),
+//!         )
+//!     },
+//! );
+//! ```
+
+use crate::consts::*;
+use core::marker::PhantomData;
+
+/// A CSI pin signal
+pub trait Signal: private::Sealed + private::Code {}
+
+/// A tag that indicates the pixel clock input
+pub enum Pixclk {}
+/// A tag that indicates the sensor's master clock output
+pub enum Mclk {}
+/// A tag that indicates the vertical sync input
+pub enum Vsync {}
+/// A tag that indicates the horizontal sync input
+pub enum Hsync {}
+/// A tag that indicates a data line; `Data<U2>` for `CSI_DATA02`
+pub struct Data<N> {
+    _n: PhantomData<N>,
+}
+
+impl Signal for Pixclk {}
+impl Signal for Mclk {}
+impl Signal for Vsync {}
+impl Signal for Hsync {}
+impl<N: Unsigned> Signal for Data<N> {}
+
+/// A CSI pin
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the CSI pin
+    const ALT: u32;
+    /// The daisy register which will select the pad
+    const DAISY: Option<super::Daisy>;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Clear`](crate::SionRequirement::Clear).
+    const SION: super::SionRequirement = super::SionRequirement::Clear;
+    /// The pin's signal
+    type Signal: Signal;
+}
+
+/// Prepare a CSI pin
+///
+/// If you do not call `prepare()` on your CSI pin, it might not work as a CSI
+/// pin.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    trace!("csi::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, P::DAISY);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!("csi::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, P::DAISY)
+}
+
+prepared_pin!("CSI");
+
+/// Compute the register writes that prepare a CSI pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+///
+/// The result records the CSI as its [`Peripheral`](crate::Peripheral).
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, P::DAISY)
+        .with_peripheral(super::Peripheral::Csi)
+}
+
+/// The data pins of a `Width`-bit bus
+///
+/// Implemented for tuples of data pins, starting with the bus' lowest data
+/// line, `DATA02`.
+pub trait DataPins<Width>: private::Sealed {
+    /// Prepare all of the data pins
+    fn prepare(&mut self);
+}
+
+macro_rules! data_pins {
+    ($width:ty: $($pin:ident $index:ty => $n:tt),+) => {
+        impl<$($pin),+> private::Sealed for ($($pin,)+) {}
+        impl<$($pin),+> DataPins<$width> for ($($pin,)+)
+        where
+            $($pin: Pin<Signal = Data<$index>>),+
+        {
+            fn prepare(&mut self) {
+                $(prepare(&mut self.$n);)+
+            }
+        }
+    };
+}
+
+data_pins!(U8:
+    D2 U2 => 0, D3 U3 => 1, D4 U4 => 2, D5 U5 => 3, D6 U6 => 4, D7 U7 => 5, D8 U8 => 6, D9 U9 => 7
+);
+
+/// CSI clock, sync, and data pins for a `Width`-bit bus
+///
+/// Use the [`Bus8`] preset to name a bus.
+#[derive(Debug)]
+pub struct Bus<Width, PIX, VS, HS, D> {
+    /// The pixel clock pin
+    pub pixclk: PIX,
+    /// The vertical sync pin
+    pub vsync: VS,
+    /// The horizontal sync pin
+    pub hsync: HS,
+    /// The data pins, starting with the lowest data line
+    pub data: D,
+    _width: PhantomData<Width>,
+}
+
+/// An 8-bit CSI bus, using `DATA02` through `DATA09`
+pub type Bus8<PIX, VS, HS, D> = Bus<U8, PIX, VS, HS, D>;
+
+impl<Width, PIX, VS, HS, D> Bus<Width, PIX, VS, HS, D>
+where
+    Width: Unsigned,
+    PIX: Pin<Signal = Pixclk>,
+    VS: Pin<Signal = Vsync>,
+    HS: Pin<Signal = Hsync>,
+    D: DataPins<Width>,
+{
+    /// The bus width, in bits
+    pub const WIDTH: u32 = Width::U32;
+
+    /// Bundle and prepare the CSI pins
+    ///
+    /// The call commits the daisy registers of all pins.
+    pub fn new(mut pixclk: PIX, mut vsync: VS, mut hsync: HS, mut data: D) -> Self {
+        prepare(&mut pixclk);
+        prepare(&mut vsync);
+        prepare(&mut hsync);
+        data.prepare();
+        Bus {
+            pixclk,
+            vsync,
+            hsync,
+            data,
+            _width: PhantomData,
+        }
+    }
+
+    /// Returns the bus width, in bits
+    pub const fn width(&self) -> u32 {
+        Self::WIDTH
+    }
+
+    /// Release the pins
+    pub fn release(self) -> (PIX, VS, HS, D) {
+        (self.pixclk, self.vsync, self.hsync, self.data)
+    }
+}
+
+mod private {
+    use crate::{consts::Unsigned, function::CsiSignal};
+
+    pub trait Sealed {}
+
+    /// A signal that's known at run time
+    pub trait Code {
+        const SIGNAL: CsiSignal;
+    }
+    impl Sealed for super::Pixclk {}
+    impl Code for super::Pixclk {
+        const SIGNAL: CsiSignal = CsiSignal::Pixclk;
+    }
+    impl Sealed for super::Mclk {}
+    impl Code for super::Mclk {
+        const SIGNAL: CsiSignal = CsiSignal::Mclk;
+    }
+    impl Sealed for super::Vsync {}
+    impl Code for super::Vsync {
+        const SIGNAL: CsiSignal = CsiSignal::Vsync;
+    }
+    impl Sealed for super::Hsync {}
+    impl Code for super::Hsync {
+        const SIGNAL: CsiSignal = CsiSignal::Hsync;
+    }
+    impl<N> Sealed for super::Data<N> {}
+    impl<N: Unsigned> Code for super::Data<N> {
+        const SIGNAL: CsiSignal = CsiSignal::Data(N::U8);
+    }
+}
+
+/// Returns the [`Function`](crate::Function) of a CSI pin
+pub const fn function<P: Pin>() -> super::Function {
+    super::Function::Csi(<P::Signal as private::Code>::SIGNAL)
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! csi {
+    (alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
+            type Signal = $signal;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{Bus8, Data, Hsync, Pin, Pixclk, Signal, Vsync};
+    use crate::{consts::*, Daisy, Iomuxc};
+    use core::marker::PhantomData;
+
+    struct FakePad<S> {
+        mux: u32,
+        pad: u32,
+        _signal: PhantomData<S>,
+    }
+
+    fn pad<S>() -> FakePad<S> {
+        FakePad {
+            mux: 5,
+            pad: 0,
+            _signal: PhantomData,
+        }
+    }
+
+    impl<S> crate::private::Sealed for FakePad<S> {}
+
+    unsafe impl<S> Iomuxc for FakePad<S> {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// The fakes don't have daisy registers; prepare_pin() commits the daisy
+    /// of every pad that it prepares, so a prepared mux implies a committed daisy.
+    impl<S: Signal> Pin for FakePad<S> {
+        const ALT: u32 = 4;
+        const DAISY: Option<Daisy> = None;
+        type Signal = S;
+    }
+
+    #[test]
+    fn bus8_prepares_all_pads() {
+        let bus = Bus8::new(
+            pad::<Pixclk>(),
+            pad::<Vsync>(),
+            pad::<Hsync>(),
+            (
+                pad::<Data<U2>>(),
+                pad::<Data<U3>>(),
+                pad::<Data<U4>>(),
+                pad::<Data<U5>>(),
+                pad::<Data<U6>>(),
+                pad::<Data<U7>>(),
+                pad::<Data<U8>>(),
+                pad::<Data<U9>>(),
+            ),
+        );
+
+        let data = &bus.data;
+        let muxes = [
+            bus.pixclk.mux,
+            bus.vsync.mux,
+            bus.hsync.mux,
+            data.0.mux,
+            data.1.mux,
+            data.2.mux,
+            data.3.mux,
+            data.4.mux,
+            data.5.mux,
+            data.6.mux,
+            data.7.mux,
+        ];
+        assert_eq!(muxes, [4; 11]);
+        assert_eq!(bus.width(), 8);
+    }
+
+    /// The select inputs for the `GPIO_AD_B1` routing
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn imxrt1060_bus8_daisies() {
+        use crate::imxrt1060::gpio_ad_b1::*;
+        use std::vec::Vec;
+
+        fn daisy<P: Pin>() -> (usize, u32) {
+            let daisy = P::DAISY.unwrap();
+            (daisy.reg(), daisy.value())
+        }
+
+        assert_eq!(daisy::<GPIO_AD_B1_04>(), (0x401F_8424, 0));
+        assert_eq!(daisy::<GPIO_AD_B1_06>(), (0x401F_8428, 0));
+        assert_eq!(daisy::<GPIO_AD_B1_07>(), (0x401F_8420, 0));
+        let data = [
+            daisy::<GPIO_AD_B1_15>(),
+            daisy::<GPIO_AD_B1_14>(),
+            daisy::<GPIO_AD_B1_13>(),
+            daisy::<GPIO_AD_B1_12>(),
+            daisy::<GPIO_AD_B1_11>(),
+            daisy::<GPIO_AD_B1_10>(),
+            daisy::<GPIO_AD_B1_09>(),
+            daisy::<GPIO_AD_B1_08>(),
+        ];
+        let expected: Vec<_> = (0x401F_8400..=0x401F_841C)
+            .step_by(4)
+            .map(|reg| (reg, 0))
+            .collect();
+        assert_eq!(data[..], expected[..]);
+        assert!(<GPIO_AD_B1_05 as Pin>::DAISY.is_none());
+    }
+
+    /// `Bus8::new()` prepares each pin, and `prepared()` describes the same writes
+    /// as `prepare()`. Together, the bus' writes must select every CSI input.
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn imxrt1060_bus8_commits_every_select_input() {
+        use super::prepared;
+        use crate::{imxrt1060::gpio_ad_b1::*, Config};
+        use std::vec::Vec;
+
+        let writes = [
+            prepared::<GPIO_AD_B1_04>(Config::zero()),
+            prepared::<GPIO_AD_B1_06>(Config::zero()),
+            prepared::<GPIO_AD_B1_07>(Config::zero()),
+            prepared::<GPIO_AD_B1_15>(Config::zero()),
+            prepared::<GPIO_AD_B1_14>(Config::zero()),
+            prepared::<GPIO_AD_B1_13>(Config::zero()),
+            prepared::<GPIO_AD_B1_12>(Config::zero()),
+            prepared::<GPIO_AD_B1_11>(Config::zero()),
+            prepared::<GPIO_AD_B1_10>(Config::zero()),
+            prepared::<GPIO_AD_B1_09>(Config::zero()),
+            prepared::<GPIO_AD_B1_08>(Config::zero()),
+        ];
+        assert!(writes.iter().all(|writes| writes.mux_val == 4));
+
+        let mut daisies: Vec<_> = writes.iter().map(|writes| writes.daisy.unwrap()).collect();
+        daisies.sort_unstable();
+        // DATA02 through DATA09, HSYNC, PIXCLK, and VSYNC
+        let expected: Vec<_> = (0x401F_8400..=0x401F_8428)
+            .step_by(4)
+            .map(|reg| (reg, 0))
+            .collect();
+        assert_eq!(daisies, expected);
+    }
+}
@@ -0,0 +1,136 @@
+//! Quadrature decoder (ENC) inputs
+//!
+//! The ENC peripheral does not connect directly to pads. Instead, its PHASEA,
+//! PHASEB, INDEX, and HOME inputs come from the crossbar (XBAR). To use a pad
+//! as an encoder input,
+//!
+//! 1. prepare the pad as an XBAR input, and
+//! 2. route the pad's crossbar input to the ENC input.
+//!
+//! This module handles the first step, and tells you the crossbar inputs you need
+//! for the second step. The crossbar programming is up to your HAL.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{enc, imxrt1060::gpio_b1::*};
+//!
+//! let mut phase_a = unsafe { GPIO_B1_00::new() };
+//! let mut phase_b = unsafe { GPIO_B1_01::new() };
+//! let inputs = enc::prepare_inputs(&mut phase_a, &mut phase_b);
+//!
+//! // Route these crossbar inputs to ENC1_PHASE_A_INPUT and ENC1_PHASE_B_INPUT
+//! assert_eq!(inputs.phase_a.index(), 14);
+//! assert_eq!(inputs.phase_b.index(), 15);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! Use [`prepare_input()`] to prepare pads for the INDEX and HOME inputs.
+
+use crate::xbar;
+
+/// The crossbar inputs for the encoder phases
+///
+/// Returned from [`prepare_inputs()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseInputs<A, B> {
+    /// Route this crossbar input to the ENC PHASEA input
+    pub phase_a: xbar::Input<A>,
+    /// Route this crossbar input to the ENC PHASEB input
+    pub phase_b: xbar::Input<B>,
+}
+
+/// Prepare two pads as the encoder phase inputs
+///
+/// The call prepares both pads as XBAR inputs, and commits their daisy registers.
+/// It returns the crossbar inputs that you'll route to the ENC phase inputs.
+pub fn prepare_inputs<PA, PB>(
+    phase_a: &mut PA,
+    phase_b: &mut PB,
+) -> PhaseInputs<PA::Index, PB::Index>
+where
+    PA: xbar::Pin,
+    PA::Direction: xbar::InputDirection,
+    PB: xbar::Pin,
+    PB::Direction: xbar::InputDirection,
+{
+    PhaseInputs {
+        phase_a: prepare_input(phase_a),
+        phase_b: prepare_input(phase_b),
+    }
+}
+
+/// Prepare a single pad as an encoder input
+///
+/// Use this for the INDEX and HOME inputs. Returns the crossbar input that
+/// you'll route to the ENC input.
+pub fn prepare_input<P>(pin: &mut P) -> xbar::Input<P::Index>
+where
+    P: xbar::Pin,
+    P::Direction: xbar::InputDirection,
+{
+    xbar::prepare(pin);
+    xbar::Input::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prepare_inputs;
+    use crate::{consts::*, xbar, Daisy, Iomuxc};
+
+    struct FakePad<Index> {
+        mux: u32,
+        pad: u32,
+        _index: core::marker::PhantomData<Index>,
+    }
+
+    impl<Index> FakePad<Index> {
+        fn new() -> Self {
+            FakePad {
+                mux: 0,
+                pad: 0,
+                _index: core::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<Index> crate::private::Sealed for FakePad<Index> {}
+
+    unsafe impl<Index> Iomuxc for FakePad<Index> {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// The fakes don't have daisy registers; xbar::prepare() commits the daisy,
+    /// and the xbar tests cover it.
+    impl xbar::Pin for FakePad<U14> {
+        const ALT: u32 = 1;
+        const DAISY: Option<Daisy> = None;
+        type Index = U14;
+        type Direction = xbar::InOut;
+    }
+
+    impl xbar::Pin for FakePad<U15> {
+        const ALT: u32 = 1;
+        const DAISY: Option<Daisy> = None;
+        type Index = U15;
+        type Direction = xbar::InOut;
+    }
+
+    #[test]
+    fn phase_inputs() {
+        let mut phase_a = FakePad::<U14>::new();
+        let mut phase_b = FakePad::<U15>::new();
+
+        let inputs = prepare_inputs(&mut phase_a, &mut phase_b);
+
+        assert_eq!(inputs.phase_a.index(), 14);
+        assert_eq!(inputs.phase_b.index(), 15);
+        assert_eq!(phase_a.mux, 1 << 4 | 1);
+        assert_eq!(phase_b.mux, 1 << 4 | 1);
+    }
+}
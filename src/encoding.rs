@@ -0,0 +1,675 @@
+//! Binary encoding of routings and plans
+//!
+//! See the [encoding layout](crate::Plan#encoding).
+
+use crate::SionRequirement;
+use crate::{plan, Config, Daisy, Function, PadId, Peripheral, Plan, PlanError, Routing};
+
+/// An error when encoding or decoding a [`Plan`] or [`Routing`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EncodingError {
+    /// The buffer is too short; the encoding needs this many bytes
+    TooShort(usize),
+    /// The buffer is longer than the encoding of its routings
+    TooLong,
+    /// The buffer does not start with [`Plan::MAGIC`]
+    MissingMagic,
+    /// The encoding's version is not [`Plan::VERSION`]
+    UnsupportedVersion(u8),
+    /// The checksum doesn't match the contents
+    Checksum,
+    /// The storage for decoded routings is too small; the plan has this many routings
+    TooManyRoutings(usize),
+    /// A value doesn't fit in its field
+    ///
+    /// Pad and daisy addresses must fit in 32 bits, SAI modules in eight bits, and
+    /// a plan may have up to 65535 routings. A function must have a
+    /// [`to_bits()`](crate::Function::to_bits) encoding.
+    Unencodable,
+    /// A routing's SION requirement is unknown
+    UnknownSion(u8),
+    /// A routing's peripheral is unknown
+    UnknownPeripheral(u8),
+    /// A routing's function is unknown; the value is its encoding
+    UnknownFunction(u16),
+    /// A reserved byte isn't zero
+    Reserved,
+    /// A routing's daisy register or value is invalid
+    InvalidDaisy,
+    /// A routing's pad configuration is invalid
+    InvalidConfig,
+    /// The decoded routings don't make a plan
+    Plan(PlanError),
+}
+
+impl From<PlanError> for EncodingError {
+    fn from(err: PlanError) -> Self {
+        EncodingError::Plan(err)
+    }
+}
+
+const HEADER_LEN: usize = 8;
+const CHECKSUM_LEN: usize = 4;
+
+const fn sion_code(sion: SionRequirement) -> u8 {
+    match sion {
+        SionRequirement::Set => 0,
+        SionRequirement::Clear => 1,
+        SionRequirement::DontCare => 2,
+    }
+}
+
+const fn sion_from_code(code: u8) -> Result<SionRequirement, EncodingError> {
+    match code {
+        0 => Ok(SionRequirement::Set),
+        1 => Ok(SionRequirement::Clear),
+        2 => Ok(SionRequirement::DontCare),
+        _ => Err(EncodingError::UnknownSion(code)),
+    }
+}
+
+/// Returns the peripheral code and the SAI module
+fn peripheral_code(peripheral: Option<Peripheral>) -> Result<(u8, u8), EncodingError> {
+    Ok(match peripheral {
+        None => (0, 0),
+        Some(Peripheral::Sai(module)) if module <= u8::MAX as u32 => (1, module as u8),
+        Some(Peripheral::Sai(_)) => return Err(EncodingError::Unencodable),
+        Some(Peripheral::Mqs) => (2, 0),
+        Some(Peripheral::Csi) => (3, 0),
+        Some(Peripheral::Lcdif) => (4, 0),
+    })
+}
+
+fn peripheral_from_code(code: u8, module: u8) -> Result<Option<Peripheral>, EncodingError> {
+    let peripheral = match code {
+        0 => None,
+        1 => return Ok(Some(Peripheral::Sai(module as u32))),
+        2 => Some(Peripheral::Mqs),
+        3 => Some(Peripheral::Csi),
+        4 => Some(Peripheral::Lcdif),
+        _ => return Err(EncodingError::UnknownPeripheral(code)),
+    };
+    if module != 0 {
+        return Err(EncodingError::Reserved);
+    }
+    Ok(peripheral)
+}
+
+fn to_u32(value: usize) -> Result<u32, EncodingError> {
+    if value > u32::MAX as usize {
+        Err(EncodingError::Unencodable)
+    } else {
+        Ok(value as u32)
+    }
+}
+
+fn read_u32(bytes: &[u8; Routing::ENCODED_LEN], offset: usize) -> u32 {
+    let mut word = [0; 4];
+    word.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(word)
+}
+
+/// Continue the CRC-32 (IEEE) `crc` with `bytes`
+///
+/// Start with `!0`, and invert the result.
+fn crc32_update(mut crc: u32, bytes: &[u8]) -> u32 {
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    crc
+}
+
+/// The CRC-32 (IEEE) of `bytes`
+fn crc32(bytes: &[u8]) -> u32 {
+    !crc32_update(!0, bytes)
+}
+
+/// Writes bytes, and computes their checksum
+struct Writer<'a> {
+    out: core::slice::IterMut<'a, u8>,
+    crc: u32,
+}
+
+impl<'a> Writer<'a> {
+    fn new(out: &'a mut [u8]) -> Self {
+        Writer {
+            out: out.iter_mut(),
+            crc: !0,
+        }
+    }
+
+    fn put(&mut self, bytes: &[u8]) {
+        self.crc = crc32_update(self.crc, bytes);
+        for (src, dst) in bytes.iter().zip(self.out.by_ref()) {
+            *dst = *src;
+        }
+    }
+
+    /// Write the checksum
+    fn finish(self) {
+        let crc = !self.crc;
+        for (src, dst) in crc.to_le_bytes().iter().zip(self.out) {
+            *dst = *src;
+        }
+    }
+}
+
+/// Reads fixed-size chunks of bytes
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.bytes.len() < N {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(N);
+        let mut chunk = [0; N];
+        chunk.copy_from_slice(head);
+        self.bytes = tail;
+        Some(chunk)
+    }
+}
+
+impl Routing {
+    /// The number of bytes in an encoded routing
+    ///
+    /// See the [encoding layout](crate::Plan#encoding) for more information.
+    pub const ENCODED_LEN: usize = 24;
+
+    /// Encode this routing
+    ///
+    /// Returns an error if the routing has a value that doesn't fit in its field.
+    /// The encoding doesn't check the routing; [`Plan::encode()`] encodes routings
+    /// that passed [`plan()`](crate::plan()).
+    pub fn to_bytes(&self) -> Result<[u8; Routing::ENCODED_LEN], EncodingError> {
+        let mut bytes = [0; Routing::ENCODED_LEN];
+        bytes[0..4].copy_from_slice(&to_u32(self.pad.0)?.to_le_bytes());
+        bytes[4] = self.alt;
+        bytes[5] = sion_code(self.sion);
+        let (peripheral, module) = peripheral_code(self.peripheral)?;
+        bytes[6] = peripheral;
+        bytes[7] = module;
+        if let Some(daisy) = self.daisy {
+            bytes[8..12].copy_from_slice(&to_u32(daisy.reg())?.to_le_bytes());
+            bytes[12] = daisy.value() as u8;
+        }
+        if let Some(function) = self.function {
+            let bits = match function.to_bits() {
+                Some(bits) => bits,
+                None => return Err(EncodingError::Unencodable),
+            };
+            bytes[13] = 1;
+            bytes[14..16].copy_from_slice(&bits.to_le_bytes());
+        }
+        let (value, mask) = self.config.to_raw();
+        bytes[16..20].copy_from_slice(&value.to_le_bytes());
+        bytes[20..24].copy_from_slice(&mask.to_le_bytes());
+        Ok(bytes)
+    }
+
+    /// Decode a routing from its [`to_bytes()`](Routing::to_bytes) encoding
+    ///
+    /// Returns an error if a field has a value that this version doesn't know. The
+    /// decoder doesn't check the alternate or the pad configuration's fields; use
+    /// [`Plan::decode()`] to check the routing.
+    pub fn from_bytes(bytes: &[u8; Routing::ENCODED_LEN]) -> Result<Self, EncodingError> {
+        let sion = sion_from_code(bytes[5])?;
+        let peripheral = peripheral_from_code(bytes[6], bytes[7])?;
+        let daisy = match (read_u32(bytes, 8), bytes[12] as u32) {
+            (0, 0) => None,
+            (reg, value) if reg != 0 && reg & 0b11 == 0 && value <= Daisy::MAX_VALUE => {
+                Some(Daisy::new(reg as usize, value))
+            }
+            _ => return Err(EncodingError::InvalidDaisy),
+        };
+        let function = match (bytes[13], u16::from_le_bytes([bytes[14], bytes[15]])) {
+            (0, 0) => None,
+            (1, bits) => match Function::from_bits(bits) {
+                Some(function) => Some(function),
+                None => return Err(EncodingError::UnknownFunction(bits)),
+            },
+            _ => return Err(EncodingError::Reserved),
+        };
+        let config = match Config::from_raw(read_u32(bytes, 16), read_u32(bytes, 20)) {
+            Some(config) => config,
+            None => return Err(EncodingError::InvalidConfig),
+        };
+        Ok(Routing {
+            pad: PadId(read_u32(bytes, 0) as usize),
+            alt: bytes[4],
+            sion,
+            daisy,
+            config,
+            peripheral,
+            function,
+        })
+    }
+}
+
+impl<'a> Plan<'a> {
+    /// The first bytes of an encoded plan
+    pub const MAGIC: [u8; 4] = *b"IMXR";
+    /// The version of the encoding
+    pub const VERSION: u8 = 1;
+
+    /// Returns the number of bytes that encode a plan with `routings` routings
+    pub const fn encoded_len(routings: usize) -> usize {
+        HEADER_LEN + routings * Routing::ENCODED_LEN + CHECKSUM_LEN
+    }
+
+    /// Encode this plan into `out`
+    ///
+    /// Returns the number of bytes written, which is [`encoded_len()`](Plan::encoded_len).
+    /// Returns an error, and leaves `out` unchanged, if `out` is too short or a
+    /// routing can't be encoded. See the [encoding layout](crate::Plan#encoding)
+    /// for more information.
+    pub fn encode(&self, out: &mut [u8]) -> Result<usize, EncodingError> {
+        let routings = self.routings();
+        let len = Self::encoded_len(routings.len());
+        if routings.len() > u16::MAX as usize {
+            return Err(EncodingError::Unencodable);
+        }
+        if out.len() < len {
+            return Err(EncodingError::TooShort(len));
+        }
+        // Encode every routing before writing, so that an error leaves out unchanged
+        for routing in routings {
+            routing.to_bytes()?;
+        }
+
+        let mut writer = Writer::new(out);
+        writer.put(&Self::MAGIC);
+        writer.put(&[Self::VERSION, 0]);
+        writer.put(&(routings.len() as u16).to_le_bytes());
+        for routing in routings {
+            writer.put(&routing.to_bytes()?);
+        }
+        writer.finish();
+        Ok(len)
+    }
+
+    /// Decode a plan from its [`encode()`](Plan::encode) encoding
+    ///
+    /// `buf` holds exactly the encoded plan. The routings are decoded into `storage`,
+    /// and the returned plan uses the front of `storage`. Returns an error if the
+    /// encoding is invalid, if `storage` is too small, or if the routings don't pass
+    /// [`plan()`](crate::plan()).
+    pub fn decode(buf: &[u8], storage: &'a mut [Routing]) -> Result<Self, EncodingError> {
+        let mut reader = Reader { bytes: buf };
+        let (magic, [version, reserved], count) =
+            match (reader.take(), reader.take(), reader.take()) {
+                (Some(magic), Some(version), Some(count)) => {
+                    (magic, version, u16::from_le_bytes(count))
+                }
+                _ => return Err(EncodingError::TooShort(Self::encoded_len(0))),
+            };
+        if magic != Self::MAGIC {
+            return Err(EncodingError::MissingMagic);
+        }
+        if version != Self::VERSION {
+            return Err(EncodingError::UnsupportedVersion(version));
+        }
+        if reserved != 0 {
+            return Err(EncodingError::Reserved);
+        }
+        let count = count as usize;
+        let len = Self::encoded_len(count);
+        if buf.len() < len {
+            return Err(EncodingError::TooShort(len));
+        }
+        if buf.len() > len {
+            return Err(EncodingError::TooLong);
+        }
+        let (contents, checksum) = buf.split_at(buf.len() - CHECKSUM_LEN);
+        if crc32(contents).to_le_bytes() != checksum {
+            return Err(EncodingError::Checksum);
+        }
+        let storage = match storage.get_mut(..count) {
+            Some(storage) => storage,
+            None => return Err(EncodingError::TooManyRoutings(count)),
+        };
+
+        for routing in storage.iter_mut() {
+            *routing = match reader.take() {
+                Some(bytes) => Routing::from_bytes(&bytes)?,
+                None => return Err(EncodingError::TooShort(len)),
+            };
+        }
+        let storage: &'a [Routing] = storage;
+        Ok(plan(storage)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{crc32, EncodingError};
+    use crate::function::{SaiSignal, SpiSignal, UartDirection};
+    use crate::{plan, Config, Daisy, PadId, Peripheral, Plan, PlanError, Routing};
+    use crate::{Function, OpenDrain, PullKeeper, RoutingError, SionRequirement, Speed};
+    use std::vec::Vec;
+
+    const UART: Config = Config::zero()
+        .set_pull_keeper(Some(PullKeeper::Pullup100k))
+        .set_speed(Speed::Medium);
+
+    const ROUTINGS: [Routing; 3] = [
+        Routing {
+            pad: PadId(0x401F_80BC),
+            alt: 2,
+            sion: SionRequirement::Clear,
+            daisy: None,
+            config: UART,
+            peripheral: None,
+            function: Some(Function::Lpuart {
+                module: 1,
+                direction: UartDirection::Tx,
+            }),
+        },
+        Routing {
+            pad: PadId(0x401F_80C0),
+            alt: 2,
+            sion: SionRequirement::Set,
+            daisy: Some(Daisy::new(0x401F_853C, 1)),
+            config: Config::modify().set_open_drain(OpenDrain::Enabled),
+            peripheral: None,
+            function: None,
+        },
+        Routing {
+            pad: PadId(0x401F_8188),
+            alt: 3,
+            sion: SionRequirement::DontCare,
+            daisy: Some(Daisy::new(0x401F_8594, 2)),
+            config: Config::zero(),
+            peripheral: Some(Peripheral::Sai(1)),
+            function: None,
+        },
+    ];
+
+    fn encode(routings: &[Routing]) -> Vec<u8> {
+        let plan = plan(routings).unwrap();
+        let mut out = std::vec![0; Plan::encoded_len(routings.len())];
+        assert_eq!(plan.encode(&mut out), Ok(out.len()));
+        out
+    }
+
+    /// Recompute the checksum after changing the encoding
+    fn reseal(buf: &mut [u8]) {
+        let len = buf.len();
+        let checksum = crc32(&buf[..len - 4]);
+        buf[len - 4..].copy_from_slice(&checksum.to_le_bytes());
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn plan_round_trip() {
+        let buf = encode(&ROUTINGS);
+        assert_eq!(buf.len(), 8 + 3 * 24 + 4);
+        assert_eq!(&buf[..8], b"IMXR\x01\x00\x03\x00");
+
+        let mut storage = [ROUTINGS[0]; 5];
+        let decoded = Plan::decode(&buf, &mut storage).unwrap();
+        assert_eq!(decoded.routings(), &ROUTINGS);
+
+        let empty = encode(&[]);
+        assert_eq!(empty.len(), Plan::encoded_len(0));
+        assert_eq!(
+            Plan::decode(&empty, &mut []).map(|plan| plan.routings().len()),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn routing_layout() {
+        let bytes = ROUTINGS[2].to_bytes().unwrap();
+        assert_eq!(&bytes[0..4], &0x401F_8188u32.to_le_bytes());
+        assert_eq!(&bytes[4..8], &[3, 2, 1, 1]);
+        assert_eq!(&bytes[8..12], &0x401F_8594u32.to_le_bytes());
+        assert_eq!(&bytes[12..16], &[2, 0, 0, 0]);
+        assert_eq!(Routing::from_bytes(&bytes), Ok(ROUTINGS[2]));
+
+        let bytes = ROUTINGS[0].to_bytes().unwrap();
+        let bits = ROUTINGS[0].function.unwrap().to_bits().unwrap();
+        assert_eq!(bytes[13], 1);
+        assert_eq!(&bytes[14..16], &bits.to_le_bytes());
+        assert_eq!(Routing::from_bytes(&bytes), Ok(ROUTINGS[0]));
+    }
+
+    #[test]
+    fn every_function_round_trips() {
+        for function in crate::function::tests::representable() {
+            let routing = Routing {
+                peripheral: function.peripheral(),
+                function: Some(function),
+                ..ROUTINGS[0]
+            };
+            let bytes = routing.to_bytes().unwrap();
+            assert_eq!(Routing::from_bytes(&bytes), Ok(routing), "{:?}", function);
+        }
+        let mclk = Function::Sai {
+            module: 3,
+            signal: SaiSignal::Mclk,
+        };
+        assert_eq!(mclk.peripheral(), Some(Peripheral::Sai(3)));
+    }
+
+    #[test]
+    fn unknown_codes() {
+        let bytes = ROUTINGS[0].to_bytes().unwrap();
+        for code in 0..=u8::MAX {
+            let mut sion = bytes;
+            sion[5] = code;
+            let mut peripheral = bytes;
+            peripheral[6] = code;
+            let decoded = (
+                Routing::from_bytes(&sion).is_ok(),
+                Routing::from_bytes(&peripheral).is_ok(),
+            );
+            assert_eq!(decoded, (code <= 2, code <= 4), "code {}", code);
+            if code > 2 {
+                assert_eq!(
+                    Routing::from_bytes(&sion),
+                    Err(EncodingError::UnknownSion(code))
+                );
+            }
+            if code > 4 {
+                assert_eq!(
+                    Routing::from_bytes(&peripheral),
+                    Err(EncodingError::UnknownPeripheral(code))
+                );
+            }
+        }
+
+        let mut module = bytes;
+        module[7] = 1; // A module for a peripheral other than SAI
+        assert_eq!(Routing::from_bytes(&module), Err(EncodingError::Reserved));
+        // A function without the flag that names one
+        let mut reserved = ROUTINGS[1].to_bytes().unwrap();
+        reserved[14] = 1;
+        assert_eq!(Routing::from_bytes(&reserved), Err(EncodingError::Reserved));
+        let mut flag = bytes;
+        flag[13] = 2;
+        assert_eq!(Routing::from_bytes(&flag), Err(EncodingError::Reserved));
+    }
+
+    #[test]
+    fn unknown_function() {
+        let mut buf = encode(&ROUTINGS);
+        // The first routing's function, with a kind that no function uses
+        buf[8 + 14..8 + 16].copy_from_slice(&0xFFFFu16.to_le_bytes());
+        reseal(&mut buf);
+        let mut storage = [ROUTINGS[0]; 3];
+        assert_eq!(
+            Plan::decode(&buf, &mut storage),
+            Err(EncodingError::UnknownFunction(0xFFFF))
+        );
+
+        // Every value that doesn't decode to a function is rejected
+        let bytes = ROUTINGS[0].to_bytes().unwrap();
+        for bits in 0..=u16::MAX {
+            let mut function = bytes;
+            function[14..16].copy_from_slice(&bits.to_le_bytes());
+            let expected = match Function::from_bits(bits) {
+                Some(function) => Ok(Some(function)),
+                None => Err(EncodingError::UnknownFunction(bits)),
+            };
+            assert_eq!(
+                Routing::from_bytes(&function).map(|routing| routing.function),
+                expected,
+                "bits {:#06x}",
+                bits
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_fields() {
+        let bytes = ROUTINGS[1].to_bytes().unwrap();
+        let mut value = bytes;
+        value[12] = 8;
+        assert_eq!(
+            Routing::from_bytes(&value),
+            Err(EncodingError::InvalidDaisy)
+        );
+        let mut reg = bytes;
+        reg[8] |= 1;
+        assert_eq!(Routing::from_bytes(&reg), Err(EncodingError::InvalidDaisy));
+        let mut missing = bytes;
+        missing[8..12].copy_from_slice(&[0; 4]);
+        assert_eq!(
+            Routing::from_bytes(&missing),
+            Err(EncodingError::InvalidDaisy)
+        );
+
+        // A configuration value outside of its mask
+        let mut config = bytes;
+        config[20..24].copy_from_slice(&[0; 4]);
+        assert_eq!(
+            Routing::from_bytes(&config),
+            Err(EncodingError::InvalidConfig)
+        );
+    }
+
+    #[test]
+    fn corrupt_plans() {
+        let buf = encode(&ROUTINGS);
+        let mut storage = [ROUTINGS[0]; 3];
+
+        assert_eq!(
+            Plan::decode(&buf[..7], &mut storage),
+            Err(EncodingError::TooShort(12))
+        );
+        assert_eq!(
+            Plan::decode(&buf[..buf.len() - 1], &mut storage),
+            Err(EncodingError::TooShort(buf.len()))
+        );
+        let mut long = buf.clone();
+        long.push(0);
+        assert_eq!(
+            Plan::decode(&long, &mut storage),
+            Err(EncodingError::TooLong)
+        );
+
+        let mut magic = buf.clone();
+        magic[0] = b'X';
+        assert_eq!(
+            Plan::decode(&magic, &mut storage),
+            Err(EncodingError::MissingMagic)
+        );
+        let mut version = buf.clone();
+        version[4] = 2;
+        assert_eq!(
+            Plan::decode(&version, &mut storage),
+            Err(EncodingError::UnsupportedVersion(2))
+        );
+
+        // Every flipped bit after the header fails the checksum
+        for bit in 8 * 8..buf.len() * 8 {
+            let mut flipped = buf.clone();
+            flipped[bit / 8] ^= 1 << (bit % 8);
+            assert_eq!(
+                Plan::decode(&flipped, &mut storage),
+                Err(EncodingError::Checksum),
+                "bit {}",
+                bit
+            );
+        }
+
+        assert_eq!(
+            Plan::decode(&buf, &mut storage[..2]),
+            Err(EncodingError::TooManyRoutings(3))
+        );
+    }
+
+    #[test]
+    fn decoded_routings_must_plan() {
+        let mut buf = encode(&ROUTINGS);
+        // Route the second pad to the first pad
+        buf[8 + 24..8 + 28].copy_from_slice(&0x401F_80BCu32.to_le_bytes());
+        reseal(&mut buf);
+        let mut storage = [ROUTINGS[0]; 3];
+        assert_eq!(
+            Plan::decode(&buf, &mut storage),
+            Err(EncodingError::Plan(PlanError::DuplicatePad {
+                pad: PadId(0x401F_80BC),
+                earlier: 0,
+                later: 1,
+            }))
+        );
+
+        let mut buf = encode(&ROUTINGS);
+        buf[8 + 4] = 0x10; // An alternate that doesn't fit
+        reseal(&mut buf);
+        assert_eq!(
+            Plan::decode(&buf, &mut storage),
+            Err(EncodingError::Plan(PlanError::Routing {
+                index: 0,
+                error: RoutingError::InvalidAlternate(0x10),
+            }))
+        );
+    }
+
+    #[test]
+    fn encode_errors() {
+        let plan = plan(&ROUTINGS).unwrap();
+        let mut out = [0xAA; 80];
+        assert_eq!(plan.encode(&mut out), Err(EncodingError::TooShort(84)));
+        assert_eq!(out, [0xAA; 80]);
+
+        let sai = [Routing {
+            peripheral: Some(Peripheral::Sai(256)),
+            ..ROUTINGS[2]
+        }];
+        let mut out = [0xAA; 36];
+        assert_eq!(
+            crate::plan(&sai).unwrap().encode(&mut out),
+            Err(EncodingError::Unencodable)
+        );
+        assert_eq!(out, [0xAA; 36]);
+
+        let spi = [Routing {
+            function: Some(Function::Lpspi {
+                module: 16,
+                signal: SpiSignal::Sck,
+            }),
+            ..ROUTINGS[0]
+        }];
+        assert_eq!(
+            crate::plan(&spi).unwrap().encode(&mut out),
+            Err(EncodingError::Unencodable)
+        );
+        assert_eq!(out, [0xAA; 36]);
+    }
+}
@@ -0,0 +1,460 @@
+//! Crate-level error type
+
+use crate::WrongPadError;
+use crate::{
+    flexspi::FcbError, xbar::LineError, ConfigError, EncodingError, InvalidFieldError, Peripheral,
+    PlanError, RoutingError,
+};
+use core::fmt;
+
+/// Any error returned by `imxrt-iomuxc`
+///
+/// Every fallible API returns an error that converts into `Error`. Use `Error`
+/// in your driver's error type, instead of naming each `imxrt-iomuxc` error.
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use imxrt_iomuxc::{Error, Speed};
+///
+/// fn speed(field: u32) -> Result<Speed, Error> {
+///     Ok(Speed::try_from(field)?)
+/// }
+///
+/// assert!(speed(2).is_ok());
+/// assert!(matches!(speed(4), Err(Error::InvalidField(_))));
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum Error {
+    /// An erased pad could not convert into a strongly-typed pad
+    WrongPad(WrongPadError),
+    /// A field encoding does not map to a configuration
+    InvalidField(InvalidFieldError),
+    /// A configuration combines fields that don't make sense
+    Config(ConfigError),
+    /// A buffer is not a FlexSPI configuration block
+    Fcb(FcbError),
+    /// A run-time routing could not be applied
+    Routing(RoutingError),
+    /// A set of routings interfere with each other
+    Plan(PlanError),
+    /// An XBAR pin could not be prepared at run time
+    Xbar(LineError),
+    /// A plan could not be encoded or decoded
+    Encoding(EncodingError),
+}
+
+impl From<WrongPadError> for Error {
+    fn from(err: WrongPadError) -> Self {
+        Error::WrongPad(err)
+    }
+}
+
+impl From<InvalidFieldError> for Error {
+    fn from(err: InvalidFieldError) -> Self {
+        Error::InvalidField(err)
+    }
+}
+
+impl From<ConfigError> for Error {
+    fn from(err: ConfigError) -> Self {
+        Error::Config(err)
+    }
+}
+
+impl From<FcbError> for Error {
+    fn from(err: FcbError) -> Self {
+        Error::Fcb(err)
+    }
+}
+
+impl From<RoutingError> for Error {
+    fn from(err: RoutingError) -> Self {
+        Error::Routing(err)
+    }
+}
+
+impl From<PlanError> for Error {
+    fn from(err: PlanError) -> Self {
+        Error::Plan(err)
+    }
+}
+
+impl From<LineError> for Error {
+    fn from(err: LineError) -> Self {
+        Error::Xbar(err)
+    }
+}
+
+impl From<EncodingError> for Error {
+    fn from(err: EncodingError) -> Self {
+        Error::Encoding(err)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for WrongPadError {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "pad {=usize:#010x} is not the requested pad",
+            self.0.id().mux_addr()
+        )
+    }
+}
+
+/// `Display` messages for each error
+#[cfg(not(feature = "min-size"))]
+mod display {
+    use super::*;
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Error::WrongPad(err) => err.fmt(f),
+                Error::InvalidField(err) => err.fmt(f),
+                Error::Config(err) => err.fmt(f),
+                Error::Fcb(err) => err.fmt(f),
+                Error::Routing(err) => err.fmt(f),
+                Error::Plan(err) => err.fmt(f),
+                Error::Xbar(err) => err.fmt(f),
+                Error::Encoding(err) => err.fmt(f),
+            }
+        }
+    }
+
+    impl fmt::Display for WrongPadError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "pad {:#010x} is not the requested pad",
+                self.0.id().mux_addr()
+            )
+        }
+    }
+
+    impl fmt::Display for InvalidFieldError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "invalid field encoding {:#x}", self.0)
+        }
+    }
+
+    impl fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(match self {
+                ConfigError::OpenDrainPulldown => "open drain with the pull-down",
+                ConfigError::OpenDrainKeeper => "open drain with the keeper",
+                ConfigError::MaxSpeedSlowSlew => "maximum speed with the slow slew rate",
+            })
+        }
+    }
+
+    impl fmt::Display for FcbError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(match self {
+                FcbError::TooShort => "buffer is too short for an FCB",
+                FcbError::MissingTag => "buffer does not start with the FCB tag",
+            })
+        }
+    }
+
+    impl fmt::Display for RoutingError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                RoutingError::UnknownPad(id) => write!(f, "unknown pad {:#010x}", id.mux_addr()),
+                RoutingError::InvalidAlternate(alt) => write!(f, "invalid alternate {}", alt),
+                RoutingError::Config(err) => err.fmt(f),
+                RoutingError::UnsupportedPad(id) => {
+                    write!(
+                        f,
+                        "pad {:#010x} does not support the function",
+                        id.mux_addr()
+                    )
+                }
+            }
+        }
+    }
+
+    impl fmt::Display for LineError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                LineError::UnknownLine(line) => write!(f, "unknown XBAR input {}", line),
+                LineError::HardwiredLine(line) => {
+                    write!(f, "XBAR input {} is not an IOMUX line", line)
+                }
+                LineError::Routing(err) => err.fmt(f),
+            }
+        }
+    }
+
+    impl fmt::Display for EncodingError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                EncodingError::TooShort(len) => write!(f, "the encoding needs {} bytes", len),
+                EncodingError::TooLong => f.write_str("buffer is longer than the encoding"),
+                EncodingError::MissingMagic => {
+                    f.write_str("buffer does not start with the plan magic")
+                }
+                EncodingError::UnsupportedVersion(version) => {
+                    write!(f, "unsupported encoding version {}", version)
+                }
+                EncodingError::Checksum => f.write_str("checksum mismatch"),
+                EncodingError::TooManyRoutings(count) => {
+                    write!(f, "no storage for {} routings", count)
+                }
+                EncodingError::Unencodable => f.write_str("value does not fit in its field"),
+                EncodingError::UnknownSion(code) => {
+                    write!(f, "unknown SION requirement {}", code)
+                }
+                EncodingError::UnknownPeripheral(code) => write!(f, "unknown peripheral {}", code),
+                EncodingError::UnknownFunction(bits) => {
+                    write!(f, "unknown function {:#06x}", bits)
+                }
+                EncodingError::Reserved => f.write_str("reserved byte is not zero"),
+                EncodingError::InvalidDaisy => f.write_str("invalid daisy"),
+                EncodingError::InvalidConfig => f.write_str("invalid pad configuration"),
+                EncodingError::Plan(err) => err.fmt(f),
+            }
+        }
+    }
+
+    impl fmt::Display for Peripheral {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Peripheral::Sai(module) => write!(f, "SAI{}", module),
+                Peripheral::Mqs => f.write_str("MQS"),
+                Peripheral::Csi => f.write_str("CSI"),
+                Peripheral::Lcdif => f.write_str("eLCDIF"),
+            }
+        }
+    }
+
+    impl fmt::Display for PlanError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                PlanError::Routing { index, error } => write!(f, "routing {}: {}", index, error),
+                PlanError::DuplicatePad {
+                    pad,
+                    earlier,
+                    later,
+                } => write!(
+                    f,
+                    "routings {} and {} both use pad {:#010x}",
+                    earlier,
+                    later,
+                    pad.mux_addr()
+                ),
+                PlanError::DaisyConflict(conflict) => write!(
+                    f,
+                    "routings {} and {} select different pads with daisy register {:#010x}",
+                    conflict.earlier, conflict.later, conflict.reg
+                ),
+                PlanError::BorrowConflict {
+                    peripheral,
+                    borrower,
+                    user,
+                } => write!(
+                    f,
+                    "routing {} borrows {}, which routing {} uses",
+                    borrower, peripheral, user
+                ),
+                PlanError::ReservedPad {
+                    pad,
+                    peripheral,
+                    index,
+                    user,
+                } => write!(
+                    f,
+                    "routing {} uses pad {:#010x}, which {} reserves for routing {}",
+                    index,
+                    pad.mux_addr(),
+                    peripheral,
+                    user
+                ),
+                PlanError::ImpossibleAssignment {
+                    index,
+                    pad,
+                    function,
+                } => write!(
+                    f,
+                    "assignment {}: pad {:#010x} can't carry {}",
+                    index,
+                    pad.mux_addr(),
+                    function
+                ),
+                PlanError::TooManyAssignments(count) => {
+                    write!(f, "no storage for {} assignments", count)
+                }
+            }
+        }
+    }
+}
+
+/// With `min-size`, each error displays as its type name
+#[cfg(feature = "min-size")]
+mod display {
+    use super::*;
+
+    macro_rules! display_name {
+        ($($ty:ident),+) => {
+            $(
+                impl fmt::Display for $ty {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.write_str(stringify!($ty))
+                    }
+                }
+            )+
+        };
+    }
+
+    display_name!(
+        Error,
+        WrongPadError,
+        InvalidFieldError,
+        ConfigError,
+        FcbError,
+        RoutingError,
+        Peripheral,
+        PlanError,
+        LineError,
+        EncodingError
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::Error;
+    use crate::{consts::*, ConfigError, InvalidFieldError, OpenDrain, Pad, Speed, WrongPadError};
+    use crate::{Peripheral, PlanError};
+    use core::convert::TryFrom;
+    use std::string::ToString;
+
+    #[derive(Debug)]
+    struct ErrorBase;
+
+    unsafe impl crate::Base for ErrorBase {
+        fn mux_base() -> *mut u32 {
+            0x5000 as *mut u32
+        }
+        fn pad_base() -> *mut u32 {
+            0x5200 as *mut u32
+        }
+    }
+
+    /// The message for an `Error`, which is the type name with `min-size`
+    fn message(full: &'static str) -> &'static str {
+        if cfg!(feature = "min-size") {
+            "Error"
+        } else {
+            full
+        }
+    }
+
+    fn wrong_pad() -> Result<Pad<ErrorBase, U1>, Error> {
+        let erased = unsafe { Pad::<ErrorBase, U3>::new() }.erase();
+        Ok(Pad::try_from(erased)?)
+    }
+
+    #[test]
+    fn wrong_pad_error() {
+        let err = wrong_pad().unwrap_err();
+        assert!(matches!(err, Error::WrongPad(WrongPadError(ref pad)) if pad.offset() == 3));
+        assert_eq!(
+            err.to_string(),
+            message("pad 0x0000500c is not the requested pad")
+        );
+    }
+
+    #[test]
+    fn invalid_field_error() {
+        let err = Error::from(Speed::try_from(4).unwrap_err());
+        assert!(matches!(err, Error::InvalidField(InvalidFieldError(4))));
+        assert_eq!(err.to_string(), message("invalid field encoding 0x4"));
+
+        let err = Error::from(OpenDrain::from_field(2).unwrap_err());
+        assert!(matches!(err, Error::InvalidField(InvalidFieldError(2))));
+    }
+
+    #[test]
+    fn config_error() {
+        let config = crate::Config::modify()
+            .set_speed(Speed::Max)
+            .set_slew_rate(crate::SlewRate::Slow);
+        let err = Error::from(config.validate().unwrap_err());
+        assert!(matches!(err, Error::Config(ConfigError::MaxSpeedSlowSlew)));
+        assert_eq!(
+            err.to_string(),
+            message("maximum speed with the slow slew rate")
+        );
+    }
+
+    #[test]
+    fn borrow_conflict_error() {
+        let err = Error::from(PlanError::BorrowConflict {
+            peripheral: Peripheral::Sai(3),
+            borrower: 2,
+            user: 0,
+        });
+        assert_eq!(
+            err.to_string(),
+            message("routing 2 borrows SAI3, which routing 0 uses")
+        );
+    }
+
+    #[test]
+    fn reserved_pad_error() {
+        let err = Error::from(PlanError::ReservedPad {
+            pad: crate::PadId(0x5004),
+            peripheral: Peripheral::Csi,
+            index: 1,
+            user: 0,
+        });
+        assert_eq!(
+            err.to_string(),
+            message("routing 1 uses pad 0x00005004, which CSI reserves for routing 0")
+        );
+    }
+
+    #[test]
+    fn impossible_assignment_error() {
+        let err = Error::from(PlanError::ImpossibleAssignment {
+            index: 1,
+            pad: crate::PadId(0x5004),
+            function: crate::Function::Mqs(crate::function::MqsSignal::Left),
+        });
+        assert_eq!(
+            err.to_string(),
+            message("assignment 1: pad 0x00005004 can't carry MQS_LEFT")
+        );
+    }
+
+    #[test]
+    fn xbar_line_error() {
+        let err = Error::from(crate::xbar::LineError::HardwiredLine(1));
+        assert_eq!(
+            err.to_string(),
+            message("XBAR input 1 is not an IOMUX line")
+        );
+    }
+
+    #[test]
+    fn encoding_error() {
+        let err = Error::from(crate::EncodingError::UnknownPeripheral(9));
+        assert!(matches!(
+            err,
+            Error::Encoding(crate::EncodingError::UnknownPeripheral(9))
+        ));
+        assert_eq!(err.to_string(), message("unknown peripheral 9"));
+    }
+
+    #[test]
+    #[cfg(feature = "min-size")]
+    fn min_size_type_names() {
+        assert_eq!(Peripheral::Mqs.to_string(), "Peripheral");
+        assert_eq!(InvalidFieldError(4).to_string(), "InvalidFieldError");
+        assert_eq!(ConfigError::OpenDrainKeeper.to_string(), "ConfigError");
+    }
+}
@@ -0,0 +1,389 @@
+//! FlexIO pad configuration
+//!
+//! A FlexIO pin is one of the numbered pins of a FlexIO module. Use [`prepare()`]
+//! to prepare any single FlexIO pin.
+//!
+//! FlexIO pins have no daisy registers or other input selections. A FlexIO module
+//! reads its pins through the mux alone. On the i.MX RT 1060, `FLEXIO2` and `FLEXIO3`
+//! share the `GPIO_B1` pads: alternate 4 selects `FLEXIO2`, and alternate 9 selects
+//! `FLEXIO3`. Those pads are `FLEXIO2` [`Pin`]s; use [`PinFor`] and [`prepare_for()`]
+//! to select `FLEXIO3`.
+//!
+//! # Consecutive pins
+//!
+//! Multi-pin FlexIO protocols, like a parallel display bus or camera capture,
+//! shift data over a range of consecutive FlexIO pins on one module.
+//! [`ConsecutivePins`] bundles those pins, and prepares all of them. Supply the
+//! pins as a tuple, starting with the lowest pin index. The bundle only accepts
+//! pins on the same module, with indices that increase by one, so your driver
+//! can program its shifters from [`first_index()`](ConsecutivePins::first_index)
+//! and [`width()`](ConsecutivePins::width).
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{flexio, imxrt1060::gpio_b0::*};
+//!
+//! let pins = flexio::ConsecutivePins::new(unsafe {
+//!     (
+//!         GPIO_B0_04::new(),
+//!         GPIO_B0_05::new(),
+//!         GPIO_B0_06::new(),
+//!         GPIO_B0_07::new(),
+//!     )
+//! });
+//! assert_eq!(pins.first_index(), 4);
+//! assert_eq!(pins.width(), 4);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! The pin indices must not have a gap. `GPIO_B0_07` is `FLEXIO2_FLEXIO07`, so it
+//! cannot follow `FLEXIO2_FLEXIO05`:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{flexio, imxrt1060::gpio_b0::*};
+//!
+//! let pins = flexio::ConsecutivePins::new(unsafe {
+//!     (
+//!         GPIO_B0_04::new(),
+//!         GPIO_B0_05::new(),
+//!         GPIO_B0_07::new(),
+//!         GPIO_B0_08::new(),
+//!     )
+//! });
+//! ```
+//!
+//! The pins must be on the same module. `GPIO_AD_B1_05` is `FLEXIO3_FLEXIO05`, so
+//! it cannot follow `FLEXIO2_FLEXIO04`:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{flexio, imxrt1060::{gpio_ad_b1::*, gpio_b0::*}};
+//!
+//! let pins = flexio::ConsecutivePins::new(unsafe {
+//!     (
+//!         GPIO_B0_04::new(),
+//!         GPIO_AD_B1_05::new(),
+//!     )
+//! });
+//! ```
+
+use crate::consts::*;
+use core::ops::Add;
+use typenum::operator_aliases::Sum;
+
+/// A FlexIO pin
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the FlexIO pin
+    const ALT: u32;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+    const SION: super::SionRequirement = super::SionRequirement::DontCare;
+    /// The FlexIO module; `U2` for `FLEXIO2`
+    type Module: Unsigned;
+    /// The FlexIO pin index; `U3` for `FLEXIO2_FLEXIO03`
+    type Index: Unsigned;
+}
+
+module_aliases!("FLEXIO":
+    1 => Flexio1 = U1,
+    2 => Flexio2 = U2,
+    3 => Flexio3 = U3,
+);
+
+/// Prepare a FlexIO pin
+///
+/// If you do not call `prepare()` on your FlexIO pin, it might not work as a
+/// FlexIO pin.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    trace!("flexio::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, None);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!(
+        "flexio::prepare_idempotent mux={:#010x}",
+        pin.mux() as usize
+    );
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, None)
+}
+
+prepared_pin!("FlexIO");
+
+/// Compute the register writes that prepare a FlexIO pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, None)
+}
+
+/// A FlexIO pin for FlexIO module `M`; `PinFor<U3>` for a `FLEXIO3` pin
+///
+/// A pad implements [`Pin`] for one FlexIO module. But the reference manual may route
+/// more than one FlexIO module to the same pad, each on a different alternate. A pad
+/// implements `PinFor` for every FlexIO module that it supports, including the
+/// [`Pin::Module`]. On the i.MX RT 1060, `GPIO_B1_00` is both `FLEXIO2_FLEXIO16`
+/// and `FLEXIO3_FLEXIO16`:
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{consts::*, flexio, imxrt1060::gpio_b1::*};
+///
+/// let mut pin = unsafe { GPIO_B1_00::new() };
+/// flexio::prepare_for::<U3, _>(&mut pin);
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+pub trait PinFor<M: Unsigned>: super::Iomuxc {
+    /// The alternate value for the FlexIO pin
+    const ALT: u32;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+    const SION: super::SionRequirement = super::SionRequirement::DontCare;
+    /// The FlexIO pin index; `U3` for `FLEXIO3_FLEXIO03`
+    type Index: Unsigned;
+}
+
+/// Prepare a FlexIO pin for FlexIO module `M`
+///
+/// Like [`prepare()`], but selects the routing to module `M`.
+pub fn prepare_for<M: Unsigned, P: PinFor<M>>(pin: &mut P) {
+    trace!("flexio::prepare_for mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, None);
+}
+
+/// Compute the register writes that prepare a FlexIO pin for FlexIO module `M`
+///
+/// Like [`prepared()`], but selects the routing to module `M`.
+pub const fn prepared_for<M, P>(config: super::Config) -> super::PreparedWrites
+where
+    M: Unsigned,
+    P: PinFor<M> + super::ConstIomuxc,
+{
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, None)
+}
+
+/// FlexIO pins with consecutive pin indices on the same module
+///
+/// Implemented for tuples of two through eight pins, and for tuples of 16 pins.
+/// The first pin has the lowest index.
+pub trait Consecutive: private::Sealed {
+    /// The FlexIO module of all pins
+    type Module: Unsigned;
+    /// The index of the first pin
+    type First: Unsigned;
+    /// The number of pins
+    type Width: Unsigned;
+    /// Prepare all of the pins
+    fn prepare(&mut self);
+}
+
+macro_rules! consecutive {
+    ($width:ty: $first:ident $(, $pin:ident + $offset:ty => $n:tt)+) => {
+        impl<$first, $($pin),+> private::Sealed for ($first, $($pin,)+) {}
+        impl<$first, $($pin),+> Consecutive for ($first, $($pin,)+)
+        where
+            $first: Pin,
+            $(
+                <$first as Pin>::Index: Add<$offset>,
+                $pin: Pin<Module = <$first as Pin>::Module, Index = Sum<<$first as Pin>::Index, $offset>>,
+            )+
+        {
+            type Module = <$first as Pin>::Module;
+            type First = <$first as Pin>::Index;
+            type Width = $width;
+            fn prepare(&mut self) {
+                prepare(&mut self.0);
+                $(prepare(&mut self.$n);)+
+            }
+        }
+    };
+}
+
+consecutive!(U2: P0, P1 + U1 => 1);
+consecutive!(U3: P0, P1 + U1 => 1, P2 + U2 => 2);
+consecutive!(U4: P0, P1 + U1 => 1, P2 + U2 => 2, P3 + U3 => 3);
+consecutive!(U5: P0, P1 + U1 => 1, P2 + U2 => 2, P3 + U3 => 3, P4 + U4 => 4);
+consecutive!(U6: P0, P1 + U1 => 1, P2 + U2 => 2, P3 + U3 => 3, P4 + U4 => 4, P5 + U5 => 5);
+consecutive!(U7: P0, P1 + U1 => 1, P2 + U2 => 2, P3 + U3 => 3, P4 + U4 => 4, P5 + U5 => 5,
+    P6 + U6 => 6
+);
+consecutive!(U8: P0, P1 + U1 => 1, P2 + U2 => 2, P3 + U3 => 3, P4 + U4 => 4, P5 + U5 => 5,
+    P6 + U6 => 6, P7 + U7 => 7
+);
+consecutive!(U16: P0, P1 + U1 => 1, P2 + U2 => 2, P3 + U3 => 3, P4 + U4 => 4, P5 + U5 => 5,
+    P6 + U6 => 6, P7 + U7 => 7, P8 + U8 => 8, P9 + U9 => 9, P10 + U10 => 10, P11 + U11 => 11,
+    P12 + U12 => 12, P13 + U13 => 13, P14 + U14 => 14, P15 + U15 => 15
+);
+
+/// FlexIO pins with consecutive pin indices on the same module
+///
+/// See the [module-level documentation](crate::flexio) for an example.
+#[derive(Debug)]
+pub struct ConsecutivePins<Pins> {
+    /// The pins, starting with the lowest pin index
+    pub pins: Pins,
+}
+
+impl<Pins: Consecutive> ConsecutivePins<Pins> {
+    /// The FlexIO module; `2` for `FLEXIO2`
+    pub const MODULE: u32 = Pins::Module::U32;
+    /// The index of the first pin
+    pub const FIRST_INDEX: u32 = Pins::First::U32;
+    /// The number of pins
+    pub const WIDTH: u32 = Pins::Width::U32;
+
+    /// Bundle and prepare the FlexIO pins
+    pub fn new(mut pins: Pins) -> Self {
+        pins.prepare();
+        ConsecutivePins { pins }
+    }
+
+    /// Returns the FlexIO module; `2` for `FLEXIO2`
+    pub const fn module(&self) -> u32 {
+        Self::MODULE
+    }
+
+    /// Returns the index of the first pin
+    pub const fn first_index(&self) -> u32 {
+        Self::FIRST_INDEX
+    }
+
+    /// Returns the number of pins
+    pub const fn width(&self) -> u32 {
+        Self::WIDTH
+    }
+
+    /// Release the pins
+    pub fn release(self) -> Pins {
+        self.pins
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Returns the [`Function`](crate::Function) of a FlexIO pin
+pub const fn function<P: Pin>() -> super::Function {
+    super::Function::FlexIo {
+        module: P::Module::U8,
+        index: P::Index::U8,
+    }
+}
+
+/// Returns the [`Function`](crate::Function) of a FlexIO pin routed to module `M`
+///
+/// Use this for a pad that implements [`PinFor<M>`](PinFor), like `GPIO_B1_00` on `FLEXIO3`.
+pub const fn function_for<M: Unsigned, P: PinFor<M>>() -> super::Function {
+    super::Function::FlexIo {
+        module: M::U8,
+        index: P::Index::U8,
+    }
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! flexio {
+    (module: $module:ty, alt: $alt:expr, pad: $pad:ty, index: $index:ty) => {
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            type Module = $module;
+            type Index = $index;
+        }
+        flexio!(for module: $module, alt: $alt, pad: $pad, index: $index);
+    };
+    // A routing to another module, for a pad that already implements Pin
+    (for module: $module:ty, alt: $alt:expr, pad: $pad:ty, index: $index:ty) => {
+        impl crate::flexio::PinFor<$module> for $pad {
+            const ALT: u32 = $alt;
+            type Index = $index;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConsecutivePins, Pin};
+    use crate::{consts::*, Iomuxc};
+    use core::marker::PhantomData;
+
+    struct FakePad<Module, Index> {
+        mux: u32,
+        pad: u32,
+        _pin: PhantomData<(Module, Index)>,
+    }
+
+    fn pad<Module, Index>() -> FakePad<Module, Index> {
+        FakePad {
+            mux: 1 << 4 | 5,
+            pad: 0,
+            _pin: PhantomData,
+        }
+    }
+
+    impl<Module, Index> crate::private::Sealed for FakePad<Module, Index> {}
+
+    unsafe impl<Module, Index> Iomuxc for FakePad<Module, Index> {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    impl<Module: Unsigned, Index: Unsigned> Pin for FakePad<Module, Index> {
+        const ALT: u32 = 4;
+        type Module = Module;
+        type Index = Index;
+    }
+
+    #[test]
+    fn consecutive_pins_prepare_all_pins() {
+        let pins = ConsecutivePins::new((
+            pad::<U3, U14>(),
+            pad::<U3, U15>(),
+            pad::<U3, U16>(),
+            pad::<U3, U17>(),
+            pad::<U3, U18>(),
+        ));
+        assert_eq!(
+            (pins.module(), pins.first_index(), pins.width()),
+            (3, 14, 5)
+        );
+        let (p0, _, _, _, p4) = pins.release();
+        // SION is untouched
+        assert_eq!((p0.mux, p4.mux), (1 << 4 | 4, 1 << 4 | 4));
+    }
+
+    #[test]
+    fn sixteen_pins() {
+        let pins = ConsecutivePins::new((
+            pad::<U2, U16>(),
+            pad::<U2, U17>(),
+            pad::<U2, U18>(),
+            pad::<U2, U19>(),
+            pad::<U2, U20>(),
+            pad::<U2, U21>(),
+            pad::<U2, U22>(),
+            pad::<U2, U23>(),
+            pad::<U2, U24>(),
+            pad::<U2, U25>(),
+            pad::<U2, U26>(),
+            pad::<U2, U27>(),
+            pad::<U2, U28>(),
+            pad::<U2, U29>(),
+            pad::<U2, U30>(),
+            pad::<U2, U31>(),
+        ));
+        assert_eq!((pins.first_index(), pins.width()), (16, 16));
+        assert_eq!(pins.pins.15.mux, 1 << 4 | 4);
+    }
+}
@@ -1,25 +1,99 @@
 //! PWM pad configuration
+//!
+//! A PWM pad either drives a submodule's output, or feeds the submodule's input
+//! capture. Use [`prepare()`] for an output, and [`prepare_capture()`] for a
+//! capture input. The two roles may be mixed within a submodule; a tachometer
+//! might drive `PWM_A` while capturing on `PWM_B`:
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{flexpwm, imxrt1060::gpio_sd_b0::*};
+//!
+//! // PWM1_SM0
+//! let mut drive = unsafe { GPIO_SD_B0_00::new() }; // PWM_A
+//! let mut tach = unsafe { GPIO_SD_B0_01::new() }; // PWM_B
+//! flexpwm::prepare(&mut drive);
+//! flexpwm::prepare_capture(&mut tach);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! # X outputs
+//!
+//! Besides `A` and `B`, each submodule has an auxiliary `X` signal. When the
+//! submodule doesn't drive `PWM_X`, `PWM_X` can be an input capture source. On the
+//! i.MX RT 1060, the X pads don't have a daisy register; each X signal has only
+//! one pad. So, `prepare_capture()` on an X pad selects the alternate without a
+//! daisy write.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{flexpwm, imxrt1060::gpio_ad_b0::*};
+//!
+//! // PWM1_SM0, output X
+//! let mut capture = unsafe { GPIO_AD_B0_02::new() };
+//! flexpwm::prepare_capture(&mut capture);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! # Safe outputs
+//!
+//! A motor-drive output must not float high while the MCU resets, or while you
+//! reconfigure the pad. Use [`prepare_with_pull()`] to pull the output to a safe level.
+//! `prepare_with_pull()` configures the pad with [`SAFE_OUTPUT_CONFIG`] and your pull
+//! *before* it switches the pad to PWM, so the pad doesn't glitch during the mux change.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{flexpwm, imxrt1060::gpio_sd_b0::*, PullKeeper};
+//!
+//! let mut high_side = unsafe { GPIO_SD_B0_00::new() };
+//! flexpwm::prepare_with_pull(&mut high_side, PullKeeper::Pulldown100k);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
 
-/// A PWM output identified; one of `A` or `B`
+/// A PWM output identified; one of `A`, `B`, or `X`
 pub trait Output: private::Sealed {}
 /// PWM output A
 pub enum A {}
 /// PWM output B
 pub enum B {}
+/// PWM auxiliary output X
+pub enum X {}
 
 impl Output for A {}
 impl Output for B {}
+impl Output for X {}
 
 mod private {
-    pub trait Sealed {}
-    impl Sealed for super::A {}
-    impl Sealed for super::B {}
+    use crate::function::PwmOutput;
+
+    pub trait Sealed {
+        const OUTPUT: PwmOutput;
+    }
+    impl Sealed for super::A {
+        const OUTPUT: PwmOutput = PwmOutput::A;
+    }
+    impl Sealed for super::B {
+        const OUTPUT: PwmOutput = PwmOutput::B;
+    }
+    impl Sealed for super::X {
+        const OUTPUT: PwmOutput = PwmOutput::X;
+    }
 }
 
 /// A PWM pin
 pub trait Pin: super::Iomuxc {
     /// The alternate mode for the PWM pin
     const ALT: u32;
+    /// The daisy register which will select the pad for input capture
+    const DAISY: Option<super::Daisy>;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+    const SION: super::SionRequirement = super::SionRequirement::DontCare;
     /// The output identifier
     type Output: Output;
     /// The PWM module; `U2` is `PWM2`
@@ -28,23 +102,216 @@ pub trait Pin: super::Iomuxc {
     type Submodule: super::consts::Unsigned;
 }
 
-/// Prepare a PWM pin
+module_aliases!("FLEXPWM":
+    1 => Flexpwm1 = U1,
+    2 => Flexpwm2 = U2,
+    3 => Flexpwm3 = U3,
+    4 => Flexpwm4 = U4,
+);
+
+/// Prepare a PWM pin as an output
+///
+/// `prepare()` does not commit the pin's daisy register. To use the pin as a
+/// capture input, use [`prepare_capture()`].
 ///
 /// # Safety
 ///
 /// `prepare()` inherits all the unsafety of the `IOMUX` supertrait.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
+    trace!("flexpwm::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, None);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!(
+        "flexpwm::prepare_idempotent mux={:#010x}",
+        pin.mux() as usize
+    );
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, None)
+}
+
+prepared_pin!("PWM");
+
+/// A pad configuration for PWM outputs that must not float
+///
+/// Enables the 100KOhm pull-down, the fast slew rate, and a medium drive strength.
+/// `SAFE_OUTPUT_CONFIG` only modifies those fields. See [`prepare_with_pull()`].
+pub const SAFE_OUTPUT_CONFIG: super::Config = super::Config::modify()
+    .set_pull_keeper(Some(super::PullKeeper::Pulldown100k))
+    .set_slew_rate(super::SlewRate::Fast)
+    .set_drive_strength(super::DriveStrength::R0_4);
+
+/// Prepare a PWM pin as an output, with a pull
+///
+/// `prepare_with_pull()` configures the pad with [`SAFE_OUTPUT_CONFIG`], replacing
+/// the pull-down with `pull`. Then, it does the work of [`prepare()`]. Since the pad
+/// is configured before the mux changes, the output is never left floating.
+pub fn prepare_with_pull<P: Pin>(pin: &mut P, pull: super::PullKeeper) {
+    trace!(
+        "flexpwm::prepare_with_pull mux={:#010x}",
+        pin.mux() as usize
+    );
+    let config = SAFE_OUTPUT_CONFIG.set_pull_keeper(Some(pull));
+    super::prepare_pin_configured(pin, config, P::ALT, P::SION, None);
+}
+
+/// Prepare a PWM pin as an input capture source
+///
+/// In addition to the work of [`prepare()`], `prepare_capture()` commits the pin's
+/// daisy register, so that the submodule captures from this pad.
+pub fn prepare_capture<P: Pin>(pin: &mut P) {
+    trace!("flexpwm::prepare_capture mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, P::DAISY);
+}
+
+/// Compute the register writes that prepare a PWM output pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, None)
+}
+
+/// Compute the register writes that prepare a PWM capture pin
+///
+/// Like [`prepared()`], but the result describes the writes of [`prepare_capture()`].
+pub const fn prepared_capture<P: Pin + super::ConstIomuxc>(
+    config: super::Config,
+) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, P::DAISY)
+}
+
+/// Returns the [`Function`](crate::Function) of a PWM pin
+pub const fn function<P: Pin>() -> super::Function {
+    use super::consts::Unsigned;
+    super::Function::FlexPwm {
+        module: P::Module::U8,
+        submodule: P::Submodule::U8,
+        output: <P::Output as private::Sealed>::OUTPUT,
+    }
 }
 
 #[allow(unused)] // Used in chip-specific modules...
 macro_rules! pwm {
-    (module: $module:ty, submodule: $submodule:ty, alt: $alt:expr, pad: $pad:ty, output: $output:ty) => {
+    (module: $module:ty, submodule: $submodule:ty, alt: $alt:expr, pad: $pad:ty, output: $output:ty, daisy: $daisy:expr) => {
         impl Pin for $pad {
             const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
             type Output = $output;
             type Module = $module;
             type Submodule = $submodule;
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        prepare, prepare_capture, prepare_with_pull, prepared, prepared_capture, Pin, A, X,
+    };
+    use crate::{consts::*, Config, ConstIomuxc, Daisy, DriveStrength, Iomuxc};
+    use crate::{PullKeeper, SlewRate};
+    use core::marker::PhantomData;
+
+    struct FakePad<O> {
+        mux: u32,
+        pad: u32,
+        _output: PhantomData<O>,
+    }
+
+    fn pad<O>(mux: u32, pad: u32) -> FakePad<O> {
+        FakePad {
+            mux,
+            pad,
+            _output: PhantomData,
+        }
+    }
+
+    impl<O> crate::private::Sealed for FakePad<O> {}
+
+    unsafe impl<O> Iomuxc for FakePad<O> {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// Only used to compute descriptors; nothing writes these addresses
+    unsafe impl<O> ConstIomuxc for FakePad<O> {
+        const MUX: usize = 0x401F_8000;
+        const PAD: usize = 0x401F_8200;
+    }
+
+    impl Pin for FakePad<A> {
+        const ALT: u32 = 1;
+        const DAISY: Option<Daisy> = Some(Daisy::new(0x401F_8458, 0));
+        type Output = A;
+        type Module = U1;
+        type Submodule = U0;
+    }
+
+    /// Like the 1060's X pads, which don't have daisy registers
+    impl Pin for FakePad<X> {
+        const ALT: u32 = 4;
+        const DAISY: Option<Daisy> = None;
+        type Output = X;
+        type Module = U1;
+        type Submodule = U0;
+    }
+
+    #[test]
+    fn output_skips_daisy() {
+        // prepare() never touches the daisy, so it's safe to call with A's
+        // hardware daisy address.
+        let mut a = pad::<A>(5, 0);
+        prepare(&mut a);
+        assert_eq!(a.mux, 1);
+        assert_eq!(prepared::<FakePad<A>>(Config::zero()).daisy, None);
+
+        let mut x = pad::<X>(5, 0);
+        prepare(&mut x);
+        assert_eq!(x.mux, 4);
+    }
+
+    #[test]
+    fn capture_commits_daisy() {
+        assert_eq!(
+            prepared_capture::<FakePad<A>>(Config::zero()).daisy,
+            Some((0x401F_8458, 0))
+        );
+
+        // An X pad has nothing to commit
+        assert_eq!(prepared_capture::<FakePad<X>>(Config::zero()).daisy, None);
+        let mut x = pad::<X>(5, 0);
+        prepare_capture(&mut x);
+        assert_eq!(x.mux, 4);
+    }
+
+    const FAST_MEDIUM: u32 = SlewRate::Fast as u32 | DriveStrength::R0_4 as u32;
+
+    #[test]
+    fn pull_before_alternate() {
+        // prepare_pin_configured() writes the pad before the mux; see
+        // prepare_writes_mux_last.
+        let mut a = pad::<A>(5, PullKeeper::Pullup22k as u32);
+        prepare_with_pull(&mut a, PullKeeper::Pulldown100k);
+
+        let expected = PullKeeper::Pulldown100k as u32 | FAST_MEDIUM;
+        assert_eq!(a.mux, 1);
+        assert_eq!(a.pad, expected);
+    }
+
+    #[test]
+    fn pull_replaces_pulldown() {
+        let mut a = pad::<A>(5, 0);
+        prepare_with_pull(&mut a, PullKeeper::Keeper);
+        assert_eq!(a.pad, PullKeeper::Keeper as u32 | FAST_MEDIUM);
+    }
+}
@@ -0,0 +1,618 @@
+//! FlexSPI pad configuration
+//!
+//! The boot ROM usually configures the FlexSPI pads for the boot flash. Take care
+//! when you reconfigure those pads while executing in place.
+//!
+//! # Data strobe
+//!
+//! The FlexSPI controller samples read data with a strobe. Its `MCR0[RXCLKSRC]`
+//! field selects where the strobe comes from, and the DQS pad needs a matching
+//! setup. When the strobe loops back from the DQS pad, the pad's input path must
+//! stay enabled with SION, even though the pad is an output. A missing SION
+//! setting causes marginal reads, which may only fail at temperature. Use
+//! [`prepare_dqs()`] with the [`DqsMode`] that matches `RXCLKSRC`.
+
+use crate::function::{FlexSpiPort, FlexSpiSignal};
+
+/// A FlexSPI port
+pub trait Port: private::Sealed + private::Value<FlexSpiPort> {}
+/// A FlexSPI signal
+pub trait Signal: private::Sealed + private::Value<FlexSpiSignal> {}
+
+/// A tag that indicates FlexSPI port A
+pub enum A {}
+/// A tag that indicates FlexSPI port B
+pub enum B {}
+
+/// A tag that indicates a FlexSPI clock pad
+pub enum Sclk {}
+/// A tag that indicates a FlexSPI chip select 0 pad
+pub enum Ss0 {}
+/// A tag that indicates a FlexSPI chip select 1 pad
+pub enum Ss1 {}
+/// A tag that indicates a FlexSPI data strobe pad
+pub enum Dqs {}
+/// A tag that indicates a FlexSPI data 0 pad
+pub enum Data0 {}
+/// A tag that indicates a FlexSPI data 1 pad
+pub enum Data1 {}
+/// A tag that indicates a FlexSPI data 2 pad
+pub enum Data2 {}
+/// A tag that indicates a FlexSPI data 3 pad
+pub enum Data3 {}
+
+impl Port for A {}
+impl Port for B {}
+
+impl Signal for Sclk {}
+impl Signal for Ss0 {}
+impl Signal for Ss1 {}
+impl Signal for Dqs {}
+impl Signal for Data0 {}
+impl Signal for Data1 {}
+impl Signal for Data2 {}
+impl Signal for Data3 {}
+
+mod private {
+    use crate::function::{FlexSpiPort, FlexSpiSignal};
+
+    pub trait Sealed {}
+    impl Sealed for super::A {}
+    impl Sealed for super::B {}
+    impl Sealed for super::Sclk {}
+    impl Sealed for super::Ss0 {}
+    impl Sealed for super::Ss1 {}
+    impl Sealed for super::Dqs {}
+    impl Sealed for super::Data0 {}
+    impl Sealed for super::Data1 {}
+    impl Sealed for super::Data2 {}
+    impl Sealed for super::Data3 {}
+
+    /// The tag's run-time value
+    pub trait Value<T> {
+        const VALUE: T;
+    }
+    impl Value<FlexSpiPort> for super::A {
+        const VALUE: FlexSpiPort = FlexSpiPort::A;
+    }
+    impl Value<FlexSpiPort> for super::B {
+        const VALUE: FlexSpiPort = FlexSpiPort::B;
+    }
+    impl Value<FlexSpiSignal> for super::Sclk {
+        const VALUE: FlexSpiSignal = FlexSpiSignal::Sclk;
+    }
+    impl Value<FlexSpiSignal> for super::Ss0 {
+        const VALUE: FlexSpiSignal = FlexSpiSignal::Ss0;
+    }
+    impl Value<FlexSpiSignal> for super::Ss1 {
+        const VALUE: FlexSpiSignal = FlexSpiSignal::Ss1;
+    }
+    impl Value<FlexSpiSignal> for super::Dqs {
+        const VALUE: FlexSpiSignal = FlexSpiSignal::Dqs;
+    }
+    impl Value<FlexSpiSignal> for super::Data0 {
+        const VALUE: FlexSpiSignal = FlexSpiSignal::Data(0);
+    }
+    impl Value<FlexSpiSignal> for super::Data1 {
+        const VALUE: FlexSpiSignal = FlexSpiSignal::Data(1);
+    }
+    impl Value<FlexSpiSignal> for super::Data2 {
+        const VALUE: FlexSpiSignal = FlexSpiSignal::Data(2);
+    }
+    impl Value<FlexSpiSignal> for super::Data3 {
+        const VALUE: FlexSpiSignal = FlexSpiSignal::Data(3);
+    }
+}
+
+/// A FlexSPI pin
+pub trait Pin: super::Iomuxc {
+    /// Alternate value for this pin
+    const ALT: u32;
+    /// The daisy register which will select the pad
+    const DAISY: Option<super::Daisy>;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+    const SION: super::SionRequirement = super::SionRequirement::DontCare;
+    /// FlexSPI port
+    type Port: Port;
+    /// FlexSPI signal
+    type Signal: Signal;
+}
+
+/// Prepare a FlexSPI pin
+///
+/// If you do not call `prepare()` on your FlexSPI pin, it might not work as a
+/// FlexSPI pin.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    trace!("flexspi::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, P::DAISY);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!(
+        "flexspi::prepare_idempotent mux={:#010x}",
+        pin.mux() as usize
+    );
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, P::DAISY)
+}
+
+/// The source of the FlexSPI read strobe
+///
+/// Select the mode that matches the FlexSPI `MCR0[RXCLKSRC]` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DqsMode {
+    /// The strobe loops back from the DQS pad
+    ///
+    /// Preparing the pin sets SION, and writes the pin's daisy register.
+    LoopbackFromPad,
+    /// The strobe loops back inside the FlexSPI controller
+    ///
+    /// Preparing the pin clears SION, and does not write the daisy register.
+    LoopbackInternal,
+    /// The flash device drives the strobe on the DQS pad
+    ///
+    /// Preparing the pin writes the pin's daisy register, and uses the pin's
+    /// SION requirement.
+    External,
+}
+
+/// Prepare a FlexSPI data strobe pin for the strobe `mode`
+///
+/// [`prepare()`] uses the pin's SION requirement and daisy register, no matter
+/// how the FlexSPI controller samples data. Use `prepare_dqs()` for a data strobe pin.
+pub fn prepare_dqs<P: Pin<Signal = Dqs>>(pin: &mut P, mode: DqsMode) {
+    trace!(
+        "flexspi::prepare_dqs mux={:#010x} mode={:?}",
+        pin.mux() as usize,
+        mode
+    );
+    super::prepare_pin(
+        pin,
+        P::ALT,
+        dqs_sion(P::SION, mode),
+        dqs_daisy(P::DAISY, mode),
+    );
+}
+
+/// The SION requirement for a data strobe pin in `mode`
+const fn dqs_sion(sion: super::SionRequirement, mode: DqsMode) -> super::SionRequirement {
+    match mode {
+        DqsMode::LoopbackFromPad => super::SionRequirement::Set,
+        DqsMode::LoopbackInternal => super::SionRequirement::Clear,
+        DqsMode::External => sion,
+    }
+}
+
+/// The daisy selection for a data strobe pin in `mode`
+const fn dqs_daisy(daisy: Option<super::Daisy>, mode: DqsMode) -> Option<super::Daisy> {
+    match mode {
+        DqsMode::LoopbackFromPad | DqsMode::External => daisy,
+        DqsMode::LoopbackInternal => None,
+    }
+}
+
+prepared_pin!("FlexSPI");
+
+/// Compute the register writes that prepare a FlexSPI pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, P::DAISY)
+}
+
+/// Compute the register writes that prepare a FlexSPI data strobe pin for `mode`
+///
+/// The result describes the same writes as [`prepare_dqs()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+pub const fn prepared_dqs<P: Pin<Signal = Dqs> + super::ConstIomuxc>(
+    config: super::Config,
+    mode: DqsMode,
+) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(
+        P::ALT,
+        dqs_sion(P::SION, mode),
+        config,
+        dqs_daisy(P::DAISY, mode),
+    )
+}
+
+/// Pad configuration overrides for a FlexSPI NOR boot configuration block
+///
+/// The FlexSPI configuration block (FCB) at the start of a boot image may override
+/// the pad configurations that the boot ROM applies to the FlexSPI pads. The
+/// 1010 and 1060 boot ROMs use the same layout: four 32-bit pad configuration
+/// values, for the chip select, clock, data, and data strobe pads, starting at
+/// [`OFFSET`](PadOverrides::OFFSET). A zero value keeps the ROM's default
+/// configuration for that group of pads.
+///
+/// Build the overrides from the same [`PreparedWrites`](crate::PreparedWrites) that
+/// your firmware uses, then write them into the FCB when you generate the boot
+/// image. This keeps the boot configuration consistent with the run-time configuration.
+///
+/// ```
+/// # #[cfg(feature = "imxrt1010")] fn main() {
+/// use imxrt_iomuxc::{flexspi, imxrt1010::gpio_sd::*, Config, DriveStrength, Speed};
+///
+/// const FLASH: Config = Config::zero()
+///     .set_speed(Speed::Max)
+///     .set_drive_strength(DriveStrength::R0_6);
+///
+/// const OVERRIDES: flexspi::PadOverrides = flexspi::PadOverrides::new()
+///     .set_cs(&flexspi::prepared::<GPIO_SD_06>(FLASH))
+///     .set_sclk(&flexspi::prepared::<GPIO_SD_10>(FLASH))
+///     .set_data(&flexspi::prepared::<GPIO_SD_09>(FLASH));
+///
+/// let mut fcb = [0u8; 512];
+/// fcb[..4].copy_from_slice(b"FCFB");
+/// OVERRIDES.write_to(&mut fcb).unwrap();
+/// # }
+/// # #[cfg(not(feature = "imxrt1010"))] fn main() {}
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PadOverrides {
+    /// The chip select pad configuration
+    pub cs: u32,
+    /// The clock pad configuration
+    pub sclk: u32,
+    /// The data pad configuration
+    pub data: u32,
+    /// The data strobe pad configuration
+    pub dqs: u32,
+}
+
+impl PadOverrides {
+    /// The byte offset of the overrides in the FCB
+    pub const OFFSET: usize = 0x60;
+    /// The FCB tag, `"FCFB"`, at the start of the FCB
+    pub const TAG: [u8; 4] = *b"FCFB";
+
+    /// No overrides; the boot ROM uses its default pad configurations
+    pub const fn new() -> Self {
+        PadOverrides {
+            cs: 0,
+            sclk: 0,
+            data: 0,
+            dqs: 0,
+        }
+    }
+
+    /// Use the pad configuration of a chip select pin
+    pub const fn set_cs(mut self, writes: &super::PreparedWrites) -> Self {
+        self.cs = writes.pad_val;
+        self
+    }
+
+    /// Use the pad configuration of a clock pin
+    pub const fn set_sclk(mut self, writes: &super::PreparedWrites) -> Self {
+        self.sclk = writes.pad_val;
+        self
+    }
+
+    /// Use the pad configuration of a data pin
+    ///
+    /// The boot ROM applies the same configuration to all of the data pads.
+    pub const fn set_data(mut self, writes: &super::PreparedWrites) -> Self {
+        self.data = writes.pad_val;
+        self
+    }
+
+    /// Use the pad configuration of a data strobe pin
+    pub const fn set_dqs(mut self, writes: &super::PreparedWrites) -> Self {
+        self.dqs = writes.pad_val;
+        self
+    }
+
+    /// Returns the overrides as they appear in the FCB
+    ///
+    /// The FCB stores each value in little endian order.
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        let values = [self.cs, self.sclk, self.data, self.dqs];
+        let mut i = 0;
+        while i < bytes.len() {
+            bytes[i] = (values[i / 4] >> (8 * (i % 4))) as u8;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Write the overrides into the FCB `fcb`
+    ///
+    /// `fcb` starts at the FCB tag. Returns an error, and leaves `fcb` unchanged,
+    /// if `fcb` is not an FCB.
+    pub fn write_to(&self, fcb: &mut [u8]) -> Result<(), FcbError> {
+        if fcb.len() < Self::OFFSET + 16 {
+            return Err(FcbError::TooShort);
+        }
+        if fcb[..4] != Self::TAG {
+            return Err(FcbError::MissingTag);
+        }
+        fcb[Self::OFFSET..Self::OFFSET + 16].copy_from_slice(&self.to_bytes());
+        Ok(())
+    }
+}
+
+/// An error when writing to an FCB
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FcbError {
+    /// The buffer is too short to hold the pad overrides
+    TooShort,
+    /// The buffer does not start with the FCB tag
+    MissingTag,
+}
+
+/// Returns the [`Function`](crate::Function) of a FlexSPI pin
+pub const fn function<P: Pin>() -> super::Function {
+    use private::Value;
+    super::Function::FlexSpi {
+        port: <P::Port as Value<FlexSpiPort>>::VALUE,
+        signal: <P::Signal as Value<FlexSpiSignal>>::VALUE,
+    }
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! flexspi {
+    (port: $port:ty, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
+            type Port = $port;
+            type Signal = $signal;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dqs_daisy, prepare_dqs, Dqs, DqsMode, FcbError, PadOverrides, Pin, A};
+    use crate::{Daisy, Iomuxc};
+
+    const SION: u32 = 1 << 4;
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// The fake doesn't have a daisy register; see dqs_daisy_by_mode
+    impl Pin for FakePad {
+        const ALT: u32 = 1;
+        const DAISY: Option<Daisy> = None;
+        type Port = A;
+        type Signal = Dqs;
+    }
+
+    #[test]
+    fn dqs_loopback_from_pad() {
+        let mut pad = FakePad { mux: 0, pad: 0 };
+        prepare_dqs(&mut pad, DqsMode::LoopbackFromPad);
+        assert_eq!(pad.mux, SION | 1);
+    }
+
+    #[test]
+    fn dqs_loopback_internal() {
+        let mut pad = FakePad { mux: SION, pad: 0 };
+        prepare_dqs(&mut pad, DqsMode::LoopbackInternal);
+        assert_eq!(pad.mux, 1);
+    }
+
+    #[test]
+    fn dqs_external() {
+        // The pin doesn't care about SION, so the bit keeps its value
+        for mux in [0, SION] {
+            let mut pad = FakePad { mux, pad: 0 };
+            prepare_dqs(&mut pad, DqsMode::External);
+            assert_eq!(pad.mux, mux | 1);
+        }
+    }
+
+    #[test]
+    fn dqs_daisy_by_mode() {
+        let daisy = Some(Daisy::new(0x401f84a4, 1));
+        assert_eq!(dqs_daisy(daisy, DqsMode::LoopbackFromPad), daisy);
+        assert_eq!(dqs_daisy(daisy, DqsMode::LoopbackInternal), None);
+        assert_eq!(dqs_daisy(daisy, DqsMode::External), daisy);
+    }
+
+    #[cfg(feature = "imxrt1010")]
+    #[test]
+    fn prepared_dqs_modes() {
+        use super::{prepared, prepared_dqs};
+        use crate::{imxrt1010::gpio_sd::GPIO_SD_12, Config, SionRequirement};
+
+        let from_pad = prepared_dqs::<GPIO_SD_12>(Config::zero(), DqsMode::LoopbackFromPad);
+        assert_eq!(from_pad.sion, SionRequirement::Set);
+        assert_eq!(from_pad.mux_val & SION, SION);
+
+        let internal = prepared_dqs::<GPIO_SD_12>(Config::zero(), DqsMode::LoopbackInternal);
+        assert_eq!(internal.sion, SionRequirement::Clear);
+        assert_eq!(internal.mux_val & SION, 0);
+        assert_eq!(internal.daisy, None);
+
+        let external = prepared_dqs::<GPIO_SD_12>(Config::zero(), DqsMode::External);
+        assert_eq!(external, prepared::<GPIO_SD_12>(Config::zero()));
+    }
+
+    fn fcb() -> [u8; 512] {
+        let mut fcb = [0; 512];
+        fcb[..4].copy_from_slice(&PadOverrides::TAG);
+        // Version 1.4.0
+        fcb[4..8].copy_from_slice(&[0x00, 0x04, 0x01, 0x56]);
+        fcb
+    }
+
+    #[test]
+    fn write_overrides() {
+        let mut fcb = fcb();
+        let overrides = PadOverrides {
+            cs: 0x10F1,
+            sclk: 0x10F9,
+            data: 0x10F1,
+            dqs: 0,
+        };
+        overrides.write_to(&mut fcb).unwrap();
+        assert_eq!(
+            fcb[0x60..0x70],
+            [0xF1, 0x10, 0, 0, 0xF9, 0x10, 0, 0, 0xF1, 0x10, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(fcb[..8], self::fcb()[..8]);
+        assert!(fcb[0x70..].iter().all(|&b| b == 0));
+    }
+
+    /// Write `value` into `fcb` at `offset`, in little endian order
+    fn put(fcb: &mut [u8; 512], offset: usize, value: u32) {
+        fcb[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    /// `FLEXSPI_LUT_SEQ()` from the SDK's `fsl_flexspi.h`
+    const fn lut_seq(cmd0: u32, pad0: u32, op0: u32, cmd1: u32, pad1: u32, op1: u32) -> u32 {
+        op0 | pad0 << 8 | cmd0 << 10 | op1 << 16 | pad1 << 24 | cmd1 << 26
+    }
+
+    /// The `memConfig` fields that both EVK FCBs set
+    fn evk_fcb() -> [u8; 512] {
+        let mut fcb = [0; 512];
+        put(&mut fcb, 0x000, 0x4246_4346); // tag, FLEXSPI_CFG_BLK_TAG
+        put(&mut fcb, 0x004, 0x5601_0400); // version, FLEXSPI_CFG_BLK_VERSION
+        fcb
+    }
+
+    /// The i.MX RT 1060 EVK HyperFlash FCB
+    ///
+    /// Reconstructed from `hyperflash_config` in the SDK's
+    /// `evkmimxrt1060_flexspi_nor_config.c`, not dumped from a board. The fixture
+    /// has the read sequence of the LUT; the other sequences are zero. The EVK
+    /// doesn't override the pad configurations.
+    fn evk1060_fcb() -> [u8; 512] {
+        let mut fcb = evk_fcb();
+        // readSampleClkSrc (external input from DQS pad), csHoldTime, csSetupTime,
+        // columnAddressWidth
+        fcb[0x00C..0x010].copy_from_slice(&[3, 3, 3, 3]);
+        // controllerMiscOption: differential clock, word addressable, safe
+        // configuration frequency, and DDR mode
+        put(&mut fcb, 0x040, 1 << 0 | 1 << 3 | 1 << 4 | 1 << 6);
+        // deviceType (serial NOR), sflashPadType (8 pads), serialClkFreq (133MHz),
+        // lutCustomSeqEnable
+        fcb[0x044..0x048].copy_from_slice(&[1, 8, 7, 1]);
+        put(&mut fcb, 0x050, 64 * 1024 * 1024); // sflashA1Size
+        fcb[0x078..0x07A].copy_from_slice(&[16, 16]); // dataValidTime
+                                                      // lookupTable[0..3]: CMD_DDR, RADDR_DDR, CADDR_DDR, DUMMY_DDR, READ_DDR, STOP
+        put(&mut fcb, 0x080, lut_seq(0x21, 3, 0xA0, 0x22, 3, 0x18));
+        put(&mut fcb, 0x084, lut_seq(0x23, 3, 0x10, 0x2C, 3, 0x06));
+        put(&mut fcb, 0x088, lut_seq(0x29, 3, 0x04, 0x00, 0, 0x00));
+        put(&mut fcb, 0x1C0, 512); // pageSize
+        put(&mut fcb, 0x1C4, 256 * 1024); // sectorSize
+        fcb[0x1C8] = 1; // ipcmdSerialClkFreq (30MHz)
+        fcb[0x1CC] = 1; // serialNorType (HyperBus)
+        put(&mut fcb, 0x1D0, 256 * 1024); // blockSize
+        fcb
+    }
+
+    /// The i.MX RT 1010 EVK QSPI FCB
+    ///
+    /// Reconstructed from `qspiflash_config` in the SDK's
+    /// `evkmimxrt1010_flexspi_nor_config.c`, not dumped from a board. The EVK
+    /// doesn't override the pad configurations.
+    fn evk1010_fcb() -> [u8; 512] {
+        let mut fcb = evk_fcb();
+        // readSampleClkSrc (loopback from DQS pad), csHoldTime, csSetupTime
+        fcb[0x00C..0x00F].copy_from_slice(&[1, 3, 3]);
+        // deviceType (serial NOR), sflashPadType (4 pads), serialClkFreq (100MHz)
+        fcb[0x044..0x047].copy_from_slice(&[1, 4, 6]);
+        put(&mut fcb, 0x050, 16 * 1024 * 1024); // sflashA1Size
+                                                // lookupTable[0..2]: CMD_SDR, RADDR_SDR, DUMMY_SDR, READ_SDR
+        put(&mut fcb, 0x080, lut_seq(0x01, 0, 0xEB, 0x02, 2, 0x18));
+        put(&mut fcb, 0x084, lut_seq(0x0C, 2, 0x06, 0x09, 2, 0x04));
+        put(&mut fcb, 0x1C0, 256); // pageSize
+        put(&mut fcb, 0x1C4, 4 * 1024); // sectorSize
+        put(&mut fcb, 0x1D0, 64 * 1024); // blockSize
+        fcb
+    }
+
+    #[test]
+    fn evk_fcbs_keep_their_fields() {
+        for evk in [evk1060_fcb(), evk1010_fcb()] {
+            // The EVKs don't override the pads, so no overrides are a no-op
+            let mut fcb = evk;
+            PadOverrides::new().write_to(&mut fcb).unwrap();
+            assert_eq!(fcb, evk);
+
+            let overrides = PadOverrides {
+                cs: 0x10F1,
+                sclk: 0x10F9,
+                data: 0x130F1,
+                dqs: 0x30B0,
+            };
+            let mut expected = evk;
+            // csPadSettingOverride, sclkPadSettingOverride, dataPadSettingOverride,
+            // and dqsPadSettingOverride
+            put(&mut expected, 0x060, 0x10F1);
+            put(&mut expected, 0x064, 0x10F9);
+            put(&mut expected, 0x068, 0x130F1);
+            put(&mut expected, 0x06C, 0x30B0);
+            overrides.write_to(&mut fcb).unwrap();
+            assert_eq!(fcb, expected);
+        }
+    }
+
+    #[cfg(feature = "imxrt1010")]
+    #[test]
+    fn evk1010_fcb_from_prepared() {
+        use super::{prepared, prepared_dqs};
+        use crate::{imxrt1010::gpio_sd::*, Config, DriveStrength, Speed};
+
+        const FLASH: Config = Config::zero()
+            .set_speed(Speed::Max)
+            .set_drive_strength(DriveStrength::R0_6);
+        let overrides = PadOverrides::new()
+            .set_cs(&prepared::<GPIO_SD_06>(FLASH))
+            .set_sclk(&prepared::<GPIO_SD_10>(FLASH))
+            .set_data(&prepared::<GPIO_SD_09>(FLASH))
+            .set_dqs(&prepared_dqs::<GPIO_SD_12>(FLASH, DqsMode::LoopbackFromPad));
+
+        let mut fcb = evk1010_fcb();
+        overrides.write_to(&mut fcb).unwrap();
+        // SPEED is 0b11 in bits 7:6, and DSE is R0/6 in bits 5:3
+        let mut expected = evk1010_fcb();
+        for offset in [0x060, 0x064, 0x068, 0x06C] {
+            put(&mut expected, offset, 0xF0);
+        }
+        assert_eq!(fcb, expected);
+    }
+
+    #[test]
+    fn not_an_fcb() {
+        let mut short = [0; 0x6F];
+        short[..4].copy_from_slice(&PadOverrides::TAG);
+        assert_eq!(
+            PadOverrides::new().write_to(&mut short),
+            Err(FcbError::TooShort)
+        );
+
+        let mut untagged = [0xAA; 512];
+        assert_eq!(
+            PadOverrides::new().write_to(&mut untagged),
+            Err(FcbError::MissingTag)
+        );
+        assert!(untagged.iter().all(|&b| b == 0xAA));
+    }
+}
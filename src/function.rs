@@ -0,0 +1,1703 @@
+//! Peripheral signals as run-time values
+//!
+//! A [`Function`] names the peripheral signal that a pad carries, like `LPUART1_TX`
+//! or `GPIO1_IO03`. The peripheral modules describe signals with traits and type
+//! tags, which only exist at compile time. `Function` describes the same signals
+//! as values, so that you can store them in tables, compare them, and print them.
+//!
+//! Each peripheral module has a `function()` that returns a pin's `Function`:
+//!
+//! ```
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{function::UartDirection, imxrt1060::gpio_ad_b0::*, lpuart, Function};
+//!
+//! const TX: Function = lpuart::function::<GPIO_AD_B0_12>();
+//! assert_eq!(
+//!     TX,
+//!     Function::Lpuart {
+//!         module: 1,
+//!         direction: UartDirection::Tx
+//!     }
+//! );
+//! # extern crate std; use std::string::ToString;
+//! assert_eq!(TX.to_string(), "LPUART1_TX");
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! # Encoding
+//!
+//! A `Function` is four bytes. For larger tables, [`Function::to_bits()`] encodes
+//! a function in a `u16`, and [`Function::from_bits()`] decodes it. The top five
+//! bits select the variant, and the low eleven bits hold the variant's fields.
+//! Each field has a fixed width, which fits every module and signal index of the
+//! supported processors.
+
+use core::fmt;
+
+/// A peripheral signal
+///
+/// Module numbers start at one, like the reference manual's names; `module: 2`
+/// for `LPUART2`. Other indices start at zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum Function {
+    /// A GPIO pin; `GPIO{module}_IO{offset}`
+    Gpio {
+        /// The GPIO module, up to 15
+        module: u8,
+        /// The pin offset, up to 31
+        offset: u8,
+    },
+    /// An ADC input; `ADC{module}_IN{input}`
+    Adc {
+        /// The ADC module, up to 3
+        module: u8,
+        /// The input, up to 31
+        input: u8,
+    },
+    /// A CSI signal
+    Csi(CsiSignal),
+    /// A FlexIO pin; `FLEXIO{module}_FLEXIO{index}`
+    FlexIo {
+        /// The FlexIO module, up to 7
+        module: u8,
+        /// The pin index, up to 31
+        index: u8,
+    },
+    /// A FlexPWM output; `FLEXPWM{module}_PWM{output}{submodule}`
+    FlexPwm {
+        /// The FlexPWM module, up to 7
+        module: u8,
+        /// The submodule, up to 3
+        submodule: u8,
+        /// The output
+        output: PwmOutput,
+    },
+    /// A FlexSPI signal
+    FlexSpi {
+        /// The port
+        port: FlexSpiPort,
+        /// The signal
+        signal: FlexSpiSignal,
+    },
+    /// An eLCDIF signal
+    Lcdif(LcdifSignal),
+    /// An LPI2C signal; `LPI2C{module}_{signal}`
+    Lpi2c {
+        /// The LPI2C module, up to 15
+        module: u8,
+        /// The signal
+        signal: I2cSignal,
+    },
+    /// An LPSPI signal; `LPSPI{module}_{signal}`
+    Lpspi {
+        /// The LPSPI module, up to 15
+        module: u8,
+        /// The signal
+        signal: SpiSignal,
+    },
+    /// An LPUART signal; `LPUART{module}_{direction}`
+    Lpuart {
+        /// The LPUART module, up to 15
+        module: u8,
+        /// The direction
+        direction: UartDirection,
+    },
+    /// An MQS channel
+    Mqs(MqsSignal),
+    /// A QTimer channel; `QTIMER{module}_TIMER{timer}`
+    QTimer {
+        /// The QTimer module, up to 7
+        module: u8,
+        /// The timer channel, up to 3
+        timer: u8,
+    },
+    /// A SAI signal; `SAI{module}_{signal}`
+    Sai {
+        /// The SAI module, up to 7
+        module: u8,
+        /// The signal
+        signal: SaiSignal,
+    },
+    /// An SRC or NMI signal
+    Src(SrcSignal),
+    /// An XBAR line
+    Xbar {
+        /// The XBAR line index, up to 127
+        index: u8,
+        /// The line direction
+        direction: XbarDirection,
+    },
+}
+
+/// A FlexPWM output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PwmOutput {
+    /// Output A
+    A,
+    /// Output B
+    B,
+    /// Auxiliary output X
+    X,
+}
+
+/// A FlexSPI port
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FlexSpiPort {
+    /// Port A
+    A,
+    /// Port B
+    B,
+}
+
+/// A FlexSPI signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FlexSpiSignal {
+    /// The clock
+    Sclk,
+    /// Chip select 0
+    Ss0,
+    /// Chip select 1
+    Ss1,
+    /// The data strobe
+    Dqs,
+    /// A data line, up to 31
+    Data(u8),
+}
+
+/// An LPI2C signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum I2cSignal {
+    /// The clock
+    Scl,
+    /// The data line
+    Sda,
+}
+
+/// An LPSPI signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SpiSignal {
+    /// The clock
+    Sck,
+    /// Data out
+    Sdo,
+    /// Data in
+    Sdi,
+    /// Chip select 0
+    Pcs0,
+}
+
+/// An LPUART signal direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UartDirection {
+    /// Transfer
+    Tx,
+    /// Receive
+    Rx,
+    /// Data set ready
+    Dsr,
+    /// Data terminal ready
+    Dtr,
+    /// Data carrier detect
+    Dcd,
+    /// Ring indicator
+    Ri,
+    /// Clear to send; `CTS_B`
+    Cts,
+    /// Request to send; `RTS_B`
+    Rts,
+}
+
+/// An MQS channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MqsSignal {
+    /// The left channel
+    Left,
+    /// The right channel
+    Right,
+}
+
+/// A CSI signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CsiSignal {
+    /// The pixel clock
+    Pixclk,
+    /// The sensor's master clock
+    Mclk,
+    /// The vertical sync
+    Vsync,
+    /// The horizontal sync
+    Hsync,
+    /// A data line, up to 31
+    Data(u8),
+}
+
+/// An eLCDIF signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LcdifSignal {
+    /// The pixel clock
+    Clk,
+    /// The data enable
+    Enable,
+    /// The horizontal sync
+    Hsync,
+    /// The vertical sync
+    Vsync,
+    /// A data line, up to 31
+    Data(u8),
+}
+
+/// A SAI signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SaiSignal {
+    /// The TX bit clock
+    TxBclk,
+    /// The TX frame sync
+    TxSync,
+    /// The RX bit clock
+    RxBclk,
+    /// The RX frame sync
+    RxSync,
+    /// The master clock
+    Mclk,
+    /// A TX data line, up to 31
+    TxData(u8),
+    /// An RX data line, up to 31
+    RxData(u8),
+    /// A pin that's both a TX and an RX data line, each up to 3
+    TxRxData {
+        /// The TX data line
+        tx: u8,
+        /// The RX data line
+        rx: u8,
+    },
+}
+
+/// An SRC or NMI signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SrcSignal {
+    /// A boot mode input, up to 31
+    BootMode(u8),
+    /// A boot configuration input, up to 31
+    BootCfg(u8),
+    /// The non-maskable interrupt input
+    Nmi,
+}
+
+/// An XBAR line direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum XbarDirection {
+    /// An input-only line
+    In,
+    /// An output-only line
+    Out,
+    /// A bidirectional line
+    InOut,
+}
+
+/// The width of the variant's fields in the encoding
+const PAYLOAD_BITS: u32 = 11;
+
+/// Returns `value` if it fits in `width` bits
+const fn fit(value: u8, width: u32) -> Option<u16> {
+    if (value as u32) < 1 << width {
+        Some(value as u16)
+    } else {
+        None
+    }
+}
+
+/// Returns the `width` bits of `bits` starting at `shift`
+const fn bits_at(bits: u16, shift: u32, width: u32) -> u8 {
+    ((bits >> shift) & ((1 << width) - 1)) as u8
+}
+
+/// Unwraps an `Option` in a `const fn`, returning `None` from the caller when
+/// it's empty
+macro_rules! try_some {
+    ($opt:expr) => {
+        match $opt {
+            Some(value) => value,
+            None => return None,
+        }
+    };
+}
+
+/// Encodes a signal that's one of several kinds, some with an index, in eight bits
+///
+/// The top three bits are the kind, and the low five bits are the index. A kind
+/// without an index uses index zero.
+const fn signal_code(kind: u16, index: u8) -> Option<u16> {
+    Some(kind << 5 | try_some!(fit(index, 5)))
+}
+
+/// Splits an eight-bit signal code into its kind and index
+const fn signal_parts(code: u8) -> (u8, u8) {
+    (code >> 5, code & 0x1F)
+}
+
+impl CsiSignal {
+    const fn code(self) -> Option<u16> {
+        match self {
+            CsiSignal::Pixclk => signal_code(0, 0),
+            CsiSignal::Mclk => signal_code(1, 0),
+            CsiSignal::Vsync => signal_code(2, 0),
+            CsiSignal::Hsync => signal_code(3, 0),
+            CsiSignal::Data(n) => signal_code(4, n),
+        }
+    }
+    const fn from_code(code: u8) -> Option<Self> {
+        match signal_parts(code) {
+            (0, 0) => Some(CsiSignal::Pixclk),
+            (1, 0) => Some(CsiSignal::Mclk),
+            (2, 0) => Some(CsiSignal::Vsync),
+            (3, 0) => Some(CsiSignal::Hsync),
+            (4, n) => Some(CsiSignal::Data(n)),
+            _ => None,
+        }
+    }
+}
+
+impl LcdifSignal {
+    const fn code(self) -> Option<u16> {
+        match self {
+            LcdifSignal::Clk => signal_code(0, 0),
+            LcdifSignal::Enable => signal_code(1, 0),
+            LcdifSignal::Hsync => signal_code(2, 0),
+            LcdifSignal::Vsync => signal_code(3, 0),
+            LcdifSignal::Data(n) => signal_code(4, n),
+        }
+    }
+    const fn from_code(code: u8) -> Option<Self> {
+        match signal_parts(code) {
+            (0, 0) => Some(LcdifSignal::Clk),
+            (1, 0) => Some(LcdifSignal::Enable),
+            (2, 0) => Some(LcdifSignal::Hsync),
+            (3, 0) => Some(LcdifSignal::Vsync),
+            (4, n) => Some(LcdifSignal::Data(n)),
+            _ => None,
+        }
+    }
+}
+
+impl FlexSpiSignal {
+    const fn code(self) -> Option<u16> {
+        match self {
+            FlexSpiSignal::Sclk => signal_code(0, 0),
+            FlexSpiSignal::Ss0 => signal_code(1, 0),
+            FlexSpiSignal::Ss1 => signal_code(2, 0),
+            FlexSpiSignal::Dqs => signal_code(3, 0),
+            FlexSpiSignal::Data(n) => signal_code(4, n),
+        }
+    }
+    const fn from_code(code: u8) -> Option<Self> {
+        match signal_parts(code) {
+            (0, 0) => Some(FlexSpiSignal::Sclk),
+            (1, 0) => Some(FlexSpiSignal::Ss0),
+            (2, 0) => Some(FlexSpiSignal::Ss1),
+            (3, 0) => Some(FlexSpiSignal::Dqs),
+            (4, n) => Some(FlexSpiSignal::Data(n)),
+            _ => None,
+        }
+    }
+}
+
+impl SaiSignal {
+    const fn code(self) -> Option<u16> {
+        match self {
+            SaiSignal::TxBclk => signal_code(0, 0),
+            SaiSignal::TxSync => signal_code(1, 0),
+            SaiSignal::RxBclk => signal_code(2, 0),
+            SaiSignal::RxSync => signal_code(3, 0),
+            SaiSignal::Mclk => signal_code(4, 0),
+            SaiSignal::TxData(n) => signal_code(5, n),
+            SaiSignal::RxData(n) => signal_code(6, n),
+            SaiSignal::TxRxData { tx, rx } => signal_code(
+                7,
+                (try_some!(fit(tx, 2)) << 2 | try_some!(fit(rx, 2))) as u8,
+            ),
+        }
+    }
+    const fn from_code(code: u8) -> Option<Self> {
+        match signal_parts(code) {
+            (0, 0) => Some(SaiSignal::TxBclk),
+            (1, 0) => Some(SaiSignal::TxSync),
+            (2, 0) => Some(SaiSignal::RxBclk),
+            (3, 0) => Some(SaiSignal::RxSync),
+            (4, 0) => Some(SaiSignal::Mclk),
+            (5, n) => Some(SaiSignal::TxData(n)),
+            (6, n) => Some(SaiSignal::RxData(n)),
+            (7, n) if n < 1 << 4 => Some(SaiSignal::TxRxData {
+                tx: n >> 2,
+                rx: n & 0b11,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl SrcSignal {
+    const fn code(self) -> Option<u16> {
+        match self {
+            SrcSignal::BootMode(n) => signal_code(0, n),
+            SrcSignal::BootCfg(n) => signal_code(1, n),
+            SrcSignal::Nmi => signal_code(2, 0),
+        }
+    }
+    const fn from_code(code: u8) -> Option<Self> {
+        match signal_parts(code) {
+            (0, n) => Some(SrcSignal::BootMode(n)),
+            (1, n) => Some(SrcSignal::BootCfg(n)),
+            (2, 0) => Some(SrcSignal::Nmi),
+            _ => None,
+        }
+    }
+}
+
+impl UartDirection {
+    const fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(UartDirection::Tx),
+            1 => Some(UartDirection::Rx),
+            2 => Some(UartDirection::Dsr),
+            3 => Some(UartDirection::Dtr),
+            4 => Some(UartDirection::Dcd),
+            5 => Some(UartDirection::Ri),
+            6 => Some(UartDirection::Cts),
+            7 => Some(UartDirection::Rts),
+            _ => None,
+        }
+    }
+}
+
+impl XbarDirection {
+    const fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(XbarDirection::In),
+            1 => Some(XbarDirection::Out),
+            2 => Some(XbarDirection::InOut),
+            _ => None,
+        }
+    }
+}
+
+impl Function {
+    /// Encode this function in a `u16`
+    ///
+    /// Returns `None` if a module number or index is too large for its field.
+    /// Every function of the supported processors fits.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{function::SpiSignal, Function};
+    ///
+    /// const SCK: Function = Function::Lpspi { module: 4, signal: SpiSignal::Sck };
+    /// const BITS: Option<u16> = SCK.to_bits();
+    /// assert_eq!(Function::from_bits(BITS.unwrap()), Some(SCK));
+    ///
+    /// let too_large = Function::Lpspi { module: 16, signal: SpiSignal::Sck };
+    /// assert_eq!(too_large.to_bits(), None);
+    /// ```
+    pub const fn to_bits(self) -> Option<u16> {
+        let (kind, payload): (u16, u16) = match self {
+            Function::Gpio { module, offset } => (
+                0,
+                try_some!(fit(module, 4)) << 5 | try_some!(fit(offset, 5)),
+            ),
+            Function::Adc { module, input } => {
+                (1, try_some!(fit(module, 2)) << 5 | try_some!(fit(input, 5)))
+            }
+            Function::Csi(signal) => (2, try_some!(signal.code())),
+            Function::FlexIo { module, index } => {
+                (3, try_some!(fit(module, 3)) << 5 | try_some!(fit(index, 5)))
+            }
+            Function::FlexPwm {
+                module,
+                submodule,
+                output,
+            } => (
+                4,
+                try_some!(fit(module, 3)) << 4 | try_some!(fit(submodule, 2)) << 2 | output as u16,
+            ),
+            Function::FlexSpi { port, signal } => {
+                (5, (port as u16) << 8 | try_some!(signal.code()))
+            }
+            Function::Lcdif(signal) => (6, try_some!(signal.code())),
+            Function::Lpi2c { module, signal } => {
+                (7, try_some!(fit(module, 4)) << 1 | signal as u16)
+            }
+            Function::Lpspi { module, signal } => {
+                (8, try_some!(fit(module, 4)) << 2 | signal as u16)
+            }
+            Function::Lpuart { module, direction } => {
+                (9, try_some!(fit(module, 4)) << 3 | direction as u16)
+            }
+            Function::Mqs(signal) => (10, signal as u16),
+            Function::QTimer { module, timer } => (
+                11,
+                try_some!(fit(module, 3)) << 2 | try_some!(fit(timer, 2)),
+            ),
+            Function::Sai { module, signal } => (
+                12,
+                try_some!(fit(module, 3)) << 8 | try_some!(signal.code()),
+            ),
+            Function::Src(signal) => (13, try_some!(signal.code())),
+            Function::Xbar { index, direction } => {
+                (14, try_some!(fit(index, 7)) << 2 | direction as u16)
+            }
+        };
+        Some(kind << PAYLOAD_BITS | payload)
+    }
+
+    /// Decode a function from its [`to_bits()`](Function::to_bits) encoding
+    ///
+    /// Returns `None` if `bits` is not the encoding of a function.
+    pub const fn from_bits(bits: u16) -> Option<Self> {
+        let kind = bits >> PAYLOAD_BITS;
+        let payload = bits & ((1 << PAYLOAD_BITS) - 1);
+        // The width of the variant's fields. The encoding leaves the higher
+        // payload bits clear.
+        let (function, width) = match kind {
+            0 => (
+                Function::Gpio {
+                    module: bits_at(payload, 5, 4),
+                    offset: bits_at(payload, 0, 5),
+                },
+                9,
+            ),
+            1 => (
+                Function::Adc {
+                    module: bits_at(payload, 5, 2),
+                    input: bits_at(payload, 0, 5),
+                },
+                7,
+            ),
+            2 => (
+                Function::Csi(try_some!(CsiSignal::from_code(bits_at(payload, 0, 8)))),
+                8,
+            ),
+            3 => (
+                Function::FlexIo {
+                    module: bits_at(payload, 5, 3),
+                    index: bits_at(payload, 0, 5),
+                },
+                8,
+            ),
+            4 => (
+                Function::FlexPwm {
+                    module: bits_at(payload, 4, 3),
+                    submodule: bits_at(payload, 2, 2),
+                    output: match bits_at(payload, 0, 2) {
+                        0 => PwmOutput::A,
+                        1 => PwmOutput::B,
+                        2 => PwmOutput::X,
+                        _ => return None,
+                    },
+                },
+                7,
+            ),
+            5 => (
+                Function::FlexSpi {
+                    port: if bits_at(payload, 8, 1) == 0 {
+                        FlexSpiPort::A
+                    } else {
+                        FlexSpiPort::B
+                    },
+                    signal: try_some!(FlexSpiSignal::from_code(bits_at(payload, 0, 8))),
+                },
+                9,
+            ),
+            6 => (
+                Function::Lcdif(try_some!(LcdifSignal::from_code(bits_at(payload, 0, 8)))),
+                8,
+            ),
+            7 => (
+                Function::Lpi2c {
+                    module: bits_at(payload, 1, 4),
+                    signal: if bits_at(payload, 0, 1) == 0 {
+                        I2cSignal::Scl
+                    } else {
+                        I2cSignal::Sda
+                    },
+                },
+                5,
+            ),
+            8 => (
+                Function::Lpspi {
+                    module: bits_at(payload, 2, 4),
+                    signal: match bits_at(payload, 0, 2) {
+                        0 => SpiSignal::Sck,
+                        1 => SpiSignal::Sdo,
+                        2 => SpiSignal::Sdi,
+                        _ => SpiSignal::Pcs0,
+                    },
+                },
+                6,
+            ),
+            9 => (
+                Function::Lpuart {
+                    module: bits_at(payload, 3, 4),
+                    direction: try_some!(UartDirection::from_code(bits_at(payload, 0, 3))),
+                },
+                7,
+            ),
+            10 => (
+                Function::Mqs(if bits_at(payload, 0, 1) == 0 {
+                    MqsSignal::Left
+                } else {
+                    MqsSignal::Right
+                }),
+                1,
+            ),
+            11 => (
+                Function::QTimer {
+                    module: bits_at(payload, 2, 3),
+                    timer: bits_at(payload, 0, 2),
+                },
+                5,
+            ),
+            12 => (
+                Function::Sai {
+                    module: bits_at(payload, 8, 3),
+                    signal: try_some!(SaiSignal::from_code(bits_at(payload, 0, 8))),
+                },
+                11,
+            ),
+            13 => (
+                Function::Src(try_some!(SrcSignal::from_code(bits_at(payload, 0, 8)))),
+                8,
+            ),
+            14 => (
+                Function::Xbar {
+                    index: bits_at(payload, 2, 7),
+                    direction: try_some!(XbarDirection::from_code(bits_at(payload, 0, 2))),
+                },
+                9,
+            ),
+            _ => return None,
+        };
+        if payload >> width != 0 {
+            return None;
+        }
+        Some(function)
+    }
+
+    /// Returns the peripheral instance that this function connects to
+    ///
+    /// Returns `None` for functions that [`Peripheral`](crate::Peripheral) doesn't
+    /// describe.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{function::SaiSignal, Function, Peripheral};
+    ///
+    /// let mclk = Function::Sai { module: 1, signal: SaiSignal::Mclk };
+    /// assert_eq!(mclk.peripheral(), Some(Peripheral::Sai(1)));
+    /// ```
+    pub const fn peripheral(self) -> Option<crate::Peripheral> {
+        match self {
+            Function::Sai { module, .. } => Some(crate::Peripheral::Sai(module as u32)),
+            Function::Mqs(_) => Some(crate::Peripheral::Mqs),
+            Function::Csi(_) => Some(crate::Peripheral::Csi),
+            Function::Lcdif(_) => Some(crate::Peripheral::Lcdif),
+            _ => None,
+        }
+    }
+}
+
+/// Displays the reference manual's signal name, like `LPUART1_TX`
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Function::Gpio { module, offset } => write!(f, "GPIO{}_IO{:02}", module, offset),
+            Function::Adc { module, input } => write!(f, "ADC{}_IN{}", module, input),
+            Function::Csi(signal) => match signal {
+                CsiSignal::Pixclk => f.write_str("CSI_PIXCLK"),
+                CsiSignal::Mclk => f.write_str("CSI_MCLK"),
+                CsiSignal::Vsync => f.write_str("CSI_VSYNC"),
+                CsiSignal::Hsync => f.write_str("CSI_HSYNC"),
+                CsiSignal::Data(n) => write!(f, "CSI_DATA{:02}", n),
+            },
+            Function::FlexIo { module, index } => {
+                write!(f, "FLEXIO{}_FLEXIO{:02}", module, index)
+            }
+            Function::FlexPwm {
+                module,
+                submodule,
+                output,
+            } => {
+                let output = match output {
+                    PwmOutput::A => 'A',
+                    PwmOutput::B => 'B',
+                    PwmOutput::X => 'X',
+                };
+                write!(f, "FLEXPWM{}_PWM{}{:02}", module, output, submodule)
+            }
+            Function::FlexSpi { port, signal } => {
+                let port = match port {
+                    FlexSpiPort::A => 'A',
+                    FlexSpiPort::B => 'B',
+                };
+                write!(f, "FLEXSPI{}_", port)?;
+                match signal {
+                    FlexSpiSignal::Sclk => f.write_str("SCLK"),
+                    FlexSpiSignal::Ss0 => f.write_str("SS0_B"),
+                    FlexSpiSignal::Ss1 => f.write_str("SS1_B"),
+                    FlexSpiSignal::Dqs => f.write_str("DQS"),
+                    FlexSpiSignal::Data(n) => write!(f, "DATA{:02}", n),
+                }
+            }
+            Function::Lcdif(signal) => match signal {
+                LcdifSignal::Clk => f.write_str("LCD_CLK"),
+                LcdifSignal::Enable => f.write_str("LCD_ENABLE"),
+                LcdifSignal::Hsync => f.write_str("LCD_HSYNC"),
+                LcdifSignal::Vsync => f.write_str("LCD_VSYNC"),
+                LcdifSignal::Data(n) => write!(f, "LCD_DATA{:02}", n),
+            },
+            Function::Lpi2c { module, signal } => {
+                let signal = match signal {
+                    I2cSignal::Scl => "SCL",
+                    I2cSignal::Sda => "SDA",
+                };
+                write!(f, "LPI2C{}_{}", module, signal)
+            }
+            Function::Lpspi { module, signal } => {
+                let signal = match signal {
+                    SpiSignal::Sck => "SCK",
+                    SpiSignal::Sdo => "SDO",
+                    SpiSignal::Sdi => "SDI",
+                    SpiSignal::Pcs0 => "PCS0",
+                };
+                write!(f, "LPSPI{}_{}", module, signal)
+            }
+            Function::Lpuart { module, direction } => {
+                let direction = match direction {
+                    UartDirection::Tx => "TX",
+                    UartDirection::Rx => "RX",
+                    UartDirection::Dsr => "DSR",
+                    UartDirection::Dtr => "DTR",
+                    UartDirection::Dcd => "DCD",
+                    UartDirection::Ri => "RI",
+                    UartDirection::Cts => "CTS_B",
+                    UartDirection::Rts => "RTS_B",
+                };
+                write!(f, "LPUART{}_{}", module, direction)
+            }
+            Function::Mqs(MqsSignal::Left) => f.write_str("MQS_LEFT"),
+            Function::Mqs(MqsSignal::Right) => f.write_str("MQS_RIGHT"),
+            Function::QTimer { module, timer } => write!(f, "QTIMER{}_TIMER{}", module, timer),
+            Function::Sai { module, signal } => {
+                write!(f, "SAI{}_", module)?;
+                match signal {
+                    SaiSignal::TxBclk => f.write_str("TX_BCLK"),
+                    SaiSignal::TxSync => f.write_str("TX_SYNC"),
+                    SaiSignal::RxBclk => f.write_str("RX_BCLK"),
+                    SaiSignal::RxSync => f.write_str("RX_SYNC"),
+                    SaiSignal::Mclk => f.write_str("MCLK"),
+                    SaiSignal::TxData(n) => write!(f, "TX_DATA{:02}", n),
+                    SaiSignal::RxData(n) => write!(f, "RX_DATA{:02}", n),
+                    SaiSignal::TxRxData { tx, rx } => {
+                        write!(f, "TX_DATA{:02}/RX_DATA{:02}", tx, rx)
+                    }
+                }
+            }
+            Function::Src(SrcSignal::BootMode(n)) => write!(f, "SRC_BOOT_MODE{:02}", n),
+            Function::Src(SrcSignal::BootCfg(n)) => write!(f, "SRC_BOOT_CFG{:02}", n),
+            Function::Src(SrcSignal::Nmi) => f.write_str("NMI"),
+            Function::Xbar { index, direction } => match direction {
+                XbarDirection::In => write!(f, "XBAR1_XBAR_IN{:02}", index),
+                XbarDirection::Out => write!(f, "XBAR1_XBAR_OUT{:02}", index),
+                XbarDirection::InOut => write!(f, "XBAR1_INOUT{:02}", index),
+            },
+        }
+    }
+}
+
+/// The pads that can carry each function
+///
+/// A chip builds its table from its pin implementations. `new()` sorts the pins
+/// by their encoded function, so that each function's pads are adjacent, and
+/// ordered by [`PadId`](crate::PadId). The table also records the [`Routing`](crate::Routing)
+/// that connects each pin.
+///
+/// A second copy of the pins is sorted by pad, then alternate, so that each pad's
+/// functions are adjacent.
+#[cfg_attr(not(any(feature = "imxrt1010", feature = "imxrt1060")), allow(unused))]
+pub(crate) struct Candidates<const N: usize> {
+    functions: [u16; N],
+    pads: [crate::PadId; N],
+    routings: [crate::Routing; N],
+    by_pad: [(crate::PadId, u8); N],
+    pad_functions: [Function; N],
+}
+
+#[cfg_attr(not(any(feature = "imxrt1010", feature = "imxrt1060")), allow(unused))]
+impl<const N: usize> Candidates<N> {
+    /// Sort `pins` by function
+    ///
+    /// Each pin is described by its function, and the writes of its `prepared()`.
+    /// Panics if a function doesn't have an encoding. Since the chips build their
+    /// tables in `const`s, the panic happens while compiling.
+    ///
+    /// The routings keep the pad's configuration, like the modules' `prepare()`.
+    /// An ADC routing also disables the pull / keeper, like [`adc::prepare()`](crate::adc::prepare).
+    /// Each routing names its pin's function.
+    pub(crate) const fn new(pins: [(Function, crate::PreparedWrites); N]) -> Self {
+        const EMPTY: crate::Routing = crate::Routing {
+            pad: crate::PadId(0),
+            alt: 0,
+            sion: crate::SionRequirement::DontCare,
+            daisy: None,
+            config: crate::Config::modify(),
+            peripheral: None,
+            function: None,
+        };
+        let mut functions = [0; N];
+        let mut pads = [crate::PadId(0); N];
+        let mut routings = [EMPTY; N];
+        let mut by_pad = [(crate::PadId(0), 0); N];
+        let mut pad_functions = [Function::Gpio {
+            module: 0,
+            offset: 0,
+        }; N];
+        let mut idx = 0;
+        while idx < N {
+            let (function, writes) = pins[idx];
+            let mut routing = crate::Routing::from_prepared(&writes).with_function(function);
+            routing.config = match function {
+                Function::Adc { .. } => crate::Config::modify().set_pull_keeper(None),
+                _ => crate::Config::modify(),
+            };
+            let bits = match function.to_bits() {
+                Some(bits) => bits,
+                None => panic!("a candidate's function has no encoding"),
+            };
+            // Insertion sort, ordered by function, then pad
+            let mut slot = idx;
+            while slot > 0
+                && (functions[slot - 1] > bits
+                    || (functions[slot - 1] == bits && pads[slot - 1].0 > routing.pad.0))
+            {
+                functions[slot] = functions[slot - 1];
+                pads[slot] = pads[slot - 1];
+                routings[slot] = routings[slot - 1];
+                slot -= 1;
+            }
+            functions[slot] = bits;
+            pads[slot] = routing.pad;
+            routings[slot] = routing;
+
+            // Insertion sort, ordered by pad, then alternate
+            let key = (routing.pad, routing.alt);
+            let mut slot = idx;
+            while slot > 0
+                && (by_pad[slot - 1].0 .0 > key.0 .0
+                    || (by_pad[slot - 1].0 .0 == key.0 .0 && by_pad[slot - 1].1 > key.1))
+            {
+                by_pad[slot] = by_pad[slot - 1];
+                pad_functions[slot] = pad_functions[slot - 1];
+                slot -= 1;
+            }
+            by_pad[slot] = key;
+            pad_functions[slot] = function;
+            idx += 1;
+        }
+        Candidates {
+            functions,
+            pads,
+            routings,
+            by_pad,
+            pad_functions,
+        }
+    }
+
+    /// Returns the range of pins that carry `function`
+    const fn range(&self, function: Function) -> (usize, usize) {
+        let bits = match function.to_bits() {
+            Some(bits) => bits,
+            None => return (0, 0),
+        };
+        let mut start = 0;
+        while start < N && self.functions[start] < bits {
+            start += 1;
+        }
+        let mut end = start;
+        while end < N && self.functions[end] == bits {
+            end += 1;
+        }
+        (start, end)
+    }
+
+    /// Returns the pads that can carry `function`
+    pub(crate) const fn get(&self, function: Function) -> &[crate::PadId] {
+        let (start, end) = self.range(function);
+        let (_, pads) = self.pads.split_at(start);
+        let (pads, _) = pads.split_at(end - start);
+        pads
+    }
+
+    /// Returns the routing that connects `function` to `pad`, if a pin does
+    pub(crate) const fn routing(
+        &self,
+        pad: crate::PadId,
+        function: Function,
+    ) -> Option<crate::Routing> {
+        let (mut idx, end) = self.range(function);
+        while idx < end {
+            if self.pads[idx].0 == pad.0 {
+                return Some(self.routings[idx]);
+            }
+            idx += 1;
+        }
+        None
+    }
+
+    /// Returns the functions of every pin on `pad`, ordered by alternate
+    pub(crate) const fn functions_at(&self, pad: crate::PadId) -> &[Function] {
+        let mut start = 0;
+        while start < N && self.by_pad[start].0 .0 < pad.0 {
+            start += 1;
+        }
+        let mut end = start;
+        while end < N && self.by_pad[end].0 .0 == pad.0 {
+            end += 1;
+        }
+        let (_, functions) = self.pad_functions.split_at(start);
+        let (functions, _) = functions.split_at(end - start);
+        functions
+    }
+
+    /// Returns the function of the pin that selects `pad` on alternate `alt`, if a pin does
+    ///
+    /// An ADC input shares the GPIO alternate, so the mux register can't tell the
+    /// two apart. ADC pins are skipped.
+    pub(crate) const fn function_at(&self, pad: crate::PadId, alt: u8) -> Option<Function> {
+        let mut idx = 0;
+        while idx < N {
+            let function = self.pad_functions[idx];
+            if self.by_pad[idx].0 .0 == pad.0
+                && self.by_pad[idx].1 == alt
+                && !matches!(function, Function::Adc { .. })
+            {
+                return Some(function);
+            }
+            idx += 1;
+        }
+        None
+    }
+
+    /// Returns each pin's function and routing, in table order
+    #[cfg(test)]
+    pub(crate) fn pins(&self) -> impl Iterator<Item = (Function, crate::Routing)> + '_ {
+        self.functions
+            .iter()
+            .zip(self.routings.iter())
+            .map(|(&bits, &routing)| (Function::from_bits(bits).unwrap(), routing))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::{string::ToString, vec::Vec};
+
+    /// Check a chip's candidates against the pin implementations in `sources`
+    ///
+    /// Each pin macro invocation in `sources` names a pad, an alternate, a daisy, and
+    /// the arguments that select the pin's function. Every invocation must have a
+    /// candidate with the same function, pad, alternate, and daisy, and every
+    /// candidate must have an invocation. `groups` locates the pads, and ADC pins use
+    /// `gpio_alt`.
+    #[cfg(any(feature = "imxrt1010", feature = "imxrt1060"))]
+    pub(crate) fn check_candidates<const N: usize>(
+        candidates: &Candidates<N>,
+        groups: &[crate::GroupInfo],
+        gpio_alt: u8,
+        sources: &[&str],
+    ) {
+        use std::{collections::HashMap, string::String};
+
+        /// `Ux` to `x`
+        fn typenum(arg: &str) -> u8 {
+            arg.strip_prefix('U').unwrap().parse().unwrap()
+        }
+
+        /// `Name<Ux>` to `x`
+        fn param(arg: &str) -> u8 {
+            let (_, rest) = arg.split_once('<').unwrap();
+            typenum(rest.strip_suffix('>').unwrap())
+        }
+
+        /// The function that a pin macro invocation describes
+        fn expected(name: &str, args: &HashMap<&str, &str>) -> Function {
+            let module = |key| typenum(args[key]);
+            let signal = args.get("signal").copied().unwrap_or_default();
+            match name {
+                "adc" => Function::Adc {
+                    module: args["module"].strip_prefix("Adc").unwrap().parse().unwrap(),
+                    input: args["input"].parse().unwrap(),
+                },
+                "csi" => Function::Csi(match signal {
+                    "Pixclk" => CsiSignal::Pixclk,
+                    "Mclk" => CsiSignal::Mclk,
+                    "Vsync" => CsiSignal::Vsync,
+                    "Hsync" => CsiSignal::Hsync,
+                    data => CsiSignal::Data(param(data)),
+                }),
+                "flexio" => Function::FlexIo {
+                    module: module("module"),
+                    index: module("index"),
+                },
+                "pwm" => Function::FlexPwm {
+                    module: module("module"),
+                    submodule: module("submodule"),
+                    output: match args["output"] {
+                        "A" => PwmOutput::A,
+                        "B" => PwmOutput::B,
+                        _ => PwmOutput::X,
+                    },
+                },
+                "flexspi" => Function::FlexSpi {
+                    port: match args["port"] {
+                        "A" => FlexSpiPort::A,
+                        _ => FlexSpiPort::B,
+                    },
+                    signal: match signal {
+                        "Sclk" => FlexSpiSignal::Sclk,
+                        "Ss0" => FlexSpiSignal::Ss0,
+                        "Ss1" => FlexSpiSignal::Ss1,
+                        "Dqs" => FlexSpiSignal::Dqs,
+                        data => {
+                            FlexSpiSignal::Data(data.strip_prefix("Data").unwrap().parse().unwrap())
+                        }
+                    },
+                },
+                "lcdif" => Function::Lcdif(match signal {
+                    "Clk" => LcdifSignal::Clk,
+                    "Enable" => LcdifSignal::Enable,
+                    "Hsync" => LcdifSignal::Hsync,
+                    "Vsync" => LcdifSignal::Vsync,
+                    data => LcdifSignal::Data(param(data)),
+                }),
+                "i2c" => Function::Lpi2c {
+                    module: module("module"),
+                    signal: match signal {
+                        "Scl" => I2cSignal::Scl,
+                        _ => I2cSignal::Sda,
+                    },
+                },
+                "spi" => Function::Lpspi {
+                    module: module("module"),
+                    signal: match signal {
+                        "Sck" => SpiSignal::Sck,
+                        "Sdo" => SpiSignal::Sdo,
+                        "Sdi" => SpiSignal::Sdi,
+                        _ => SpiSignal::Pcs0,
+                    },
+                },
+                "uart" => Function::Lpuart {
+                    module: module("module"),
+                    direction: match args["direction"] {
+                        "Tx" => UartDirection::Tx,
+                        "Rx" => UartDirection::Rx,
+                        "Cts" => UartDirection::Cts,
+                        _ => UartDirection::Rts,
+                    },
+                },
+                "mqs" => Function::Mqs(match signal {
+                    "Left" => MqsSignal::Left,
+                    _ => MqsSignal::Right,
+                }),
+                "qtimer" => Function::QTimer {
+                    module: module("module"),
+                    timer: module("timer"),
+                },
+                "sai" => Function::Sai {
+                    module: module("module"),
+                    signal: match signal {
+                        "TxBclk" => SaiSignal::TxBclk,
+                        "TxSync" => SaiSignal::TxSync,
+                        "RxBclk" => SaiSignal::RxBclk,
+                        "RxSync" => SaiSignal::RxSync,
+                        "Mclk" => SaiSignal::Mclk,
+                        "TxData" => SaiSignal::TxData(0),
+                        "RxData" => SaiSignal::RxData(0),
+                        both => {
+                            let (tx, rx) = both
+                                .strip_prefix("TxData")
+                                .and_then(|rest| rest.split_once("RxData"))
+                                .unwrap();
+                            SaiSignal::TxRxData {
+                                tx: tx.parse().unwrap(),
+                                rx: rx.parse().unwrap(),
+                            }
+                        }
+                    },
+                },
+                "src" => Function::Src(match signal {
+                    "Nmi" => SrcSignal::Nmi,
+                    mode if mode.starts_with("BootMode") => SrcSignal::BootMode(param(mode)),
+                    cfg => SrcSignal::BootCfg(param(cfg)),
+                }),
+                "xbar" => Function::Xbar {
+                    index: module("index"),
+                    direction: match args["direction"] {
+                        "In" => XbarDirection::In,
+                        "Out" => XbarDirection::Out,
+                        _ => XbarDirection::InOut,
+                    },
+                },
+                _ => panic!("unknown pin macro {}!", name),
+            }
+        }
+
+        let pad_id = |pad: &str| {
+            let (group, number) = pad.rsplit_once('_').unwrap();
+            let number: usize = number.parse().unwrap();
+            let info = groups.iter().find(|info| info.name == group).unwrap();
+            assert!(number < info.pad_count, "{} is not a pad", pad);
+            crate::PadId(info.base_id.0 + 4 * number)
+        };
+
+        let mut pins: Vec<_> = candidates.pins().collect();
+        let mut invocations = 0;
+        for source in sources {
+            let daisies: HashMap<&str, (usize, u32)> = source
+                .lines()
+                .filter_map(|line| {
+                    let (name, value) = line
+                        .trim()
+                        .strip_prefix("pub const ")?
+                        .split_once(": Daisy = Daisy::new(")?;
+                    let (reg, value) = value.strip_suffix(");")?.split_once(", ")?;
+                    let reg = usize::from_str_radix(reg.strip_prefix("0x")?, 16).ok()?;
+                    Some((name, (reg, value.parse().ok()?)))
+                })
+                .collect();
+
+            for line in source.lines() {
+                let (name, rest) = match line.split_once("!(") {
+                    Some((name, rest))
+                        if !name.is_empty()
+                            && name
+                                .chars()
+                                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()) =>
+                    {
+                        (name, rest)
+                    }
+                    _ => continue,
+                };
+                let rest = rest.strip_suffix(");").unwrap();
+                let rest = rest.strip_prefix("for ").unwrap_or(rest);
+                let args: HashMap<&str, &str> = rest
+                    .split(", ")
+                    .map(|arg| arg.split_once(": ").unwrap())
+                    .collect();
+
+                let pad = pad_id(args["pad"]);
+                let function = expected(name, &args);
+                let alt: u8 = match args.get("alt") {
+                    Some(alt) => alt.parse().unwrap(),
+                    None => gpio_alt,
+                };
+                // prepare() drives a PWM output, which doesn't select its pad
+                let daisy = match args.get("daisy").copied() {
+                    None | Some("None") => None,
+                    Some(_) if name == "pwm" => None,
+                    Some(daisy) => {
+                        let daisy = daisy
+                            .strip_prefix("Some(")
+                            .and_then(|d| d.strip_suffix(')'))
+                            .unwrap_or(daisy);
+                        Some(
+                            *daisies
+                                .get(daisy)
+                                .unwrap_or_else(|| panic!("{} is undefined", daisy)),
+                        )
+                    }
+                };
+
+                let description = String::from(line);
+                let idx = pins
+                    .iter()
+                    .position(|(f, routing)| *f == function && routing.pad == pad)
+                    .unwrap_or_else(|| panic!("no candidate for {}", description));
+                let (_, routing) = pins.swap_remove(idx);
+                assert_eq!(routing.alt, alt, "{}", description);
+                assert_eq!(
+                    routing.daisy.map(|daisy| (daisy.reg(), daisy.value())),
+                    daisy,
+                    "{}",
+                    description
+                );
+                invocations += 1;
+            }
+        }
+        assert!(invocations > 0);
+        assert!(pins.is_empty(), "candidates without pins: {:?}", pins);
+    }
+
+    /// Lists every variant of a field-less enum
+    ///
+    /// The list doesn't compile if the enum gains a variant, so `representable()`
+    /// can't silently skip it.
+    macro_rules! variants {
+        ($ty:ident: $($variant:ident),+ $(,)?) => {{
+            let _exhaustive = |value: $ty| match value {
+                $($ty::$variant => ()),+
+            };
+            [$($ty::$variant),+]
+        }};
+    }
+
+    /// Every function that the encoding can represent
+    pub(crate) fn representable() -> Vec<Function> {
+        // Update the lists below when a variant is added, then add the variant here
+        let _exhaustive = |function: Function| match function {
+            Function::Gpio { .. }
+            | Function::Adc { .. }
+            | Function::Csi(
+                CsiSignal::Pixclk
+                | CsiSignal::Mclk
+                | CsiSignal::Vsync
+                | CsiSignal::Hsync
+                | CsiSignal::Data(_),
+            )
+            | Function::FlexIo { .. }
+            | Function::FlexPwm { .. }
+            | Function::FlexSpi {
+                signal:
+                    FlexSpiSignal::Sclk
+                    | FlexSpiSignal::Ss0
+                    | FlexSpiSignal::Ss1
+                    | FlexSpiSignal::Dqs
+                    | FlexSpiSignal::Data(_),
+                ..
+            }
+            | Function::Lcdif(
+                LcdifSignal::Clk
+                | LcdifSignal::Enable
+                | LcdifSignal::Hsync
+                | LcdifSignal::Vsync
+                | LcdifSignal::Data(_),
+            )
+            | Function::Lpi2c { .. }
+            | Function::Lpspi { .. }
+            | Function::Lpuart { .. }
+            | Function::Mqs(_)
+            | Function::QTimer { .. }
+            | Function::Sai {
+                signal:
+                    SaiSignal::TxBclk
+                    | SaiSignal::TxSync
+                    | SaiSignal::RxBclk
+                    | SaiSignal::RxSync
+                    | SaiSignal::Mclk
+                    | SaiSignal::TxData(_)
+                    | SaiSignal::RxData(_)
+                    | SaiSignal::TxRxData { .. },
+                ..
+            }
+            | Function::Src(SrcSignal::BootMode(_) | SrcSignal::BootCfg(_) | SrcSignal::Nmi)
+            | Function::Xbar { .. } => (),
+        };
+
+        let mut all = Vec::new();
+        for module in 0..16 {
+            for offset in 0..32 {
+                all.push(Function::Gpio { module, offset });
+            }
+            for signal in variants!(I2cSignal: Scl, Sda) {
+                all.push(Function::Lpi2c { module, signal });
+            }
+            for signal in variants!(SpiSignal: Sck, Sdo, Sdi, Pcs0) {
+                all.push(Function::Lpspi { module, signal });
+            }
+            for direction in variants!(UartDirection: Tx, Rx, Dsr, Dtr, Dcd, Ri, Cts, Rts) {
+                all.push(Function::Lpuart { module, direction });
+            }
+        }
+        for module in 0..4 {
+            for input in 0..32 {
+                all.push(Function::Adc { module, input });
+            }
+        }
+        for module in 0..8 {
+            for index in 0..32 {
+                all.push(Function::FlexIo { module, index });
+            }
+            for submodule in 0..4 {
+                for output in variants!(PwmOutput: A, B, X) {
+                    all.push(Function::FlexPwm {
+                        module,
+                        submodule,
+                        output,
+                    });
+                }
+            }
+            for timer in 0..4 {
+                all.push(Function::QTimer { module, timer });
+            }
+            let mut sai = Vec::from([
+                SaiSignal::TxBclk,
+                SaiSignal::TxSync,
+                SaiSignal::RxBclk,
+                SaiSignal::RxSync,
+                SaiSignal::Mclk,
+            ]);
+            for n in 0..32 {
+                sai.push(SaiSignal::TxData(n));
+                sai.push(SaiSignal::RxData(n));
+            }
+            for tx in 0..4 {
+                for rx in 0..4 {
+                    sai.push(SaiSignal::TxRxData { tx, rx });
+                }
+            }
+            all.extend(
+                sai.into_iter()
+                    .map(|signal| Function::Sai { module, signal }),
+            );
+        }
+        let mut csi = Vec::from([
+            CsiSignal::Pixclk,
+            CsiSignal::Mclk,
+            CsiSignal::Vsync,
+            CsiSignal::Hsync,
+        ]);
+        let mut lcdif = Vec::from([
+            LcdifSignal::Clk,
+            LcdifSignal::Enable,
+            LcdifSignal::Hsync,
+            LcdifSignal::Vsync,
+        ]);
+        let mut flexspi = Vec::from([
+            FlexSpiSignal::Sclk,
+            FlexSpiSignal::Ss0,
+            FlexSpiSignal::Ss1,
+            FlexSpiSignal::Dqs,
+        ]);
+        let mut src = Vec::from([SrcSignal::Nmi]);
+        for n in 0..32 {
+            csi.push(CsiSignal::Data(n));
+            lcdif.push(LcdifSignal::Data(n));
+            flexspi.push(FlexSpiSignal::Data(n));
+            src.push(SrcSignal::BootMode(n));
+            src.push(SrcSignal::BootCfg(n));
+        }
+        all.extend(csi.into_iter().map(Function::Csi));
+        all.extend(lcdif.into_iter().map(Function::Lcdif));
+        all.extend(src.into_iter().map(Function::Src));
+        for port in variants!(FlexSpiPort: A, B) {
+            for &signal in &flexspi {
+                all.push(Function::FlexSpi { port, signal });
+            }
+        }
+        all.extend(variants!(MqsSignal: Left, Right).map(Function::Mqs));
+        for index in 0..128 {
+            for direction in variants!(XbarDirection: In, Out, InOut) {
+                all.push(Function::Xbar { index, direction });
+            }
+        }
+        all
+    }
+
+    #[test]
+    fn representable_functions_round_trip() {
+        let all = representable();
+        let mut bits: Vec<u16> = all
+            .iter()
+            .map(|function| {
+                let bits = function.to_bits().unwrap();
+                assert_eq!(Function::from_bits(bits), Some(*function), "{:?}", function);
+                bits
+            })
+            .collect();
+
+        // No two functions share an encoding
+        bits.sort_unstable();
+        bits.dedup();
+        assert_eq!(bits.len(), all.len());
+    }
+
+    #[test]
+    fn every_encoding_round_trips() {
+        let mut decoded = 0;
+        for bits in 0..=u16::MAX {
+            if let Some(function) = Function::from_bits(bits) {
+                assert_eq!(function.to_bits(), Some(bits), "{:#06x}", bits);
+                decoded += 1;
+            }
+        }
+        // Every encoding that decodes is the encoding of a representable function
+        assert_eq!(decoded, representable().len());
+    }
+
+    #[test]
+    fn fields_too_large() {
+        for function in [
+            Function::Gpio {
+                module: 16,
+                offset: 0,
+            },
+            Function::Gpio {
+                module: 1,
+                offset: 32,
+            },
+            Function::Xbar {
+                index: 128,
+                direction: XbarDirection::In,
+            },
+            Function::Sai {
+                module: 1,
+                signal: SaiSignal::TxData(32),
+            },
+            Function::Sai {
+                module: 1,
+                signal: SaiSignal::TxRxData { tx: 4, rx: 0 },
+            },
+            Function::Csi(CsiSignal::Data(u8::MAX)),
+        ] {
+            assert_eq!(function.to_bits(), None, "{:?}", function);
+        }
+    }
+
+    #[test]
+    fn function_size() {
+        assert_eq!(core::mem::size_of::<Function>(), 4);
+    }
+
+    #[test]
+    fn display() {
+        for (function, name) in [
+            (
+                Function::Gpio {
+                    module: 1,
+                    offset: 3,
+                },
+                "GPIO1_IO03",
+            ),
+            (
+                Function::Adc {
+                    module: 1,
+                    input: 7,
+                },
+                "ADC1_IN7",
+            ),
+            (Function::Csi(CsiSignal::Data(2)), "CSI_DATA02"),
+            (
+                Function::FlexIo {
+                    module: 2,
+                    index: 16,
+                },
+                "FLEXIO2_FLEXIO16",
+            ),
+            (
+                Function::FlexPwm {
+                    module: 2,
+                    submodule: 0,
+                    output: PwmOutput::A,
+                },
+                "FLEXPWM2_PWMA00",
+            ),
+            (
+                Function::FlexPwm {
+                    module: 1,
+                    submodule: 3,
+                    output: PwmOutput::X,
+                },
+                "FLEXPWM1_PWMX03",
+            ),
+            (
+                Function::FlexSpi {
+                    port: FlexSpiPort::B,
+                    signal: FlexSpiSignal::Ss0,
+                },
+                "FLEXSPIB_SS0_B",
+            ),
+            (Function::Lcdif(LcdifSignal::Data(23)), "LCD_DATA23"),
+            (
+                Function::Lpi2c {
+                    module: 1,
+                    signal: I2cSignal::Sda,
+                },
+                "LPI2C1_SDA",
+            ),
+            (
+                Function::Lpspi {
+                    module: 4,
+                    signal: SpiSignal::Pcs0,
+                },
+                "LPSPI4_PCS0",
+            ),
+            (
+                Function::Lpuart {
+                    module: 3,
+                    direction: UartDirection::Cts,
+                },
+                "LPUART3_CTS_B",
+            ),
+            (Function::Mqs(MqsSignal::Left), "MQS_LEFT"),
+            (
+                Function::QTimer {
+                    module: 3,
+                    timer: 1,
+                },
+                "QTIMER3_TIMER1",
+            ),
+            (
+                Function::Sai {
+                    module: 1,
+                    signal: SaiSignal::TxRxData { tx: 1, rx: 3 },
+                },
+                "SAI1_TX_DATA01/RX_DATA03",
+            ),
+            (Function::Src(SrcSignal::BootCfg(4)), "SRC_BOOT_CFG04"),
+            (
+                Function::Xbar {
+                    index: 14,
+                    direction: XbarDirection::InOut,
+                },
+                "XBAR1_INOUT14",
+            ),
+        ] {
+            assert_eq!(function.to_string(), name);
+        }
+    }
+
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn imxrt1060_functions() {
+        use crate::imxrt1060::{gpio_ad_b0::*, gpio_ad_b1::*, gpio_b0::*, gpio_b1::*};
+        use crate::{adc, csi, flexio, flexpwm, gpio, lcdif, lpi2c, lpspi, lpuart, mqs, qtimer};
+        use crate::{consts::U1, imxrt1060::gpio_emc::GPIO_EMC_06, sai, src, xbar};
+
+        for (function, expected) in [
+            (
+                gpio::function::<GPIO_AD_B0_12>(),
+                Function::Gpio {
+                    module: 1,
+                    offset: 12,
+                },
+            ),
+            (
+                adc::function::<adc::Adc1, GPIO_AD_B1_02>(),
+                Function::Adc {
+                    module: 1,
+                    input: 7,
+                },
+            ),
+            (
+                csi::function::<GPIO_AD_B1_04>(),
+                Function::Csi(CsiSignal::Pixclk),
+            ),
+            (
+                flexio::function::<GPIO_B1_00>(),
+                Function::FlexIo {
+                    module: 2,
+                    index: 16,
+                },
+            ),
+            (
+                flexpwm::function::<GPIO_EMC_06>(),
+                Function::FlexPwm {
+                    module: 2,
+                    submodule: 0,
+                    output: PwmOutput::A,
+                },
+            ),
+            (
+                lcdif::function::<GPIO_B0_01>(),
+                Function::Lcdif(LcdifSignal::Enable),
+            ),
+            (
+                lpi2c::function::<GPIO_AD_B1_00>(),
+                Function::Lpi2c {
+                    module: 1,
+                    signal: I2cSignal::Scl,
+                },
+            ),
+            (
+                lpspi::function::<GPIO_B0_03>(),
+                Function::Lpspi {
+                    module: 4,
+                    signal: SpiSignal::Sck,
+                },
+            ),
+            (
+                lpuart::function::<GPIO_AD_B0_13>(),
+                Function::Lpuart {
+                    module: 1,
+                    direction: UartDirection::Rx,
+                },
+            ),
+            (
+                mqs::function::<GPIO_B0_00>(),
+                Function::Mqs(MqsSignal::Right),
+            ),
+            (
+                qtimer::function::<GPIO_B0_01>(),
+                Function::QTimer {
+                    module: 1,
+                    timer: 1,
+                },
+            ),
+            (
+                sai::function::<U1, GPIO_B0_12>(),
+                Function::Sai {
+                    module: 1,
+                    signal: SaiSignal::TxRxData { tx: 1, rx: 3 },
+                },
+            ),
+            (
+                src::function::<GPIO_AD_B0_05>(),
+                Function::Src(SrcSignal::BootMode(1)),
+            ),
+            (
+                xbar::function::<GPIO_B1_00>(),
+                Function::Xbar {
+                    index: 14,
+                    direction: XbarDirection::InOut,
+                },
+            ),
+        ] {
+            assert_eq!(function, expected);
+            assert_eq!(
+                Function::from_bits(function.to_bits().unwrap()),
+                Some(function)
+            );
+        }
+    }
+
+    #[cfg(feature = "imxrt1010")]
+    #[test]
+    fn imxrt1010_functions() {
+        use crate::{flexspi, imxrt1010::gpio_sd::*};
+
+        assert_eq!(
+            flexspi::function::<GPIO_SD_12>(),
+            Function::FlexSpi {
+                port: FlexSpiPort::A,
+                signal: FlexSpiSignal::Dqs,
+            }
+        );
+        assert_eq!(
+            flexspi::function::<GPIO_SD_09>().to_string(),
+            "FLEXSPIA_DATA00"
+        );
+    }
+}
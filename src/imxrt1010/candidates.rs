@@ -0,0 +1,105 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1010/*.csv. Do not edit.
+//! Candidate pads for each function
+
+use super::pads::{gpio_ad::*, gpio_sd::*, gpio::*};
+use crate::{flexio, flexspi, lpi2c, lpspi, lpuart, function::Candidates, Config};
+
+/// Every pin that the chip implements, except for GPIOs
+///
+/// Each pin is paired with the writes of its module's `prepared()`, which name
+/// the pin's pad, alternate, and daisy.
+pub(super) const CANDIDATES: Candidates<84> = Candidates::new([
+    // FLEXIO
+    (flexio::function::<GPIO_02>(), flexio::prepared::<GPIO_02>(Config::zero())),
+    (flexio::function::<GPIO_03>(), flexio::prepared::<GPIO_03>(Config::zero())),
+    (flexio::function::<GPIO_04>(), flexio::prepared::<GPIO_04>(Config::zero())),
+    (flexio::function::<GPIO_05>(), flexio::prepared::<GPIO_05>(Config::zero())),
+    (flexio::function::<GPIO_06>(), flexio::prepared::<GPIO_06>(Config::zero())),
+    (flexio::function::<GPIO_07>(), flexio::prepared::<GPIO_07>(Config::zero())),
+    (flexio::function::<GPIO_08>(), flexio::prepared::<GPIO_08>(Config::zero())),
+    (flexio::function::<GPIO_09>(), flexio::prepared::<GPIO_09>(Config::zero())),
+    (flexio::function::<GPIO_10>(), flexio::prepared::<GPIO_10>(Config::zero())),
+    (flexio::function::<GPIO_11>(), flexio::prepared::<GPIO_11>(Config::zero())),
+    (flexio::function::<GPIO_12>(), flexio::prepared::<GPIO_12>(Config::zero())),
+    (flexio::function::<GPIO_13>(), flexio::prepared::<GPIO_13>(Config::zero())),
+    (flexio::function::<GPIO_AD_00>(), flexio::prepared::<GPIO_AD_00>(Config::zero())),
+    (flexio::function::<GPIO_AD_01>(), flexio::prepared::<GPIO_AD_01>(Config::zero())),
+    (flexio::function::<GPIO_AD_02>(), flexio::prepared::<GPIO_AD_02>(Config::zero())),
+    (flexio::function::<GPIO_AD_03>(), flexio::prepared::<GPIO_AD_03>(Config::zero())),
+    (flexio::function::<GPIO_AD_04>(), flexio::prepared::<GPIO_AD_04>(Config::zero())),
+    (flexio::function::<GPIO_AD_05>(), flexio::prepared::<GPIO_AD_05>(Config::zero())),
+    (flexio::function::<GPIO_AD_06>(), flexio::prepared::<GPIO_AD_06>(Config::zero())),
+    (flexio::function::<GPIO_AD_07>(), flexio::prepared::<GPIO_AD_07>(Config::zero())),
+    (flexio::function::<GPIO_AD_08>(), flexio::prepared::<GPIO_AD_08>(Config::zero())),
+    (flexio::function::<GPIO_AD_09>(), flexio::prepared::<GPIO_AD_09>(Config::zero())),
+    (flexio::function::<GPIO_AD_10>(), flexio::prepared::<GPIO_AD_10>(Config::zero())),
+    (flexio::function::<GPIO_AD_11>(), flexio::prepared::<GPIO_AD_11>(Config::zero())),
+    (flexio::function::<GPIO_AD_12>(), flexio::prepared::<GPIO_AD_12>(Config::zero())),
+    (flexio::function::<GPIO_AD_13>(), flexio::prepared::<GPIO_AD_13>(Config::zero())),
+    (flexio::function::<GPIO_AD_14>(), flexio::prepared::<GPIO_AD_14>(Config::zero())),
+
+    // FLEXSPI
+    (flexspi::function::<GPIO_SD_06>(), flexspi::prepared::<GPIO_SD_06>(Config::zero())),
+    (flexspi::function::<GPIO_SD_07>(), flexspi::prepared::<GPIO_SD_07>(Config::zero())),
+    (flexspi::function::<GPIO_SD_08>(), flexspi::prepared::<GPIO_SD_08>(Config::zero())),
+    (flexspi::function::<GPIO_SD_09>(), flexspi::prepared::<GPIO_SD_09>(Config::zero())),
+    (flexspi::function::<GPIO_SD_10>(), flexspi::prepared::<GPIO_SD_10>(Config::zero())),
+    (flexspi::function::<GPIO_SD_11>(), flexspi::prepared::<GPIO_SD_11>(Config::zero())),
+    (flexspi::function::<GPIO_SD_12>(), flexspi::prepared::<GPIO_SD_12>(Config::zero())),
+
+    // LPI2C
+    (lpi2c::function::<GPIO_AD_14>(), lpi2c::prepared::<GPIO_AD_14>(Config::zero())),
+    (lpi2c::function::<GPIO_SD_06>(), lpi2c::prepared::<GPIO_SD_06>(Config::zero())),
+    (lpi2c::function::<GPIO_12>(), lpi2c::prepared::<GPIO_12>(Config::zero())),
+    (lpi2c::function::<GPIO_02>(), lpi2c::prepared::<GPIO_02>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_13>(), lpi2c::prepared::<GPIO_AD_13>(Config::zero())),
+    (lpi2c::function::<GPIO_SD_05>(), lpi2c::prepared::<GPIO_SD_05>(Config::zero())),
+    (lpi2c::function::<GPIO_11>(), lpi2c::prepared::<GPIO_11>(Config::zero())),
+    (lpi2c::function::<GPIO_01>(), lpi2c::prepared::<GPIO_01>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_08>(), lpi2c::prepared::<GPIO_AD_08>(Config::zero())),
+    (lpi2c::function::<GPIO_SD_08>(), lpi2c::prepared::<GPIO_SD_08>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_02>(), lpi2c::prepared::<GPIO_AD_02>(Config::zero())),
+    (lpi2c::function::<GPIO_10>(), lpi2c::prepared::<GPIO_10>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_07>(), lpi2c::prepared::<GPIO_AD_07>(Config::zero())),
+    (lpi2c::function::<GPIO_SD_07>(), lpi2c::prepared::<GPIO_SD_07>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_01>(), lpi2c::prepared::<GPIO_AD_01>(Config::zero())),
+    (lpi2c::function::<GPIO_09>(), lpi2c::prepared::<GPIO_09>(Config::zero())),
+
+    // LPSPI
+    (lpspi::function::<GPIO_AD_05>(), lpspi::prepared::<GPIO_AD_05>(Config::zero())),
+    (lpspi::function::<GPIO_SD_07>(), lpspi::prepared::<GPIO_SD_07>(Config::zero())),
+    (lpspi::function::<GPIO_AD_06>(), lpspi::prepared::<GPIO_AD_06>(Config::zero())),
+    (lpspi::function::<GPIO_SD_08>(), lpspi::prepared::<GPIO_SD_08>(Config::zero())),
+    (lpspi::function::<GPIO_AD_03>(), lpspi::prepared::<GPIO_AD_03>(Config::zero())),
+    (lpspi::function::<GPIO_SD_05>(), lpspi::prepared::<GPIO_SD_05>(Config::zero())),
+    (lpspi::function::<GPIO_AD_04>(), lpspi::prepared::<GPIO_AD_04>(Config::zero())),
+    (lpspi::function::<GPIO_SD_06>(), lpspi::prepared::<GPIO_SD_06>(Config::zero())),
+    (lpspi::function::<GPIO_AD_11>(), lpspi::prepared::<GPIO_AD_11>(Config::zero())),
+    (lpspi::function::<GPIO_SD_12>(), lpspi::prepared::<GPIO_SD_12>(Config::zero())),
+    (lpspi::function::<GPIO_AD_12>(), lpspi::prepared::<GPIO_AD_12>(Config::zero())),
+    (lpspi::function::<GPIO_SD_11>(), lpspi::prepared::<GPIO_SD_11>(Config::zero())),
+    (lpspi::function::<GPIO_AD_09>(), lpspi::prepared::<GPIO_AD_09>(Config::zero())),
+    (lpspi::function::<GPIO_SD_09>(), lpspi::prepared::<GPIO_SD_09>(Config::zero())),
+    (lpspi::function::<GPIO_AD_10>(), lpspi::prepared::<GPIO_AD_10>(Config::zero())),
+    (lpspi::function::<GPIO_SD_10>(), lpspi::prepared::<GPIO_SD_10>(Config::zero())),
+
+    // LPUART
+    (lpuart::function::<GPIO_09>(), lpuart::prepared::<GPIO_09>(Config::zero())),
+    (lpuart::function::<GPIO_SD_11>(), lpuart::prepared::<GPIO_SD_11>(Config::zero())),
+    (lpuart::function::<GPIO_10>(), lpuart::prepared::<GPIO_10>(Config::zero())),
+    (lpuart::function::<GPIO_SD_12>(), lpuart::prepared::<GPIO_SD_12>(Config::zero())),
+    (lpuart::function::<GPIO_13>(), lpuart::prepared::<GPIO_13>(Config::zero())),
+    (lpuart::function::<GPIO_SD_09>(), lpuart::prepared::<GPIO_SD_09>(Config::zero())),
+    (lpuart::function::<GPIO_AD_00>(), lpuart::prepared::<GPIO_AD_00>(Config::zero())),
+    (lpuart::function::<GPIO_SD_10>(), lpuart::prepared::<GPIO_SD_10>(Config::zero())),
+    (lpuart::function::<GPIO_11>(), lpuart::prepared::<GPIO_11>(Config::zero())),
+    (lpuart::function::<GPIO_AD_07>(), lpuart::prepared::<GPIO_AD_07>(Config::zero())),
+    (lpuart::function::<GPIO_07>(), lpuart::prepared::<GPIO_07>(Config::zero())),
+    (lpuart::function::<GPIO_12>(), lpuart::prepared::<GPIO_12>(Config::zero())),
+    (lpuart::function::<GPIO_AD_08>(), lpuart::prepared::<GPIO_AD_08>(Config::zero())),
+    (lpuart::function::<GPIO_08>(), lpuart::prepared::<GPIO_08>(Config::zero())),
+    (lpuart::function::<GPIO_AD_01>(), lpuart::prepared::<GPIO_AD_01>(Config::zero())),
+    (lpuart::function::<GPIO_05>(), lpuart::prepared::<GPIO_05>(Config::zero())),
+    (lpuart::function::<GPIO_AD_02>(), lpuart::prepared::<GPIO_AD_02>(Config::zero())),
+    (lpuart::function::<GPIO_06>(), lpuart::prepared::<GPIO_06>(Config::zero())),
+]);
@@ -0,0 +1,85 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1010/flexio.csv. Do not edit.
+//! FlexIO pin implementations
+//!
+//! The i.MX RT 1010 has one FlexIO module, `FLEXIO1`, with 27 pins. A FlexIO
+//! UART can use any two of them. [`FLEXIO1_PADS`] lists the pads by pin index,
+//! so that a driver can check run-time pad choices, and find the shifter pin
+//! index for a pad.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{
+//!     consts::Unsigned,
+//!     flexio::{self, Pin},
+//!     imxrt1010::{gpio_ad::*, FLEXIO1_PADS},
+//! };
+//!
+//! /// Prepares a FlexIO UART, and returns the TX and RX pin indices
+//! fn flexio_uart<T, R>(tx: &mut T, rx: &mut R) -> (usize, usize)
+//! where
+//!     T: Pin,
+//!     R: Pin<Module = T::Module>,
+//! {
+//!     flexio::prepare(tx);
+//!     flexio::prepare(rx);
+//!     (T::Index::USIZE, R::Index::USIZE)
+//! }
+//!
+//! let mut tx = unsafe { GPIO_AD_02::new() };
+//! let mut rx = unsafe { GPIO_AD_01::new() };
+//! let (tx_index, rx_index) = flexio_uart(&mut tx, &mut rx);
+//! assert_eq!((tx_index, rx_index), (14, 13));
+//! assert_eq!(FLEXIO1_PADS[tx_index], GPIO_AD_02::ID);
+//! ```
+
+use super::pads::{gpio::*, gpio_ad::*};
+use crate::{consts::*, flexio::Pin, Function, PadId};
+
+/// The `FLEXIO1` pads, indexed by FlexIO pin
+///
+/// `FLEXIO1_PADS[7]` is the pad for `FLEXIO1_FLEXIO07`. See
+/// [`candidates()`](super::candidates).
+pub const FLEXIO1_PADS: [PadId; 27] = flexio1_pads();
+
+const fn flexio1_pads() -> [PadId; 27] {
+    let mut pads = [PadId(0); 27];
+    let mut index = 0;
+    while index < pads.len() {
+        pads[index] = super::candidates(Function::FlexIo {
+            module: 1,
+            index: index as u8,
+        })[0];
+        index += 1;
+    }
+    pads
+}
+
+//
+// FLEXIO1
+//
+flexio!(module: U1, alt: 4, pad: GPIO_02, index: U0);
+flexio!(module: U1, alt: 4, pad: GPIO_03, index: U1);
+flexio!(module: U1, alt: 4, pad: GPIO_04, index: U2);
+flexio!(module: U1, alt: 4, pad: GPIO_05, index: U3);
+flexio!(module: U1, alt: 4, pad: GPIO_06, index: U4);
+flexio!(module: U1, alt: 4, pad: GPIO_07, index: U5);
+flexio!(module: U1, alt: 4, pad: GPIO_08, index: U6);
+flexio!(module: U1, alt: 4, pad: GPIO_09, index: U7);
+flexio!(module: U1, alt: 4, pad: GPIO_10, index: U8);
+flexio!(module: U1, alt: 4, pad: GPIO_11, index: U9);
+flexio!(module: U1, alt: 4, pad: GPIO_12, index: U10);
+flexio!(module: U1, alt: 4, pad: GPIO_13, index: U11);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_00, index: U12);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_01, index: U13);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_02, index: U14);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_03, index: U15);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_04, index: U16);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_05, index: U17);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_06, index: U18);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_07, index: U19);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_08, index: U20);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_09, index: U21);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_10, index: U22);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_11, index: U23);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_12, index: U24);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_13, index: U25);
+flexio!(module: U1, alt: 4, pad: GPIO_AD_14, index: U26);
@@ -0,0 +1,21 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1010/flexspi.csv. Do not edit.
+//! FlexSPI pin implementations
+//!
+//! Port A signals have a single routing, so they do not use daisy registers.
+
+use super::pads::gpio_sd::*;
+use crate::{
+    flexspi::{Data0, Data1, Data2, Data3, Dqs, Pin, Sclk, Ss0, A},
+    Daisy,
+};
+
+//
+// Port A
+//
+flexspi!(port: A, alt: 0, pad: GPIO_SD_06, signal: Ss0, daisy: None);
+flexspi!(port: A, alt: 0, pad: GPIO_SD_07, signal: Data1, daisy: None);
+flexspi!(port: A, alt: 0, pad: GPIO_SD_08, signal: Data2, daisy: None);
+flexspi!(port: A, alt: 0, pad: GPIO_SD_09, signal: Data0, daisy: None);
+flexspi!(port: A, alt: 0, pad: GPIO_SD_10, signal: Sclk, daisy: None);
+flexspi!(port: A, alt: 0, pad: GPIO_SD_11, signal: Data3, daisy: None);
+flexspi!(port: A, alt: 0, pad: GPIO_SD_12, signal: Dqs, daisy: None);
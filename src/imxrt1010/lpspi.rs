@@ -1,3 +1,4 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1010/lpspi.csv. Do not edit.
 //! SPI pin implementations
 
 use super::pads::{gpio_ad::*, gpio_sd::*};
@@ -47,25 +48,26 @@ spi!(module: U2, alt: 1, pad: GPIO_SD_09, signal: Sdi, daisy: DAISY_LPSPI2_SDI_G
 spi!(module: U2, alt: 0, pad: GPIO_AD_10, signal: Sdo, daisy: DAISY_LPSPI2_SDO_GPIO_AD_10);
 spi!(module: U2, alt: 1, pad: GPIO_SD_10, signal: Sdo, daisy: DAISY_LPSPI2_SDO_GPIO_SD_10);
 
+/// Auto-generated Daisy constants
 mod daisy {
+    #![allow(unused)]
     use super::Daisy;
 
-    pub const DAISY_LPSPI1_PCS_0_GPIO_AD_05: Daisy = Daisy::new(0x401f81d0 as *mut u32, 0);
-    pub const DAISY_LPSPI1_PCS_0_GPIO_SD_07: Daisy = Daisy::new(0x401f81d0 as *mut u32, 1);
-    pub const DAISY_LPSPI1_SCK_GPIO_AD_06: Daisy = Daisy::new(0x401f81d4 as *mut u32, 0);
-    pub const DAISY_LPSPI1_SCK_GPIO_SD_08: Daisy = Daisy::new(0x401f81d4 as *mut u32, 1);
-    pub const DAISY_LPSPI1_SDI_GPIO_AD_03: Daisy = Daisy::new(0x401f81d8 as *mut u32, 0);
-    pub const DAISY_LPSPI1_SDI_GPIO_SD_05: Daisy = Daisy::new(0x401f81d8 as *mut u32, 1);
-    pub const DAISY_LPSPI1_SDO_GPIO_AD_04: Daisy = Daisy::new(0x401f81dc as *mut u32, 0);
-    pub const DAISY_LPSPI1_SDO_GPIO_SD_06: Daisy = Daisy::new(0x401f81dc as *mut u32, 1);
-    pub const DAISY_LPSPI2_PCS_0_GPIO_AD_11: Daisy = Daisy::new(0x401f81e0 as *mut u32, 0);
-    pub const DAISY_LPSPI2_PCS_0_GPIO_SD_12: Daisy = Daisy::new(0x401f81e0 as *mut u32, 1);
-    pub const DAISY_LPSPI2_SCK_GPIO_AD_12: Daisy = Daisy::new(0x401f81e4 as *mut u32, 0);
-    pub const DAISY_LPSPI2_SCK_GPIO_SD_11: Daisy = Daisy::new(0x401f81e4 as *mut u32, 1);
-    pub const DAISY_LPSPI2_SDI_GPIO_AD_09: Daisy = Daisy::new(0x401f81e8 as *mut u32, 0);
-    pub const DAISY_LPSPI2_SDI_GPIO_SD_09: Daisy = Daisy::new(0x401f81e8 as *mut u32, 1);
-    pub const DAISY_LPSPI2_SDO_GPIO_AD_10: Daisy = Daisy::new(0x401f81ec as *mut u32, 0);
-    pub const DAISY_LPSPI2_SDO_GPIO_SD_10: Daisy = Daisy::new(0x401f81ec as *mut u32, 1);
+    pub const DAISY_LPSPI1_PCS_0_GPIO_AD_05: Daisy = Daisy::new(0x401f81d0, 0);
+    pub const DAISY_LPSPI1_PCS_0_GPIO_SD_07: Daisy = Daisy::new(0x401f81d0, 1);
+    pub const DAISY_LPSPI1_SCK_GPIO_AD_06: Daisy = Daisy::new(0x401f81d4, 0);
+    pub const DAISY_LPSPI1_SCK_GPIO_SD_08: Daisy = Daisy::new(0x401f81d4, 1);
+    pub const DAISY_LPSPI1_SDI_GPIO_AD_03: Daisy = Daisy::new(0x401f81d8, 0);
+    pub const DAISY_LPSPI1_SDI_GPIO_SD_05: Daisy = Daisy::new(0x401f81d8, 1);
+    pub const DAISY_LPSPI1_SDO_GPIO_AD_04: Daisy = Daisy::new(0x401f81dc, 0);
+    pub const DAISY_LPSPI1_SDO_GPIO_SD_06: Daisy = Daisy::new(0x401f81dc, 1);
+    pub const DAISY_LPSPI2_PCS_0_GPIO_AD_11: Daisy = Daisy::new(0x401f81e0, 0);
+    pub const DAISY_LPSPI2_PCS_0_GPIO_SD_12: Daisy = Daisy::new(0x401f81e0, 1);
+    pub const DAISY_LPSPI2_SCK_GPIO_AD_12: Daisy = Daisy::new(0x401f81e4, 0);
+    pub const DAISY_LPSPI2_SCK_GPIO_SD_11: Daisy = Daisy::new(0x401f81e4, 1);
+    pub const DAISY_LPSPI2_SDI_GPIO_AD_09: Daisy = Daisy::new(0x401f81e8, 0);
+    pub const DAISY_LPSPI2_SDI_GPIO_SD_09: Daisy = Daisy::new(0x401f81e8, 1);
+    pub const DAISY_LPSPI2_SDO_GPIO_AD_10: Daisy = Daisy::new(0x401f81ec, 0);
+    pub const DAISY_LPSPI2_SDO_GPIO_SD_10: Daisy = Daisy::new(0x401f81ec, 1);
 }
-
 use daisy::*;
@@ -1,67 +1,121 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1010/lpuart.csv. Do not edit.
 //! UART pin implementations
 
 use super::pads::{gpio::*, gpio_ad::*, gpio_sd::*};
 use crate::{
     consts::*,
-    lpuart::{Pin, Rx, Tx},
-    Daisy,
+    function::UartDirection,
+    lpuart::{Pin, PinTable, Rx, TableEntry, Tx},
+    Daisy, Function, PadId,
 };
 
+/// The TX pads of each LPUART module
+///
+/// Index the array with the module number; `LPUART_TX_PADS[3]` holds the `LPUART3`
+/// TX pads. Index 0 is empty. See [`candidates()`](super::candidates).
+pub const LPUART_TX_PADS: [&[PadId]; 5] = module_pads(UartDirection::Tx);
+
+/// The RX pads of each LPUART module
+///
+/// Index the array with the module number; `LPUART_RX_PADS[3]` holds the `LPUART3`
+/// RX pads. Index 0 is empty. See [`candidates()`](super::candidates).
+pub const LPUART_RX_PADS: [&[PadId]; 5] = module_pads(UartDirection::Rx);
+
+const fn module_pads(direction: UartDirection) -> [&'static [PadId]; 5] {
+    let mut pads: [&[PadId]; 5] = [&[]; 5];
+    let mut module = 1;
+    while module < pads.len() {
+        pads[module] = super::candidates(Function::Lpuart {
+            module: module as u8,
+            direction,
+        });
+        module += 1;
+    }
+    pads
+}
+
 //
 // UART1
 //
-uart!(module: U1, alt: 0, pad: GPIO_09,      direction: Rx, daisy: Some(DAISY_LPUART1_RXD_GPIO_09));
-uart!(module: U1, alt: 2, pad: GPIO_SD_11,   direction: Rx, daisy: Some(DAISY_LPUART1_RXD_GPIO_SD_11));
-uart!(module: U1, alt: 0, pad: GPIO_10,      direction: Tx, daisy: Some(DAISY_LPUART1_TXD_GPIO_10));
-uart!(module: U1, alt: 2, pad: GPIO_SD_12,   direction: Tx, daisy: Some(DAISY_LPUART1_TXD_GPIO_SD_12));
+uart!(module: U1, alt: 0, pad: GPIO_09, direction: Rx, daisy: Some(DAISY_LPUART1_RXD_GPIO_09));
+uart!(module: U1, alt: 2, pad: GPIO_SD_11, direction: Rx, daisy: Some(DAISY_LPUART1_RXD_GPIO_SD_11));
+uart!(module: U1, alt: 0, pad: GPIO_10, direction: Tx, daisy: Some(DAISY_LPUART1_TXD_GPIO_10));
+uart!(module: U1, alt: 2, pad: GPIO_SD_12, direction: Tx, daisy: Some(DAISY_LPUART1_TXD_GPIO_SD_12));
 
 //
 // UART2
 //
-uart!(module: U2, alt: 0, pad: GPIO_13,      direction: Rx, daisy: Some(DAISY_LPUART2_RXD_GPIO_13));
-uart!(module: U2, alt: 2, pad: GPIO_SD_09,   direction: Rx, daisy: Some(DAISY_LPUART2_RXD_GPIO_SD_09));
-uart!(module: U2, alt: 0, pad: GPIO_AD_00,   direction: Tx, daisy: Some(DAISY_LPUART2_TXD_GPIO_AD_00));
-uart!(module: U2, alt: 2, pad: GPIO_SD_10,   direction: Tx, daisy: Some(DAISY_LPUART2_TXD_GPIO_SD_10));
+uart!(module: U2, alt: 0, pad: GPIO_13, direction: Rx, daisy: Some(DAISY_LPUART2_RXD_GPIO_13));
+uart!(module: U2, alt: 2, pad: GPIO_SD_09, direction: Rx, daisy: Some(DAISY_LPUART2_RXD_GPIO_SD_09));
+uart!(module: U2, alt: 0, pad: GPIO_AD_00, direction: Tx, daisy: Some(DAISY_LPUART2_TXD_GPIO_AD_00));
+uart!(module: U2, alt: 2, pad: GPIO_SD_10, direction: Tx, daisy: Some(DAISY_LPUART2_TXD_GPIO_SD_10));
 
 //
 // UART3
 //
-uart!(module: U3, alt: 0, pad: GPIO_11,      direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_11));
-uart!(module: U3, alt: 1, pad: GPIO_AD_07,   direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_AD_07));
-uart!(module: U3, alt: 3, pad: GPIO_07,      direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_07));
-uart!(module: U3, alt: 0, pad: GPIO_12,      direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_12));
-uart!(module: U3, alt: 1, pad: GPIO_AD_08,   direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_AD_08));
-uart!(module: U3, alt: 3, pad: GPIO_08,      direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_08));
+uart!(module: U3, alt: 0, pad: GPIO_11, direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_11));
+uart!(module: U3, alt: 1, pad: GPIO_AD_07, direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_AD_07));
+uart!(module: U3, alt: 3, pad: GPIO_07, direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_07));
+uart!(module: U3, alt: 0, pad: GPIO_12, direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_12));
+uart!(module: U3, alt: 1, pad: GPIO_AD_08, direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_AD_08));
+uart!(module: U3, alt: 3, pad: GPIO_08, direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_08));
 
 //
 // UART4
 //
-uart!(module: U4, alt: 0, pad: GPIO_AD_01,   direction: Rx, daisy: Some(DAISY_LPUART4_RXD_GPIO_AD_01));
-uart!(module: U4, alt: 3, pad: GPIO_05,      direction: Rx, daisy: Some(DAISY_LPUART4_RXD_GPIO_05));
-uart!(module: U4, alt: 0, pad: GPIO_AD_02,   direction: Tx, daisy: Some(DAISY_LPUART4_TXD_GPIO_AD_02));
-uart!(module: U4, alt: 3, pad: GPIO_06,      direction: Tx, daisy: Some(DAISY_LPUART4_TXD_GPIO_06));
+uart!(module: U4, alt: 0, pad: GPIO_AD_01, direction: Rx, daisy: Some(DAISY_LPUART4_RXD_GPIO_AD_01));
+uart!(module: U4, alt: 3, pad: GPIO_05, direction: Rx, daisy: Some(DAISY_LPUART4_RXD_GPIO_05));
+uart!(module: U4, alt: 0, pad: GPIO_AD_02, direction: Tx, daisy: Some(DAISY_LPUART4_TXD_GPIO_AD_02));
+uart!(module: U4, alt: 3, pad: GPIO_06, direction: Tx, daisy: Some(DAISY_LPUART4_TXD_GPIO_06));
 
 /// Auto-generated Daisy constants
 mod daisy {
+    #![allow(unused)]
     use super::Daisy;
 
-    pub const DAISY_LPUART1_RXD_GPIO_SD_11: Daisy = Daisy::new(0x401f81f0 as *mut u32, 0);
-    pub const DAISY_LPUART1_RXD_GPIO_09: Daisy = Daisy::new(0x401f81f0 as *mut u32, 1);
-    pub const DAISY_LPUART1_TXD_GPIO_SD_12: Daisy = Daisy::new(0x401f81f4 as *mut u32, 0);
-    pub const DAISY_LPUART1_TXD_GPIO_10: Daisy = Daisy::new(0x401f81f4 as *mut u32, 1);
-    pub const DAISY_LPUART2_RXD_GPIO_SD_09: Daisy = Daisy::new(0x401f81f8 as *mut u32, 0);
-    pub const DAISY_LPUART2_RXD_GPIO_13: Daisy = Daisy::new(0x401f81f8 as *mut u32, 1);
-    pub const DAISY_LPUART2_TXD_GPIO_AD_00: Daisy = Daisy::new(0x401f81fc as *mut u32, 0);
-    pub const DAISY_LPUART2_TXD_GPIO_SD_10: Daisy = Daisy::new(0x401f81fc as *mut u32, 1);
-    pub const DAISY_LPUART3_RXD_GPIO_AD_07: Daisy = Daisy::new(0x401f8200 as *mut u32, 0);
-    pub const DAISY_LPUART3_RXD_GPIO_11: Daisy = Daisy::new(0x401f8200 as *mut u32, 1);
-    pub const DAISY_LPUART3_RXD_GPIO_07: Daisy = Daisy::new(0x401f8200 as *mut u32, 2);
-    pub const DAISY_LPUART3_TXD_GPIO_AD_08: Daisy = Daisy::new(0x401f8204 as *mut u32, 0);
-    pub const DAISY_LPUART3_TXD_GPIO_12: Daisy = Daisy::new(0x401f8204 as *mut u32, 1);
-    pub const DAISY_LPUART3_TXD_GPIO_08: Daisy = Daisy::new(0x401f8204 as *mut u32, 2);
-    pub const DAISY_LPUART4_RXD_GPIO_AD_01: Daisy = Daisy::new(0x401f8208 as *mut u32, 0);
-    pub const DAISY_LPUART4_RXD_GPIO_05: Daisy = Daisy::new(0x401f8208 as *mut u32, 1);
-    pub const DAISY_LPUART4_TXD_GPIO_AD_02: Daisy = Daisy::new(0x401f820c as *mut u32, 0);
-    pub const DAISY_LPUART4_TXD_GPIO_06: Daisy = Daisy::new(0x401f820c as *mut u32, 1);
+    pub const DAISY_LPUART1_RXD_GPIO_SD_11: Daisy = Daisy::new(0x401f81f0, 0);
+    pub const DAISY_LPUART1_RXD_GPIO_09: Daisy = Daisy::new(0x401f81f0, 1);
+    pub const DAISY_LPUART1_TXD_GPIO_SD_12: Daisy = Daisy::new(0x401f81f4, 0);
+    pub const DAISY_LPUART1_TXD_GPIO_10: Daisy = Daisy::new(0x401f81f4, 1);
+    pub const DAISY_LPUART2_RXD_GPIO_SD_09: Daisy = Daisy::new(0x401f81f8, 0);
+    pub const DAISY_LPUART2_RXD_GPIO_13: Daisy = Daisy::new(0x401f81f8, 1);
+    pub const DAISY_LPUART2_TXD_GPIO_AD_00: Daisy = Daisy::new(0x401f81fc, 0);
+    pub const DAISY_LPUART2_TXD_GPIO_SD_10: Daisy = Daisy::new(0x401f81fc, 1);
+    pub const DAISY_LPUART3_RXD_GPIO_AD_07: Daisy = Daisy::new(0x401f8200, 0);
+    pub const DAISY_LPUART3_RXD_GPIO_11: Daisy = Daisy::new(0x401f8200, 1);
+    pub const DAISY_LPUART3_RXD_GPIO_07: Daisy = Daisy::new(0x401f8200, 2);
+    pub const DAISY_LPUART3_TXD_GPIO_AD_08: Daisy = Daisy::new(0x401f8204, 0);
+    pub const DAISY_LPUART3_TXD_GPIO_12: Daisy = Daisy::new(0x401f8204, 1);
+    pub const DAISY_LPUART3_TXD_GPIO_08: Daisy = Daisy::new(0x401f8204, 2);
+    pub const DAISY_LPUART4_RXD_GPIO_AD_01: Daisy = Daisy::new(0x401f8208, 0);
+    pub const DAISY_LPUART4_RXD_GPIO_05: Daisy = Daisy::new(0x401f8208, 1);
+    pub const DAISY_LPUART4_TXD_GPIO_AD_02: Daisy = Daisy::new(0x401f820c, 0);
+    pub const DAISY_LPUART4_TXD_GPIO_06: Daisy = Daisy::new(0x401f820c, 1);
 }
 use daisy::*;
+
+impl PinTable for super::ErasedPads {
+    fn pins(&self) -> &[TableEntry] {
+        const PINS: [TableEntry; 18] = [
+            TableEntry::new::<U1, GPIO_09>(),
+            TableEntry::new::<U1, GPIO_SD_11>(),
+            TableEntry::new::<U1, GPIO_10>(),
+            TableEntry::new::<U1, GPIO_SD_12>(),
+            TableEntry::new::<U2, GPIO_13>(),
+            TableEntry::new::<U2, GPIO_SD_09>(),
+            TableEntry::new::<U2, GPIO_AD_00>(),
+            TableEntry::new::<U2, GPIO_SD_10>(),
+            TableEntry::new::<U3, GPIO_11>(),
+            TableEntry::new::<U3, GPIO_AD_07>(),
+            TableEntry::new::<U3, GPIO_07>(),
+            TableEntry::new::<U3, GPIO_12>(),
+            TableEntry::new::<U3, GPIO_AD_08>(),
+            TableEntry::new::<U3, GPIO_08>(),
+            TableEntry::new::<U4, GPIO_AD_01>(),
+            TableEntry::new::<U4, GPIO_05>(),
+            TableEntry::new::<U4, GPIO_AD_02>(),
+            TableEntry::new::<U4, GPIO_06>(),
+        ];
+        &PINS
+    }
+}
@@ -1,7 +1,8 @@
 //! Pads for the i.MX RT 1010 processor family
 //!
 //! The module exports all of the i.MX RT 1010 processor's pads. Pads that can support peripheral
-//! functions are tagged with `imxrt-iomuxc` traits.
+//! functions are tagged with `imxrt-iomuxc` traits. The [`examples`] module shows one set
+//! of i.MX RT 1010 pads for each peripheral.
 //!
 //! # Example
 //!
@@ -87,16 +88,487 @@
 //! // GPIO_10 is a UART1 TX pin, and GPIO_13 is a UART2 RX pin
 //! uart_new(gpio_10, gpio_13, 115_200);
 //! ```
+//!
+//! # Dedicated balls
+//!
+//! Some balls, like the crystal oscillator and USB PHY balls, are not controlled by the
+//! IOMUXC. The [`dedicated`] module represents them with marker types, and `Pads` owns
+//! them in its `dedicated` member. The marker types do not implement `Iomuxc`, so you
+//! cannot configure or prepare them:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{configure, imxrt1010::Pads, Config};
+//!
+//! let mut pads = unsafe { Pads::new() };
+//! configure(&mut pads.dedicated.xtali, Config::zero());
+//! ```
 
+#[rustfmt::skip] // Generated, with long lines
+mod candidates;
+mod flexio;
+mod flexspi;
 mod lpi2c;
 mod lpspi;
 mod lpuart;
 
 include!(concat!(env!("OUT_DIR"), "/imxrt1010.rs"));
+pub use flexio::FLEXIO1_PADS;
+pub use lpuart::{LPUART_RX_PADS, LPUART_TX_PADS};
 pub use pads::*;
 
+/// Pads that the boot ROM uses to read the boot image
+///
+/// These are the FlexSPI port A pads. When you execute in place, reconfiguring
+/// any of these pads may break instruction fetches.
+pub const BOOT_CRITICAL_PADS: [crate::PadId; 7] = [
+    gpio_sd::GPIO_SD_06::ID,
+    gpio_sd::GPIO_SD_07::ID,
+    gpio_sd::GPIO_SD_08::ID,
+    gpio_sd::GPIO_SD_09::ID,
+    gpio_sd::GPIO_SD_10::ID,
+    gpio_sd::GPIO_SD_11::ID,
+    gpio_sd::GPIO_SD_12::ID,
+];
+
+/// Returns a read-only view of the pad `id`
+///
+/// Returns `None` if `id` is not an i.MX RT 1010 pad.
+pub fn pad_ref(id: crate::PadId) -> Option<crate::PadRef> {
+    // In every group, each pad register is 0xB0 bytes after its mux register.
+    crate::PadRef::lookup(id, &groups::GROUPS, 0xB0)
+}
+
+/// Returns the pads that can carry `function`
+///
+/// The pads come from the chip's pin implementations, so they're the pads that
+/// satisfy the peripheral module's `Pin` trait, ordered by [`PadId`](crate::PadId).
+/// A pad that this crate doesn't implement for `function` isn't a candidate.
+/// Returns an empty slice for GPIOs, and for functions that the chip doesn't have.
+///
+/// ```
+/// use imxrt_iomuxc::{function::UartDirection, imxrt1010, Function};
+/// use imxrt1010::{gpio::GPIO_09, gpio_sd::GPIO_SD_11};
+///
+/// let rx = Function::Lpuart { module: 1, direction: UartDirection::Rx };
+/// assert_eq!(imxrt1010::candidates(rx), [GPIO_SD_11::ID, GPIO_09::ID]);
+/// ```
+pub const fn candidates(function: crate::Function) -> &'static [crate::PadId] {
+    candidates::CANDIDATES.get(function)
+}
+
+/// Returns the routing that connects `function` to the pad `id`
+///
+/// The routing comes from the chip's pin implementations, like [`candidates()`]. It
+/// has the pin's alternate, SION requirement, and daisy selection. Its pad
+/// configuration is [`Config::modify()`](crate::Config::modify), so it keeps the pad's
+/// settings, like the modules' `prepare()`; an ADC routing also disables the pull /
+/// keeper. Change the `config` to configure the pad. Returns `None` if the pad can't
+/// carry `function`, and for GPIOs. Use [`gpio::prepared()`](crate::gpio::prepared)
+/// to route a GPIO.
+pub const fn resolve(id: crate::PadId, function: crate::Function) -> Option<crate::Routing> {
+    candidates::CANDIDATES.routing(id, function)
+}
+
+/// Returns the functions that share the pad `id`
+///
+/// A pad carries one alternate at a time, so routing the pad to one of these
+/// functions precludes the others. The functions come from the chip's pin
+/// implementations, like [`candidates()`], and they're ordered by alternate. A
+/// function that this crate doesn't implement isn't listed, and neither is the
+/// pad's GPIO. Returns an empty slice if `id` is not an i.MX RT 1010 pad.
+///
+/// ```
+/// use imxrt_iomuxc::{imxrt1010::{self, gpio_sd::GPIO_SD_12}, Function};
+///
+/// // FLEXSPI_A_DQS, LPSPI2, and LPUART1_TX
+/// let functions = imxrt1010::conflicting_functions(GPIO_SD_12::ID);
+/// assert_eq!(functions.len(), 3);
+/// assert!(matches!(functions[2], Function::Lpuart { module: 1, .. }));
+/// ```
+pub const fn conflicting_functions(id: crate::PadId) -> &'static [crate::Function] {
+    candidates::CANDIDATES.functions_at(id)
+}
+
+/// Returns the function that the pad `id` carries on the alternate `alt`
+///
+/// The function comes from the chip's pin implementations, like [`candidates()`].
+/// Returns `None` for the GPIO alternate, and if this crate doesn't implement the
+/// pad's function on `alt`.
+pub const fn function_at(id: crate::PadId, alt: u8) -> Option<crate::Function> {
+    candidates::CANDIDATES.function_at(id, alt)
+}
+
+/// Plan a set of `(PadId, Function)` assignments
+///
+/// Each assignment is [resolved](resolve) to a routing in the front of `storage`,
+/// and the routings are checked with [`plan()`](crate::plan). The returned plan has
+/// the alternate, daisy selection, and pad configuration of each assignment, in
+/// assignment order. Returns
+///
+/// - [`PlanError::ImpossibleAssignment`](crate::PlanError::ImpossibleAssignment)
+///   if a pad can't carry its function.
+/// - [`PlanError::TooManyAssignments`](crate::PlanError::TooManyAssignments) if
+///   `storage` is shorter than `assignments`.
+/// - the other `plan()` errors, like two assignments on one pad, or two pads for
+///   the same peripheral input.
+///
+/// ```
+/// use imxrt_iomuxc::{function::UartDirection, imxrt1010, Function, PlanError};
+/// use imxrt1010::{gpio::{GPIO_09, GPIO_10}, gpio_sd::GPIO_SD_11};
+///
+/// let tx = Function::Lpuart { module: 1, direction: UartDirection::Tx };
+/// let rx = Function::Lpuart { module: 1, direction: UartDirection::Rx };
+///
+/// let mut storage = [imxrt1010::resolve(GPIO_10::ID, tx).unwrap(); 2];
+/// let plan = imxrt1010::plan_assignments(
+///     &[(GPIO_10::ID, tx), (GPIO_09::ID, rx)],
+///     &mut storage,
+/// )
+/// .unwrap();
+/// assert_eq!(plan.routings()[1].alt, 0);
+///
+/// // Both pads select the RX input
+/// assert!(matches!(
+///     imxrt1010::plan_assignments(&[(GPIO_09::ID, rx), (GPIO_SD_11::ID, rx)], &mut storage),
+///     Err(PlanError::DaisyConflict(_))
+/// ));
+/// ```
+pub fn plan_assignments<'a>(
+    assignments: &[(crate::PadId, crate::Function)],
+    storage: &'a mut [crate::Routing],
+) -> Result<crate::Plan<'a>, crate::PlanError> {
+    crate::routing::plan_assignments(assignments, storage, resolve)
+}
+
+/// The GPIO alternate
+///
+/// Every i.MX RT 1010 pad is a GPIO on ALT5; the `gpio_alts` test checks it.
+#[cfg(test)]
+const GPIO_ALT: u8 = 5;
+
+/// Returns `true` if the pad `id` implements the alternate `alt`
+///
+/// The alternates come from the chip's pad mux data, so a pad supports an
+/// alternate even if this crate doesn't implement a pin for it. Returns `false`
+/// if `id` is not an i.MX RT 1010 pad.
+///
+/// ```
+/// use imxrt_iomuxc::imxrt1010::{self, gpio_sd::GPIO_SD_12};
+///
+/// // LPUART1_TX, and GPIO2_IO12
+/// assert!(imxrt1010::supports_alternate(GPIO_SD_12::ID, 2));
+/// assert!(imxrt1010::supports_alternate(GPIO_SD_12::ID, 5));
+/// assert!(!imxrt1010::supports_alternate(GPIO_SD_12::ID, 6));
+/// ```
+pub fn supports_alternate(id: crate::PadId, alt: u8) -> bool {
+    crate::GroupInfo::supports_alternate(id, alt, &groups::GROUPS, &ALTERNATES)
+}
+
+/// Returns the pad's GPIO module, and its interrupt configuration register index
+/// and field shift
+///
+/// The result is `(module, index, shift)`, with the same `index` and `shift` as the
+/// pad's [`gpio::Pin::ICR_INDEX`](crate::gpio::Pin::ICR_INDEX) and
+/// [`ICR_SHIFT`](crate::gpio::Pin::ICR_SHIFT). Use it when you only know the pad at
+/// run time. Returns `None` if `id` is not an i.MX RT 1010 pad.
+///
+/// ```
+/// use imxrt_iomuxc::imxrt1010::{self, gpio_sd::GPIO_SD_12};
+///
+/// // GPIO2_IO12 is in GPIO2's ICR1, bits 25:24
+/// assert_eq!(imxrt1010::gpio_icr(GPIO_SD_12::ID), Some((2, 0, 24)));
+/// ```
+pub fn gpio_icr(id: crate::PadId) -> Option<(u8, usize, u32)> {
+    crate::GroupInfo::gpio_icr(id, &groups::GROUPS, &GPIOS)
+}
+
+chip_examples! {
+    chip: imxrt1010,
+    lpuart: { module: U1, tx: gpio_sd::GPIO_SD_12, rx: gpio_sd::GPIO_SD_11 },
+    lpi2c: { module: U1, scl: gpio_ad::GPIO_AD_14, sda: gpio_ad::GPIO_AD_13 },
+    lpspi: {
+        module: U1,
+        sck: gpio_ad::GPIO_AD_06,
+        sdo: gpio_ad::GPIO_AD_04,
+        sdi: gpio_ad::GPIO_AD_03,
+        pcs0: gpio_ad::GPIO_AD_05
+    },
+}
+
 mod bases {
     define_base!(GPIO_AD, 0x401F_8010, 0x401F_80C0);
     define_base!(GPIO_SD, 0x401F_804C, 0x401F_80FC);
     define_base!(GPIO, 0x401F_8088, 0x401F_8138);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PadGroup;
+
+    fn check_group<G: PadGroup>(group: G, info: crate::GroupInfo, count: usize) {
+        assert_eq!(G::PAD_COUNT, count);
+        assert_eq!(info.pad_count, count);
+        assert_eq!(G::OFFSETS.len(), count);
+        let mut pads = group.erased_iter().peekable();
+        assert_eq!(pads.peek().map(|pad| pad.id()), Some(info.base_id));
+        let offsets = pads.map(|pad| pad.offset());
+        assert!(offsets.eq(G::OFFSETS.iter().map(|&offset| offset as usize)));
+    }
+
+    #[test]
+    fn pad_groups() {
+        let pads = unsafe { Pads::new() };
+        check_group(pads.gpio_ad, gpio_ad::INFO, 16);
+        check_group(pads.gpio_sd, gpio_sd::INFO, 16);
+        check_group(pads.gpio, gpio::INFO, 16);
+    }
+
+    #[test]
+    fn pad_refs_from_ids() {
+        let pads = unsafe { Pads::new() };
+        let erased = pads
+            .gpio_ad
+            .erased_iter()
+            .chain(pads.gpio_sd.erased_iter())
+            .chain(pads.gpio.erased_iter());
+        for pad in erased {
+            assert_eq!(pad_ref(pad.id()), Some(pad.pad_ref()));
+        }
+        assert_eq!(pad_ref(crate::PadId(0x401F_800C)), None);
+        let unaligned = BOOT_CRITICAL_PADS[0].mux_addr() + 2;
+        assert_eq!(pad_ref(crate::PadId(unaligned)), None);
+    }
+
+    #[test]
+    fn groups_match_modules() {
+        let expected = [
+            ("GPIO_AD", gpio_ad::GPIO_AD_00::ID),
+            ("GPIO_SD", gpio_sd::GPIO_SD_00::ID),
+            ("GPIO", gpio::GPIO_00::ID),
+        ];
+        assert_eq!(groups::GROUPS.len(), expected.len());
+        for (info, (name, base_id)) in groups::GROUPS.iter().zip(expected.iter()) {
+            assert_eq!((info.name, info.base_id), (*name, *base_id));
+        }
+    }
+
+    #[test]
+    fn boot_critical_pads_are_flexspi() {
+        use crate::{flexspi::prepared, Config};
+        use gpio_sd::*;
+
+        const CONFIG: Config = Config::zero();
+        let prepared = [
+            prepared::<GPIO_SD_06>(CONFIG),
+            prepared::<GPIO_SD_07>(CONFIG),
+            prepared::<GPIO_SD_08>(CONFIG),
+            prepared::<GPIO_SD_09>(CONFIG),
+            prepared::<GPIO_SD_10>(CONFIG),
+            prepared::<GPIO_SD_11>(CONFIG),
+            prepared::<GPIO_SD_12>(CONFIG),
+        ];
+        for (writes, id) in prepared.iter().zip(BOOT_CRITICAL_PADS.iter()) {
+            assert_eq!(writes.mux_addr, id.mux_addr());
+            assert_eq!(writes.mux_val, 0);
+            assert_eq!(writes.daisy, None);
+        }
+    }
+
+    #[test]
+    fn candidates_cover_pins() {
+        crate::function::tests::check_candidates(
+            &candidates::CANDIDATES,
+            &groups::GROUPS,
+            GPIO_ALT,
+            &[
+                include_str!("flexio.rs"),
+                include_str!("flexspi.rs"),
+                include_str!("lpi2c.rs"),
+                include_str!("lpspi.rs"),
+                include_str!("lpuart.rs"),
+            ],
+        );
+    }
+
+    #[test]
+    fn alternates_cover_pins() {
+        for (function, routing) in candidates::CANDIDATES.pins() {
+            let (pad, alt) = (routing.pad, routing.alt);
+            assert!(
+                supports_alternate(pad, alt),
+                "{:?} ALT{} for {:?}",
+                pad,
+                alt,
+                function
+            );
+        }
+        for group in groups::GROUPS.iter() {
+            for pad in 0..group.pad_count {
+                let id = crate::PadId(group.base_id.mux_addr() + 4 * pad);
+                assert!(supports_alternate(id, GPIO_ALT), "{:?} GPIO", id);
+            }
+        }
+    }
+
+    #[test]
+    fn conflicting_functions_cover_pins() {
+        for (function, routing) in candidates::CANDIDATES.pins() {
+            assert!(conflicting_functions(routing.pad).contains(&function));
+            if !matches!(function, crate::Function::Adc { .. }) {
+                assert_eq!(function_at(routing.pad, routing.alt), Some(function));
+            }
+        }
+    }
+
+    #[test]
+    fn known_candidates() {
+        use crate::function::I2cSignal;
+        use crate::Function;
+
+        // Reference manual, LPI2C1_SCL_SELECT_INPUT
+        let scl = Function::Lpi2c {
+            module: 1,
+            signal: I2cSignal::Scl,
+        };
+        assert_eq!(
+            candidates(scl),
+            [
+                gpio_ad::GPIO_AD_14::ID,
+                gpio_sd::GPIO_SD_06::ID,
+                gpio::GPIO_02::ID,
+                gpio::GPIO_12::ID
+            ]
+        );
+
+        // Reference manual, LPUART3_TXD_SELECT_INPUT
+        assert_eq!(
+            LPUART_TX_PADS[3],
+            [
+                gpio_ad::GPIO_AD_08::ID,
+                gpio::GPIO_08::ID,
+                gpio::GPIO_12::ID
+            ]
+        );
+        assert_eq!(LPUART_RX_PADS.len(), 5);
+        assert!(LPUART_RX_PADS[0].is_empty());
+    }
+
+    #[test]
+    fn flexio1_pads() {
+        use crate::flexio::{function, Pin};
+        use crate::Function;
+
+        // Each FLEXIO1 pin has one pad, so the table has no duplicates
+        for (index, pad) in FLEXIO1_PADS.iter().enumerate() {
+            let pin = Function::FlexIo {
+                module: 1,
+                index: index as u8,
+            };
+            assert_eq!(candidates(pin), [*pad]);
+        }
+        assert_eq!(FLEXIO1_PADS[0], gpio::GPIO_02::ID);
+        assert_eq!(FLEXIO1_PADS[26], gpio_ad::GPIO_AD_14::ID);
+
+        assert_eq!(<gpio::GPIO_13 as Pin>::ALT, 4);
+        assert_eq!(
+            function::<gpio_ad::GPIO_AD_00>(),
+            Function::FlexIo {
+                module: 1,
+                index: 12
+            }
+        );
+    }
+
+    #[test]
+    fn lpuart_no_swaps() {
+        use crate::lpuart::PinTable;
+
+        // No pad is both a TX and an RX pin of the same UART
+        let pads = unsafe { Pads::new() }.erase();
+        for pin in pads.pins() {
+            assert_eq!(pin.swapped(pads.pins()), None, "{:?}", pin);
+        }
+    }
+
+    #[test]
+    fn module_aliases() {
+        use crate::{flexio, gpio, lpi2c, lpspi, lpuart};
+
+        // Compiles only if each alias names the module of the pin impl
+        fn flexio1<P: flexio::Pin<Module = flexio::module::Flexio1>>() {}
+        fn lpuart4<P: lpuart::Pin<Module = lpuart::module::Lpuart4>>() {}
+        fn lpi2c2<P: lpi2c::Pin<Module = lpi2c::module::Lpi2c2>>() {}
+        fn lpspi2<P: lpspi::Pin<Module = lpspi::module::Lpspi2>>() {}
+        fn gpio1<P: gpio::Pin<Module = gpio::module::Gpio1>>() {}
+
+        flexio1::<gpio_ad::GPIO_AD_05>();
+        lpuart4::<gpio_ad::GPIO_AD_01>();
+        lpi2c2::<gpio_ad::GPIO_AD_08>();
+        lpspi2::<gpio_ad::GPIO_AD_11>();
+        gpio1::<gpio_ad::GPIO_AD_00>();
+    }
+
+    #[test]
+    fn daisy_tables() {
+        use crate::tests::check_daisy_table;
+        check_daisy_table(include_str!("lpi2c.rs"));
+        check_daisy_table(include_str!("lpspi.rs"));
+        check_daisy_table(include_str!("lpuart.rs"));
+    }
+
+    /// A daisy register with one source could skip its write, if its reset value
+    /// selects that source. No 1010 register qualifies.
+    #[test]
+    fn daisies_have_many_sources() {
+        extern crate std;
+        use std::collections::BTreeMap;
+
+        let mut sources: BTreeMap<&str, usize> = BTreeMap::new();
+        let tables = [
+            include_str!("lpi2c.rs"),
+            include_str!("lpspi.rs"),
+            include_str!("lpuart.rs"),
+        ];
+        for line in tables.iter().flat_map(|table| table.lines()) {
+            if let Some((_, args)) = line.split_once("Daisy::new(") {
+                let (reg, _) = args.split_once(", ").unwrap();
+                *sources.entry(reg).or_default() += 1;
+            }
+        }
+        assert_eq!(sources.len(), 21);
+        for (reg, count) in sources {
+            assert!(count > 1, "{} has one source", reg);
+        }
+    }
+
+    #[test]
+    fn gpio_icr_matches_pins() {
+        assert_eq!(GPIO_PINS.len(), 48);
+        for (idx, &(id, module, index, shift)) in GPIO_PINS.iter().enumerate() {
+            // GPIO_AD_15 has the same mux register as GPIO_SD_00, and GPIO_SD_15 has
+            // the same register as GPIO_00. The lookup finds the earlier pad.
+            if GPIO_PINS[..idx].iter().any(|pin| pin.0 == id) {
+                continue;
+            }
+            assert_eq!(gpio_icr(id), Some((module, index, shift)), "{:?}", id);
+        }
+        assert_eq!(gpio_icr(crate::PadId(0x1234)), None);
+    }
+
+    #[test]
+    fn gpio_alts() {
+        crate::tests::check_gpio_alts(
+            include_str!(concat!(env!("OUT_DIR"), "/imxrt1010.rs")),
+            48,
+            GPIO_ALT,
+            &[
+                include_str!("flexio.rs"),
+                include_str!("flexspi.rs"),
+                include_str!("lpi2c.rs"),
+                include_str!("lpspi.rs"),
+                include_str!("lpuart.rs"),
+            ],
+        );
+    }
+}
@@ -1,3 +1,4 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/adc.csv. Do not edit.
 //! ADC pin implementations
 //!
 //! Implementation derived from Table 66-2: ADC External Signals
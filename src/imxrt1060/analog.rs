@@ -0,0 +1,173 @@
+//! Analog-capable pads, and parking unused pads
+
+use super::pads::{gpio_ad_b0, gpio_ad_b1};
+use crate::{Config, ErasedPad, Iomuxc, PadId, ParkStrategy, PullKeeper, SionRequirement};
+
+/// Pads that can connect to an ADC input
+///
+/// The list derives from the ADC pin implementations. The i.MX RT 1060 does not
+/// have any dedicated, analog-only pads.
+pub const ANALOG_PADS: [PadId; 20] = [
+    gpio_ad_b0::GPIO_AD_B0_12::ID,
+    gpio_ad_b0::GPIO_AD_B0_13::ID,
+    gpio_ad_b0::GPIO_AD_B0_14::ID,
+    gpio_ad_b0::GPIO_AD_B0_15::ID,
+    gpio_ad_b1::GPIO_AD_B1_00::ID,
+    gpio_ad_b1::GPIO_AD_B1_01::ID,
+    gpio_ad_b1::GPIO_AD_B1_02::ID,
+    gpio_ad_b1::GPIO_AD_B1_03::ID,
+    gpio_ad_b1::GPIO_AD_B1_04::ID,
+    gpio_ad_b1::GPIO_AD_B1_05::ID,
+    gpio_ad_b1::GPIO_AD_B1_06::ID,
+    gpio_ad_b1::GPIO_AD_B1_07::ID,
+    gpio_ad_b1::GPIO_AD_B1_08::ID,
+    gpio_ad_b1::GPIO_AD_B1_09::ID,
+    gpio_ad_b1::GPIO_AD_B1_10::ID,
+    gpio_ad_b1::GPIO_AD_B1_11::ID,
+    gpio_ad_b1::GPIO_AD_B1_12::ID,
+    gpio_ad_b1::GPIO_AD_B1_13::ID,
+    gpio_ad_b1::GPIO_AD_B1_14::ID,
+    gpio_ad_b1::GPIO_AD_B1_15::ID,
+];
+
+/// Returns `true` if the pad can connect to an ADC input
+///
+/// ```
+/// use imxrt_iomuxc::imxrt1060::{gpio_ad_b1::GPIO_AD_B1_11, gpio_b0::GPIO_B0_00, is_analog_capable};
+///
+/// assert!(is_analog_capable(GPIO_AD_B1_11::ID));
+/// assert!(!is_analog_capable(GPIO_B0_00::ID));
+/// ```
+pub fn is_analog_capable(id: PadId) -> bool {
+    ANALOG_PADS.contains(&id)
+}
+
+/// The GPIO alternate for all pads
+const GPIO_ALT: u32 = 5;
+
+/// Park unused pads in a low-power state
+///
+/// Each pad becomes a GPIO input with its SION bit cleared. With
+/// [`ParkStrategy::DigitalKeeper`], each pad enables its keeper. With
+/// [`ParkStrategy::PreferAnalog`], analog-capable pads disable their pull / keeper,
+/// which has lower leakage, while all other pads enable their keeper.
+///
+/// `park_all()` overwrites each pad's configuration. `pads` may be a slice or array
+/// of erased pads, or any iterator over erased pads, like [`Remainder::iter_mut()`](crate::Remainder::iter_mut).
+///
+/// `park_all()` skips the [`BOOT_CRITICAL_PADS`](super::BOOT_CRITICAL_PADS), so that it
+/// doesn't break execute-in-place. If you don't boot from FlexSPI flash, use
+/// [`park_all_including_boot()`] to park those pads, too.
+pub fn park_all<'a, I>(pads: I, strategy: ParkStrategy)
+where
+    I: IntoIterator<Item = &'a mut ErasedPad>,
+{
+    park_all_except(pads, strategy, &super::BOOT_CRITICAL_PADS);
+}
+
+/// Park unused pads in a low-power state, including the boot-critical pads
+///
+/// Like [`park_all()`], but also parks the [`BOOT_CRITICAL_PADS`](super::BOOT_CRITICAL_PADS).
+pub fn park_all_including_boot<'a, I>(pads: I, strategy: ParkStrategy)
+where
+    I: IntoIterator<Item = &'a mut ErasedPad>,
+{
+    park_all_except(pads, strategy, &[]);
+}
+
+fn park_all_except<'a, I>(pads: I, strategy: ParkStrategy, skip: &[PadId])
+where
+    I: IntoIterator<Item = &'a mut ErasedPad>,
+{
+    for pad in pads {
+        let id = pad.id();
+        if !skip.contains(&id) {
+            park(pad, is_analog_capable(id), strategy);
+        }
+    }
+}
+
+fn park<I: Iomuxc>(pad: &mut I, analog: bool, strategy: ParkStrategy) {
+    let pull_keeper = match strategy {
+        ParkStrategy::PreferAnalog if analog => None,
+        _ => Some(PullKeeper::Keeper),
+    };
+    crate::prepare_pin_configured(
+        pad,
+        Config::zero().set_pull_keeper(pull_keeper),
+        GPIO_ALT,
+        SionRequirement::Clear,
+        None,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_analog_capable, park, park_all_except};
+    use crate::{imxrt1060::*, ErasedPad, Iomuxc, ParkStrategy, PullKeeper};
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    #[test]
+    fn analog_capable() {
+        assert!(is_analog_capable(gpio_ad_b0::GPIO_AD_B0_12::ID));
+        assert!(is_analog_capable(gpio_ad_b1::GPIO_AD_B1_00::ID));
+        assert!(is_analog_capable(gpio_ad_b1::GPIO_AD_B1_15::ID));
+        assert!(!is_analog_capable(gpio_ad_b0::GPIO_AD_B0_11::ID));
+        assert!(!is_analog_capable(gpio_emc::GPIO_EMC_00::ID));
+    }
+
+    #[test]
+    fn park_strategies() {
+        const PARKED: [(bool, ParkStrategy, u32); 4] = [
+            (
+                false,
+                ParkStrategy::DigitalKeeper,
+                PullKeeper::Keeper as u32,
+            ),
+            (true, ParkStrategy::DigitalKeeper, PullKeeper::Keeper as u32),
+            (false, ParkStrategy::PreferAnalog, PullKeeper::Keeper as u32),
+            (true, ParkStrategy::PreferAnalog, 0),
+        ];
+        for (analog, strategy, pad_val) in PARKED.iter().copied() {
+            let mut pad = FakePad {
+                mux: 1 << 4 | 2,
+                pad: 0x10B0,
+            };
+            park(&mut pad, analog, strategy);
+            assert_eq!(pad.mux, 5);
+            assert_eq!(pad.pad, pad_val);
+        }
+    }
+
+    #[test]
+    fn park_all_skips() {
+        let (mut mux, mut pad) = ([1 << 4 | 2; 3], [0x10B0; 3]);
+        let mut erased = |offset| ErasedPad {
+            mux_base: mux.as_mut_ptr(),
+            pad_base: pad.as_mut_ptr(),
+            offset,
+        };
+        let mut pads = [erased(0), erased(1), erased(2)];
+        let skip = [pads[1].id()];
+        park_all_except(&mut pads, ParkStrategy::DigitalKeeper, &skip);
+
+        assert_eq!(mux, [5, 1 << 4 | 2, 5]);
+        let keeper = PullKeeper::Keeper as u32;
+        assert_eq!(pad, [keeper, 0x10B0, keeper]);
+    }
+}
@@ -0,0 +1,407 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/*.csv. Do not edit.
+//! Candidate pads for each function
+
+use super::pads::{gpio_emc::*, gpio_ad_b0::*, gpio_ad_b1::*, gpio_b0::*, gpio_b1::*, gpio_sd_b0::*, gpio_sd_b1::*};
+use crate::{adc, consts::*, csi, flexio, flexpwm, lcdif, lpi2c, lpspi, lpuart, mqs, qtimer, sai, src, xbar, function::Candidates, Config};
+
+/// Every pin that the chip implements, except for GPIOs
+///
+/// Each pin is paired with the writes of its module's `prepared()`, which name
+/// the pin's pad, alternate, and daisy.
+pub(super) const CANDIDATES: Candidates<370> = Candidates::new([
+    // ADC
+    (adc::function::<adc::Adc1, GPIO_AD_B1_11>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_11>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B0_12>(), adc::prepared::<adc::Adc1, GPIO_AD_B0_12>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B0_13>(), adc::prepared::<adc::Adc1, GPIO_AD_B0_13>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B0_14>(), adc::prepared::<adc::Adc1, GPIO_AD_B0_14>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B0_15>(), adc::prepared::<adc::Adc1, GPIO_AD_B0_15>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_00>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_00>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_01>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_01>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_02>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_02>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_03>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_03>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_04>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_04>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_05>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_05>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_06>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_06>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_07>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_07>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_08>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_08>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_09>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_09>(Config::zero())),
+    (adc::function::<adc::Adc1, GPIO_AD_B1_10>(), adc::prepared::<adc::Adc1, GPIO_AD_B1_10>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_11>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_11>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_12>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_12>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_13>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_13>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_14>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_14>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_15>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_15>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_00>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_00>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_01>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_01>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_02>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_02>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_03>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_03>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_04>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_04>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_05>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_05>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_06>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_06>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_07>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_07>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_08>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_08>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_09>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_09>(Config::zero())),
+    (adc::function::<adc::Adc2, GPIO_AD_B1_10>(), adc::prepared::<adc::Adc2, GPIO_AD_B1_10>(Config::zero())),
+
+    // CSI
+    (csi::function::<GPIO_AD_B1_04>(), csi::prepared::<GPIO_AD_B1_04>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_05>(), csi::prepared::<GPIO_AD_B1_05>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_06>(), csi::prepared::<GPIO_AD_B1_06>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_07>(), csi::prepared::<GPIO_AD_B1_07>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_08>(), csi::prepared::<GPIO_AD_B1_08>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_09>(), csi::prepared::<GPIO_AD_B1_09>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_10>(), csi::prepared::<GPIO_AD_B1_10>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_11>(), csi::prepared::<GPIO_AD_B1_11>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_12>(), csi::prepared::<GPIO_AD_B1_12>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_13>(), csi::prepared::<GPIO_AD_B1_13>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_14>(), csi::prepared::<GPIO_AD_B1_14>(Config::zero())),
+    (csi::function::<GPIO_AD_B1_15>(), csi::prepared::<GPIO_AD_B1_15>(Config::zero())),
+
+    // FLEXIO
+    (flexio::function::<GPIO_EMC_04>(), flexio::prepared::<GPIO_EMC_04>(Config::zero())),
+    (flexio::function::<GPIO_EMC_05>(), flexio::prepared::<GPIO_EMC_05>(Config::zero())),
+    (flexio::function::<GPIO_EMC_06>(), flexio::prepared::<GPIO_EMC_06>(Config::zero())),
+    (flexio::function::<GPIO_EMC_07>(), flexio::prepared::<GPIO_EMC_07>(Config::zero())),
+    (flexio::function::<GPIO_EMC_08>(), flexio::prepared::<GPIO_EMC_08>(Config::zero())),
+    (flexio::function::<GPIO_B0_00>(), flexio::prepared::<GPIO_B0_00>(Config::zero())),
+    (flexio::function::<GPIO_B0_01>(), flexio::prepared::<GPIO_B0_01>(Config::zero())),
+    (flexio::function::<GPIO_B0_02>(), flexio::prepared::<GPIO_B0_02>(Config::zero())),
+    (flexio::function::<GPIO_B0_03>(), flexio::prepared::<GPIO_B0_03>(Config::zero())),
+    (flexio::function::<GPIO_B0_04>(), flexio::prepared::<GPIO_B0_04>(Config::zero())),
+    (flexio::function::<GPIO_B0_05>(), flexio::prepared::<GPIO_B0_05>(Config::zero())),
+    (flexio::function::<GPIO_B0_06>(), flexio::prepared::<GPIO_B0_06>(Config::zero())),
+    (flexio::function::<GPIO_B0_07>(), flexio::prepared::<GPIO_B0_07>(Config::zero())),
+    (flexio::function::<GPIO_B0_08>(), flexio::prepared::<GPIO_B0_08>(Config::zero())),
+    (flexio::function::<GPIO_B0_09>(), flexio::prepared::<GPIO_B0_09>(Config::zero())),
+    (flexio::function::<GPIO_B0_10>(), flexio::prepared::<GPIO_B0_10>(Config::zero())),
+    (flexio::function::<GPIO_B0_11>(), flexio::prepared::<GPIO_B0_11>(Config::zero())),
+    (flexio::function::<GPIO_B0_12>(), flexio::prepared::<GPIO_B0_12>(Config::zero())),
+    (flexio::function::<GPIO_B0_13>(), flexio::prepared::<GPIO_B0_13>(Config::zero())),
+    (flexio::function::<GPIO_B0_14>(), flexio::prepared::<GPIO_B0_14>(Config::zero())),
+    (flexio::function::<GPIO_B0_15>(), flexio::prepared::<GPIO_B0_15>(Config::zero())),
+    (flexio::function::<GPIO_B1_00>(), flexio::prepared::<GPIO_B1_00>(Config::zero())),
+    (flexio::function::<GPIO_B1_01>(), flexio::prepared::<GPIO_B1_01>(Config::zero())),
+    (flexio::function::<GPIO_B1_02>(), flexio::prepared::<GPIO_B1_02>(Config::zero())),
+    (flexio::function::<GPIO_B1_03>(), flexio::prepared::<GPIO_B1_03>(Config::zero())),
+    (flexio::function::<GPIO_B1_04>(), flexio::prepared::<GPIO_B1_04>(Config::zero())),
+    (flexio::function::<GPIO_B1_05>(), flexio::prepared::<GPIO_B1_05>(Config::zero())),
+    (flexio::function::<GPIO_B1_06>(), flexio::prepared::<GPIO_B1_06>(Config::zero())),
+    (flexio::function::<GPIO_B1_07>(), flexio::prepared::<GPIO_B1_07>(Config::zero())),
+    (flexio::function::<GPIO_B1_08>(), flexio::prepared::<GPIO_B1_08>(Config::zero())),
+    (flexio::function::<GPIO_B1_09>(), flexio::prepared::<GPIO_B1_09>(Config::zero())),
+    (flexio::function::<GPIO_B1_10>(), flexio::prepared::<GPIO_B1_10>(Config::zero())),
+    (flexio::function::<GPIO_B1_11>(), flexio::prepared::<GPIO_B1_11>(Config::zero())),
+    (flexio::function::<GPIO_B1_12>(), flexio::prepared::<GPIO_B1_12>(Config::zero())),
+    (flexio::function::<GPIO_B1_13>(), flexio::prepared::<GPIO_B1_13>(Config::zero())),
+    (flexio::function::<GPIO_B1_14>(), flexio::prepared::<GPIO_B1_14>(Config::zero())),
+    (flexio::function::<GPIO_B1_15>(), flexio::prepared::<GPIO_B1_15>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_00>(), flexio::prepared::<GPIO_AD_B1_00>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_01>(), flexio::prepared::<GPIO_AD_B1_01>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_02>(), flexio::prepared::<GPIO_AD_B1_02>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_03>(), flexio::prepared::<GPIO_AD_B1_03>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_04>(), flexio::prepared::<GPIO_AD_B1_04>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_05>(), flexio::prepared::<GPIO_AD_B1_05>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_06>(), flexio::prepared::<GPIO_AD_B1_06>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_07>(), flexio::prepared::<GPIO_AD_B1_07>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_08>(), flexio::prepared::<GPIO_AD_B1_08>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_09>(), flexio::prepared::<GPIO_AD_B1_09>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_10>(), flexio::prepared::<GPIO_AD_B1_10>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_11>(), flexio::prepared::<GPIO_AD_B1_11>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_12>(), flexio::prepared::<GPIO_AD_B1_12>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_13>(), flexio::prepared::<GPIO_AD_B1_13>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_14>(), flexio::prepared::<GPIO_AD_B1_14>(Config::zero())),
+    (flexio::function::<GPIO_AD_B1_15>(), flexio::prepared::<GPIO_AD_B1_15>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_00>(), flexio::prepared_for::<U3, GPIO_B1_00>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_01>(), flexio::prepared_for::<U3, GPIO_B1_01>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_02>(), flexio::prepared_for::<U3, GPIO_B1_02>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_03>(), flexio::prepared_for::<U3, GPIO_B1_03>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_04>(), flexio::prepared_for::<U3, GPIO_B1_04>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_05>(), flexio::prepared_for::<U3, GPIO_B1_05>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_06>(), flexio::prepared_for::<U3, GPIO_B1_06>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_07>(), flexio::prepared_for::<U3, GPIO_B1_07>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_08>(), flexio::prepared_for::<U3, GPIO_B1_08>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_09>(), flexio::prepared_for::<U3, GPIO_B1_09>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_10>(), flexio::prepared_for::<U3, GPIO_B1_10>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_11>(), flexio::prepared_for::<U3, GPIO_B1_11>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_12>(), flexio::prepared_for::<U3, GPIO_B1_12>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_13>(), flexio::prepared_for::<U3, GPIO_B1_13>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_14>(), flexio::prepared_for::<U3, GPIO_B1_14>(Config::zero())),
+    (flexio::function_for::<U3, GPIO_B1_15>(), flexio::prepared_for::<U3, GPIO_B1_15>(Config::zero())),
+
+    // FLEXPWM
+    (flexpwm::function::<GPIO_EMC_23>(), flexpwm::prepared::<GPIO_EMC_23>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B0_00>(), flexpwm::prepared::<GPIO_SD_B0_00>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_24>(), flexpwm::prepared::<GPIO_EMC_24>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B0_01>(), flexpwm::prepared::<GPIO_SD_B0_01>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_25>(), flexpwm::prepared::<GPIO_EMC_25>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B0_02>(), flexpwm::prepared::<GPIO_SD_B0_02>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_26>(), flexpwm::prepared::<GPIO_EMC_26>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B0_03>(), flexpwm::prepared::<GPIO_SD_B0_03>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_27>(), flexpwm::prepared::<GPIO_EMC_27>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B0_04>(), flexpwm::prepared::<GPIO_SD_B0_04>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_28>(), flexpwm::prepared::<GPIO_EMC_28>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B0_05>(), flexpwm::prepared::<GPIO_SD_B0_05>(Config::zero())),
+    (flexpwm::function::<GPIO_AD_B0_10>(), flexpwm::prepared::<GPIO_AD_B0_10>(Config::zero())),
+    (flexpwm::function::<GPIO_B1_00>(), flexpwm::prepared::<GPIO_B1_00>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_12>(), flexpwm::prepared::<GPIO_EMC_12>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_38>(), flexpwm::prepared::<GPIO_EMC_38>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B1_00>(), flexpwm::prepared::<GPIO_SD_B1_00>(Config::zero())),
+    (flexpwm::function::<GPIO_AD_B0_11>(), flexpwm::prepared::<GPIO_AD_B0_11>(Config::zero())),
+    (flexpwm::function::<GPIO_B1_01>(), flexpwm::prepared::<GPIO_B1_01>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_13>(), flexpwm::prepared::<GPIO_EMC_13>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_39>(), flexpwm::prepared::<GPIO_EMC_39>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B1_01>(), flexpwm::prepared::<GPIO_SD_B1_01>(Config::zero())),
+    (flexpwm::function::<GPIO_AD_B0_02>(), flexpwm::prepared::<GPIO_AD_B0_02>(Config::zero())),
+    (flexpwm::function::<GPIO_AD_B0_03>(), flexpwm::prepared::<GPIO_AD_B0_03>(Config::zero())),
+    (flexpwm::function::<GPIO_AD_B0_12>(), flexpwm::prepared::<GPIO_AD_B0_12>(Config::zero())),
+    (flexpwm::function::<GPIO_AD_B0_13>(), flexpwm::prepared::<GPIO_AD_B0_13>(Config::zero())),
+    (flexpwm::function::<GPIO_B0_06>(), flexpwm::prepared::<GPIO_B0_06>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_06>(), flexpwm::prepared::<GPIO_EMC_06>(Config::zero())),
+    (flexpwm::function::<GPIO_B0_07>(), flexpwm::prepared::<GPIO_B0_07>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_07>(), flexpwm::prepared::<GPIO_EMC_07>(Config::zero())),
+    (flexpwm::function::<GPIO_B0_08>(), flexpwm::prepared::<GPIO_B0_08>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_08>(), flexpwm::prepared::<GPIO_EMC_08>(Config::zero())),
+    (flexpwm::function::<GPIO_B0_09>(), flexpwm::prepared::<GPIO_B0_09>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_09>(), flexpwm::prepared::<GPIO_EMC_09>(Config::zero())),
+    (flexpwm::function::<GPIO_B0_10>(), flexpwm::prepared::<GPIO_B0_10>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_10>(), flexpwm::prepared::<GPIO_EMC_10>(Config::zero())),
+    (flexpwm::function::<GPIO_B0_11>(), flexpwm::prepared::<GPIO_B0_11>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_11>(), flexpwm::prepared::<GPIO_EMC_11>(Config::zero())),
+    (flexpwm::function::<GPIO_B1_02>(), flexpwm::prepared::<GPIO_B1_02>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_19>(), flexpwm::prepared::<GPIO_EMC_19>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B1_02>(), flexpwm::prepared::<GPIO_SD_B1_02>(Config::zero())),
+    (flexpwm::function::<GPIO_B1_03>(), flexpwm::prepared::<GPIO_B1_03>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_20>(), flexpwm::prepared::<GPIO_EMC_20>(Config::zero())),
+    (flexpwm::function::<GPIO_SD_B1_03>(), flexpwm::prepared::<GPIO_SD_B1_03>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_29>(), flexpwm::prepared::<GPIO_EMC_29>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_30>(), flexpwm::prepared::<GPIO_EMC_30>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_31>(), flexpwm::prepared::<GPIO_EMC_31>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_32>(), flexpwm::prepared::<GPIO_EMC_32>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_33>(), flexpwm::prepared::<GPIO_EMC_33>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_34>(), flexpwm::prepared::<GPIO_EMC_34>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_21>(), flexpwm::prepared::<GPIO_EMC_21>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_22>(), flexpwm::prepared::<GPIO_EMC_22>(Config::zero())),
+    (flexpwm::function::<GPIO_AD_B1_08>(), flexpwm::prepared::<GPIO_AD_B1_08>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_00>(), flexpwm::prepared::<GPIO_EMC_00>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_01>(), flexpwm::prepared::<GPIO_EMC_01>(Config::zero())),
+    (flexpwm::function::<GPIO_AD_B1_09>(), flexpwm::prepared::<GPIO_AD_B1_09>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_02>(), flexpwm::prepared::<GPIO_EMC_02>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_03>(), flexpwm::prepared::<GPIO_EMC_03>(Config::zero())),
+    (flexpwm::function::<GPIO_B1_14>(), flexpwm::prepared::<GPIO_B1_14>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_04>(), flexpwm::prepared::<GPIO_EMC_04>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_05>(), flexpwm::prepared::<GPIO_EMC_05>(Config::zero())),
+    (flexpwm::function::<GPIO_B1_15>(), flexpwm::prepared::<GPIO_B1_15>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_17>(), flexpwm::prepared::<GPIO_EMC_17>(Config::zero())),
+    (flexpwm::function::<GPIO_EMC_18>(), flexpwm::prepared::<GPIO_EMC_18>(Config::zero())),
+
+    // LCDIF
+    (lcdif::function::<GPIO_B0_00>(), lcdif::prepared::<GPIO_B0_00>(Config::zero())),
+    (lcdif::function::<GPIO_B0_01>(), lcdif::prepared::<GPIO_B0_01>(Config::zero())),
+    (lcdif::function::<GPIO_B0_02>(), lcdif::prepared::<GPIO_B0_02>(Config::zero())),
+    (lcdif::function::<GPIO_B0_03>(), lcdif::prepared::<GPIO_B0_03>(Config::zero())),
+    (lcdif::function::<GPIO_B0_04>(), lcdif::prepared::<GPIO_B0_04>(Config::zero())),
+    (lcdif::function::<GPIO_B0_05>(), lcdif::prepared::<GPIO_B0_05>(Config::zero())),
+    (lcdif::function::<GPIO_B0_06>(), lcdif::prepared::<GPIO_B0_06>(Config::zero())),
+    (lcdif::function::<GPIO_B0_07>(), lcdif::prepared::<GPIO_B0_07>(Config::zero())),
+    (lcdif::function::<GPIO_B0_08>(), lcdif::prepared::<GPIO_B0_08>(Config::zero())),
+    (lcdif::function::<GPIO_B0_09>(), lcdif::prepared::<GPIO_B0_09>(Config::zero())),
+    (lcdif::function::<GPIO_B0_10>(), lcdif::prepared::<GPIO_B0_10>(Config::zero())),
+    (lcdif::function::<GPIO_B0_11>(), lcdif::prepared::<GPIO_B0_11>(Config::zero())),
+    (lcdif::function::<GPIO_B0_12>(), lcdif::prepared::<GPIO_B0_12>(Config::zero())),
+    (lcdif::function::<GPIO_B0_13>(), lcdif::prepared::<GPIO_B0_13>(Config::zero())),
+    (lcdif::function::<GPIO_B0_14>(), lcdif::prepared::<GPIO_B0_14>(Config::zero())),
+    (lcdif::function::<GPIO_B0_15>(), lcdif::prepared::<GPIO_B0_15>(Config::zero())),
+    (lcdif::function::<GPIO_B1_00>(), lcdif::prepared::<GPIO_B1_00>(Config::zero())),
+    (lcdif::function::<GPIO_B1_01>(), lcdif::prepared::<GPIO_B1_01>(Config::zero())),
+    (lcdif::function::<GPIO_B1_02>(), lcdif::prepared::<GPIO_B1_02>(Config::zero())),
+    (lcdif::function::<GPIO_B1_03>(), lcdif::prepared::<GPIO_B1_03>(Config::zero())),
+    (lcdif::function::<GPIO_B1_04>(), lcdif::prepared::<GPIO_B1_04>(Config::zero())),
+    (lcdif::function::<GPIO_B1_05>(), lcdif::prepared::<GPIO_B1_05>(Config::zero())),
+    (lcdif::function::<GPIO_B1_06>(), lcdif::prepared::<GPIO_B1_06>(Config::zero())),
+    (lcdif::function::<GPIO_B1_07>(), lcdif::prepared::<GPIO_B1_07>(Config::zero())),
+    (lcdif::function::<GPIO_B1_08>(), lcdif::prepared::<GPIO_B1_08>(Config::zero())),
+    (lcdif::function::<GPIO_B1_09>(), lcdif::prepared::<GPIO_B1_09>(Config::zero())),
+    (lcdif::function::<GPIO_B1_10>(), lcdif::prepared::<GPIO_B1_10>(Config::zero())),
+    (lcdif::function::<GPIO_B1_11>(), lcdif::prepared::<GPIO_B1_11>(Config::zero())),
+
+    // LPI2C
+    (lpi2c::function::<GPIO_AD_B1_00>(), lpi2c::prepared::<GPIO_AD_B1_00>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_B1_01>(), lpi2c::prepared::<GPIO_AD_B1_01>(Config::zero())),
+    (lpi2c::function::<GPIO_SD_B1_04>(), lpi2c::prepared::<GPIO_SD_B1_04>(Config::zero())),
+    (lpi2c::function::<GPIO_SD_B1_05>(), lpi2c::prepared::<GPIO_SD_B1_05>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_B1_07>(), lpi2c::prepared::<GPIO_AD_B1_07>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_B1_06>(), lpi2c::prepared::<GPIO_AD_B1_06>(Config::zero())),
+    (lpi2c::function::<GPIO_SD_B0_00>(), lpi2c::prepared::<GPIO_SD_B0_00>(Config::zero())),
+    (lpi2c::function::<GPIO_SD_B0_01>(), lpi2c::prepared::<GPIO_SD_B0_01>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_B0_12>(), lpi2c::prepared::<GPIO_AD_B0_12>(Config::zero())),
+    (lpi2c::function::<GPIO_AD_B0_13>(), lpi2c::prepared::<GPIO_AD_B0_13>(Config::zero())),
+
+    // LPSPI
+    (lpspi::function::<GPIO_EMC_30>(), lpspi::prepared::<GPIO_EMC_30>(Config::zero())),
+    (lpspi::function::<GPIO_SD_B0_01>(), lpspi::prepared::<GPIO_SD_B0_01>(Config::zero())),
+    (lpspi::function::<GPIO_EMC_27>(), lpspi::prepared::<GPIO_EMC_27>(Config::zero())),
+    (lpspi::function::<GPIO_SD_B0_00>(), lpspi::prepared::<GPIO_SD_B0_00>(Config::zero())),
+    (lpspi::function::<GPIO_EMC_29>(), lpspi::prepared::<GPIO_EMC_29>(Config::zero())),
+    (lpspi::function::<GPIO_SD_B0_03>(), lpspi::prepared::<GPIO_SD_B0_03>(Config::zero())),
+    (lpspi::function::<GPIO_EMC_28>(), lpspi::prepared::<GPIO_EMC_28>(Config::zero())),
+    (lpspi::function::<GPIO_SD_B0_02>(), lpspi::prepared::<GPIO_SD_B0_02>(Config::zero())),
+    (lpspi::function::<GPIO_EMC_00>(), lpspi::prepared::<GPIO_EMC_00>(Config::zero())),
+    (lpspi::function::<GPIO_SD_B1_07>(), lpspi::prepared::<GPIO_SD_B1_07>(Config::zero())),
+    (lpspi::function::<GPIO_EMC_02>(), lpspi::prepared::<GPIO_EMC_02>(Config::zero())),
+    (lpspi::function::<GPIO_SD_B1_08>(), lpspi::prepared::<GPIO_SD_B1_08>(Config::zero())),
+    (lpspi::function::<GPIO_EMC_03>(), lpspi::prepared::<GPIO_EMC_03>(Config::zero())),
+    (lpspi::function::<GPIO_SD_B1_09>(), lpspi::prepared::<GPIO_SD_B1_09>(Config::zero())),
+    (lpspi::function::<GPIO_EMC_01>(), lpspi::prepared::<GPIO_EMC_01>(Config::zero())),
+    (lpspi::function::<GPIO_SD_B1_06>(), lpspi::prepared::<GPIO_SD_B1_06>(Config::zero())),
+    (lpspi::function::<GPIO_AD_B1_15>(), lpspi::prepared::<GPIO_AD_B1_15>(Config::zero())),
+    (lpspi::function::<GPIO_AD_B0_00>(), lpspi::prepared::<GPIO_AD_B0_00>(Config::zero())),
+    (lpspi::function::<GPIO_AD_B1_14>(), lpspi::prepared::<GPIO_AD_B1_14>(Config::zero())),
+    (lpspi::function::<GPIO_AD_B0_01>(), lpspi::prepared::<GPIO_AD_B0_01>(Config::zero())),
+    (lpspi::function::<GPIO_AD_B1_13>(), lpspi::prepared::<GPIO_AD_B1_13>(Config::zero())),
+    (lpspi::function::<GPIO_AD_B0_02>(), lpspi::prepared::<GPIO_AD_B0_02>(Config::zero())),
+    (lpspi::function::<GPIO_AD_B1_12>(), lpspi::prepared::<GPIO_AD_B1_12>(Config::zero())),
+    (lpspi::function::<GPIO_AD_B0_03>(), lpspi::prepared::<GPIO_AD_B0_03>(Config::zero())),
+    (lpspi::function::<GPIO_B1_07>(), lpspi::prepared::<GPIO_B1_07>(Config::zero())),
+    (lpspi::function::<GPIO_B0_03>(), lpspi::prepared::<GPIO_B0_03>(Config::zero())),
+    (lpspi::function::<GPIO_B1_06>(), lpspi::prepared::<GPIO_B1_06>(Config::zero())),
+    (lpspi::function::<GPIO_B0_02>(), lpspi::prepared::<GPIO_B0_02>(Config::zero())),
+    (lpspi::function::<GPIO_B1_05>(), lpspi::prepared::<GPIO_B1_05>(Config::zero())),
+    (lpspi::function::<GPIO_B0_01>(), lpspi::prepared::<GPIO_B0_01>(Config::zero())),
+    (lpspi::function::<GPIO_B1_04>(), lpspi::prepared::<GPIO_B1_04>(Config::zero())),
+    (lpspi::function::<GPIO_B0_00>(), lpspi::prepared::<GPIO_B0_00>(Config::zero())),
+
+    // LPUART
+    (lpuart::function::<GPIO_AD_B0_13>(), lpuart::prepared::<GPIO_AD_B0_13>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B0_12>(), lpuart::prepared::<GPIO_AD_B0_12>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B1_03>(), lpuart::prepared::<GPIO_AD_B1_03>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B1_02>(), lpuart::prepared::<GPIO_AD_B1_02>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B1_07>(), lpuart::prepared::<GPIO_AD_B1_07>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B1_06>(), lpuart::prepared::<GPIO_AD_B1_06>(Config::zero())),
+    (lpuart::function::<GPIO_B1_01>(), lpuart::prepared::<GPIO_B1_01>(Config::zero())),
+    (lpuart::function::<GPIO_B1_00>(), lpuart::prepared::<GPIO_B1_00>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B0_03>(), lpuart::prepared::<GPIO_AD_B0_03>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B0_02>(), lpuart::prepared::<GPIO_AD_B0_02>(Config::zero())),
+    (lpuart::function::<GPIO_EMC_32>(), lpuart::prepared::<GPIO_EMC_32>(Config::zero())),
+    (lpuart::function::<GPIO_EMC_31>(), lpuart::prepared::<GPIO_EMC_31>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B1_11>(), lpuart::prepared::<GPIO_AD_B1_11>(Config::zero())),
+    (lpuart::function::<GPIO_AD_B1_10>(), lpuart::prepared::<GPIO_AD_B1_10>(Config::zero())),
+
+    // MQS
+    (mqs::function::<GPIO_B0_00>(), mqs::prepared::<GPIO_B0_00>(Config::zero())),
+    (mqs::function::<GPIO_B0_01>(), mqs::prepared::<GPIO_B0_01>(Config::zero())),
+
+    // QTIMER
+    (qtimer::function::<GPIO_B0_00>(), qtimer::prepared::<GPIO_B0_00>(Config::zero())),
+    (qtimer::function::<GPIO_B0_01>(), qtimer::prepared::<GPIO_B0_01>(Config::zero())),
+    (qtimer::function::<GPIO_B0_02>(), qtimer::prepared::<GPIO_B0_02>(Config::zero())),
+    (qtimer::function::<GPIO_B1_08>(), qtimer::prepared::<GPIO_B1_08>(Config::zero())),
+    (qtimer::function::<GPIO_B0_03>(), qtimer::prepared::<GPIO_B0_03>(Config::zero())),
+    (qtimer::function::<GPIO_B0_04>(), qtimer::prepared::<GPIO_B0_04>(Config::zero())),
+    (qtimer::function::<GPIO_B0_05>(), qtimer::prepared::<GPIO_B0_05>(Config::zero())),
+    (qtimer::function::<GPIO_B1_09>(), qtimer::prepared::<GPIO_B1_09>(Config::zero())),
+    (qtimer::function::<GPIO_B0_06>(), qtimer::prepared::<GPIO_B0_06>(Config::zero())),
+    (qtimer::function::<GPIO_B0_07>(), qtimer::prepared::<GPIO_B0_07>(Config::zero())),
+    (qtimer::function::<GPIO_B0_08>(), qtimer::prepared::<GPIO_B0_08>(Config::zero())),
+    (qtimer::function::<GPIO_B1_10>(), qtimer::prepared::<GPIO_B1_10>(Config::zero())),
+    (qtimer::function::<GPIO_AD_B1_00>(), qtimer::prepared::<GPIO_AD_B1_00>(Config::zero())),
+    (qtimer::function::<GPIO_AD_B1_01>(), qtimer::prepared::<GPIO_AD_B1_01>(Config::zero())),
+    (qtimer::function::<GPIO_AD_B1_02>(), qtimer::prepared::<GPIO_AD_B1_02>(Config::zero())),
+    (qtimer::function::<GPIO_AD_B1_03>(), qtimer::prepared::<GPIO_AD_B1_03>(Config::zero())),
+    (qtimer::function::<GPIO_B0_09>(), qtimer::prepared::<GPIO_B0_09>(Config::zero())),
+    (qtimer::function::<GPIO_B0_10>(), qtimer::prepared::<GPIO_B0_10>(Config::zero())),
+    (qtimer::function::<GPIO_B0_11>(), qtimer::prepared::<GPIO_B0_11>(Config::zero())),
+    (qtimer::function::<GPIO_B1_11>(), qtimer::prepared::<GPIO_B1_11>(Config::zero())),
+
+    // SAI
+    (sai::function::<U1, GPIO_SD_B1_08>(), sai::prepared::<U1, GPIO_SD_B1_08>(Config::zero())),
+    (sai::function::<U1, GPIO_B1_02>(), sai::prepared::<U1, GPIO_B1_02>(Config::zero())),
+    (sai::function::<U1, GPIO_AD_B1_14>(), sai::prepared::<U1, GPIO_AD_B1_14>(Config::zero())),
+    (sai::function::<U1, GPIO_AD_B1_15>(), sai::prepared::<U1, GPIO_AD_B1_15>(Config::zero())),
+    (sai::function::<U1, GPIO_B1_03>(), sai::prepared::<U1, GPIO_B1_03>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_09>(), sai::prepared::<U1, GPIO_SD_B1_09>(Config::zero())),
+    (sai::function::<U1, GPIO_B0_13>(), sai::prepared::<U1, GPIO_B0_13>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_03>(), sai::prepared::<U1, GPIO_SD_B1_03>(Config::zero())),
+    (sai::function::<U1, GPIO_AD_B1_09>(), sai::prepared::<U1, GPIO_AD_B1_09>(Config::zero())),
+    (sai::function::<U1, GPIO_AD_B1_11>(), sai::prepared::<U1, GPIO_AD_B1_11>(Config::zero())),
+    (sai::function::<U1, GPIO_B0_15>(), sai::prepared::<U1, GPIO_B0_15>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_05>(), sai::prepared::<U1, GPIO_SD_B1_05>(Config::zero())),
+    (sai::function::<U1, GPIO_AD_B1_10>(), sai::prepared::<U1, GPIO_AD_B1_10>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_04>(), sai::prepared::<U1, GPIO_SD_B1_04>(Config::zero())),
+    (sai::function::<U1, GPIO_B0_14>(), sai::prepared::<U1, GPIO_B0_14>(Config::zero())),
+    (sai::function::<U1, GPIO_AD_B1_13>(), sai::prepared::<U1, GPIO_AD_B1_13>(Config::zero())),
+    (sai::function::<U1, GPIO_B1_01>(), sai::prepared::<U1, GPIO_B1_01>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_07>(), sai::prepared::<U1, GPIO_SD_B1_07>(Config::zero())),
+    (sai::function::<U1, GPIO_B1_00>(), sai::prepared::<U1, GPIO_B1_00>(Config::zero())),
+    (sai::function::<U1, GPIO_AD_B1_12>(), sai::prepared::<U1, GPIO_AD_B1_12>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_06>(), sai::prepared::<U1, GPIO_SD_B1_06>(Config::zero())),
+    (sai::function::<U1, GPIO_B0_12>(), sai::prepared::<U1, GPIO_B0_12>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_02>(), sai::prepared::<U1, GPIO_SD_B1_02>(Config::zero())),
+    (sai::function::<U1, GPIO_B0_11>(), sai::prepared::<U1, GPIO_B0_11>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_01>(), sai::prepared::<U1, GPIO_SD_B1_01>(Config::zero())),
+    (sai::function::<U1, GPIO_B0_10>(), sai::prepared::<U1, GPIO_B0_10>(Config::zero())),
+    (sai::function::<U1, GPIO_SD_B1_00>(), sai::prepared::<U1, GPIO_SD_B1_00>(Config::zero())),
+    (sai::function::<U2, GPIO_AD_B0_05>(), sai::prepared::<U2, GPIO_AD_B0_05>(Config::zero())),
+    (sai::function::<U2, GPIO_EMC_06>(), sai::prepared::<U2, GPIO_EMC_06>(Config::zero())),
+    (sai::function::<U2, GPIO_AD_B0_04>(), sai::prepared::<U2, GPIO_AD_B0_04>(Config::zero())),
+    (sai::function::<U2, GPIO_EMC_05>(), sai::prepared::<U2, GPIO_EMC_05>(Config::zero())),
+    (sai::function::<U2, GPIO_EMC_10>(), sai::prepared::<U2, GPIO_EMC_10>(Config::zero())),
+    (sai::function::<U2, GPIO_AD_B0_06>(), sai::prepared::<U2, GPIO_AD_B0_06>(Config::zero())),
+    (sai::function::<U2, GPIO_EMC_09>(), sai::prepared::<U2, GPIO_EMC_09>(Config::zero())),
+    (sai::function::<U2, GPIO_AD_B0_07>(), sai::prepared::<U2, GPIO_AD_B0_07>(Config::zero())),
+    (sai::function::<U2, GPIO_EMC_07>(), sai::prepared::<U2, GPIO_EMC_07>(Config::zero())),
+    (sai::function::<U2, GPIO_AD_B0_10>(), sai::prepared::<U2, GPIO_AD_B0_10>(Config::zero())),
+    (sai::function::<U2, GPIO_EMC_04>(), sai::prepared::<U2, GPIO_EMC_04>(Config::zero())),
+    (sai::function::<U2, GPIO_AD_B0_09>(), sai::prepared::<U2, GPIO_AD_B0_09>(Config::zero())),
+    (sai::function::<U2, GPIO_AD_B0_08>(), sai::prepared::<U2, GPIO_AD_B0_08>(Config::zero())),
+    (sai::function::<U2, GPIO_EMC_08>(), sai::prepared::<U2, GPIO_EMC_08>(Config::zero())),
+    (sai::function::<U3, GPIO_EMC_38>(), sai::prepared::<U3, GPIO_EMC_38>(Config::zero())),
+    (sai::function::<U3, GPIO_SD_B1_03>(), sai::prepared::<U3, GPIO_SD_B1_03>(Config::zero())),
+    (sai::function::<U3, GPIO_EMC_39>(), sai::prepared::<U3, GPIO_EMC_39>(Config::zero())),
+    (sai::function::<U3, GPIO_SD_B1_02>(), sai::prepared::<U3, GPIO_SD_B1_02>(Config::zero())),
+    (sai::function::<U3, GPIO_EMC_35>(), sai::prepared::<U3, GPIO_EMC_35>(Config::zero())),
+    (sai::function::<U3, GPIO_SD_B1_06>(), sai::prepared::<U3, GPIO_SD_B1_06>(Config::zero())),
+    (sai::function::<U3, GPIO_EMC_34>(), sai::prepared::<U3, GPIO_EMC_34>(Config::zero())),
+    (sai::function::<U3, GPIO_SD_B1_05>(), sai::prepared::<U3, GPIO_SD_B1_05>(Config::zero())),
+    (sai::function::<U3, GPIO_EMC_37>(), sai::prepared::<U3, GPIO_EMC_37>(Config::zero())),
+    (sai::function::<U3, GPIO_SD_B1_04>(), sai::prepared::<U3, GPIO_SD_B1_04>(Config::zero())),
+    (sai::function::<U3, GPIO_EMC_36>(), sai::prepared::<U3, GPIO_EMC_36>(Config::zero())),
+    (sai::function::<U3, GPIO_SD_B1_01>(), sai::prepared::<U3, GPIO_SD_B1_01>(Config::zero())),
+    (sai::function::<U3, GPIO_EMC_33>(), sai::prepared::<U3, GPIO_EMC_33>(Config::zero())),
+    (sai::function::<U3, GPIO_SD_B1_00>(), sai::prepared::<U3, GPIO_SD_B1_00>(Config::zero())),
+
+    // SRC
+    (src::function::<GPIO_AD_B0_04>(), src::prepared::<GPIO_AD_B0_04>(Config::zero())),
+    (src::function::<GPIO_AD_B0_05>(), src::prepared::<GPIO_AD_B0_05>(Config::zero())),
+    (src::function::<GPIO_B0_04>(), src::prepared::<GPIO_B0_04>(Config::zero())),
+    (src::function::<GPIO_B0_05>(), src::prepared::<GPIO_B0_05>(Config::zero())),
+    (src::function::<GPIO_B0_06>(), src::prepared::<GPIO_B0_06>(Config::zero())),
+    (src::function::<GPIO_B0_07>(), src::prepared::<GPIO_B0_07>(Config::zero())),
+    (src::function::<GPIO_B0_08>(), src::prepared::<GPIO_B0_08>(Config::zero())),
+    (src::function::<GPIO_B0_09>(), src::prepared::<GPIO_B0_09>(Config::zero())),
+    (src::function::<GPIO_B0_10>(), src::prepared::<GPIO_B0_10>(Config::zero())),
+    (src::function::<GPIO_B0_11>(), src::prepared::<GPIO_B0_11>(Config::zero())),
+    (src::function::<GPIO_B0_12>(), src::prepared::<GPIO_B0_12>(Config::zero())),
+    (src::function::<GPIO_B0_13>(), src::prepared::<GPIO_B0_13>(Config::zero())),
+    (src::function::<GPIO_B0_14>(), src::prepared::<GPIO_B0_14>(Config::zero())),
+    (src::function::<GPIO_B0_15>(), src::prepared::<GPIO_B0_15>(Config::zero())),
+    (src::function::<GPIO_AD_B0_12>(), src::prepared::<GPIO_AD_B0_12>(Config::zero())),
+
+    // XBAR
+    (xbar::function::<GPIO_EMC_00>(), xbar::prepared::<GPIO_EMC_00>(Config::zero())),
+    (xbar::function::<GPIO_EMC_01>(), xbar::prepared::<GPIO_EMC_01>(Config::zero())),
+    (xbar::function::<GPIO_EMC_02>(), xbar::prepared::<GPIO_EMC_02>(Config::zero())),
+    (xbar::function::<GPIO_EMC_03>(), xbar::prepared::<GPIO_EMC_03>(Config::zero())),
+    (xbar::function::<GPIO_EMC_04>(), xbar::prepared::<GPIO_EMC_04>(Config::zero())),
+    (xbar::function::<GPIO_EMC_05>(), xbar::prepared::<GPIO_EMC_05>(Config::zero())),
+    (xbar::function::<GPIO_EMC_06>(), xbar::prepared::<GPIO_EMC_06>(Config::zero())),
+    (xbar::function::<GPIO_EMC_07>(), xbar::prepared::<GPIO_EMC_07>(Config::zero())),
+    (xbar::function::<GPIO_EMC_08>(), xbar::prepared::<GPIO_EMC_08>(Config::zero())),
+    (xbar::function::<GPIO_AD_B0_00>(), xbar::prepared::<GPIO_AD_B0_00>(Config::zero())),
+    (xbar::function::<GPIO_AD_B0_01>(), xbar::prepared::<GPIO_AD_B0_01>(Config::zero())),
+    (xbar::function::<GPIO_AD_B0_02>(), xbar::prepared::<GPIO_AD_B0_02>(Config::zero())),
+    (xbar::function::<GPIO_AD_B0_03>(), xbar::prepared::<GPIO_AD_B0_03>(Config::zero())),
+    (xbar::function::<GPIO_B1_00>(), xbar::prepared::<GPIO_B1_00>(Config::zero())),
+    (xbar::function::<GPIO_B1_01>(), xbar::prepared::<GPIO_B1_01>(Config::zero())),
+    (xbar::function::<GPIO_B1_02>(), xbar::prepared::<GPIO_B1_02>(Config::zero())),
+    (xbar::function::<GPIO_B1_03>(), xbar::prepared::<GPIO_B1_03>(Config::zero())),
+]);
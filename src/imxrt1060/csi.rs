@@ -0,0 +1,52 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/csi.csv. Do not edit.
+//! CSI pin implementations
+//!
+//! The CSI routes over `GPIO_AD_B1_04` through `GPIO_AD_B1_15`, which supports
+//! an 8-bit bus. The chip has no select inputs for `DATA00` or `DATA01`, and
+//! the crate doesn't route them.
+
+use super::pads::gpio_ad_b1::*;
+use crate::{
+    consts::*,
+    csi::{Data, Hsync, Mclk, Pin, Pixclk, Vsync},
+    Daisy,
+};
+
+//
+// Clock and sync
+//
+csi!(alt: 4, pad: GPIO_AD_B1_04, signal: Pixclk, daisy: Some(DAISY_CSI_PIXCLK_GPIO_AD_B1_04));
+csi!(alt: 4, pad: GPIO_AD_B1_05, signal: Mclk, daisy: None);
+csi!(alt: 4, pad: GPIO_AD_B1_06, signal: Vsync, daisy: Some(DAISY_CSI_VSYNC_GPIO_AD_B1_06));
+csi!(alt: 4, pad: GPIO_AD_B1_07, signal: Hsync, daisy: Some(DAISY_CSI_HSYNC_GPIO_AD_B1_07));
+
+//
+// Data
+//
+csi!(alt: 4, pad: GPIO_AD_B1_08, signal: Data<U9>, daisy: Some(DAISY_CSI_DATA09_GPIO_AD_B1_08));
+csi!(alt: 4, pad: GPIO_AD_B1_09, signal: Data<U8>, daisy: Some(DAISY_CSI_DATA08_GPIO_AD_B1_09));
+csi!(alt: 4, pad: GPIO_AD_B1_10, signal: Data<U7>, daisy: Some(DAISY_CSI_DATA07_GPIO_AD_B1_10));
+csi!(alt: 4, pad: GPIO_AD_B1_11, signal: Data<U6>, daisy: Some(DAISY_CSI_DATA06_GPIO_AD_B1_11));
+csi!(alt: 4, pad: GPIO_AD_B1_12, signal: Data<U5>, daisy: Some(DAISY_CSI_DATA05_GPIO_AD_B1_12));
+csi!(alt: 4, pad: GPIO_AD_B1_13, signal: Data<U4>, daisy: Some(DAISY_CSI_DATA04_GPIO_AD_B1_13));
+csi!(alt: 4, pad: GPIO_AD_B1_14, signal: Data<U3>, daisy: Some(DAISY_CSI_DATA03_GPIO_AD_B1_14));
+csi!(alt: 4, pad: GPIO_AD_B1_15, signal: Data<U2>, daisy: Some(DAISY_CSI_DATA02_GPIO_AD_B1_15));
+
+/// Auto-generated Daisy constants
+mod daisy {
+    #![allow(unused)]
+    use super::Daisy;
+
+    pub const DAISY_CSI_DATA02_GPIO_AD_B1_15: Daisy = Daisy::new(0x401f8400, 0);
+    pub const DAISY_CSI_DATA03_GPIO_AD_B1_14: Daisy = Daisy::new(0x401f8404, 0);
+    pub const DAISY_CSI_DATA04_GPIO_AD_B1_13: Daisy = Daisy::new(0x401f8408, 0);
+    pub const DAISY_CSI_DATA05_GPIO_AD_B1_12: Daisy = Daisy::new(0x401f840c, 0);
+    pub const DAISY_CSI_DATA06_GPIO_AD_B1_11: Daisy = Daisy::new(0x401f8410, 0);
+    pub const DAISY_CSI_DATA07_GPIO_AD_B1_10: Daisy = Daisy::new(0x401f8414, 0);
+    pub const DAISY_CSI_DATA08_GPIO_AD_B1_09: Daisy = Daisy::new(0x401f8418, 0);
+    pub const DAISY_CSI_DATA09_GPIO_AD_B1_08: Daisy = Daisy::new(0x401f841c, 0);
+    pub const DAISY_CSI_HSYNC_GPIO_AD_B1_07: Daisy = Daisy::new(0x401f8420, 0);
+    pub const DAISY_CSI_PIXCLK_GPIO_AD_B1_04: Daisy = Daisy::new(0x401f8424, 0);
+    pub const DAISY_CSI_VSYNC_GPIO_AD_B1_06: Daisy = Daisy::new(0x401f8428, 0);
+}
+use daisy::*;
@@ -0,0 +1,87 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/flexio.csv. Do not edit.
+//! FlexIO pin implementations
+
+use super::pads::{gpio_ad_b1::*, gpio_b0::*, gpio_b1::*, gpio_emc::*};
+use crate::{consts::*, flexio::Pin};
+
+//
+// FLEXIO1
+//
+// Other FLEXIO1 pads are not yet implemented.
+flexio!(module: U1, alt: 4, pad: GPIO_EMC_04, index: U4);
+flexio!(module: U1, alt: 4, pad: GPIO_EMC_05, index: U5);
+flexio!(module: U1, alt: 4, pad: GPIO_EMC_06, index: U6);
+flexio!(module: U1, alt: 4, pad: GPIO_EMC_07, index: U7);
+flexio!(module: U1, alt: 4, pad: GPIO_EMC_08, index: U8);
+
+//
+// FLEXIO2
+//
+flexio!(module: U2, alt: 4, pad: GPIO_B0_00, index: U0);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_01, index: U1);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_02, index: U2);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_03, index: U3);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_04, index: U4);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_05, index: U5);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_06, index: U6);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_07, index: U7);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_08, index: U8);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_09, index: U9);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_10, index: U10);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_11, index: U11);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_12, index: U12);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_13, index: U13);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_14, index: U14);
+flexio!(module: U2, alt: 4, pad: GPIO_B0_15, index: U15);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_00, index: U16);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_01, index: U17);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_02, index: U18);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_03, index: U19);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_04, index: U20);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_05, index: U21);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_06, index: U22);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_07, index: U23);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_08, index: U24);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_09, index: U25);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_10, index: U26);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_11, index: U27);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_12, index: U28);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_13, index: U29);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_14, index: U30);
+flexio!(module: U2, alt: 4, pad: GPIO_B1_15, index: U31);
+
+//
+// FLEXIO3
+//
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_00, index: U0);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_01, index: U1);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_02, index: U2);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_03, index: U3);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_04, index: U4);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_05, index: U5);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_06, index: U6);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_07, index: U7);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_08, index: U8);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_09, index: U9);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_10, index: U10);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_11, index: U11);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_12, index: U12);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_13, index: U13);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_14, index: U14);
+flexio!(module: U3, alt: 9, pad: GPIO_AD_B1_15, index: U15);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_00, index: U16);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_01, index: U17);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_02, index: U18);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_03, index: U19);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_04, index: U20);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_05, index: U21);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_06, index: U22);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_07, index: U23);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_08, index: U24);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_09, index: U25);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_10, index: U26);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_11, index: U27);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_12, index: U28);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_13, index: U29);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_14, index: U30);
+flexio!(for module: U3, alt: 9, pad: GPIO_B1_15, index: U31);
@@ -0,0 +1,151 @@
+//! JTAG (SJC) pad reclamation
+//!
+//! After reset, `GPIO_AD_B0_06` through `GPIO_AD_B0_11` are muxed to the
+//! System JTAG Controller (SJC). Reclaiming those pads for other functions
+//! removes your debug access until the pads are restored, or until the
+//! next reset.
+//!
+//! Use [`reclaim_jtag_pads()`] to take the pads away from the SJC. The function
+//! returns a [`JtagPads`] token, which you'll need to give back the pads with
+//! [`restore_jtag()`].
+//!
+//! ```no_run
+//! use imxrt_iomuxc::imxrt1060::{jtag, Pads};
+//!
+//! let pads = unsafe { Pads::new() };
+//! let mut jtag_pads = jtag::Pads {
+//!     tms: pads.gpio_ad_b0.p06,
+//!     tck: pads.gpio_ad_b0.p07,
+//!     mode: pads.gpio_ad_b0.p08,
+//!     tdi: pads.gpio_ad_b0.p09,
+//!     tdo: pads.gpio_ad_b0.p10,
+//!     trstb: pads.gpio_ad_b0.p11,
+//! };
+//!
+//! // Debug access is lost here...
+//! let token = jtag::reclaim_jtag_pads(&mut jtag_pads);
+//! // Use the pads as GPIOs, or prepare them for other peripherals...
+//! imxrt_iomuxc::gpio::prepare(&mut jtag_pads.tdo);
+//! // ...and restore debug access when finished.
+//! jtag::restore_jtag(token, &mut jtag_pads);
+//! ```
+
+use super::gpio_ad_b0::{
+    GPIO_AD_B0_06, GPIO_AD_B0_07, GPIO_AD_B0_08, GPIO_AD_B0_09, GPIO_AD_B0_10, GPIO_AD_B0_11,
+};
+use crate::Iomuxc;
+
+/// The SJC alternate for all JTAG pads
+///
+/// This is also the reset value of the mux registers: ALT0, with SION cleared.
+const SJC_ALT: u32 = 0;
+
+/// The GPIO alternate for all JTAG pads
+const GPIO_ALT: u32 = 5;
+
+/// The pads that carry the JTAG signals after reset
+pub struct Pads {
+    /// `JTAG_TMS`
+    pub tms: GPIO_AD_B0_06,
+    /// `JTAG_TCK`
+    pub tck: GPIO_AD_B0_07,
+    /// `JTAG_MOD`
+    pub mode: GPIO_AD_B0_08,
+    /// `JTAG_TDI`
+    pub tdi: GPIO_AD_B0_09,
+    /// `JTAG_TDO`
+    pub tdo: GPIO_AD_B0_10,
+    /// `JTAG_TRSTB`
+    pub trstb: GPIO_AD_B0_11,
+}
+
+/// A token indicating that the JTAG pads are no longer muxed to the SJC
+///
+/// Pass the token to [`restore_jtag()`] to restore debug access.
+#[must_use = "restore_jtag() requires this token to restore debug access"]
+#[derive(Debug)]
+pub struct JtagPads {
+    _private: (),
+}
+
+/// Reclaim the JTAG pads from the SJC
+///
+/// All pads are muxed to their GPIO alternate, and their SION bits are cleared.
+/// After this call, you may prepare any pad for another peripheral. The call
+/// does not change any pad configuration.
+///
+/// Once this returns, you will lose debug access through JTAG / SWD.
+pub fn reclaim_jtag_pads(pads: &mut Pads) -> JtagPads {
+    reclaim(&mut pads.tms);
+    reclaim(&mut pads.tck);
+    reclaim(&mut pads.mode);
+    reclaim(&mut pads.tdi);
+    reclaim(&mut pads.tdo);
+    reclaim(&mut pads.trstb);
+    JtagPads { _private: () }
+}
+
+/// Restore the JTAG pads to the SJC
+///
+/// The mux registers are restored to their reset values, which selects the SJC.
+/// The call does not change any pad configuration.
+pub fn restore_jtag(token: JtagPads, pads: &mut Pads) {
+    let _ = token;
+    restore(&mut pads.tms);
+    restore(&mut pads.tck);
+    restore(&mut pads.mode);
+    restore(&mut pads.tdi);
+    restore(&mut pads.tdo);
+    restore(&mut pads.trstb);
+}
+
+fn reclaim<I: Iomuxc>(pad: &mut I) {
+    crate::alternate(pad, GPIO_ALT);
+    crate::clear_sion(pad);
+}
+
+fn restore<I: Iomuxc>(pad: &mut I) {
+    crate::alternate(pad, SJC_ALT);
+    crate::clear_sion(pad);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reclaim, restore};
+    use crate::Iomuxc;
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut pad = FakePad {
+            mux: 0,
+            pad: 0x70A0,
+        };
+
+        reclaim(&mut pad);
+        assert_eq!(pad.mux, 5);
+        assert_eq!(pad.pad, 0x70A0);
+
+        // Simulate a peripheral using the reclaimed pad
+        pad.mux = 1 << 4 | 3;
+
+        restore(&mut pad);
+        assert_eq!(pad.mux, 0);
+        assert_eq!(pad.pad, 0x70A0);
+    }
+}
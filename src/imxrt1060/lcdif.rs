@@ -0,0 +1,45 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/lcdif.csv. Do not edit.
+//! eLCDIF pin implementations
+
+use super::pads::{gpio_b0::*, gpio_b1::*};
+use crate::{
+    consts::*,
+    lcdif::{Clk, Data, Enable, Hsync, Pin, Vsync},
+};
+
+//
+// Control
+//
+lcdif!(alt: 0, pad: GPIO_B0_00, signal: Clk);
+lcdif!(alt: 0, pad: GPIO_B0_01, signal: Enable);
+lcdif!(alt: 0, pad: GPIO_B0_02, signal: Hsync);
+lcdif!(alt: 0, pad: GPIO_B0_03, signal: Vsync);
+
+//
+// Data
+//
+lcdif!(alt: 0, pad: GPIO_B0_04, signal: Data<U0>);
+lcdif!(alt: 0, pad: GPIO_B0_05, signal: Data<U1>);
+lcdif!(alt: 0, pad: GPIO_B0_06, signal: Data<U2>);
+lcdif!(alt: 0, pad: GPIO_B0_07, signal: Data<U3>);
+lcdif!(alt: 0, pad: GPIO_B0_08, signal: Data<U4>);
+lcdif!(alt: 0, pad: GPIO_B0_09, signal: Data<U5>);
+lcdif!(alt: 0, pad: GPIO_B0_10, signal: Data<U6>);
+lcdif!(alt: 0, pad: GPIO_B0_11, signal: Data<U7>);
+lcdif!(alt: 0, pad: GPIO_B0_12, signal: Data<U8>);
+lcdif!(alt: 0, pad: GPIO_B0_13, signal: Data<U9>);
+lcdif!(alt: 0, pad: GPIO_B0_14, signal: Data<U10>);
+lcdif!(alt: 0, pad: GPIO_B0_15, signal: Data<U11>);
+
+lcdif!(alt: 0, pad: GPIO_B1_00, signal: Data<U12>);
+lcdif!(alt: 0, pad: GPIO_B1_01, signal: Data<U13>);
+lcdif!(alt: 0, pad: GPIO_B1_02, signal: Data<U14>);
+lcdif!(alt: 0, pad: GPIO_B1_03, signal: Data<U15>);
+lcdif!(alt: 0, pad: GPIO_B1_04, signal: Data<U16>);
+lcdif!(alt: 0, pad: GPIO_B1_05, signal: Data<U17>);
+lcdif!(alt: 0, pad: GPIO_B1_06, signal: Data<U18>);
+lcdif!(alt: 0, pad: GPIO_B1_07, signal: Data<U19>);
+lcdif!(alt: 0, pad: GPIO_B1_08, signal: Data<U20>);
+lcdif!(alt: 0, pad: GPIO_B1_09, signal: Data<U21>);
+lcdif!(alt: 0, pad: GPIO_B1_10, signal: Data<U22>);
+lcdif!(alt: 0, pad: GPIO_B1_11, signal: Data<U23>);
@@ -1,12 +1,39 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/lpuart.csv. Do not edit.
 //! UART pin implementations
 
 use super::pads::{gpio_ad_b0::*, gpio_ad_b1::*, gpio_b1::*, gpio_emc::*};
 use crate::{
     consts::*,
-    lpuart::{Pin, Rx, Tx},
-    Daisy,
+    function::UartDirection,
+    lpuart::{Pin, PinTable, Rx, TableEntry, Tx},
+    Daisy, Function, PadId,
 };
 
+/// The TX pads of each LPUART module
+///
+/// Index the array with the module number; `LPUART_TX_PADS[3]` holds the `LPUART3`
+/// TX pads. Index 0 is empty. See [`candidates()`](super::candidates).
+pub const LPUART_TX_PADS: [&[PadId]; 9] = module_pads(UartDirection::Tx);
+
+/// The RX pads of each LPUART module
+///
+/// Index the array with the module number; `LPUART_RX_PADS[3]` holds the `LPUART3`
+/// RX pads. Index 0 is empty. See [`candidates()`](super::candidates).
+pub const LPUART_RX_PADS: [&[PadId]; 9] = module_pads(UartDirection::Rx);
+
+const fn module_pads(direction: UartDirection) -> [&'static [PadId]; 9] {
+    let mut pads: [&[PadId]; 9] = [&[]; 9];
+    let mut module = 1;
+    while module < pads.len() {
+        pads[module] = super::candidates(Function::Lpuart {
+            module: module as u8,
+            direction,
+        });
+        module += 1;
+    }
+    pads
+}
+
 //
 // UART1
 //
@@ -60,42 +87,63 @@ mod daisy {
     #![allow(unused)]
     use super::Daisy;
 
-    pub const DAISY_LPUART2_RX_GPIO_SD_B1_10: Daisy = Daisy::new(0x401f852c as *mut u32, 0);
-    pub const DAISY_LPUART2_RX_GPIO_AD_B1_03: Daisy = Daisy::new(0x401f852c as *mut u32, 1);
-    pub const DAISY_LPUART2_TX_GPIO_SD_B1_11: Daisy = Daisy::new(0x401f8530 as *mut u32, 0);
-    pub const DAISY_LPUART2_TX_GPIO_AD_B1_02: Daisy = Daisy::new(0x401f8530 as *mut u32, 1);
-    pub const DAISY_LPUART3_CT_GPIOS_B_EMC_15: Daisy = Daisy::new(0x401f8534 as *mut u32, 0);
-    pub const DAISY_LPUART3_CT_GPIOS_B_AD_B1_04: Daisy = Daisy::new(0x401f8534 as *mut u32, 1);
-    pub const DAISY_LPUART3_RX_GPIO_AD_B1_07: Daisy = Daisy::new(0x401f8538 as *mut u32, 0);
-    pub const DAISY_LPUART3_RX_GPIO_EMC_14: Daisy = Daisy::new(0x401f8538 as *mut u32, 1);
-    pub const DAISY_LPUART3_RX_GPIO_B0_09: Daisy = Daisy::new(0x401f8538 as *mut u32, 2);
-    pub const DAISY_LPUART3_TX_GPIO_AD_B1_06: Daisy = Daisy::new(0x401f853c as *mut u32, 0);
-    pub const DAISY_LPUART3_TX_GPIO_EMC_13: Daisy = Daisy::new(0x401f853c as *mut u32, 1);
-    pub const DAISY_LPUART3_TX_GPIO_B0_08: Daisy = Daisy::new(0x401f853c as *mut u32, 2);
-    pub const DAISY_LPUART4_RX_GPIO_SD_B1_01: Daisy = Daisy::new(0x401f8540 as *mut u32, 0);
-    pub const DAISY_LPUART4_RX_GPIO_EMC_20: Daisy = Daisy::new(0x401f8540 as *mut u32, 1);
-    pub const DAISY_LPUART4_RX_GPIO_B1_01: Daisy = Daisy::new(0x401f8540 as *mut u32, 2);
-    pub const DAISY_LPUART4_TX_GPIO_SD_B1_00: Daisy = Daisy::new(0x401f8544 as *mut u32, 0);
-    pub const DAISY_LPUART4_TX_GPIO_EMC_19: Daisy = Daisy::new(0x401f8544 as *mut u32, 1);
-    pub const DAISY_LPUART4_TX_GPIO_B1_00: Daisy = Daisy::new(0x401f8544 as *mut u32, 2);
-    pub const DAISY_LPUART5_RX_GPIO_EMC_24: Daisy = Daisy::new(0x401f8548 as *mut u32, 0);
-    pub const DAISY_LPUART5_RX_GPIO_B1_13: Daisy = Daisy::new(0x401f8548 as *mut u32, 1);
-    pub const DAISY_LPUART5_TX_GPIO_EMC_23: Daisy = Daisy::new(0x401f854c as *mut u32, 0);
-    pub const DAISY_LPUART5_TX_GPIO_B1_12: Daisy = Daisy::new(0x401f854c as *mut u32, 1);
-    pub const DAISY_LPUART6_RX_GPIO_EMC_26: Daisy = Daisy::new(0x401f8550 as *mut u32, 0);
-    pub const DAISY_LPUART6_RX_GPIO_AD_B0_03: Daisy = Daisy::new(0x401f8550 as *mut u32, 1);
-    pub const DAISY_LPUART6_TX_GPIO_EMC_25: Daisy = Daisy::new(0x401f8554 as *mut u32, 0);
-    pub const DAISY_LPUART6_TX_GPIO_AD_B0_02: Daisy = Daisy::new(0x401f8554 as *mut u32, 1);
-    pub const DAISY_LPUART7_RX_GPIO_SD_B1_09: Daisy = Daisy::new(0x401f8558 as *mut u32, 0);
-    pub const DAISY_LPUART7_RX_GPIO_EMC_32: Daisy = Daisy::new(0x401f8558 as *mut u32, 1);
-    pub const DAISY_LPUART7_TX_GPIO_SD_B1_08: Daisy = Daisy::new(0x401f855c as *mut u32, 0);
-    pub const DAISY_LPUART7_TX_GPIO_EMC_31: Daisy = Daisy::new(0x401f855c as *mut u32, 1);
-    pub const DAISY_LPUART8_RX_GPIO_SD_B0_05: Daisy = Daisy::new(0x401f8560 as *mut u32, 0);
-    pub const DAISY_LPUART8_RX_GPIO_AD_B1_11: Daisy = Daisy::new(0x401f8560 as *mut u32, 1);
-    pub const DAISY_LPUART8_RX_GPIO_EMC_39: Daisy = Daisy::new(0x401f8560 as *mut u32, 2);
-    pub const DAISY_LPUART8_TX_GPIO_SD_B0_04: Daisy = Daisy::new(0x401f8564 as *mut u32, 0);
-    pub const DAISY_LPUART8_TX_GPIO_AD_B1_10: Daisy = Daisy::new(0x401f8564 as *mut u32, 1);
-    pub const DAISY_LPUART8_TX_GPIO_EMC_38: Daisy = Daisy::new(0x401f8564 as *mut u32, 2);
+    pub const DAISY_LPUART2_RX_GPIO_SD_B1_10: Daisy = Daisy::new(0x401f852c, 0);
+    pub const DAISY_LPUART2_RX_GPIO_AD_B1_03: Daisy = Daisy::new(0x401f852c, 1);
+    pub const DAISY_LPUART2_TX_GPIO_SD_B1_11: Daisy = Daisy::new(0x401f8530, 0);
+    pub const DAISY_LPUART2_TX_GPIO_AD_B1_02: Daisy = Daisy::new(0x401f8530, 1);
+    pub const DAISY_LPUART3_CT_GPIOS_B_EMC_15: Daisy = Daisy::new(0x401f8534, 0);
+    pub const DAISY_LPUART3_CT_GPIOS_B_AD_B1_04: Daisy = Daisy::new(0x401f8534, 1);
+    pub const DAISY_LPUART3_RX_GPIO_AD_B1_07: Daisy = Daisy::new(0x401f8538, 0);
+    pub const DAISY_LPUART3_RX_GPIO_EMC_14: Daisy = Daisy::new(0x401f8538, 1);
+    pub const DAISY_LPUART3_RX_GPIO_B0_09: Daisy = Daisy::new(0x401f8538, 2);
+    pub const DAISY_LPUART3_TX_GPIO_AD_B1_06: Daisy = Daisy::new(0x401f853c, 0);
+    pub const DAISY_LPUART3_TX_GPIO_EMC_13: Daisy = Daisy::new(0x401f853c, 1);
+    pub const DAISY_LPUART3_TX_GPIO_B0_08: Daisy = Daisy::new(0x401f853c, 2);
+    pub const DAISY_LPUART4_RX_GPIO_SD_B1_01: Daisy = Daisy::new(0x401f8540, 0);
+    pub const DAISY_LPUART4_RX_GPIO_EMC_20: Daisy = Daisy::new(0x401f8540, 1);
+    pub const DAISY_LPUART4_RX_GPIO_B1_01: Daisy = Daisy::new(0x401f8540, 2);
+    pub const DAISY_LPUART4_TX_GPIO_SD_B1_00: Daisy = Daisy::new(0x401f8544, 0);
+    pub const DAISY_LPUART4_TX_GPIO_EMC_19: Daisy = Daisy::new(0x401f8544, 1);
+    pub const DAISY_LPUART4_TX_GPIO_B1_00: Daisy = Daisy::new(0x401f8544, 2);
+    pub const DAISY_LPUART5_RX_GPIO_EMC_24: Daisy = Daisy::new(0x401f8548, 0);
+    pub const DAISY_LPUART5_RX_GPIO_B1_13: Daisy = Daisy::new(0x401f8548, 1);
+    pub const DAISY_LPUART5_TX_GPIO_EMC_23: Daisy = Daisy::new(0x401f854c, 0);
+    pub const DAISY_LPUART5_TX_GPIO_B1_12: Daisy = Daisy::new(0x401f854c, 1);
+    pub const DAISY_LPUART6_RX_GPIO_EMC_26: Daisy = Daisy::new(0x401f8550, 0);
+    pub const DAISY_LPUART6_RX_GPIO_AD_B0_03: Daisy = Daisy::new(0x401f8550, 1);
+    pub const DAISY_LPUART6_TX_GPIO_EMC_25: Daisy = Daisy::new(0x401f8554, 0);
+    pub const DAISY_LPUART6_TX_GPIO_AD_B0_02: Daisy = Daisy::new(0x401f8554, 1);
+    pub const DAISY_LPUART7_RX_GPIO_SD_B1_09: Daisy = Daisy::new(0x401f8558, 0);
+    pub const DAISY_LPUART7_RX_GPIO_EMC_32: Daisy = Daisy::new(0x401f8558, 1);
+    pub const DAISY_LPUART7_TX_GPIO_SD_B1_08: Daisy = Daisy::new(0x401f855c, 0);
+    pub const DAISY_LPUART7_TX_GPIO_EMC_31: Daisy = Daisy::new(0x401f855c, 1);
+    pub const DAISY_LPUART8_RX_GPIO_SD_B0_05: Daisy = Daisy::new(0x401f8560, 0);
+    pub const DAISY_LPUART8_RX_GPIO_AD_B1_11: Daisy = Daisy::new(0x401f8560, 1);
+    pub const DAISY_LPUART8_RX_GPIO_EMC_39: Daisy = Daisy::new(0x401f8560, 2);
+    pub const DAISY_LPUART8_TX_GPIO_SD_B0_04: Daisy = Daisy::new(0x401f8564, 0);
+    pub const DAISY_LPUART8_TX_GPIO_AD_B1_10: Daisy = Daisy::new(0x401f8564, 1);
+    pub const DAISY_LPUART8_TX_GPIO_EMC_38: Daisy = Daisy::new(0x401f8564, 2);
 }
-
 use daisy::*;
+
+impl PinTable for super::ErasedPads {
+    fn pins(&self) -> &[TableEntry] {
+        const PINS: [TableEntry; 14] = [
+            TableEntry::new::<U1, GPIO_AD_B0_13>(),
+            TableEntry::new::<U1, GPIO_AD_B0_12>(),
+            TableEntry::new::<U2, GPIO_AD_B1_03>(),
+            TableEntry::new::<U2, GPIO_AD_B1_02>(),
+            TableEntry::new::<U3, GPIO_AD_B1_07>(),
+            TableEntry::new::<U3, GPIO_AD_B1_06>(),
+            TableEntry::new::<U4, GPIO_B1_01>(),
+            TableEntry::new::<U4, GPIO_B1_00>(),
+            TableEntry::new::<U6, GPIO_AD_B0_03>(),
+            TableEntry::new::<U6, GPIO_AD_B0_02>(),
+            TableEntry::new::<U7, GPIO_EMC_32>(),
+            TableEntry::new::<U7, GPIO_EMC_31>(),
+            TableEntry::new::<U8, GPIO_AD_B1_11>(),
+            TableEntry::new::<U8, GPIO_AD_B1_10>(),
+        ];
+        &PINS
+    }
+}
@@ -1,7 +1,8 @@
 //! Pads for the i.MX RT 1060 processor family
 //!
 //! The module exports all of the i.MX RT 1060 processor's pads. Pads that can support peripheral
-//! functions are tagged with `imxrt-iomuxc` traits.
+//! functions are tagged with `imxrt-iomuxc` traits. The [`examples`] module shows one set
+//! of i.MX RT 1060 pads for each peripheral.
 //!
 //! # Example
 //!
@@ -87,16 +88,276 @@
 //! // GPIO_AD_B1_02 is a UART2 TX pin, but GPIO_AD_B0_13 is a UART1 RX pin
 //! uart_new(gpio_ad_b1_02, gpio_ad_b0_13, 115_200);
 //! ```
+//!
+//! # Dedicated balls
+//!
+//! Some balls, like the crystal oscillator and USB PHY balls, are not controlled by the
+//! IOMUXC. The [`dedicated`] module represents them with marker types, and `Pads` owns
+//! them in its `dedicated` member. The marker types do not implement `Iomuxc`, so you
+//! cannot configure or prepare them:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{configure, imxrt1060::Pads, Config};
+//!
+//! let mut pads = unsafe { Pads::new() };
+//! configure(&mut pads.dedicated.xtali, Config::zero());
+//! ```
 
 mod adc;
+mod analog;
+#[rustfmt::skip] // Generated, with long lines
+mod candidates;
+mod csi;
+mod flexio;
 mod flexpwm;
+pub mod jtag;
+mod lcdif;
 mod lpi2c;
 mod lpspi;
 mod lpuart;
+mod mqs;
+pub mod plans;
+mod qtimer;
 mod sai;
+mod src;
+mod xbar;
+mod xbar_table;
 include!(concat!(env!("OUT_DIR"), "/imxrt1060.rs"));
+pub use analog::{is_analog_capable, park_all, park_all_including_boot, ANALOG_PADS};
+pub use lpuart::{LPUART_RX_PADS, LPUART_TX_PADS};
 pub use pads::*;
 
+/// Pads that the boot ROM uses to read the boot image
+///
+/// These are the FlexSPI port A pads. When you execute in place, reconfiguring
+/// any of these pads may break instruction fetches. The typed peripheral APIs still
+/// accept these pads, but [`park_all()`] skips them.
+pub const BOOT_CRITICAL_PADS: [crate::PadId; 7] = [
+    gpio_sd_b1::GPIO_SD_B1_05::ID,
+    gpio_sd_b1::GPIO_SD_B1_06::ID,
+    gpio_sd_b1::GPIO_SD_B1_07::ID,
+    gpio_sd_b1::GPIO_SD_B1_08::ID,
+    gpio_sd_b1::GPIO_SD_B1_09::ID,
+    gpio_sd_b1::GPIO_SD_B1_10::ID,
+    gpio_sd_b1::GPIO_SD_B1_11::ID,
+];
+
+/// Returns a read-only view of the pad `id`
+///
+/// Returns `None` if `id` is not an i.MX RT 1060 pad.
+pub fn pad_ref(id: crate::PadId) -> Option<crate::PadRef> {
+    // In every group, each pad register is 0x1F0 bytes after its mux register.
+    crate::PadRef::lookup(id, &groups::GROUPS, 0x1F0)
+}
+
+/// Returns the pads that can carry `function`
+///
+/// The pads come from the chip's pin implementations, so they're the pads that
+/// satisfy the peripheral module's `Pin` trait, ordered by [`PadId`](crate::PadId).
+/// A pad that this crate doesn't implement for `function` isn't a candidate.
+/// Returns an empty slice for GPIOs, and for functions that the chip doesn't have.
+///
+/// ```
+/// use imxrt_iomuxc::{function::I2cSignal, imxrt1060, Function};
+/// use imxrt1060::{gpio_ad_b1::GPIO_AD_B1_00, gpio_sd_b1::GPIO_SD_B1_04};
+///
+/// let scl = Function::Lpi2c { module: 1, signal: I2cSignal::Scl };
+/// assert_eq!(
+///     imxrt1060::candidates(scl),
+///     [GPIO_AD_B1_00::ID, GPIO_SD_B1_04::ID]
+/// );
+/// ```
+pub const fn candidates(function: crate::Function) -> &'static [crate::PadId] {
+    candidates::CANDIDATES.get(function)
+}
+
+/// Returns the routing that connects `function` to the pad `id`
+///
+/// The routing comes from the chip's pin implementations, like [`candidates()`]. It
+/// has the pin's alternate, SION requirement, and daisy selection. Its pad
+/// configuration is [`Config::modify()`](crate::Config::modify), so it keeps the pad's
+/// settings, like the modules' `prepare()`; an ADC routing also disables the pull /
+/// keeper. Change the `config` to configure the pad. Returns `None` if the pad can't
+/// carry `function`, and for GPIOs. Use [`gpio::prepared()`](crate::gpio::prepared)
+/// to route a GPIO.
+pub const fn resolve(id: crate::PadId, function: crate::Function) -> Option<crate::Routing> {
+    candidates::CANDIDATES.routing(id, function)
+}
+
+/// Returns the functions that share the pad `id`
+///
+/// A pad carries one alternate at a time, so routing the pad to one of these
+/// functions precludes the others. The functions come from the chip's pin
+/// implementations, like [`candidates()`], and they're ordered by alternate. A
+/// function that this crate doesn't implement isn't listed, and neither is the
+/// pad's GPIO. Returns an empty slice if `id` is not an i.MX RT 1060 pad.
+///
+/// ```
+/// use imxrt_iomuxc::{imxrt1060::{self, gpio_ad_b1::GPIO_AD_B1_12}, Function};
+///
+/// // LPSPI3_PCS0, SAI1_RX_DATA00, CSI_DATA05, ADC2_IN1, and FLEXIO3_FLEXIO12
+/// let functions = imxrt1060::conflicting_functions(GPIO_AD_B1_12::ID);
+/// assert!(functions.contains(&imxrt1060::function_at(GPIO_AD_B1_12::ID, 3).unwrap()));
+/// assert!(matches!(functions[0], Function::Lpspi { module: 3, .. }));
+/// ```
+pub const fn conflicting_functions(id: crate::PadId) -> &'static [crate::Function] {
+    candidates::CANDIDATES.functions_at(id)
+}
+
+/// Returns the function that the pad `id` carries on the alternate `alt`
+///
+/// The function comes from the chip's pin implementations, like [`candidates()`].
+/// Returns `None` for the GPIO alternate, and if this crate doesn't implement the
+/// pad's function on `alt`.
+pub const fn function_at(id: crate::PadId, alt: u8) -> Option<crate::Function> {
+    candidates::CANDIDATES.function_at(id, alt)
+}
+
+/// Plan a set of `(PadId, Function)` assignments
+///
+/// Each assignment is [resolved](resolve) to a routing in the front of `storage`,
+/// and the routings are checked with [`plan()`](crate::plan). The returned plan has
+/// the alternate, daisy selection, and pad configuration of each assignment, in
+/// assignment order. Returns
+///
+/// - [`PlanError::ImpossibleAssignment`](crate::PlanError::ImpossibleAssignment)
+///   if a pad can't carry its function.
+/// - [`PlanError::TooManyAssignments`](crate::PlanError::TooManyAssignments) if
+///   `storage` is shorter than `assignments`.
+/// - the other `plan()` errors, like two assignments on one pad, or two pads for
+///   the same peripheral input.
+///
+/// ```
+/// use imxrt_iomuxc::{function::UartDirection, imxrt1060, Function, PlanError};
+/// use imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13};
+///
+/// let tx = Function::Lpuart { module: 1, direction: UartDirection::Tx };
+/// let rx = Function::Lpuart { module: 1, direction: UartDirection::Rx };
+///
+/// let mut storage = [imxrt1060::resolve(GPIO_AD_B0_12::ID, tx).unwrap(); 2];
+/// let plan = imxrt1060::plan_assignments(
+///     &[(GPIO_AD_B0_12::ID, tx), (GPIO_AD_B0_13::ID, rx)],
+///     &mut storage,
+/// )
+/// .unwrap();
+/// assert_eq!(plan.routings()[0].alt, 2);
+///
+/// // GPIO_AD_B0_13 can't be a TX pad
+/// assert!(matches!(
+///     imxrt1060::plan_assignments(&[(GPIO_AD_B0_13::ID, tx)], &mut storage),
+///     Err(PlanError::ImpossibleAssignment { index: 0, .. })
+/// ));
+/// ```
+pub fn plan_assignments<'a>(
+    assignments: &[(crate::PadId, crate::Function)],
+    storage: &'a mut [crate::Routing],
+) -> Result<crate::Plan<'a>, crate::PlanError> {
+    crate::routing::plan_assignments(assignments, storage, resolve)
+}
+
+/// The GPIO alternate
+///
+/// Every i.MX RT 1060 pad is a GPIO on ALT5; the `gpio_alts` test checks it.
+#[cfg(test)]
+const GPIO_ALT: u8 = 5;
+
+/// Returns `true` if the pad `id` implements the alternate `alt`
+///
+/// The alternates come from the chip's pad mux data, so a pad supports an
+/// alternate even if this crate doesn't implement a pin for it. Returns `false`
+/// if `id` is not an i.MX RT 1060 pad.
+///
+/// ```
+/// use imxrt_iomuxc::imxrt1060::{self, gpio_ad_b0::GPIO_AD_B0_12};
+///
+/// // LPUART1_TX, and GPIO1_IO12
+/// assert!(imxrt1060::supports_alternate(GPIO_AD_B0_12::ID, 2));
+/// assert!(imxrt1060::supports_alternate(GPIO_AD_B0_12::ID, 5));
+/// // WDOG2_B, which this crate doesn't implement
+/// assert!(imxrt1060::supports_alternate(GPIO_AD_B0_12::ID, 3));
+/// assert!(!imxrt1060::supports_alternate(GPIO_AD_B0_12::ID, 8));
+/// ```
+pub fn supports_alternate(id: crate::PadId, alt: u8) -> bool {
+    crate::GroupInfo::supports_alternate(id, alt, &groups::GROUPS, &ALTERNATES)
+}
+
+/// Returns the pad's GPIO module, and its interrupt configuration register index
+/// and field shift
+///
+/// The result is `(module, index, shift)`, with the same `index` and `shift` as the
+/// pad's [`gpio::Pin::ICR_INDEX`](crate::gpio::Pin::ICR_INDEX) and
+/// [`ICR_SHIFT`](crate::gpio::Pin::ICR_SHIFT). Use it when you only know the pad at
+/// run time. Returns `None` if `id` is not an i.MX RT 1060 pad.
+///
+/// ```
+/// use imxrt_iomuxc::imxrt1060::{self, gpio_ad_b1::GPIO_AD_B1_00};
+///
+/// // GPIO1_IO16 is in GPIO1's ICR2, bits 1:0
+/// assert_eq!(imxrt1060::gpio_icr(GPIO_AD_B1_00::ID), Some((1, 1, 0)));
+/// ```
+pub fn gpio_icr(id: crate::PadId) -> Option<(u8, usize, u32)> {
+    crate::GroupInfo::gpio_icr(id, &groups::GROUPS, &GPIOS)
+}
+
+chip_examples! {
+    chip: imxrt1060,
+    lpuart: { module: U1, tx: gpio_ad_b0::GPIO_AD_B0_12, rx: gpio_ad_b0::GPIO_AD_B0_13 },
+    lpi2c: { module: U1, scl: gpio_ad_b1::GPIO_AD_B1_00, sda: gpio_ad_b1::GPIO_AD_B1_01 },
+    lpspi: {
+        module: U4,
+        sck: gpio_b0::GPIO_B0_03,
+        sdo: gpio_b0::GPIO_B0_02,
+        sdi: gpio_b0::GPIO_B0_01,
+        pcs0: gpio_b0::GPIO_B0_00
+    },
+    flexpwm: { module: U2, submodule: U0, output: A, pin: gpio_emc::GPIO_EMC_06 },
+    adc: { module: Adc1, input: 7, pin: gpio_ad_b1::GPIO_AD_B1_02 },
+}
+
+/// Pads that the parallel bus peripherals reserve
+///
+/// The CSI reserves its 8-bit bus on `GPIO_AD_B1_04` through `GPIO_AD_B1_15`.
+/// The eLCDIF reserves its control pins and an 8-bit bus on `GPIO_B0_00` through
+/// `GPIO_B0_11`. Wider LCD buses use more pads, but a board may use those pads
+/// for other functions when its panel is narrower. See
+/// [`Plan::check_reservations()`](crate::Plan::check_reservations).
+pub const RESERVATIONS: [crate::Reservation; 2] = [
+    crate::Reservation {
+        peripheral: crate::Peripheral::Csi,
+        pads: &[
+            gpio_ad_b1::GPIO_AD_B1_04::ID,
+            gpio_ad_b1::GPIO_AD_B1_05::ID,
+            gpio_ad_b1::GPIO_AD_B1_06::ID,
+            gpio_ad_b1::GPIO_AD_B1_07::ID,
+            gpio_ad_b1::GPIO_AD_B1_08::ID,
+            gpio_ad_b1::GPIO_AD_B1_09::ID,
+            gpio_ad_b1::GPIO_AD_B1_10::ID,
+            gpio_ad_b1::GPIO_AD_B1_11::ID,
+            gpio_ad_b1::GPIO_AD_B1_12::ID,
+            gpio_ad_b1::GPIO_AD_B1_13::ID,
+            gpio_ad_b1::GPIO_AD_B1_14::ID,
+            gpio_ad_b1::GPIO_AD_B1_15::ID,
+        ],
+    },
+    crate::Reservation {
+        peripheral: crate::Peripheral::Lcdif,
+        pads: &[
+            gpio_b0::GPIO_B0_00::ID,
+            gpio_b0::GPIO_B0_01::ID,
+            gpio_b0::GPIO_B0_02::ID,
+            gpio_b0::GPIO_B0_03::ID,
+            gpio_b0::GPIO_B0_04::ID,
+            gpio_b0::GPIO_B0_05::ID,
+            gpio_b0::GPIO_B0_06::ID,
+            gpio_b0::GPIO_B0_07::ID,
+            gpio_b0::GPIO_B0_08::ID,
+            gpio_b0::GPIO_B0_09::ID,
+            gpio_b0::GPIO_B0_10::ID,
+            gpio_b0::GPIO_B0_11::ID,
+        ],
+    },
+];
+
 mod bases {
     define_base!(GPIO_EMC, 0x401F_8014, 0x401F_8204);
     define_base!(GPIO_AD_B0, 0x401F_80BC, 0x401F_82AC);
@@ -106,3 +367,834 @@ mod bases {
     define_base!(GPIO_SD_B0, 0x401F_81BC, 0x401F_83AC);
     define_base!(GPIO_SD_B1, 0x401F_81D4, 0x401F_83C4);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PadGroup;
+
+    fn check_group<G: PadGroup>(group: G, info: crate::GroupInfo, count: usize) {
+        assert_eq!(G::PAD_COUNT, count);
+        assert_eq!(info.pad_count, count);
+        assert_eq!(G::OFFSETS.len(), count);
+        let mut pads = group.erased_iter().peekable();
+        assert_eq!(pads.peek().map(|pad| pad.id()), Some(info.base_id));
+        let offsets = pads.map(|pad| pad.offset());
+        assert!(offsets.eq(G::OFFSETS.iter().map(|&offset| offset as usize)));
+    }
+
+    #[test]
+    fn pad_groups() {
+        let pads = unsafe { Pads::new() };
+        check_group(pads.gpio_emc, gpio_emc::INFO, 42);
+        check_group(pads.gpio_ad_b0, gpio_ad_b0::INFO, 16);
+        check_group(pads.gpio_ad_b1, gpio_ad_b1::INFO, 16);
+        check_group(pads.gpio_b0, gpio_b0::INFO, 16);
+        check_group(pads.gpio_b1, gpio_b1::INFO, 16);
+        check_group(pads.gpio_sd_b0, gpio_sd_b0::INFO, 6);
+        check_group(pads.gpio_sd_b1, gpio_sd_b1::INFO, 12);
+    }
+
+    #[test]
+    fn unsupported_alternate() {
+        use crate::{apply_routing, Config, PadId, Routing, RoutingError, SionRequirement};
+
+        let id = gpio_ad_b0::GPIO_AD_B0_12::ID;
+        // LPI2C4_SCL, CCM_PMIC_READY, LPUART1_TX, WDOG2_B, FLEXPWM1_PWMX02,
+        // GPIO1_IO12, ENET_1588_EVENT1_OUT, and NMI
+        let supported: [u8; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+        for alt in 0..16 {
+            assert_eq!(supports_alternate(id, alt), supported.contains(&alt));
+        }
+        assert!(!supports_alternate(PadId(0x1234), GPIO_ALT));
+
+        // The routing is rejected before any write, so the real pads are safe to use
+        let routing = Routing {
+            pad: id,
+            alt: 8,
+            sion: SionRequirement::Clear,
+            daisy: None,
+            config: Config::zero(),
+            peripheral: None,
+            function: None,
+        };
+        let mut pads = unsafe { Pads::new() }.erase();
+        assert_eq!(
+            unsafe { apply_routing(&mut pads, &routing) },
+            Err(RoutingError::UnsupportedPad(id))
+        );
+    }
+
+    #[test]
+    fn conflicting_functions_on_shared_pads() {
+        extern crate std;
+        use crate::PadId;
+        use std::{format, string::String, vec::Vec};
+
+        fn names(id: PadId) -> Vec<String> {
+            conflicting_functions(id)
+                .iter()
+                .map(|function| format!("{}", function))
+                .collect()
+        }
+
+        // SAI shares its data pads with the FlexPWM, XBAR, LPUART, and eLCDIF
+        assert_eq!(
+            names(gpio_sd_b1::GPIO_SD_B1_00::ID),
+            [
+                "FLEXPWM1_PWMA03",
+                "SAI1_TX_DATA03/RX_DATA01",
+                "SAI3_RX_DATA00"
+            ]
+        );
+        assert_eq!(
+            names(gpio_emc::GPIO_EMC_04::ID),
+            [
+                "FLEXPWM4_PWMA02",
+                "SAI2_TX_DATA00",
+                "XBAR1_INOUT06",
+                "FLEXIO1_FLEXIO04"
+            ]
+        );
+        let id = gpio_b1::GPIO_B1_00::ID;
+        assert_eq!(
+            names(id)[..4],
+            [
+                "LCD_DATA12",
+                "XBAR1_INOUT14",
+                "LPUART4_TX",
+                "SAI1_RX_DATA00"
+            ]
+        );
+        assert_eq!(
+            function_at(id, 3).map(|function| format!("{}", function)),
+            Some(String::from("SAI1_RX_DATA00"))
+        );
+
+        // The GPIO and ADC1_IN1 share ALT5
+        assert!(names(gpio_ad_b0::GPIO_AD_B0_12::ID).contains(&String::from("ADC1_IN1")));
+        assert_eq!(function_at(gpio_ad_b0::GPIO_AD_B0_12::ID, GPIO_ALT), None);
+        assert!(conflicting_functions(PadId(0x1234)).is_empty());
+
+        for (function, routing) in candidates::CANDIDATES.pins() {
+            assert!(conflicting_functions(routing.pad).contains(&function));
+        }
+    }
+
+    #[test]
+    fn pad_refs_from_ids() {
+        let pads = unsafe { Pads::new() };
+        let erased = pads
+            .gpio_emc
+            .erased_iter()
+            .chain(pads.gpio_ad_b0.erased_iter())
+            .chain(pads.gpio_ad_b1.erased_iter())
+            .chain(pads.gpio_b0.erased_iter())
+            .chain(pads.gpio_b1.erased_iter())
+            .chain(pads.gpio_sd_b0.erased_iter())
+            .chain(pads.gpio_sd_b1.erased_iter());
+        for pad in erased {
+            assert_eq!(pad_ref(pad.id()), Some(pad.pad_ref()));
+        }
+        assert_eq!(pad_ref(crate::PadId(0x401F_8010)), None);
+        let unaligned = BOOT_CRITICAL_PADS[0].mux_addr() + 2;
+        assert_eq!(pad_ref(crate::PadId(unaligned)), None);
+    }
+
+    #[test]
+    fn groups_match_modules() {
+        let expected = [
+            ("GPIO_EMC", gpio_emc::GPIO_EMC_00::ID),
+            ("GPIO_AD_B0", gpio_ad_b0::GPIO_AD_B0_00::ID),
+            ("GPIO_AD_B1", gpio_ad_b1::GPIO_AD_B1_00::ID),
+            ("GPIO_B0", gpio_b0::GPIO_B0_00::ID),
+            ("GPIO_B1", gpio_b1::GPIO_B1_00::ID),
+            ("GPIO_SD_B0", gpio_sd_b0::GPIO_SD_B0_00::ID),
+            ("GPIO_SD_B1", gpio_sd_b1::GPIO_SD_B1_00::ID),
+        ];
+        assert_eq!(groups::GROUPS.len(), expected.len());
+        for (info, (name, base_id)) in groups::GROUPS.iter().zip(expected.iter()) {
+            assert_eq!((info.name, info.base_id), (*name, *base_id));
+        }
+    }
+
+    #[test]
+    fn boot_critical_pads_accept_typed_pins() {
+        use crate::{consts::U1, sai, Config};
+        use gpio_sd_b1::*;
+
+        // SAI1 pins on the FlexSPI port A pads
+        const CONFIG: Config = Config::zero();
+        let prepared = [
+            sai::prepared::<U1, GPIO_SD_B1_05>(CONFIG),
+            sai::prepared::<U1, GPIO_SD_B1_09>(CONFIG),
+        ];
+        for writes in &prepared {
+            let addr = writes.mux_addr;
+            assert!(BOOT_CRITICAL_PADS.iter().any(|id| id.mux_addr() == addr));
+            assert_eq!(writes.mux_val & 0b111, 3);
+        }
+        assert_eq!(BOOT_CRITICAL_PADS[0].mux_addr(), 0x401F_81E8);
+    }
+
+    #[test]
+    fn daisy_tables() {
+        use crate::tests::check_daisy_table;
+        check_daisy_table(include_str!("csi.rs"));
+        check_daisy_table(include_str!("flexpwm.rs"));
+        check_daisy_table(include_str!("lpi2c.rs"));
+        check_daisy_table(include_str!("lpspi.rs"));
+        check_daisy_table(include_str!("lpuart.rs"));
+        check_daisy_table(include_str!("qtimer.rs"));
+        check_daisy_table(include_str!("sai.rs"));
+        check_daisy_table(include_str!("xbar.rs"));
+    }
+
+    #[test]
+    fn gpio_icr_matches_pins() {
+        assert_eq!(GPIO_PINS.len(), 124);
+        for &(id, module, index, shift) in GPIO_PINS {
+            assert_eq!(gpio_icr(id), Some((module, index, shift)), "{:?}", id);
+        }
+        assert_eq!(gpio_icr(crate::PadId(0x1234)), None);
+    }
+
+    #[test]
+    fn gpio_alts() {
+        crate::tests::check_gpio_alts(
+            include_str!(concat!(env!("OUT_DIR"), "/imxrt1060.rs")),
+            124,
+            GPIO_ALT,
+            &[
+                include_str!("csi.rs"),
+                include_str!("flexio.rs"),
+                include_str!("flexpwm.rs"),
+                include_str!("lcdif.rs"),
+                include_str!("lpi2c.rs"),
+                include_str!("lpspi.rs"),
+                include_str!("lpuart.rs"),
+                include_str!("mqs.rs"),
+                include_str!("qtimer.rs"),
+                include_str!("sai.rs"),
+                include_str!("src.rs"),
+                include_str!("xbar.rs"),
+            ],
+        );
+    }
+
+    #[test]
+    fn gpio_emc_pins() {
+        use crate::gpio::{function, Pin};
+        use crate::Function;
+        use gpio_emc::*;
+
+        fn gpio(module: u8, offset: u8) -> Function {
+            Function::Gpio { module, offset }
+        }
+
+        // Every EMC pad is a GPIO on ALT5, split across GPIO4 and GPIO3
+        assert_eq!(GPIO_EMC_00::ALT, 5);
+        assert_eq!(function::<GPIO_EMC_00>(), gpio(4, 0));
+        assert_eq!(GPIO_EMC_31::ALT, 5);
+        assert_eq!(function::<GPIO_EMC_31>(), gpio(4, 31));
+        assert_eq!(GPIO_EMC_32::ALT, 5);
+        assert_eq!(function::<GPIO_EMC_32>(), gpio(3, 18));
+        assert_eq!(GPIO_EMC_41::ALT, 5);
+        assert_eq!(function::<GPIO_EMC_41>(), gpio(3, 27));
+    }
+
+    #[test]
+    fn xbar_lines() {
+        use crate::xbar::{Line, PinTable};
+
+        let pads = unsafe { Pads::new() }.erase();
+        assert!(pads.line_has_pad_input(2));
+        assert!(pads.line_has_pad_input(10));
+        assert!(!pads.line_has_pad_input(1));
+        assert!(!pads.line_has_pad_input(42));
+        assert_eq!(pads.line(88), None);
+
+        // A pin has a daisy if, and only if, its line is selectable
+        for pin in pads.pins() {
+            assert_eq!(
+                pads.line(pin.index()) == Some(Line::Selectable),
+                pin.daisy().is_some(),
+                "{:?}",
+                pin
+            );
+        }
+    }
+
+    #[test]
+    fn pwm_x_pins() {
+        use crate::flexpwm::{self, Pin, X};
+        use crate::{consts::Unsigned, Config};
+        use gpio_ad_b0::*;
+
+        fn check<P: Pin<Output = X> + crate::ConstIomuxc>(submodule: usize) {
+            assert_eq!(P::Submodule::USIZE, submodule);
+            assert_eq!(P::ALT, 4);
+            // No daisy for either role
+            assert_eq!(flexpwm::prepared::<P>(Config::zero()).daisy, None);
+            assert_eq!(flexpwm::prepared_capture::<P>(Config::zero()).daisy, None);
+        }
+
+        check::<GPIO_AD_B0_02>(0);
+        check::<GPIO_AD_B0_03>(1);
+        check::<GPIO_AD_B0_12>(2);
+        check::<GPIO_AD_B0_13>(3);
+        extern crate std;
+        use std::string::ToString;
+        assert_eq!(
+            flexpwm::function::<GPIO_AD_B0_13>().to_string(),
+            "FLEXPWM1_PWMX03"
+        );
+    }
+
+    #[test]
+    fn lpuart_no_swaps() {
+        use crate::lpuart::PinTable;
+
+        // No pad is both a TX and an RX pin of the same UART
+        let pads = unsafe { Pads::new() }.erase();
+        for pin in pads.pins() {
+            assert_eq!(pin.swapped(pads.pins()), None, "{:?}", pin);
+        }
+    }
+
+    #[test]
+    fn candidates_cover_pins() {
+        crate::function::tests::check_candidates(
+            &candidates::CANDIDATES,
+            &groups::GROUPS,
+            GPIO_ALT,
+            &[
+                include_str!("adc.rs"),
+                include_str!("csi.rs"),
+                include_str!("flexio.rs"),
+                include_str!("flexpwm.rs"),
+                include_str!("lcdif.rs"),
+                include_str!("lpi2c.rs"),
+                include_str!("lpspi.rs"),
+                include_str!("lpuart.rs"),
+                include_str!("mqs.rs"),
+                include_str!("qtimer.rs"),
+                include_str!("sai.rs"),
+                include_str!("src.rs"),
+                include_str!("xbar.rs"),
+            ],
+        );
+    }
+
+    #[test]
+    fn alternates_cover_pins() {
+        for (function, routing) in candidates::CANDIDATES.pins() {
+            let (pad, alt) = (routing.pad, routing.alt);
+            assert!(
+                supports_alternate(pad, alt),
+                "{:?} ALT{} for {:?}",
+                pad,
+                alt,
+                function
+            );
+        }
+        for group in groups::GROUPS.iter() {
+            for pad in 0..group.pad_count {
+                let id = crate::PadId(group.base_id.mux_addr() + 4 * pad);
+                assert!(supports_alternate(id, GPIO_ALT), "{:?} GPIO", id);
+            }
+        }
+    }
+
+    #[test]
+    fn known_candidates() {
+        use crate::function::{FlexSpiPort, FlexSpiSignal, I2cSignal, SaiSignal};
+        use crate::Function;
+
+        // Reference manual, LPI2C1_SCL_SELECT_INPUT
+        let scl = Function::Lpi2c {
+            module: 1,
+            signal: I2cSignal::Scl,
+        };
+        assert_eq!(
+            candidates(scl),
+            [gpio_ad_b1::GPIO_AD_B1_00::ID, gpio_sd_b1::GPIO_SD_B1_04::ID]
+        );
+
+        // Reference manual, SAI1_MCLK2_SELECT_INPUT
+        let mclk = Function::Sai {
+            module: 1,
+            signal: SaiSignal::Mclk,
+        };
+        assert_eq!(
+            candidates(mclk),
+            [
+                gpio_ad_b1::GPIO_AD_B1_09::ID,
+                gpio_b0::GPIO_B0_13::ID,
+                gpio_sd_b1::GPIO_SD_B1_03::ID
+            ]
+        );
+
+        // FLEXIO2 and FLEXIO3 share GPIO_B1_00
+        for module in [2, 3] {
+            let flexio = Function::FlexIo { module, index: 16 };
+            assert_eq!(candidates(flexio), [gpio_b1::GPIO_B1_00::ID]);
+        }
+
+        assert_eq!(LPUART_TX_PADS[1], [gpio_ad_b0::GPIO_AD_B0_12::ID]);
+        assert_eq!(LPUART_RX_PADS[8], [gpio_ad_b1::GPIO_AD_B1_11::ID]);
+        assert!(LPUART_TX_PADS[0].is_empty());
+
+        // GPIOs aren't candidates, and the 1060 doesn't have FlexSPI pins
+        assert!(candidates(Function::Gpio {
+            module: 1,
+            offset: 3
+        })
+        .is_empty());
+        assert!(candidates(Function::FlexSpi {
+            port: FlexSpiPort::A,
+            signal: FlexSpiSignal::Sclk
+        })
+        .is_empty());
+    }
+
+    #[test]
+    fn plan_assignments_resolves_pins() {
+        use crate::function::{I2cSignal, UartDirection};
+        use crate::{adc, lpi2c, lpuart, Config, Function, Routing};
+
+        let tx = Function::Lpuart {
+            module: 1,
+            direction: UartDirection::Tx,
+        };
+        let rx = Function::Lpuart {
+            module: 1,
+            direction: UartDirection::Rx,
+        };
+        let scl = Function::Lpi2c {
+            module: 1,
+            signal: I2cSignal::Scl,
+        };
+        let adc = Function::Adc {
+            module: 2,
+            input: 3,
+        };
+        let assignments = [
+            (gpio_ad_b0::GPIO_AD_B0_12::ID, tx),
+            (gpio_ad_b0::GPIO_AD_B0_13::ID, rx),
+            (gpio_ad_b1::GPIO_AD_B1_00::ID, scl),
+            (gpio_ad_b1::GPIO_AD_B1_14::ID, adc),
+        ];
+
+        // The typed routings, keeping the pad configuration like the modules' prepare(),
+        // and naming the assigned function
+        let expected = |routing: Routing, config: Config, function| Routing {
+            config,
+            ..routing.with_function(function)
+        };
+        let expected = [
+            expected(
+                Routing::from_prepared(&lpuart::prepared::<gpio_ad_b0::GPIO_AD_B0_12>(
+                    Config::zero(),
+                )),
+                Config::modify(),
+                tx,
+            ),
+            expected(
+                Routing::from_prepared(&lpuart::prepared::<gpio_ad_b0::GPIO_AD_B0_13>(
+                    Config::zero(),
+                )),
+                Config::modify(),
+                rx,
+            ),
+            expected(
+                Routing::from_prepared(&lpi2c::prepared::<gpio_ad_b1::GPIO_AD_B1_00>(
+                    Config::zero(),
+                )),
+                Config::modify(),
+                scl,
+            ),
+            expected(
+                Routing::from_prepared(&adc::prepared::<adc::Adc2, gpio_ad_b1::GPIO_AD_B1_14>(
+                    Config::zero(),
+                )),
+                Config::modify().set_pull_keeper(None),
+                adc,
+            ),
+        ];
+
+        let mut storage = [expected[0]; 5];
+        let plan = plan_assignments(&assignments, &mut storage).unwrap();
+        assert_eq!(plan.routings(), expected);
+
+        // Reference manual, LPI2C1_SCL_SELECT_INPUT
+        assert_eq!(plan.routings()[2].alt, 3);
+        assert_eq!(
+            plan.routings()[2]
+                .daisy
+                .map(|daisy| (daisy.reg(), daisy.value())),
+            Some((0x401F_84CC, 1))
+        );
+        for (routing, (pad, function)) in plan.routings().iter().zip(assignments) {
+            assert_eq!(resolve(pad, function), Some(*routing));
+        }
+        assert!(plan_assignments(&[], &mut [])
+            .unwrap()
+            .routings()
+            .is_empty());
+    }
+
+    #[test]
+    fn plan_assignments_rejects_bad_plans() {
+        use crate::function::{I2cSignal, MqsSignal, SaiSignal, UartDirection};
+        use crate::{Function, Peripheral, PlanError};
+
+        let tx = Function::Lpuart {
+            module: 1,
+            direction: UartDirection::Tx,
+        };
+        let rx = Function::Lpuart {
+            module: 1,
+            direction: UartDirection::Rx,
+        };
+        let scl = Function::Lpi2c {
+            module: 1,
+            signal: I2cSignal::Scl,
+        };
+        let gpio = Function::Gpio {
+            module: 1,
+            offset: 12,
+        };
+        let mut storage = [resolve(gpio_ad_b0::GPIO_AD_B0_12::ID, tx).unwrap(); 3];
+
+        // GPIO_AD_B0_13 can't be LPUART1_TX
+        assert_eq!(
+            plan_assignments(
+                &[
+                    (gpio_ad_b0::GPIO_AD_B0_12::ID, tx),
+                    (gpio_ad_b0::GPIO_AD_B0_13::ID, tx)
+                ],
+                &mut storage
+            ),
+            Err(PlanError::ImpossibleAssignment {
+                index: 1,
+                pad: gpio_ad_b0::GPIO_AD_B0_13::ID,
+                function: tx
+            })
+        );
+        // GPIOs aren't in the pin tables, and neither are unknown pads
+        for (pad, function) in [
+            (gpio_ad_b0::GPIO_AD_B0_12::ID, gpio),
+            (crate::PadId(0x1234), tx),
+        ] {
+            assert_eq!(
+                plan_assignments(&[(pad, function)], &mut storage),
+                Err(PlanError::ImpossibleAssignment {
+                    index: 0,
+                    pad,
+                    function
+                })
+            );
+        }
+        // Two functions on one pad
+        assert_eq!(
+            plan_assignments(
+                &[
+                    (gpio_ad_b0::GPIO_AD_B0_12::ID, tx),
+                    (gpio_ad_b0::GPIO_AD_B0_13::ID, rx),
+                    (
+                        gpio_ad_b0::GPIO_AD_B0_12::ID,
+                        Function::Adc {
+                            module: 1,
+                            input: 1
+                        }
+                    ),
+                ],
+                &mut storage
+            ),
+            Err(PlanError::DuplicatePad {
+                pad: gpio_ad_b0::GPIO_AD_B0_12::ID,
+                earlier: 0,
+                later: 2
+            })
+        );
+        // Two pads for LPI2C1_SCL steal the same daisy
+        assert!(matches!(
+            plan_assignments(
+                &[
+                    (gpio_ad_b1::GPIO_AD_B1_00::ID, scl),
+                    (gpio_sd_b1::GPIO_SD_B1_04::ID, scl)
+                ],
+                &mut storage
+            ),
+            Err(PlanError::DaisyConflict(crate::DaisyConflict {
+                earlier: 0,
+                later: 1,
+                ..
+            }))
+        ));
+        // MQS borrows SAI3
+        assert_eq!(
+            plan_assignments(
+                &[
+                    (
+                        gpio_emc::GPIO_EMC_38::ID,
+                        Function::Sai {
+                            module: 3,
+                            signal: SaiSignal::TxBclk
+                        }
+                    ),
+                    (gpio_b0::GPIO_B0_01::ID, Function::Mqs(MqsSignal::Left)),
+                ],
+                &mut storage
+            ),
+            Err(PlanError::BorrowConflict {
+                peripheral: Peripheral::Sai(3),
+                borrower: 1,
+                user: 0
+            })
+        );
+        // Not enough storage
+        assert_eq!(
+            plan_assignments(
+                &[
+                    (gpio_ad_b0::GPIO_AD_B0_12::ID, tx),
+                    (gpio_ad_b0::GPIO_AD_B0_13::ID, rx)
+                ],
+                &mut storage[..1]
+            ),
+            Err(PlanError::TooManyAssignments(2))
+        );
+    }
+
+    #[test]
+    fn lpspi1_gpio_sd_b0_daisies() {
+        use crate::{lpspi::prepared, Config};
+        use gpio_sd_b0::*;
+
+        // LPSPI1 selects the GPIO_SD_B0 pads with value 1, except for PCS0
+        const CONFIG: Config = Config::zero();
+        let daisies = [
+            prepared::<GPIO_SD_B0_00>(CONFIG).daisy,
+            prepared::<GPIO_SD_B0_01>(CONFIG).daisy,
+            prepared::<GPIO_SD_B0_02>(CONFIG).daisy,
+            prepared::<GPIO_SD_B0_03>(CONFIG).daisy,
+        ];
+        assert_eq!(
+            daisies,
+            [
+                Some((0x401F_84F0, 1)), // SCK
+                Some((0x401F_84EC, 0)), // PCS0
+                Some((0x401F_84F8, 1)), // SDO
+                Some((0x401F_84F4, 1)), // SDI
+            ]
+        );
+    }
+
+    #[test]
+    fn module_aliases() {
+        use crate::{adc, flexio, flexpwm, gpio, lpi2c, lpspi, lpuart, qtimer, sai};
+        use {gpio_ad_b0::*, gpio_ad_b1::*, gpio_b0::*, gpio_emc::*};
+
+        // Compiles only if each alias names the module of the pin impl
+        fn lpuart2<P: lpuart::Pin<Module = lpuart::module::Lpuart2>>() {}
+        fn lpi2c3<P: lpi2c::Pin<Module = lpi2c::module::Lpi2c3>>() {}
+        fn lpspi4<P: lpspi::Pin<Module = lpspi::module::Lpspi4>>() {}
+        fn flexpwm4<P: flexpwm::Pin<Module = flexpwm::module::Flexpwm4>>() {}
+        fn qtimer4<P: qtimer::Pin<Module = qtimer::module::Qtimer4>>() {}
+        fn flexio3<P: flexio::Pin<Module = flexio::module::Flexio3>>() {}
+        fn sai3<P: sai::Pin<sai::module::Sai3>>() {}
+        fn adc2<P: adc::Pin<adc::module::Adc2>>() {}
+        fn gpio1<P: gpio::Pin<Module = gpio::module::Gpio1>>() {}
+
+        lpuart2::<GPIO_AD_B1_02>();
+        lpi2c3::<GPIO_AD_B1_07>();
+        lpspi4::<GPIO_B0_03>();
+        flexpwm4::<GPIO_AD_B1_08>();
+        qtimer4::<GPIO_B0_09>();
+        flexio3::<GPIO_AD_B1_00>();
+        sai3::<GPIO_EMC_38>();
+        adc2::<GPIO_AD_B1_00>();
+        gpio1::<GPIO_AD_B0_12>();
+    }
+
+    #[test]
+    fn flexio3_pins() {
+        use crate::consts::Unsigned;
+        use crate::flexio::{module::Flexio3, Pin, PinFor};
+        use {gpio_ad_b1::*, gpio_b1::*};
+
+        fn pin<P: PinFor<Flexio3>>() -> (u32, u32) {
+            (P::ALT, P::Index::U32)
+        }
+
+        // FLEXIO3_FLEXIO00 through 15 on GPIO_AD_B1, and 16 through 31 on GPIO_B1
+        assert_eq!(pin::<GPIO_AD_B1_00>(), (9, 0));
+        assert_eq!(pin::<GPIO_AD_B1_15>(), (9, 15));
+        assert_eq!(pin::<GPIO_B1_00>(), (9, 16));
+        assert_eq!(pin::<GPIO_B1_15>(), (9, 31));
+
+        // The GPIO_B1 pads are FLEXIO2 pins first
+        assert_eq!(<GPIO_B1_00 as Pin>::Module::U32, 2);
+        assert_eq!(<GPIO_B1_00 as Pin>::ALT, 4);
+    }
+
+    #[test]
+    fn teensy_audio_sai1_daisies() {
+        use crate::{consts::U1, sai::prepared, Config};
+        use {gpio_ad_b1::*, gpio_b1::*};
+
+        // SAI1 on the Teensy 4 audio shield pins (7, 8, 20, 21, 23)
+        const CONFIG: Config = Config::zero();
+        let daisies = [
+            prepared::<U1, GPIO_B1_01>(CONFIG).daisy,
+            prepared::<U1, GPIO_B1_00>(CONFIG).daisy,
+            prepared::<U1, GPIO_AD_B1_10>(CONFIG).daisy,
+            prepared::<U1, GPIO_AD_B1_11>(CONFIG).daisy,
+            prepared::<U1, GPIO_AD_B1_09>(CONFIG).daisy,
+        ];
+        assert_eq!(
+            daisies,
+            [
+                None,                   // TX_DATA0
+                Some((0x401F_8594, 2)), // RX_DATA0
+                Some((0x401F_85A4, 1)), // RX_SYNC
+                Some((0x401F_8590, 1)), // RX_BCLK
+                Some((0x401F_858C, 1)), // MCLK
+            ]
+        );
+    }
+
+    #[test]
+    fn adc_inputs() {
+        use crate::adc::{Adc1, Adc2, Pin};
+        use gpio_ad_b0::*;
+        use gpio_ad_b1::*;
+
+        fn input<U: crate::consts::Unsigned, P: Pin<U>>() -> u32 {
+            P::INPUT
+        }
+
+        // Table 66-2: ADC External Signals. The table index is the input.
+        let adc1 = [
+            input::<Adc1, GPIO_AD_B1_11>(),
+            input::<Adc1, GPIO_AD_B0_12>(),
+            input::<Adc1, GPIO_AD_B0_13>(),
+            input::<Adc1, GPIO_AD_B0_14>(),
+            input::<Adc1, GPIO_AD_B0_15>(),
+            input::<Adc1, GPIO_AD_B1_00>(),
+            input::<Adc1, GPIO_AD_B1_01>(),
+            input::<Adc1, GPIO_AD_B1_02>(),
+            input::<Adc1, GPIO_AD_B1_03>(),
+            input::<Adc1, GPIO_AD_B1_04>(),
+            input::<Adc1, GPIO_AD_B1_05>(),
+            input::<Adc1, GPIO_AD_B1_06>(),
+            input::<Adc1, GPIO_AD_B1_07>(),
+            input::<Adc1, GPIO_AD_B1_08>(),
+            input::<Adc1, GPIO_AD_B1_09>(),
+            input::<Adc1, GPIO_AD_B1_10>(),
+        ];
+        let adc2 = [
+            input::<Adc2, GPIO_AD_B1_11>(),
+            input::<Adc2, GPIO_AD_B1_12>(),
+            input::<Adc2, GPIO_AD_B1_13>(),
+            input::<Adc2, GPIO_AD_B1_14>(),
+            input::<Adc2, GPIO_AD_B1_15>(),
+            input::<Adc2, GPIO_AD_B1_00>(),
+            input::<Adc2, GPIO_AD_B1_01>(),
+            input::<Adc2, GPIO_AD_B1_02>(),
+            input::<Adc2, GPIO_AD_B1_03>(),
+            input::<Adc2, GPIO_AD_B1_04>(),
+            input::<Adc2, GPIO_AD_B1_05>(),
+            input::<Adc2, GPIO_AD_B1_06>(),
+            input::<Adc2, GPIO_AD_B1_07>(),
+            input::<Adc2, GPIO_AD_B1_08>(),
+            input::<Adc2, GPIO_AD_B1_09>(),
+            input::<Adc2, GPIO_AD_B1_10>(),
+        ];
+        assert!(adc1.iter().copied().eq(0..16));
+        assert!(adc2.iter().copied().eq(0..16));
+    }
+
+    #[test]
+    fn mqs_borrows_sai3() {
+        use crate::{consts::*, mqs, plan, sai, Config, Peripheral, PlanError, Routing};
+        use gpio_b0::*;
+        use gpio_emc::*;
+        use gpio_sd_b1::GPIO_SD_B1_08;
+
+        const CONFIG: Config = Config::zero();
+        let routings = [
+            Routing::from_prepared(&sai::prepared::<U3, GPIO_EMC_36>(CONFIG)), // TX_DATA
+            Routing::from_prepared(&sai::prepared::<U3, GPIO_EMC_33>(CONFIG)), // RX_DATA
+            Routing::from_prepared(&mqs::prepared::<GPIO_B0_01>(CONFIG)),
+        ];
+        assert_eq!(
+            plan(&routings),
+            Err(PlanError::BorrowConflict {
+                peripheral: Peripheral::Sai(3),
+                borrower: 2,
+                user: 0,
+            })
+        );
+
+        // MQS works alongside the other SAIs
+        let routings = [
+            Routing::from_prepared(&mqs::prepared::<GPIO_B0_00>(CONFIG)),
+            Routing::from_prepared(&mqs::prepared::<GPIO_B0_01>(CONFIG)),
+            Routing::from_prepared(&sai::prepared::<U1, GPIO_SD_B1_08>(CONFIG)),
+        ];
+        assert!(plan(&routings).is_ok());
+    }
+
+    #[test]
+    fn parallel_bus_reservations() {
+        use crate::{csi, lcdif, lpi2c, lpspi, lpuart, plan, Config, Peripheral, PlanError};
+        use crate::{PadId, Routing};
+        use gpio_ad_b1::*;
+        use gpio_b0::*;
+
+        const CONFIG: Config = Config::zero();
+        let reserved = |routings: &[Routing]| {
+            plan(routings)
+                .and_then(|plan| plan.check_reservations(&RESERVATIONS))
+                .map(|_| ())
+        };
+
+        // LPUART3 on the CSI's sync pads
+        let routings = [
+            Routing::from_prepared(&csi::prepared::<GPIO_AD_B1_04>(CONFIG)), // PIXCLK
+            Routing::from_prepared(&csi::prepared::<GPIO_AD_B1_15>(CONFIG)), // DATA02
+            Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B1_07>(CONFIG)), // RX
+        ];
+        assert_eq!(
+            reserved(&routings),
+            Err(PlanError::ReservedPad {
+                pad: GPIO_AD_B1_07::ID,
+                peripheral: Peripheral::Csi,
+                index: 2,
+                user: 0,
+            })
+        );
+
+        // LPSPI4 on the eLCDIF's control pads
+        let routings = [
+            Routing::from_prepared(&lpspi::prepared::<GPIO_B0_03>(CONFIG)), // SCK
+            Routing::from_prepared(&lcdif::prepared::<GPIO_B0_04>(CONFIG)), // DATA00
+        ];
+        assert!(matches!(
+            reserved(&routings),
+            Err(PlanError::ReservedPad { pad, peripheral: Peripheral::Lcdif, index: 0, user: 1 })
+                if pad == PadId::of::<GPIO_B0_03>()
+        ));
+
+        // LPI2C1 sits beside the CSI, and LPSPI4 is fine without the eLCDIF
+        let routings = [
+            Routing::from_prepared(&csi::prepared::<GPIO_AD_B1_04>(CONFIG)), // PIXCLK
+            Routing::from_prepared(&lpi2c::prepared::<GPIO_AD_B1_00>(CONFIG)), // SCL
+            Routing::from_prepared(&lpi2c::prepared::<GPIO_AD_B1_01>(CONFIG)), // SDA
+            Routing::from_prepared(&lpspi::prepared::<GPIO_B0_03>(CONFIG)),  // SCK
+        ];
+        assert_eq!(reserved(&routings), Ok(()));
+    }
+}
@@ -0,0 +1,8 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/mqs.csv. Do not edit.
+//! MQS pin implementations
+
+use super::pads::gpio_b0::*;
+use crate::mqs::{Left, Pin, Right};
+
+mqs!(alt: 2, pad: GPIO_B0_00, signal: Right);
+mqs!(alt: 2, pad: GPIO_B0_01, signal: Left);
@@ -0,0 +1,138 @@
+//! Example routings for the `GPIO_B1` pads
+//!
+//! The `GPIO_B1` pads carry the upper eLCDIF data lines, and FlexIO2 pins 16
+//! through 31. A board picks one role for each pad. Each constant in this module
+//! is a coherent set of [`Routing`]s for one of those roles. [`plan()`](crate::plan)
+//! accepts every set.
+//!
+//! Start from a set, and change it for your board. The routings are `Copy`, so
+//! copy the array, then change the pad configurations, or replace the routings
+//! that your board doesn't use:
+//!
+//! ```
+//! use imxrt_iomuxc::{imxrt1060::plans, plan, Speed};
+//!
+//! let mut routings = plans::LCD_24BIT;
+//! for routing in routings.iter_mut() {
+//!     routing.config = plans::LCD_CONFIG.set_speed(Speed::Max);
+//! }
+//! assert!(plan(&routings).is_ok());
+//! ```
+
+use super::pads::{gpio_b0::*, gpio_b1::*};
+use crate::{flexio, lcdif, Config, ConstIomuxc, DriveStrength, Routing, SlewRate, Speed};
+
+/// The pad configuration for the eLCDIF routings
+///
+/// A fast slew rate, and a medium drive strength, suit a panel cable of a few
+/// centimeters.
+pub const LCD_CONFIG: Config = Config::zero()
+    .set_slew_rate(SlewRate::Fast)
+    .set_speed(Speed::Fast)
+    .set_drive_strength(DriveStrength::R0_6);
+
+const fn lcd<P: lcdif::Pin + ConstIomuxc>() -> Routing {
+    Routing::from_prepared(&lcdif::prepared::<P>(LCD_CONFIG))
+}
+
+/// A 24-bit eLCDIF panel
+///
+/// The control pins and `DATA00` through `DATA11` are on `GPIO_B0`. `DATA12`
+/// through `DATA23` use `GPIO_B1_00` through `GPIO_B1_11`. The routings are in
+/// the same order as the [`Bus24`](crate::lcdif::Bus24) pins: clock, enable,
+/// HSYNC, VSYNC, then the data lines.
+pub const LCD_24BIT: [Routing; 28] = [
+    lcd::<GPIO_B0_00>(),
+    lcd::<GPIO_B0_01>(),
+    lcd::<GPIO_B0_02>(),
+    lcd::<GPIO_B0_03>(),
+    lcd::<GPIO_B0_04>(),
+    lcd::<GPIO_B0_05>(),
+    lcd::<GPIO_B0_06>(),
+    lcd::<GPIO_B0_07>(),
+    lcd::<GPIO_B0_08>(),
+    lcd::<GPIO_B0_09>(),
+    lcd::<GPIO_B0_10>(),
+    lcd::<GPIO_B0_11>(),
+    lcd::<GPIO_B0_12>(),
+    lcd::<GPIO_B0_13>(),
+    lcd::<GPIO_B0_14>(),
+    lcd::<GPIO_B0_15>(),
+    lcd::<GPIO_B1_00>(),
+    lcd::<GPIO_B1_01>(),
+    lcd::<GPIO_B1_02>(),
+    lcd::<GPIO_B1_03>(),
+    lcd::<GPIO_B1_04>(),
+    lcd::<GPIO_B1_05>(),
+    lcd::<GPIO_B1_06>(),
+    lcd::<GPIO_B1_07>(),
+    lcd::<GPIO_B1_08>(),
+    lcd::<GPIO_B1_09>(),
+    lcd::<GPIO_B1_10>(),
+    lcd::<GPIO_B1_11>(),
+];
+
+/// The pad configuration for the FlexIO routings
+pub const FLEXIO_CONFIG: Config = Config::zero()
+    .set_speed(Speed::Fast)
+    .set_drive_strength(DriveStrength::R0_6);
+
+const fn flexio<P: flexio::Pin + ConstIomuxc>() -> Routing {
+    Routing::from_prepared(&flexio::prepared::<P>(FLEXIO_CONFIG))
+}
+
+/// FlexIO2 pins 16 through 31, on `GPIO_B1_00` through `GPIO_B1_15`
+///
+/// The routings are in pin index order. The pins suit a 16-bit parallel bus; see
+/// [`ConsecutivePins`](crate::flexio::ConsecutivePins).
+pub const FLEXIO2_B1: [Routing; 16] = [
+    flexio::<GPIO_B1_00>(),
+    flexio::<GPIO_B1_01>(),
+    flexio::<GPIO_B1_02>(),
+    flexio::<GPIO_B1_03>(),
+    flexio::<GPIO_B1_04>(),
+    flexio::<GPIO_B1_05>(),
+    flexio::<GPIO_B1_06>(),
+    flexio::<GPIO_B1_07>(),
+    flexio::<GPIO_B1_08>(),
+    flexio::<GPIO_B1_09>(),
+    flexio::<GPIO_B1_10>(),
+    flexio::<GPIO_B1_11>(),
+    flexio::<GPIO_B1_12>(),
+    flexio::<GPIO_B1_13>(),
+    flexio::<GPIO_B1_14>(),
+    flexio::<GPIO_B1_15>(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{FLEXIO2_B1, LCD_24BIT};
+    use crate::{imxrt1060::gpio_b1, plan, PlanError};
+
+    #[test]
+    fn plans_are_valid() {
+        assert!(plan(&LCD_24BIT).is_ok());
+        assert!(plan(&FLEXIO2_B1).is_ok());
+    }
+
+    #[test]
+    fn plans_share_gpio_b1() {
+        extern crate std;
+        let mut both = std::vec::Vec::new();
+        both.extend_from_slice(&LCD_24BIT);
+        both.extend_from_slice(&FLEXIO2_B1);
+        assert_eq!(
+            plan(&both),
+            Err(PlanError::DuplicatePad {
+                pad: gpio_b1::GPIO_B1_00::ID,
+                earlier: 16,
+                later: 28,
+            })
+        );
+
+        // The upper FlexIO2 pins don't overlap the LCD data lines
+        both.drain(..28 + 12);
+        both.extend_from_slice(&LCD_24BIT);
+        assert!(plan(&both).is_ok());
+    }
+}
@@ -0,0 +1,61 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/qtimer.csv. Do not edit.
+//! QTIMER pin implementations
+
+use super::pads::{gpio_ad_b1::*, gpio_b0::*, gpio_b1::*};
+use crate::{consts::*, qtimer::Pin, Daisy};
+
+//
+// QTIMER1
+//
+qtimer!(module: U1, timer: U0, alt: 1, pad: GPIO_B0_00, daisy: None);
+qtimer!(module: U1, timer: U1, alt: 1, pad: GPIO_B0_01, daisy: None);
+qtimer!(module: U1, timer: U2, alt: 1, pad: GPIO_B0_02, daisy: None);
+qtimer!(module: U1, timer: U3, alt: 1, pad: GPIO_B1_08, daisy: None);
+
+//
+// QTIMER2
+//
+qtimer!(module: U2, timer: U0, alt: 1, pad: GPIO_B0_03, daisy: Some(DAISY_QTIMER2_TIMER0_GPIO_B0_03));
+qtimer!(module: U2, timer: U1, alt: 1, pad: GPIO_B0_04, daisy: Some(DAISY_QTIMER2_TIMER1_GPIO_B0_04));
+qtimer!(module: U2, timer: U2, alt: 1, pad: GPIO_B0_05, daisy: Some(DAISY_QTIMER2_TIMER2_GPIO_B0_05));
+qtimer!(module: U2, timer: U3, alt: 1, pad: GPIO_B1_09, daisy: Some(DAISY_QTIMER2_TIMER3_GPIO_B1_09));
+
+//
+// QTIMER3
+//
+qtimer!(module: U3, timer: U0, alt: 1, pad: GPIO_B0_06, daisy: Some(DAISY_QTIMER3_TIMER0_GPIO_B0_06));
+qtimer!(module: U3, timer: U1, alt: 1, pad: GPIO_B0_07, daisy: Some(DAISY_QTIMER3_TIMER1_GPIO_B0_07));
+qtimer!(module: U3, timer: U2, alt: 1, pad: GPIO_B0_08, daisy: Some(DAISY_QTIMER3_TIMER2_GPIO_B0_08));
+qtimer!(module: U3, timer: U3, alt: 1, pad: GPIO_B1_10, daisy: Some(DAISY_QTIMER3_TIMER3_GPIO_B1_10));
+qtimer!(module: U3, timer: U0, alt: 1, pad: GPIO_AD_B1_00, daisy: Some(DAISY_QTIMER3_TIMER0_GPIO_AD_B1_00));
+qtimer!(module: U3, timer: U1, alt: 1, pad: GPIO_AD_B1_01, daisy: Some(DAISY_QTIMER3_TIMER1_GPIO_AD_B1_01));
+qtimer!(module: U3, timer: U2, alt: 1, pad: GPIO_AD_B1_02, daisy: Some(DAISY_QTIMER3_TIMER2_GPIO_AD_B1_02));
+qtimer!(module: U3, timer: U3, alt: 1, pad: GPIO_AD_B1_03, daisy: Some(DAISY_QTIMER3_TIMER3_GPIO_AD_B1_03));
+
+//
+// QTIMER4
+//
+qtimer!(module: U4, timer: U0, alt: 1, pad: GPIO_B0_09, daisy: None);
+qtimer!(module: U4, timer: U1, alt: 1, pad: GPIO_B0_10, daisy: None);
+qtimer!(module: U4, timer: U2, alt: 1, pad: GPIO_B0_11, daisy: None);
+qtimer!(module: U4, timer: U3, alt: 1, pad: GPIO_B1_11, daisy: None);
+
+/// Auto-generated Daisy constants
+mod daisy {
+    #![allow(unused)]
+    use super::Daisy;
+
+    pub const DAISY_QTIMER2_TIMER0_GPIO_B0_03: Daisy = Daisy::new(0x401f856c, 1);
+    pub const DAISY_QTIMER2_TIMER1_GPIO_B0_04: Daisy = Daisy::new(0x401f8570, 1);
+    pub const DAISY_QTIMER2_TIMER2_GPIO_B0_05: Daisy = Daisy::new(0x401f8574, 1);
+    pub const DAISY_QTIMER2_TIMER3_GPIO_B1_09: Daisy = Daisy::new(0x401f8578, 1);
+    pub const DAISY_QTIMER3_TIMER0_GPIO_B0_06: Daisy = Daisy::new(0x401f857c, 0);
+    pub const DAISY_QTIMER3_TIMER0_GPIO_AD_B1_00: Daisy = Daisy::new(0x401f857c, 1);
+    pub const DAISY_QTIMER3_TIMER1_GPIO_B0_07: Daisy = Daisy::new(0x401f8580, 0);
+    pub const DAISY_QTIMER3_TIMER1_GPIO_AD_B1_01: Daisy = Daisy::new(0x401f8580, 1);
+    pub const DAISY_QTIMER3_TIMER2_GPIO_B0_08: Daisy = Daisy::new(0x401f8584, 0);
+    pub const DAISY_QTIMER3_TIMER2_GPIO_AD_B1_02: Daisy = Daisy::new(0x401f8584, 1);
+    pub const DAISY_QTIMER3_TIMER3_GPIO_B1_10: Daisy = Daisy::new(0x401f8588, 0);
+    pub const DAISY_QTIMER3_TIMER3_GPIO_AD_B1_03: Daisy = Daisy::new(0x401f8588, 1);
+}
+use daisy::*;
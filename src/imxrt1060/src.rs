@@ -0,0 +1,38 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/src.csv. Do not edit.
+//! SRC and NMI pin implementations
+
+use super::pads::{gpio_ad_b0::*, gpio_b0::*};
+use crate::{
+    consts::*,
+    src::{BootCfg, BootMode, Nmi, Pin, Strap},
+};
+
+/// The pad configuration reset value of every strap pad
+const RESET_PAD: u32 = 0x10B0;
+
+//
+// Boot mode; ALT0 after reset
+//
+src!(alt: 0, pad: GPIO_AD_B0_04, signal: BootMode<U0>, reset_mux: 0, reset_pad: RESET_PAD);
+src!(alt: 0, pad: GPIO_AD_B0_05, signal: BootMode<U1>, reset_mux: 0, reset_pad: RESET_PAD);
+
+//
+// Boot configuration; GPIO (ALT5) after reset
+//
+src!(alt: 6, pad: GPIO_B0_04, signal: BootCfg<U0>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_05, signal: BootCfg<U1>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_06, signal: BootCfg<U2>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_07, signal: BootCfg<U3>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_08, signal: BootCfg<U4>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_09, signal: BootCfg<U5>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_10, signal: BootCfg<U6>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_11, signal: BootCfg<U7>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_12, signal: BootCfg<U8>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_13, signal: BootCfg<U9>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_14, signal: BootCfg<U10>, reset_mux: 5, reset_pad: RESET_PAD);
+src!(alt: 6, pad: GPIO_B0_15, signal: BootCfg<U11>, reset_mux: 5, reset_pad: RESET_PAD);
+
+//
+// NMI
+//
+src!(alt: 7, pad: GPIO_AD_B0_12, signal: Nmi);
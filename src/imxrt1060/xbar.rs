@@ -0,0 +1,54 @@
+// Generated by imxrt-iomuxc-build from data/imxrt1060/xbar.csv. Do not edit.
+//! XBAR pin implementations
+
+use super::pads::{gpio_ad_b0::*, gpio_b1::*, gpio_emc::*};
+use crate::{
+    consts::*,
+    xbar::{In, InOut, Pin},
+    Daisy,
+};
+
+xbar!(index: U2, direction: In, alt: 3, pad: GPIO_EMC_00, daisy: Some(DAISY_XBAR1_IN02_GPIO_EMC_00));
+xbar!(index: U3, direction: In, alt: 3, pad: GPIO_EMC_01, daisy: Some(DAISY_XBAR1_IN03_GPIO_EMC_01));
+xbar!(index: U4, direction: InOut, alt: 3, pad: GPIO_EMC_02, daisy: Some(DAISY_XBAR1_IN04_GPIO_EMC_02));
+xbar!(index: U5, direction: InOut, alt: 3, pad: GPIO_EMC_03, daisy: Some(DAISY_XBAR1_IN05_GPIO_EMC_03));
+xbar!(index: U6, direction: InOut, alt: 3, pad: GPIO_EMC_04, daisy: Some(DAISY_XBAR1_IN06_GPIO_EMC_04));
+xbar!(index: U7, direction: InOut, alt: 3, pad: GPIO_EMC_05, daisy: Some(DAISY_XBAR1_IN07_GPIO_EMC_05));
+xbar!(index: U8, direction: InOut, alt: 3, pad: GPIO_EMC_06, daisy: Some(DAISY_XBAR1_IN08_GPIO_EMC_06));
+xbar!(index: U9, direction: InOut, alt: 3, pad: GPIO_EMC_07, daisy: Some(DAISY_XBAR1_IN09_GPIO_EMC_07));
+xbar!(index: U17, direction: InOut, alt: 3, pad: GPIO_EMC_08, daisy: Some(DAISY_XBAR1_IN17_GPIO_EMC_08));
+
+xbar!(index: U14, direction: InOut, alt: 1, pad: GPIO_AD_B0_00, daisy: Some(DAISY_XBAR1_IN14_GPIO_AD_B0_00));
+xbar!(index: U15, direction: InOut, alt: 1, pad: GPIO_AD_B0_01, daisy: Some(DAISY_XBAR1_IN15_GPIO_AD_B0_01));
+xbar!(index: U16, direction: InOut, alt: 1, pad: GPIO_AD_B0_02, daisy: Some(DAISY_XBAR1_IN16_GPIO_AD_B0_02));
+xbar!(index: U17, direction: InOut, alt: 1, pad: GPIO_AD_B0_03, daisy: Some(DAISY_XBAR1_IN17_GPIO_AD_B0_03));
+
+xbar!(index: U14, direction: InOut, alt: 1, pad: GPIO_B1_00, daisy: Some(DAISY_XBAR1_IN14_GPIO_B1_00));
+xbar!(index: U15, direction: InOut, alt: 1, pad: GPIO_B1_01, daisy: Some(DAISY_XBAR1_IN15_GPIO_B1_01));
+xbar!(index: U16, direction: InOut, alt: 1, pad: GPIO_B1_02, daisy: Some(DAISY_XBAR1_IN16_GPIO_B1_02));
+xbar!(index: U17, direction: InOut, alt: 1, pad: GPIO_B1_03, daisy: Some(DAISY_XBAR1_IN17_GPIO_B1_03));
+
+/// Auto-generated Daisy constants
+mod daisy {
+    #![allow(unused)]
+    use super::Daisy;
+
+    pub const DAISY_XBAR1_IN02_GPIO_EMC_00: Daisy = Daisy::new(0x401f860c, 0);
+    pub const DAISY_XBAR1_IN03_GPIO_EMC_01: Daisy = Daisy::new(0x401f8610, 0);
+    pub const DAISY_XBAR1_IN04_GPIO_EMC_02: Daisy = Daisy::new(0x401f8614, 0);
+    pub const DAISY_XBAR1_IN05_GPIO_EMC_03: Daisy = Daisy::new(0x401f8618, 0);
+    pub const DAISY_XBAR1_IN06_GPIO_EMC_04: Daisy = Daisy::new(0x401f861c, 0);
+    pub const DAISY_XBAR1_IN07_GPIO_EMC_05: Daisy = Daisy::new(0x401f8620, 0);
+    pub const DAISY_XBAR1_IN08_GPIO_EMC_06: Daisy = Daisy::new(0x401f8624, 0);
+    pub const DAISY_XBAR1_IN09_GPIO_EMC_07: Daisy = Daisy::new(0x401f8628, 0);
+    pub const DAISY_XBAR1_IN17_GPIO_EMC_08: Daisy = Daisy::new(0x401f862c, 0);
+    pub const DAISY_XBAR1_IN17_GPIO_AD_B0_03: Daisy = Daisy::new(0x401f862c, 1);
+    pub const DAISY_XBAR1_IN17_GPIO_B1_03: Daisy = Daisy::new(0x401f862c, 2);
+    pub const DAISY_XBAR1_IN14_GPIO_AD_B0_00: Daisy = Daisy::new(0x401f8644, 0);
+    pub const DAISY_XBAR1_IN14_GPIO_B1_00: Daisy = Daisy::new(0x401f8644, 1);
+    pub const DAISY_XBAR1_IN15_GPIO_AD_B0_01: Daisy = Daisy::new(0x401f8648, 0);
+    pub const DAISY_XBAR1_IN15_GPIO_B1_01: Daisy = Daisy::new(0x401f8648, 1);
+    pub const DAISY_XBAR1_IN16_GPIO_AD_B0_02: Daisy = Daisy::new(0x401f864c, 0);
+    pub const DAISY_XBAR1_IN16_GPIO_B1_02: Daisy = Daisy::new(0x401f864c, 1);
+}
+use daisy::*;
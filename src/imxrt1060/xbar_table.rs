@@ -0,0 +1,50 @@
+//! XBAR line and pin tables
+
+use super::pads::{gpio_ad_b0::*, gpio_b1::*, gpio_emc::*};
+use crate::xbar::{Line, PinTable, TableEntry};
+
+impl PinTable for super::ErasedPads {
+    fn pins(&self) -> &[TableEntry] {
+        &PINS
+    }
+
+    fn line(&self, line: u8) -> Option<Line> {
+        line_kind(line)
+    }
+}
+
+/// The XBARA1 inputs
+///
+/// Inputs 0 and 1 are the logic low and logic high levels. Inputs 2 through 25 are
+/// the IOMUX lines `XBAR1_IN02` through `XBAR1_IN25`, including the bidirectional
+/// lines `XBAR1_INOUT04` through `XBAR1_INOUT19`. `XBAR1_INOUT10` through
+/// `XBAR1_INOUT13` each have one pad, so they don't have a select input register.
+/// The remaining inputs are internal peripheral signals.
+const fn line_kind(line: u8) -> Option<Line> {
+    match line {
+        2..=9 | 14..=25 => Some(Line::Selectable),
+        10..=13 => Some(Line::Pad),
+        0..=1 | 26..=87 => Some(Line::Hardwired),
+        _ => None,
+    }
+}
+
+const PINS: [TableEntry; 17] = [
+    TableEntry::new::<GPIO_EMC_00>(),
+    TableEntry::new::<GPIO_EMC_01>(),
+    TableEntry::new::<GPIO_EMC_02>(),
+    TableEntry::new::<GPIO_EMC_03>(),
+    TableEntry::new::<GPIO_EMC_04>(),
+    TableEntry::new::<GPIO_EMC_05>(),
+    TableEntry::new::<GPIO_EMC_06>(),
+    TableEntry::new::<GPIO_EMC_07>(),
+    TableEntry::new::<GPIO_EMC_08>(),
+    TableEntry::new::<GPIO_AD_B0_00>(),
+    TableEntry::new::<GPIO_AD_B0_01>(),
+    TableEntry::new::<GPIO_AD_B0_02>(),
+    TableEntry::new::<GPIO_AD_B0_03>(),
+    TableEntry::new::<GPIO_B1_00>(),
+    TableEntry::new::<GPIO_B1_01>(),
+    TableEntry::new::<GPIO_B1_02>(),
+    TableEntry::new::<GPIO_B1_03>(),
+];
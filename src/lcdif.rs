@@ -0,0 +1,363 @@
+//! Enhanced LCD interface (eLCDIF) pad configuration
+//!
+//! An eLCDIF pin is one of the panel's control signals, or one of its data lines.
+//! Use [`prepare()`] to prepare any single eLCDIF pin.
+//!
+//! # Bus presets
+//!
+//! Panels use 8, 16, 18, or 24-bit data buses, and boards only wire the lower
+//! data lines. [`Bus8`], [`Bus16`], [`Bus18`], and [`Bus24`] bundle the control
+//! pins with exactly that many data pins, and prepare all of the pins. Supply the
+//! data pins as a tuple, starting with `DATA00`. The bus [`WIDTH`](Bus::WIDTH)
+//! tells your eLCDIF driver the bus width.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{imxrt1060::gpio_b0::*, lcdif};
+//!
+//! let bus = lcdif::Bus8::new(
+//!     unsafe { GPIO_B0_00::new() },
+//!     unsafe { GPIO_B0_01::new() },
+//!     unsafe { GPIO_B0_02::new() },
+//!     unsafe { GPIO_B0_03::new() },
+//!     unsafe {
+//!         (
+//!             GPIO_B0_04::new(),
+//!             GPIO_B0_05::new(),
+//!             GPIO_B0_06::new(),
+//!             GPIO_B0_07::new(),
+//!             GPIO_B0_08::new(),
+//!             GPIO_B0_09::new(),
+//!             GPIO_B0_10::new(),
+//!             GPIO_B0_11::new(),
+//!         )
+//!     },
+//! );
+//! assert_eq!(bus.width(), 8);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! Each data pin must be in its place. `GPIO_B0_05` is `DATA01`, so it cannot
+//! be `DATA00`:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{imxrt1060::gpio_b0::*, lcdif};
+//!
+//! let bus = lcdif::Bus8::new(
+//!     unsafe { GPIO_B0_00::new() },
+//!     unsafe { GPIO_B0_01::new() },
+//!     unsafe { GPIO_B0_02::new() },
+//!     unsafe { GPIO_B0_03::new() },
+//!     unsafe {
+//!         (
+//!             GPIO_B0_05::new(),
+//!             GPIO_B0_04::new(),
+//!             GPIO_B0_06::new(),
+//!             GPIO_B0_07::new(),
+//!             GPIO_B0_08::new(),
+//!             GPIO_B0_09::new(),
+//!             GPIO_B0_10::new(),
+//!             GPIO_B0_11::new(),
+//!         )
+//!     },
+//! );
+//! ```
+
+use crate::consts::*;
+use core::marker::PhantomData;
+
+/// An eLCDIF pin signal
+pub trait Signal: private::Sealed + private::Code {}
+
+/// A tag that indicates the pixel clock
+pub enum Clk {}
+/// A tag that indicates the data enable
+pub enum Enable {}
+/// A tag that indicates the horizontal sync
+pub enum Hsync {}
+/// A tag that indicates the vertical sync
+pub enum Vsync {}
+/// A tag that indicates a data line; `Data<U3>` for `LCD_DATA03`
+pub struct Data<N> {
+    _n: PhantomData<N>,
+}
+
+impl Signal for Clk {}
+impl Signal for Enable {}
+impl Signal for Hsync {}
+impl Signal for Vsync {}
+impl<N: Unsigned> Signal for Data<N> {}
+
+/// An eLCDIF pin
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the eLCDIF pin
+    const ALT: u32;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+    const SION: super::SionRequirement = super::SionRequirement::DontCare;
+    /// The pin's signal
+    type Signal: Signal;
+}
+
+/// Prepare an eLCDIF pin
+///
+/// If you do not call `prepare()` on your eLCDIF pin, it might not work as an
+/// eLCDIF pin.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    trace!("lcdif::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, None);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!("lcdif::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, None)
+}
+
+prepared_pin!("eLCDIF");
+
+/// Compute the register writes that prepare an eLCDIF pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+///
+/// The result records the eLCDIF as its [`Peripheral`](crate::Peripheral).
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, None)
+        .with_peripheral(super::Peripheral::Lcdif)
+}
+
+/// The data pins of a `Width`-bit bus
+///
+/// Implemented for tuples of data pins, where the first pin is `DATA00`, the
+/// second pin is `DATA01`, and so on.
+pub trait DataPins<Width>: private::Sealed {
+    /// Prepare all of the data pins
+    fn prepare(&mut self);
+}
+
+macro_rules! data_pins {
+    ($width:ty: $($pin:ident $index:ty => $n:tt),+) => {
+        impl<$($pin),+> private::Sealed for ($($pin,)+) {}
+        impl<$($pin),+> DataPins<$width> for ($($pin,)+)
+        where
+            $($pin: Pin<Signal = Data<$index>>),+
+        {
+            fn prepare(&mut self) {
+                $(prepare(&mut self.$n);)+
+            }
+        }
+    };
+}
+
+data_pins!(U8:
+    D0 U0 => 0, D1 U1 => 1, D2 U2 => 2, D3 U3 => 3, D4 U4 => 4, D5 U5 => 5, D6 U6 => 6, D7 U7 => 7
+);
+data_pins!(U16:
+    D0 U0 => 0, D1 U1 => 1, D2 U2 => 2, D3 U3 => 3, D4 U4 => 4, D5 U5 => 5, D6 U6 => 6, D7 U7 => 7,
+    D8 U8 => 8, D9 U9 => 9, D10 U10 => 10, D11 U11 => 11, D12 U12 => 12, D13 U13 => 13,
+    D14 U14 => 14, D15 U15 => 15
+);
+data_pins!(U18:
+    D0 U0 => 0, D1 U1 => 1, D2 U2 => 2, D3 U3 => 3, D4 U4 => 4, D5 U5 => 5, D6 U6 => 6, D7 U7 => 7,
+    D8 U8 => 8, D9 U9 => 9, D10 U10 => 10, D11 U11 => 11, D12 U12 => 12, D13 U13 => 13,
+    D14 U14 => 14, D15 U15 => 15, D16 U16 => 16, D17 U17 => 17
+);
+data_pins!(U24:
+    D0 U0 => 0, D1 U1 => 1, D2 U2 => 2, D3 U3 => 3, D4 U4 => 4, D5 U5 => 5, D6 U6 => 6, D7 U7 => 7,
+    D8 U8 => 8, D9 U9 => 9, D10 U10 => 10, D11 U11 => 11, D12 U12 => 12, D13 U13 => 13,
+    D14 U14 => 14, D15 U15 => 15, D16 U16 => 16, D17 U17 => 17, D18 U18 => 18, D19 U19 => 19,
+    D20 U20 => 20, D21 U21 => 21, D22 U22 => 22, D23 U23 => 23
+);
+
+/// eLCDIF control and data pins for a `Width`-bit bus
+///
+/// Use one of the presets, like [`Bus16`], to name a bus.
+#[derive(Debug)]
+pub struct Bus<Width, CLK, EN, HS, VS, D> {
+    /// The pixel clock pin
+    pub clk: CLK,
+    /// The data enable pin
+    pub enable: EN,
+    /// The horizontal sync pin
+    pub hsync: HS,
+    /// The vertical sync pin
+    pub vsync: VS,
+    /// The data pins, starting with `DATA00`
+    pub data: D,
+    _width: PhantomData<Width>,
+}
+
+/// An 8-bit eLCDIF bus
+pub type Bus8<CLK, EN, HS, VS, D> = Bus<U8, CLK, EN, HS, VS, D>;
+/// A 16-bit eLCDIF bus
+pub type Bus16<CLK, EN, HS, VS, D> = Bus<U16, CLK, EN, HS, VS, D>;
+/// An 18-bit eLCDIF bus
+pub type Bus18<CLK, EN, HS, VS, D> = Bus<U18, CLK, EN, HS, VS, D>;
+/// A 24-bit eLCDIF bus
+pub type Bus24<CLK, EN, HS, VS, D> = Bus<U24, CLK, EN, HS, VS, D>;
+
+impl<Width, CLK, EN, HS, VS, D> Bus<Width, CLK, EN, HS, VS, D>
+where
+    Width: Unsigned,
+    CLK: Pin<Signal = Clk>,
+    EN: Pin<Signal = Enable>,
+    HS: Pin<Signal = Hsync>,
+    VS: Pin<Signal = Vsync>,
+    D: DataPins<Width>,
+{
+    /// The bus width, in bits
+    pub const WIDTH: u32 = Width::U32;
+
+    /// Bundle and prepare the eLCDIF pins
+    pub fn new(mut clk: CLK, mut enable: EN, mut hsync: HS, mut vsync: VS, mut data: D) -> Self {
+        prepare(&mut clk);
+        prepare(&mut enable);
+        prepare(&mut hsync);
+        prepare(&mut vsync);
+        data.prepare();
+        Bus {
+            clk,
+            enable,
+            hsync,
+            vsync,
+            data,
+            _width: PhantomData,
+        }
+    }
+
+    /// Returns the bus width, in bits
+    pub const fn width(&self) -> u32 {
+        Self::WIDTH
+    }
+
+    /// Release the pins
+    pub fn release(self) -> (CLK, EN, HS, VS, D) {
+        (self.clk, self.enable, self.hsync, self.vsync, self.data)
+    }
+}
+
+mod private {
+    use crate::{consts::Unsigned, function::LcdifSignal};
+
+    pub trait Sealed {}
+
+    /// A signal that's known at run time
+    pub trait Code {
+        const SIGNAL: LcdifSignal;
+    }
+    impl Sealed for super::Clk {}
+    impl Code for super::Clk {
+        const SIGNAL: LcdifSignal = LcdifSignal::Clk;
+    }
+    impl Sealed for super::Enable {}
+    impl Code for super::Enable {
+        const SIGNAL: LcdifSignal = LcdifSignal::Enable;
+    }
+    impl Sealed for super::Hsync {}
+    impl Code for super::Hsync {
+        const SIGNAL: LcdifSignal = LcdifSignal::Hsync;
+    }
+    impl Sealed for super::Vsync {}
+    impl Code for super::Vsync {
+        const SIGNAL: LcdifSignal = LcdifSignal::Vsync;
+    }
+    impl<N> Sealed for super::Data<N> {}
+    impl<N: Unsigned> Code for super::Data<N> {
+        const SIGNAL: LcdifSignal = LcdifSignal::Data(N::U8);
+    }
+}
+
+/// Returns the [`Function`](crate::Function) of an eLCDIF pin
+pub const fn function<P: Pin>() -> super::Function {
+    super::Function::Lcdif(<P::Signal as private::Code>::SIGNAL)
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! lcdif {
+    (alt: $alt:expr, pad: $pad:ty, signal: $signal:ty) => {
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            type Signal = $signal;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bus18, Clk, Data, Enable, Hsync, Pin, Signal, Vsync};
+    use crate::{consts::*, Iomuxc};
+    use core::marker::PhantomData;
+
+    struct FakePad<S> {
+        mux: u32,
+        pad: u32,
+        _signal: PhantomData<S>,
+    }
+
+    fn pad<S>() -> FakePad<S> {
+        FakePad {
+            mux: 1 << 4 | 5,
+            pad: 0,
+            _signal: PhantomData,
+        }
+    }
+
+    impl<S> crate::private::Sealed for FakePad<S> {}
+
+    unsafe impl<S> Iomuxc for FakePad<S> {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    impl<S: Signal> Pin for FakePad<S> {
+        const ALT: u32 = 0;
+        type Signal = S;
+    }
+
+    #[test]
+    fn bus18_prepares_all_pins() {
+        let bus = Bus18::new(
+            pad::<Clk>(),
+            pad::<Enable>(),
+            pad::<Hsync>(),
+            pad::<Vsync>(),
+            (
+                pad::<Data<U0>>(),
+                pad::<Data<U1>>(),
+                pad::<Data<U2>>(),
+                pad::<Data<U3>>(),
+                pad::<Data<U4>>(),
+                pad::<Data<U5>>(),
+                pad::<Data<U6>>(),
+                pad::<Data<U7>>(),
+                pad::<Data<U8>>(),
+                pad::<Data<U9>>(),
+                pad::<Data<U10>>(),
+                pad::<Data<U11>>(),
+                pad::<Data<U12>>(),
+                pad::<Data<U13>>(),
+                pad::<Data<U14>>(),
+                pad::<Data<U15>>(),
+                pad::<Data<U16>>(),
+                pad::<Data<U17>>(),
+            ),
+        );
+
+        assert_eq!(bus.width(), 18);
+        // SION is untouched
+        let muxes = [bus.clk.mux, bus.enable.mux, bus.hsync.mux, bus.vsync.mux];
+        assert!(muxes.iter().all(|&mux| mux == 1 << 4));
+        assert_eq!((bus.data.0.mux, bus.data.17.mux), (1 << 4, 1 << 4));
+    }
+}
@@ -13,6 +13,8 @@
 //!
 //! Processor pads may be enabled using feature flags. For example, the `imxrt1060` feature
 //! flag exposes an `imxrt1060` module that defines all i.MX RT 1060 processor pads.
+//! Each processor module has an `examples` module, which prepares real pads for
+//! each peripheral.
 //!
 //! # Design Guidance
 //!
@@ -27,8 +29,9 @@
 //! The API will expect that the user is responsible for manually configuring the type-erased pad.
 //!
 //! ```no_run
-//! use imxrt_iomuxc::{ErasedPad, lpuart::{Pin, Tx, Rx}};
-//! # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_13, GPIO_AD_B0_12};
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{ErasedPad, PadId, lpuart::{Pin, Tx, Rx}};
+//! # use imxrt_iomuxc::imxrt1060::{gpio_ad_b0::{GPIO_AD_B0_13, GPIO_AD_B0_12}, Pads};
 //! # pub struct UART;
 //!
 //! impl UART {
@@ -55,55 +58,232 @@
 //! let uart1 = UART::new(gpio_ad_b0_12, gpio_ad_b0_13);
 //!
 //! // Optional: create a UART peripheral from type-erased pads...
-//! let gpio_ad_b0_13 = unsafe { GPIO_AD_B0_13::new() };
-//! let gpio_ad_b0_12 = unsafe { GPIO_AD_B0_12::new() };
+//! let mut pads = unsafe { Pads::new() }.erase();
 //!
-//! let mut rx_pad = gpio_ad_b0_13.erase();
-//! let mut tx_pad = gpio_ad_b0_12.erase();
-//!
-//! // User is responsible for configuring the pad,
-//! // since we can't call `prepare()` on the pad...
-//! unsafe {
-//!     // Daisy registers and values aren't attached
-//!     // to erased pads, so we have to reference this
-//!     // manually.
-//!     <GPIO_AD_B0_13 as imxrt_iomuxc::lpuart::Pin>::DAISY.map(|daisy| daisy.write());
-//!     <GPIO_AD_B0_12 as imxrt_iomuxc::lpuart::Pin>::DAISY.map(|daisy| daisy.write());
-//! }
-//! imxrt_iomuxc::alternate(&mut tx_pad, 2);
-//! imxrt_iomuxc::alternate(&mut rx_pad, 2);
-//! imxrt_iomuxc::clear_sion(&mut tx_pad);
-//! imxrt_iomuxc::clear_sion(&mut rx_pad);
+//! // User is responsible for configuring the pads, since
+//! // we can't call `prepare()` on the pads. `prepare_erased()`
+//! // checks that the pads are UART1 pins before preparing them.
+//! imxrt_iomuxc::lpuart::prepare_erased(
+//!     &mut pads,
+//!     1,
+//!     PadId::of::<GPIO_AD_B0_12>(),
+//!     PadId::of::<GPIO_AD_B0_13>(),
+//! ).unwrap();
 //! // Pads are configured for UART settings
+//! let [.., tx_pad, rx_pad, _, _] = pads.gpio_ad_b0;
 //! let uart1 = UART::new_unchecked(tx_pad, rx_pad);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
 //! ```
+//!
+//! # Module numbers
+//!
+//! A pin names its peripheral module with a type number, like `U2` for `LPUART2`.
+//! Module numbers are 1-based, matching the reference manual: `U1` is the first
+//! module, and there is no `U0` module. The numbers within a module are 0-based,
+//! also matching the reference manual: `U0` is `PWM1_SM0`, `TIMER0`, `FLEXIO1_FLEXIO00`,
+//! `GPIO1_IO00`, or ADC input 0.
+//!
+//! Each peripheral module with numbered modules has a `module` module of type
+//! aliases, like [`lpuart::module::Lpuart2`]. Use the aliases in bounds so that
+//! they read like the reference manual:
+//!
+//! ```
+//! use imxrt_iomuxc::lpuart;
+//!
+//! fn uart2_new<T>(tx: T)
+//! where
+//!     T: lpuart::Pin<Direction = lpuart::Tx, Module = lpuart::module::Lpuart2>,
+//! {
+//!     // Configure LPUART2...
+//! }
+//! ```
+//!
+//! A chip may not have every module that has an alias.
+//!
+//! # Write Ordering
+//!
+//! A peripheral's `prepare()` writes up to three registers, always in this order:
+//!
+//! 1. the pad configuration register, if the `prepare()` configures the pad,
+//! 2. the daisy register, if the pin has one,
+//! 3. the mux register, which selects the alternate and applies the SION requirement.
+//!
+//! Since the mux register is last, the pad connects to the peripheral only after its
+//! pad configuration and input selection are in place. This prevents glitches on
+//! the pin while it's prepared. [`apply_routing()`] writes in the same order. If
+//! you apply [`PreparedWrites`] yourself, use the same order.
+//!
+//! # Idempotent prepare
+//!
+//! Each peripheral module also has a `prepare_idempotent()`. It reads the pad
+//! configuration, daisy, and mux registers, and writes only the registers that
+//! don't already hold the pin's values, in the same order as `prepare()`. It
+//! returns `true` if it wrote a register. After a warm restart, when most pads
+//! are already prepared, `prepare_idempotent()` skips the writes and their trace
+//! messages, so a mux register never changes while it already selects the pin.
+//!
+//! # Panics
+//!
+//! Release builds of the run-time APIs do not panic, so bootloaders and other
+//! builds without a useful panic handler may use them. Lookups return an `Option`
+//! or a `Result` instead of indexing. Debug builds keep their `debug_assert!`s,
+//! which catch invalid pad configurations.
+//!
+//! The `panic_free` example enforces the guarantee. In the `panic-free` profile,
+//! the example's panic handler calls an undefined function, so the example fails
+//! to link if any run-time API it calls can still panic:
+//!
+//! ```text
+//! cargo build --profile panic-free --example panic_free --features panic-free-test
+//! ```
+//!
+//! The `const fn`s that build the chip tables, like `Daisy::new()`, may panic.
+//! Those panics happen while compiling, not at run time.
+//!
+//! # Code size
+//!
+//! Error messages pull in `core`'s formatting code, which costs flash. Enable the
+//! `min-size` feature to remove the messages: each error's `Display` output is its
+//! type name, like `ConfigError`, and `Pad`'s `Debug` output is `Pad`. The errors
+//! keep their variants, so you can still `match` on them. The `size` example
+//! measures the difference.
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[macro_use]
+mod trace;
+
+/// Defines a peripheral module's `PreparedPin` token and `prepare_owned()`
+///
+/// The module must define a `Pin` trait and a `prepare()` function. `$name`
+/// names the pin in the documentation, like "UART".
+macro_rules! prepared_pin {
+    ($name:literal) => {
+        #[doc = concat!("A prepared ", $name, " pin")]
+        ///
+        /// Returned from [`prepare_owned()`]. A driver can require a `PreparedPin<P>`
+        /// instead of a bare pin, so that forgetting to prepare the pin is a compile
+        /// error. Bound the driver on `P`, the pin type, to constrain the pin. Use
+        /// [`release()`](PreparedPin::release) to get the pin back.
+        #[derive(Debug)]
+        pub struct PreparedPin<P> {
+            pin: P,
+        }
+
+        impl<P: Pin> PreparedPin<P> {
+            /// Release the pin
+            pub fn release(self) -> P {
+                self.pin
+            }
+        }
+
+        #[doc = concat!("Prepare the ", $name, " pin, and return a token that proves it's prepared")]
+        ///
+        /// The call prepares the pin as [`prepare()`] does, then moves the pin into
+        /// a [`PreparedPin`].
+        pub fn prepare_owned<P: Pin>(mut pin: P) -> PreparedPin<P> {
+            prepare(&mut pin);
+            PreparedPin { pin }
+        }
+
+        /// Use a pad that's already configured, without touching its registers
+        impl<P: Pin> From<crate::ConfiguredPad<P>> for PreparedPin<P> {
+            fn from(pad: crate::ConfiguredPad<P>) -> Self {
+                PreparedPin { pin: pad.release() }
+            }
+        }
+    };
+}
+
+/// Defines a peripheral module's `module` type aliases
+///
+/// `$name` names the peripheral in the documentation, like "LPUART". Each
+/// `$number => $alias = $type` defines the alias of one module, like
+/// `2 => Lpuart2 = U2`.
+macro_rules! module_aliases {
+    ($name:literal: $($number:literal => $alias:ident = $type:ident),+ $(,)?) => {
+        #[doc = concat!("Type numbers for each ", $name, " module")]
+        ///
+        /// Use the aliases in bounds instead of the type numbers. See
+        /// [Module numbers](crate#module-numbers) for the numbering. A chip may not
+        /// have every module.
+        pub mod module {
+            $(
+                #[doc = concat!("Type number for ", $name, stringify!($number))]
+                pub type $alias = crate::consts::$type;
+            )+
+        }
+    };
+}
+
 #[macro_use]
 pub mod adc;
 mod config;
 #[macro_use]
+pub mod csi;
+pub mod enc;
+mod encoding;
+mod error;
+#[macro_use]
+pub mod flexio;
+#[macro_use]
 pub mod flexpwm;
 #[macro_use]
+pub mod flexspi;
+pub mod function;
+#[macro_use]
+pub mod lcdif;
+#[macro_use]
 pub mod lpi2c;
 #[macro_use]
 pub mod lpspi;
 #[macro_use]
 pub mod lpuart;
 #[macro_use]
+pub mod mqs;
+pub mod pad;
+mod pinout;
+mod prepared;
+#[macro_use]
+pub mod qtimer;
+mod remainder;
+mod routing;
+#[macro_use]
 pub mod sai;
+#[macro_use]
+pub mod src;
+#[cfg(feature = "debug-pad-tracking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-pad-tracking")))]
+pub mod tracking;
+#[macro_use]
+pub mod xbar;
 
 use core::ptr;
 
 pub use config::{
-    configure, Config, DriveStrength, Hysteresis, OpenDrain, PullKeeper, SlewRate, Speed,
+    configure, modify_field, modify_fields, Config, ConfigError, DriveStrength, Field, Hysteresis,
+    InvalidFieldError, OpenDrain, PullKeeper, SlewRate, Speed,
 };
 
 #[allow(deprecated)]
 pub use config::{PullKeep, PullKeepSelect, PullUpDown};
 
+pub use encoding::EncodingError;
+pub use error::Error;
+pub use function::Function;
+#[doc(hidden)]
+pub use pinout::__pinout_plan;
+pub use prepared::{detect_daisy_conflicts, DaisyConflict, PreparedWrites};
+pub use remainder::Remainder;
+#[doc(hidden)]
+pub use remainder::{__pad_type, __take_pad};
+pub use routing::{
+    apply_routing, plan, validate, Mismatch, PadLookup, Plan, PlanError, Reservation, Routing,
+    RoutingError,
+};
+
 /// Re-export of top-level components, without the chip-specific modules.
 ///
 /// `prelude` is to help HAL implementors re-export the `imxrt-iomuxc` APIs
@@ -119,17 +299,34 @@ pub use config::{PullKeep, PullKeepSelect, PullUpDown};
 ///     pub use imxrt_iomuxc::imxrt1060::*;
 /// }
 /// ```
+///
+/// The prelude does not re-export deprecated items. If your HAL still exposes the
+/// deprecated pull / keeper types, re-export [`prelude::legacy`](crate::prelude::legacy)
+/// instead.
 pub mod prelude {
     pub use crate::config::{
-        configure, Config, DriveStrength, Hysteresis, OpenDrain, PullKeeper, SlewRate, Speed,
+        configure, modify_field, modify_fields, Config, ConfigError, DriveStrength, Field,
+        Hysteresis, InvalidFieldError, OpenDrain, PullKeeper, SlewRate, Speed,
     };
 
-    #[allow(deprecated)]
-    pub use crate::config::{PullKeep, PullKeepSelect, PullUpDown};
-
     pub use crate::{
-        consts, flexpwm, gpio, lpi2c, lpspi, lpuart, Daisy, ErasedPad, Pad, WrongPadError,
+        apply_routing, consts, detect_daisy_conflicts, flexpwm, gpio, init_table, lpi2c, lpspi,
+        lpuart, pinout, plan, take, validate, ConfiguredPad, Daisy, DaisyConflict, EncodingError,
+        ErasedPad, Error, Mismatch, Pad, PadGroup, PadId, PadLookup, PadRef, ParkStrategy,
+        Peripheral, Plan, PlanError, PreparedWrites, Remainder, Reservation, Routing, RoutingError,
+        SionRequirement, WrongPadError,
     };
+
+    /// The prelude, along with the deprecated pull / keeper types
+    ///
+    /// Use `legacy` in a HAL that still exposes `PullKeep`, `PullKeepSelect`, and
+    /// `PullUpDown`.
+    pub mod legacy {
+        pub use super::*;
+
+        #[allow(deprecated)]
+        pub use crate::config::{PullKeep, PullKeepSelect, PullUpDown};
+    }
 }
 
 /// Type-level constants and traits
@@ -169,6 +366,26 @@ pub unsafe trait Base {
     fn pad_base() -> *mut u32;
 }
 
+/// A pad group base with addresses that are known at compile time
+///
+/// `ConstBase` lets us compute pad register addresses in `const` contexts.
+/// All processor-specific bases implement `ConstBase`.
+///
+/// This trait is for developers who are preparing processor-specific crates that implement
+/// the `imxrt-iomuxc` traits. **Do not** implement this trait if you are an end user.
+///
+/// # Safety
+///
+/// You must ensure that the two addresses are correct for your processor, and that they
+/// match the pointers returned by the [`Base`] implementation.
+#[doc(hidden)] // Private trait that needs to be public
+pub unsafe trait ConstBase: Base {
+    /// Address of the starting multiplexer register
+    const MUX_BASE: usize;
+    /// Address of the starting pad configuration register
+    const PAD_BASE: usize;
+}
+
 /// Define an IOMUXC base
 ///
 /// `base_name` is the name of the IOMUXC register base. For something like
@@ -195,6 +412,154 @@ macro_rules! define_base {
                 $pad_base as *mut u32
             }
         }
+
+        unsafe impl crate::ConstBase for $base_name {
+            const MUX_BASE: usize = $mux_base;
+            const PAD_BASE: usize = $pad_base;
+        }
+    };
+}
+
+/// Define a processor's `examples` module
+///
+/// `chip` is the name of the processor module, like `imxrt1060`. Each peripheral
+/// names one set of that processor's pads, as `group::PAD`, and the module numbers
+/// that the pads connect to. The macro writes a doctest for each peripheral. The
+/// doctest requires the peripheral's trait bounds on each pad, so it stops
+/// compiling if a pad no longer implements the trait. `flexpwm` and `adc` are
+/// optional, since not every processor module tags those pads.
+#[allow(unused)] // May be used in processor-specific modules
+macro_rules! chip_examples {
+    (
+        chip: $chip:ident,
+        lpuart: { module: $uart:ident, tx: $txg:ident::$tx:ident, rx: $rxg:ident::$rx:ident },
+        lpi2c: { module: $i2c:ident, scl: $sclg:ident::$scl:ident, sda: $sdag:ident::$sda:ident },
+        lpspi: {
+            module: $spi:ident,
+            sck: $sckg:ident::$sck:ident,
+            sdo: $sdog:ident::$sdo:ident,
+            sdi: $sdig:ident::$sdi:ident,
+            pcs0: $pcsg:ident::$pcs:ident
+        }
+        $(, flexpwm: {
+            module: $pwm:ident,
+            submodule: $sm:ident,
+            output: $out:ident,
+            pin: $pwmg:ident::$pwmp:ident
+        })?
+        $(, adc: { module: $adc:ident, input: $input:literal, pin: $adcg:ident::$adcp:ident })?
+        $(,)?
+    ) => {
+        /// Pads that implement each peripheral's traits
+        ///
+        /// Each section prepares one real set of pads for a peripheral. If you're
+        /// not sure that a pad implements a peripheral's trait, start from these
+        /// pads, then check the trait's implementors for the other choices.
+        ///
+        /// # LPUART
+        ///
+        #[doc = concat!("`", stringify!($tx), "` and `", stringify!($rx), "` are the TX and RX pins of LPUART module `", stringify!($uart), "`.")]
+        ///
+        /// ```no_run
+        #[doc = concat!("use imxrt_iomuxc::{consts::", stringify!($uart), ", lpuart};")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($txg), "::", stringify!($tx), ";")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($rxg), "::", stringify!($rx), ";")]
+        ///
+        /// fn uart<T, R>(tx: &mut T, rx: &mut R)
+        /// where
+        #[doc = concat!("    T: lpuart::Pin<Direction = lpuart::Tx, Module = ", stringify!($uart), ">,")]
+        #[doc = concat!("    R: lpuart::Pin<Direction = lpuart::Rx, Module = ", stringify!($uart), ">,")]
+        /// {
+        ///     lpuart::prepare(tx);
+        ///     lpuart::prepare(rx);
+        /// }
+        ///
+        #[doc = concat!("let mut tx = unsafe { ", stringify!($tx), "::new() };")]
+        #[doc = concat!("let mut rx = unsafe { ", stringify!($rx), "::new() };")]
+        /// uart(&mut tx, &mut rx);
+        /// ```
+        ///
+        /// # LPI2C
+        ///
+        #[doc = concat!("`", stringify!($scl), "` and `", stringify!($sda), "` are the SCL and SDA pins of LPI2C module `", stringify!($i2c), "`.")]
+        ///
+        /// ```no_run
+        #[doc = concat!("use imxrt_iomuxc::{consts::", stringify!($i2c), ", lpi2c};")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($sclg), "::", stringify!($scl), ";")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($sdag), "::", stringify!($sda), ";")]
+        ///
+        /// fn i2c<C, D>(scl: &mut C, sda: &mut D)
+        /// where
+        #[doc = concat!("    C: lpi2c::Pin<Signal = lpi2c::Scl, Module = ", stringify!($i2c), ">,")]
+        #[doc = concat!("    D: lpi2c::Pin<Signal = lpi2c::Sda, Module = ", stringify!($i2c), ">,")]
+        /// {
+        ///     lpi2c::prepare(scl);
+        ///     lpi2c::prepare(sda);
+        /// }
+        ///
+        #[doc = concat!("let mut scl = unsafe { ", stringify!($scl), "::new() };")]
+        #[doc = concat!("let mut sda = unsafe { ", stringify!($sda), "::new() };")]
+        /// i2c(&mut scl, &mut sda);
+        /// ```
+        ///
+        /// # LPSPI
+        ///
+        #[doc = concat!("`", stringify!($sck), "`, `", stringify!($sdo), "`, `", stringify!($sdi), "`, and `", stringify!($pcs), "` are the SCK, SDO, SDI, and PCS0 pins of LPSPI module `", stringify!($spi), "`.")]
+        ///
+        /// ```no_run
+        #[doc = concat!("use imxrt_iomuxc::{consts::", stringify!($spi), ", lpspi};")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($sckg), "::", stringify!($sck), ";")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($sdog), "::", stringify!($sdo), ";")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($sdig), "::", stringify!($sdi), ";")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($pcsg), "::", stringify!($pcs), ";")]
+        ///
+        /// fn spi<P, Signal>(pin: &mut P)
+        /// where
+        #[doc = concat!("    P: lpspi::Pin<Signal = Signal, Module = ", stringify!($spi), ">,")]
+        /// {
+        ///     lpspi::prepare(pin);
+        /// }
+        ///
+        #[doc = concat!("spi::<_, lpspi::Sck>(&mut unsafe { ", stringify!($sck), "::new() });")]
+        #[doc = concat!("spi::<_, lpspi::Sdo>(&mut unsafe { ", stringify!($sdo), "::new() });")]
+        #[doc = concat!("spi::<_, lpspi::Sdi>(&mut unsafe { ", stringify!($sdi), "::new() });")]
+        #[doc = concat!("spi::<_, lpspi::Pcs0>(&mut unsafe { ", stringify!($pcs), "::new() });")]
+        /// ```
+        $(
+        ///
+        /// # FlexPWM
+        ///
+        #[doc = concat!("`", stringify!($pwmp), "` is output `", stringify!($out), "` of submodule `", stringify!($sm), "`, in FlexPWM module `", stringify!($pwm), "`.")]
+        ///
+        /// ```no_run
+        #[doc = concat!("use imxrt_iomuxc::{consts::{", stringify!($pwm), ", ", stringify!($sm), "}, flexpwm};")]
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($pwmg), "::", stringify!($pwmp), ";")]
+        ///
+        /// fn pwm<P>(pin: &mut P)
+        /// where
+        #[doc = concat!("    P: flexpwm::Pin<Output = flexpwm::", stringify!($out), ", Module = ", stringify!($pwm), ", Submodule = ", stringify!($sm), ">,")]
+        /// {
+        ///     flexpwm::prepare(pin);
+        /// }
+        ///
+        #[doc = concat!("pwm(&mut unsafe { ", stringify!($pwmp), "::new() });")]
+        /// ```
+        )?
+        $(
+        ///
+        /// # ADC
+        ///
+        #[doc = concat!("`", stringify!($adcp), "` is input ", stringify!($input), " of `", stringify!($adc), "`.")]
+        ///
+        /// ```no_run
+        /// use imxrt_iomuxc::adc;
+        #[doc = concat!("use imxrt_iomuxc::", stringify!($chip), "::", stringify!($adcg), "::", stringify!($adcp), ";")]
+        ///
+        #[doc = concat!("const _: () = assert!(<", stringify!($adcp), " as adc::Pin<adc::", stringify!($adc), ">>::INPUT == ", stringify!($input), ");")]
+        #[doc = concat!("adc::prepare::<adc::", stringify!($adc), ", _>(&mut unsafe { ", stringify!($adcp), "::new() });")]
+        /// ```
+        )?
+        pub mod examples {}
     };
 }
 
@@ -210,6 +575,10 @@ pub mod imxrt1010;
 #[cfg_attr(docsrs, doc(cfg(feature = "imxrt1060")))]
 pub mod imxrt1060;
 
+#[cfg(feature = "teensy4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "teensy4")))]
+pub mod teensy4;
+
 /// An IOMUXC-capable pad which can support I/O multiplexing
 ///
 /// # Safety
@@ -225,11 +594,68 @@ pub unsafe trait Iomuxc: private::Sealed {
     fn pad(&mut self) -> *mut u32;
 }
 
+/// An IOMUXC-capable pad with register addresses that are known at compile time
+///
+/// All strongly-typed pads implement `ConstIomuxc`. Type-erased pads do not, since
+/// their addresses are only known at run time.
+///
+/// # Safety
+///
+/// The addresses must be the same as the pointers returned by the `Iomuxc`
+/// implementation.
+pub unsafe trait ConstIomuxc: Iomuxc {
+    /// The absolute address of the multiplex register
+    const MUX: usize;
+    /// The absolute address of the pad configuration register
+    const PAD: usize;
+}
+
 mod private {
     pub trait Sealed {}
 }
 
-const SION_BIT: u32 = 1 << 4;
+pub(crate) const SION_BIT: u32 = 1 << 4;
+
+/// A pin function's requirement for the pad's SION bit
+///
+/// The software input on (SION) bit forces the pad's input path on, regardless of
+/// the selected alternate. Some functions need the input path, like the bidirectional
+/// I2C signals. Others expect that the input path is off.
+///
+/// Each peripheral `Pin` trait has a `SION` constant, with a default for the peripheral.
+/// A pad may override the default. The peripheral's `prepare()` function honors the
+/// requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SionRequirement {
+    /// Set the SION bit
+    Set,
+    /// Clear the SION bit
+    Clear,
+    /// The function works either way
+    ///
+    /// `prepare()` does not change the SION bit. Since a [`PreparedWrites`] writes the
+    /// entire mux register, it clears the SION bit.
+    DontCare,
+}
+
+impl SionRequirement {
+    /// Apply the requirement to the mux register value `mux`
+    const fn apply(self, mux: u32) -> u32 {
+        match self {
+            SionRequirement::Set => mux | SION_BIT,
+            SionRequirement::Clear => mux & !SION_BIT,
+            SionRequirement::DontCare => mux,
+        }
+    }
+
+    /// Returns the complete mux register value for the alternate `alt`
+    pub(crate) const fn mux_value(self, alt: u32) -> u32 {
+        match self {
+            SionRequirement::Set => alt | SION_BIT,
+            SionRequirement::Clear | SionRequirement::DontCare => alt,
+        }
+    }
+}
 
 /// Set the SION bit in a pad's MUX register
 ///
@@ -254,11 +680,23 @@ pub fn set_sion<I: Iomuxc>(pad: &mut I) {
     // By taking a mutable reference, the caller has to ensure atomicity of this
     // read-modify-write operation (or, violate the requirement with more unsafe
     // code).
-    unsafe {
-        let mut mux = ptr::read_volatile(pad.mux());
-        mux |= SION_BIT;
-        ptr::write_volatile(pad.mux(), mux);
-    }
+    unsafe { set_sion_raw(pad.mux()) }
+}
+
+/// The shared body of [`set_sion()`]
+///
+/// The generic front-ends funnel into non-generic functions, so that each pad
+/// type doesn't carry its own copy of the register access.
+#[inline(never)]
+unsafe fn set_sion_raw(mux_reg: *mut u32) {
+    let mut mux = ptr::read_volatile(mux_reg);
+    mux |= SION_BIT;
+    trace!(
+        "set_sion mux={:#010x} value={:#010x}",
+        mux_reg as usize,
+        mux
+    );
+    ptr::write_volatile(mux_reg, mux);
 }
 
 /// Clear the SION bit in a pad's MUX register
@@ -272,11 +710,20 @@ pub fn set_sion<I: Iomuxc>(pad: &mut I) {
 #[inline(always)]
 pub fn clear_sion<I: Iomuxc>(pad: &mut I) {
     // Safety: same justification as set_sion
-    unsafe {
-        let mut mux = ptr::read_volatile(pad.mux());
-        mux &= !SION_BIT;
-        ptr::write_volatile(pad.mux(), mux);
-    }
+    unsafe { clear_sion_raw(pad.mux()) }
+}
+
+/// The shared body of [`clear_sion()`]
+#[inline(never)]
+unsafe fn clear_sion_raw(mux_reg: *mut u32) {
+    let mut mux = ptr::read_volatile(mux_reg);
+    mux &= !SION_BIT;
+    trace!(
+        "clear_sion mux={:#010x} value={:#010x}",
+        mux_reg as usize,
+        mux
+    );
+    ptr::write_volatile(mux_reg, mux);
 }
 
 /// Set an alternate value for the pad
@@ -289,14 +736,133 @@ pub fn clear_sion<I: Iomuxc>(pad: &mut I) {
 /// pads cannot be used with a peripheral's `prepare()` function.
 #[inline(always)]
 pub fn alternate<I: Iomuxc>(pad: &mut I, alt: u32) {
-    const ALT_MASK: u32 = 0b1111;
     // Safety: same justification as set_sion. Argument extends to
     // pad values and alternate values.
-    unsafe {
-        let mut mux = ptr::read_volatile(pad.mux());
-        mux = (mux & !ALT_MASK) | (alt & ALT_MASK);
-        ptr::write_volatile(pad.mux(), mux);
+    unsafe { alternate_raw(pad.mux(), alt) }
+}
+
+/// The shared body of [`alternate()`]
+#[inline(never)]
+unsafe fn alternate_raw(mux_reg: *mut u32, alt: u32) {
+    let mut mux = ptr::read_volatile(mux_reg);
+    mux = (mux & !ALT_MASK) | (alt & ALT_MASK);
+    trace!(
+        "alternate mux={:#010x} alt={} value={:#010x}",
+        mux_reg as usize,
+        alt,
+        mux
+    );
+    ptr::write_volatile(mux_reg, mux);
+}
+
+/// The alternate field of a mux register
+pub(crate) const ALT_MASK: u32 = 0b1111;
+
+/// Write a pin's daisy register, then select its alternate and apply its SION requirement
+///
+/// This is the body of each peripheral's `prepare()`. The mux register is written
+/// last, and only once. See [Write Ordering](crate#write-ordering).
+#[inline(always)]
+fn prepare_pin<I: Iomuxc>(pin: &mut I, alt: u32, sion: SionRequirement, daisy: Option<Daisy>) {
+    // Safety: same justification as set_sion. The daisy register is
+    // selected by the pin's implementation.
+    unsafe { prepare_pin_raw(pin.mux(), alt, sion, daisy) }
+}
+
+/// Configure a pin's pad, then do the work of [`prepare_pin()`]
+///
+/// This is the body of each peripheral's `prepare()` that also configures the pad.
+#[inline(always)]
+fn prepare_pin_configured<I: Iomuxc>(
+    pin: &mut I,
+    config: Config,
+    alt: u32,
+    sion: SionRequirement,
+    daisy: Option<Daisy>,
+) {
+    configure(pin, config);
+    prepare_pin(pin, alt, sion, daisy);
+}
+
+/// Like [`prepare_pin()`], but skip each write that wouldn't change its register
+///
+/// Returns `true` if it wrote a register. The writes that remain keep their
+/// order.
+#[inline(always)]
+fn prepare_pin_idempotent<I: Iomuxc>(
+    pin: &mut I,
+    alt: u32,
+    sion: SionRequirement,
+    daisy: Option<Daisy>,
+) -> bool {
+    // Safety: see prepare_pin.
+    unsafe { prepare_pin_idempotent_raw(pin.mux(), alt, sion, daisy) }
+}
+
+/// Like [`prepare_pin_configured()`], but skip each write that wouldn't change its
+/// register
+#[inline(always)]
+fn prepare_pin_configured_idempotent<I: Iomuxc>(
+    pin: &mut I,
+    config: Config,
+    alt: u32,
+    sion: SionRequirement,
+    daisy: Option<Daisy>,
+) -> bool {
+    let configured = config::configure_idempotent(pin, config);
+    prepare_pin_idempotent(pin, alt, sion, daisy) | configured
+}
+
+/// The shared body of [`prepare_pin_idempotent()`]
+#[inline(never)]
+unsafe fn prepare_pin_idempotent_raw(
+    mux_reg: *mut u32,
+    alt: u32,
+    sion: SionRequirement,
+    daisy: Option<Daisy>,
+) -> bool {
+    let mut written = false;
+    if let Some(daisy) = daisy {
+        if daisy.read() != daisy.value() {
+            daisy.write();
+            written = true;
+        }
     }
+    let current = ptr::read_volatile(mux_reg);
+    let mux = sion.apply((current & !ALT_MASK) | (alt & ALT_MASK));
+    if mux != current {
+        trace!(
+            "prepare mux={:#010x} alt={} value={:#010x}",
+            mux_reg as usize,
+            alt,
+            mux
+        );
+        ptr::write_volatile(mux_reg, mux);
+        written = true;
+    }
+    written
+}
+
+/// The shared body of [`prepare_pin()`]
+#[inline(never)]
+unsafe fn prepare_pin_raw(
+    mux_reg: *mut u32,
+    alt: u32,
+    sion: SionRequirement,
+    daisy: Option<Daisy>,
+) {
+    if let Some(daisy) = daisy {
+        daisy.write();
+    }
+    let mux = ptr::read_volatile(mux_reg);
+    let mux = sion.apply((mux & !ALT_MASK) | (alt & ALT_MASK));
+    trace!(
+        "prepare mux={:#010x} alt={} value={:#010x}",
+        mux_reg as usize,
+        alt,
+        mux
+    );
+    ptr::write_volatile(mux_reg, mux);
 }
 
 /// An i.MXT RT pad
@@ -305,7 +871,22 @@ pub fn alternate<I: Iomuxc>(pad: &mut I, alt: u32) {
 /// constant (type) that describes the pad number.
 ///
 /// `Pad`s have no size.
-#[derive(Debug)]
+///
+/// The `Debug` output shows the pad's register addresses, unless you enable the
+/// `min-size` feature:
+///
+/// ```
+/// # #[cfg(all(feature = "imxrt1060", not(feature = "min-size")))] fn main() {
+/// use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_03;
+///
+/// let pad = unsafe { GPIO_AD_B0_03::new() };
+/// assert_eq!(
+///     format!("{:?}", pad),
+///     "Pad { mux: 0x401f80c8, pad: 0x401f82b8 }"
+/// );
+/// # }
+/// # #[cfg(not(all(feature = "imxrt1060", not(feature = "min-size"))))] fn main() {}
+/// ```
 pub struct Pad<Base, Offset> {
     base: ::core::marker::PhantomData<Base>,
     offset: ::core::marker::PhantomData<Offset>,
@@ -332,6 +913,27 @@ impl<Base, Offset> Pad<Base, Offset> {
     }
 }
 
+impl<Base, Offset> core::fmt::Debug for Pad<Base, Offset>
+where
+    Base: crate::Base,
+    Offset: crate::consts::Unsigned,
+{
+    #[cfg(not(feature = "min-size"))]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mux = Base::mux_base() as usize + 4 * Offset::USIZE;
+        let pad = Base::pad_base() as usize + 4 * Offset::USIZE;
+        f.debug_struct("Pad")
+            .field("mux", &format_args!("{:#010x}", mux))
+            .field("pad", &format_args!("{:#010x}", pad))
+            .finish()
+    }
+
+    #[cfg(feature = "min-size")]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Pad")
+    }
+}
+
 unsafe impl<Base, Offset> Send for Pad<Base, Offset>
 where
     Base: Send,
@@ -344,6 +946,28 @@ where
     Base: crate::Base,
     Offset: crate::consts::Unsigned,
 {
+    /// Returns a read-only view of the pad's registers
+    #[inline(always)]
+    pub fn pad_ref(&self) -> PadRef {
+        PadRef {
+            mux: Base::mux_base() as usize + 4 * Offset::USIZE,
+            pad: Base::pad_base() as usize + 4 * Offset::USIZE,
+        }
+    }
+
+    /// Adopt a pad that the boot ROM, or a bootloader, already configured
+    ///
+    /// `assume_configured()` does not touch any registers. See [`ConfiguredPad`]
+    /// for more information.
+    ///
+    /// # Safety
+    ///
+    /// See [`ConfiguredPad::assume()`].
+    #[inline(always)]
+    pub unsafe fn assume_configured(self) -> ConfiguredPad<Self> {
+        ConfiguredPad::assume(self)
+    }
+
     /// Erase the pad's type, returning an `ErasedPad`
     #[inline(always)]
     pub fn erase(self) -> ErasedPad {
@@ -434,6 +1058,135 @@ where
     }
 }
 
+impl<Base, Offset> Pad<Base, Offset>
+where
+    Base: crate::ConstBase,
+    Offset: crate::consts::Unsigned,
+{
+    /// The pad's identifier
+    pub const ID: PadId = PadId::of::<Self>();
+}
+
+unsafe impl<Base, Offset> crate::ConstIomuxc for Pad<Base, Offset>
+where
+    Base: crate::ConstBase,
+    Offset: crate::consts::Unsigned,
+{
+    const MUX: usize = Base::MUX_BASE + 4 * Offset::USIZE;
+    const PAD: usize = Base::PAD_BASE + 4 * Offset::USIZE;
+}
+
+/// A group of pads that share a base, like `GPIO_AD_B0`
+///
+/// Each pad group's `Pads` struct implements `PadGroup`. Use the trait to write
+/// generic code that walks all of the pads in a group.
+///
+/// ```no_run
+/// use imxrt_iomuxc::{ErasedPad, PadGroup};
+///
+/// /// Clear the SION bit on all pads in a group
+/// fn clear_all_sion<G: PadGroup>(group: G) {
+///     for mut pad in group.erased_iter() {
+///         imxrt_iomuxc::clear_sion(&mut pad);
+///     }
+/// }
+/// # #[cfg(feature = "imxrt1060")]
+/// # clear_all_sion(unsafe { imxrt_iomuxc::imxrt1060::gpio_b0::Pads::new() });
+/// ```
+pub trait PadGroup {
+    /// The number of pads in the group
+    const PAD_COUNT: usize;
+    /// The offsets of all pads in the group, in ascending order
+    ///
+    /// A pad's offset is its number; `GPIO_AD_B0_03` has offset 3.
+    const OFFSETS: &'static [u8];
+    /// An iterator over the group's erased pads
+    type ErasedIter: Iterator<Item = ErasedPad>;
+    /// Erase all pads in the group, and iterate over them in offset order
+    fn erased_iter(self) -> Self::ErasedIter;
+}
+
+/// Describes a pad group at run time
+///
+/// Each chip's group module, like `imxrt1060::gpio_ad_b0`, has an `INFO` constant
+/// that describes the group. The chip's `groups::GROUPS` array lists every group,
+/// so that generic code can reason about groups as data.
+///
+/// ```
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::imxrt1060::{gpio_sd_b0, groups::GROUPS};
+///
+/// let total: usize = GROUPS.iter().map(|group| group.pad_count).sum();
+/// assert_eq!(total, 124);
+///
+/// let group = GROUPS.iter().find(|group| group.name == "GPIO_SD_B0").unwrap();
+/// assert_eq!(*group, gpio_sd_b0::INFO);
+/// assert_eq!(group.base_id, gpio_sd_b0::GPIO_SD_B0_00::ID);
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GroupInfo {
+    /// The group's name, like `"GPIO_AD_B0"`
+    pub name: &'static str,
+    /// The number of pads in the group
+    pub pad_count: usize,
+    /// The identifier of the group's first pad
+    pub base_id: PadId,
+}
+
+impl GroupInfo {
+    /// Returns the index of pad `id`'s group in `groups`, and the pad's index in that group
+    #[cfg_attr(not(any(feature = "imxrt1010", feature = "imxrt1060")), allow(unused))]
+    fn position(id: PadId, groups: &[GroupInfo]) -> Option<(usize, usize)> {
+        groups.iter().enumerate().find_map(|(idx, group)| {
+            let offset = id.mux_addr().checked_sub(group.base_id.mux_addr())?;
+            if offset % 4 == 0 && offset / 4 < group.pad_count {
+                Some((idx, offset / 4))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns `true` if pad `id` implements alternate `alt`
+    ///
+    /// `alternates` holds the generated alternate masks of each group in `groups`.
+    #[cfg_attr(not(any(feature = "imxrt1010", feature = "imxrt1060")), allow(unused))]
+    fn supports_alternate(id: PadId, alt: u8, groups: &[GroupInfo], alternates: &[&[u16]]) -> bool {
+        match Self::position(id, groups) {
+            Some((group, pad)) => alt < 16 && alternates[group][pad] & 1 << alt != 0,
+            None => false,
+        }
+    }
+
+    /// Returns the GPIO module and offset of pad `id`
+    ///
+    /// `gpios` holds the generated GPIOs of each group in `groups`.
+    #[cfg_attr(not(any(feature = "imxrt1010", feature = "imxrt1060")), allow(unused))]
+    fn gpio(id: PadId, groups: &[GroupInfo], gpios: &[&[(u8, u8)]]) -> Option<(u8, u8)> {
+        let (group, pad) = Self::position(id, groups)?;
+        match gpios[group][pad] {
+            (0, _) => None,
+            gpio => Some(gpio),
+        }
+    }
+
+    /// Returns the GPIO module, and the interrupt configuration register index and
+    /// field shift, of pad `id`
+    #[cfg_attr(not(any(feature = "imxrt1010", feature = "imxrt1060")), allow(unused))]
+    fn gpio_icr(
+        id: PadId,
+        groups: &[GroupInfo],
+        gpios: &[&[(u8, u8)]],
+    ) -> Option<(u8, usize, u32)> {
+        let (module, offset) = Self::gpio(id, groups, gpios)?;
+        let (index, shift) = gpio::icr(offset as u32);
+        Some((module, index, shift))
+    }
+}
+
 /// A pad that has its type erased
 ///
 /// `ErasedPad` moves the pad state to run time, rather than compile time.
@@ -467,6 +1220,164 @@ pub struct ErasedPad {
 
 impl private::Sealed for ErasedPad {}
 
+#[cfg(feature = "debug-pad-tracking")]
+impl Drop for ErasedPad {
+    fn drop(&mut self) {
+        tracking::record_drop(self.id());
+    }
+}
+
+impl ErasedPad {
+    /// Returns the pad's offset from the start of its group
+    ///
+    /// `GPIO_AD_B0_03` has offset 3.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the pad's identifier
+    pub fn id(&self) -> PadId {
+        PadId(self.mux_base as usize + 4 * self.offset)
+    }
+
+    /// Returns a read-only view of the pad's registers
+    pub fn pad_ref(&self) -> PadRef {
+        PadRef {
+            mux: self.mux_base as usize + 4 * self.offset,
+            pad: self.pad_base as usize + 4 * self.offset,
+        }
+    }
+}
+
+/// A pad identifier
+///
+/// A `PadId` identifies a pad at run time, without owning the pad. Use `PadId`s
+/// in tables, and to compare pads. Strongly-typed pads have an [`ID`](Pad::ID)
+/// constant, and erased pads have an [`id()`](ErasedPad::id) method.
+///
+/// The identifier is the address of the pad's mux register, which is unique
+/// for each pad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PadId(usize);
+
+impl PadId {
+    /// Returns the identifier of the pad `P`
+    pub const fn of<P: ConstIomuxc>() -> Self {
+        PadId(P::MUX)
+    }
+
+    /// Returns the address of the pad's mux register
+    pub const fn mux_addr(self) -> usize {
+        self.0
+    }
+}
+
+/// A read-only view of a pad's registers
+///
+/// A `PadRef` reads a pad's mux and pad configuration registers, but it never
+/// writes them. It's `Copy`, so a diagnostic or logging task can keep a `PadRef`
+/// while a driver owns, and configures, the pad.
+///
+/// Get a `PadRef` from a strongly-typed pad with [`Pad::pad_ref()`], from an erased
+/// pad with [`ErasedPad::pad_ref()`], or from a [`PadId`] with a chip's `pad_ref()`
+/// function, like `imxrt1060::pad_ref()`.
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::GPIO_AD_B0_12, lpuart};
+///
+/// let mut tx = unsafe { GPIO_AD_B0_12::new() };
+/// let view = tx.pad_ref();
+///
+/// lpuart::prepare(&mut tx);
+/// assert_eq!(view.mux_value() & 0b1111, 2);
+/// assert!(view.verify(&lpuart::prepared::<GPIO_AD_B0_12>(view.read_config())));
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PadRef {
+    mux: usize,
+    pad: usize,
+}
+
+impl PadRef {
+    /// Find the pad `id` in `groups`, when each pad register is `distance` bytes
+    /// after its mux register
+    #[cfg_attr(not(any(feature = "imxrt1010", feature = "imxrt1060")), allow(unused))]
+    fn lookup(id: PadId, groups: &[GroupInfo], distance: usize) -> Option<Self> {
+        GroupInfo::position(id, groups).map(|_| PadRef {
+            mux: id.mux_addr(),
+            pad: id.mux_addr() + distance,
+        })
+    }
+
+    /// Returns the pad's identifier
+    pub const fn id(self) -> PadId {
+        PadId(self.mux)
+    }
+
+    /// Returns the address of the pad's configuration register
+    pub const fn pad_addr(self) -> usize {
+        self.pad
+    }
+
+    /// Read the pad's mux register
+    #[inline(always)]
+    pub fn mux_value(self) -> u32 {
+        // Safety: the address came from a pad, and reading the IOMUXC
+        // registers has no side effects.
+        unsafe { core::ptr::read_volatile(self.mux as *const u32) }
+    }
+
+    /// Read the pad's configuration register
+    #[inline(always)]
+    pub fn pad_value(self) -> u32 {
+        // Safety: see mux_value().
+        unsafe { core::ptr::read_volatile(self.pad as *const u32) }
+    }
+
+    /// Read the pad's configuration register as a [`Config`]
+    ///
+    /// The result is a [`Config::zero()`] configuration that describes every field.
+    pub fn read_config(self) -> Config {
+        Config::from_register(self.pad_value())
+    }
+
+    /// Returns `true` if the pad's registers hold the values in `writes`
+    ///
+    /// `verify()` compares the mux and pad configuration registers. If `writes`
+    /// doesn't care about the SION bit, `verify()` ignores the bit. It does not
+    /// read the daisy register. Returns `false` if `writes` describes another pad.
+    pub fn verify(self, writes: &PreparedWrites) -> bool {
+        let mux = match writes.sion {
+            SionRequirement::DontCare => self.mux_value() & !SION_BIT,
+            SionRequirement::Set | SionRequirement::Clear => self.mux_value(),
+        };
+        writes.mux_addr == self.mux
+            && writes.pad_addr == self.pad
+            && mux == writes.mux_val
+            && self.pad_value() == writes.pad_val
+    }
+}
+
+impl<Base, Offset> From<&Pad<Base, Offset>> for PadRef
+where
+    Base: crate::Base,
+    Offset: crate::consts::Unsigned,
+{
+    fn from(pad: &Pad<Base, Offset>) -> Self {
+        pad.pad_ref()
+    }
+}
+
+impl From<&ErasedPad> for PadRef {
+    fn from(pad: &ErasedPad) -> Self {
+        pad.pad_ref()
+    }
+}
+
 unsafe impl crate::Iomuxc for ErasedPad {
     #[inline(always)]
     fn mux(&mut self) -> *mut u32 {
@@ -500,6 +1411,9 @@ where
             && erased_pad.pad_base == Base::pad_base()
             && erased_pad.offset == Offset::USIZE
         {
+            // Converting isn't a drop
+            #[cfg(feature = "debug-pad-tracking")]
+            core::mem::forget(erased_pad);
             Ok(unsafe { Self::new() })
         } else {
             Err(WrongPadError(erased_pad))
@@ -507,6 +1421,122 @@ where
     }
 }
 
+/// A pad that the boot ROM, or a bootloader, already configured
+///
+/// The boot ROM configures the FlexSPI pads before it runs your program, and a
+/// bootloader might configure the SDRAM pads. Rewriting those pads may glitch
+/// them. Adopt the pad as a `ConfiguredPad` to keep typed ownership, without
+/// touching any registers, then convert it into a peripheral's `PreparedPin`:
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1010")] fn main() {
+/// use imxrt_iomuxc::{flexspi, imxrt1010::gpio_sd::GPIO_SD_10};
+///
+/// // The boot ROM configured the FlexSPI clock.
+/// let sclk = unsafe { GPIO_SD_10::new().assume_configured() };
+/// let config = sclk.pad_ref().read_config();
+/// debug_assert!(sclk.verify(&flexspi::prepared::<GPIO_SD_10>(config)));
+/// let sclk: flexspi::PreparedPin<_> = sclk.into();
+/// # }
+/// # #[cfg(not(feature = "imxrt1010"))] fn main() {}
+/// ```
+///
+/// Adopting a pad, and converting it, performs no register accesses.
+#[derive(Debug)]
+pub struct ConfiguredPad<P> {
+    pin: P,
+}
+
+impl<P: Iomuxc> ConfiguredPad<P> {
+    /// Adopt `pin` without touching its registers
+    ///
+    /// # Safety
+    ///
+    /// The pad must already be configured for each function that you convert the
+    /// `ConfiguredPad` into. Otherwise, the `PreparedPin` token is a lie.
+    pub unsafe fn assume(pin: P) -> Self {
+        ConfiguredPad { pin }
+    }
+
+    /// Release the pin
+    pub fn release(self) -> P {
+        self.pin
+    }
+}
+
+impl<Base, Offset> ConfiguredPad<Pad<Base, Offset>>
+where
+    Base: crate::Base,
+    Offset: crate::consts::Unsigned,
+{
+    /// Returns a read-only view of the pad's registers
+    pub fn pad_ref(&self) -> PadRef {
+        self.pin.pad_ref()
+    }
+
+    /// Returns `true` if the pad's registers hold the values in `writes`
+    ///
+    /// Use `verify()` in a `debug_assert!` to check the adoption. See
+    /// [`PadRef::verify()`] for more information.
+    pub fn verify(&self, writes: &PreparedWrites) -> bool {
+        self.pad_ref().verify(writes)
+    }
+}
+
+/// How to park an unused pad
+///
+/// Chip modules with a `park_all()` function accept a `ParkStrategy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParkStrategy {
+    /// Every pad enables its keeper
+    DigitalKeeper,
+    /// Analog-capable pads disable their pull / keeper, which has lower leakage.
+    /// All other pads enable their keeper.
+    PreferAnalog,
+}
+
+/// A peripheral instance
+///
+/// [`PreparedWrites`] and [`Routing`] record the peripheral that a pin connects to,
+/// so that [`plan()`] can find routings that compete for the same peripheral, and
+/// so that [`Plan::check_reservations()`] can find routings on a peripheral's
+/// reserved pads. Only the `sai`, `mqs`, `csi`, and `lcdif` modules record their
+/// peripheral; other pins use `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum Peripheral {
+    /// A SAI module; `Sai(3)` for SAI3
+    Sai(u32),
+    /// The medium quality sound (MQS) module
+    Mqs,
+    /// The CMOS sensor interface (CSI)
+    Csi,
+    /// The enhanced LCD interface (eLCDIF)
+    Lcdif,
+}
+
+impl Peripheral {
+    /// Returns the peripheral that this peripheral borrows
+    ///
+    /// A borrowed peripheral drives this peripheral internally, so pins of the two
+    /// peripherals can't be used together.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{mqs, Peripheral};
+    ///
+    /// assert_eq!(Peripheral::Mqs.borrows(), Some(Peripheral::Sai(3)));
+    /// assert_eq!(Peripheral::Mqs.borrows(), mqs::BORROWS);
+    /// assert_eq!(Peripheral::Sai(3).borrows(), None);
+    /// ```
+    pub const fn borrows(self) -> Option<Peripheral> {
+        match self {
+            Peripheral::Mqs => mqs::BORROWS,
+            Peripheral::Sai(_) | Peripheral::Csi | Peripheral::Lcdif => None,
+        }
+    }
+}
+
 /// A daisy selection
 ///
 /// A daisy chain specifies which pad will be used for a peripheral's
@@ -514,18 +1544,50 @@ where
 /// value.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Daisy {
-    reg: *mut u32,
+    reg: usize,
     value: u32,
 }
 
 impl Daisy {
+    /// The largest value of any select input field
+    const MAX_VALUE: u32 = 0b111;
+
     /// Create a new select input that, when utilized, will write
-    /// `value` into `reg`
+    /// `value` into the register at address `reg`
+    ///
+    /// Panics if `reg` isn't a register address, or if `value` doesn't fit in a
+    /// select input field. Since the daisy tables are constants, a bad entry is a
+    /// compile-time error.
     #[allow(unused)] // Used behind feature flags
-    const fn new(reg: *mut u32, value: u32) -> Self {
+    const fn new(reg: usize, value: u32) -> Self {
+        assert!(reg & 0b11 == 0, "Daisy register address is not aligned");
+        assert!(
+            value <= Self::MAX_VALUE,
+            "Daisy value does not fit in the select input field"
+        );
         Daisy { reg, value }
     }
 
+    /// Returns the address of the select input register
+    pub const fn reg(&self) -> usize {
+        self.reg
+    }
+
+    /// Returns the value that selects the pad
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Read the select input register
+    ///
+    /// # Safety
+    ///
+    /// The register address must come from the chip's daisy tables.
+    #[inline(always)]
+    pub(crate) unsafe fn read(self) -> u32 {
+        ptr::read_volatile(self.reg as *const u32)
+    }
+
     /// Commit the settings defined by this `Daisy` value to the hardware
     ///
     /// # Safety
@@ -534,7 +1596,8 @@ impl Daisy {
     /// rules around mutable static memory apply.
     #[inline(always)]
     pub unsafe fn write(self) {
-        ptr::write_volatile(self.reg, self.value);
+        trace!("daisy reg={:#010x} value={:#010x}", self.reg, self.value);
+        ptr::write_volatile(self.reg as *mut u32, self.value);
     }
 }
 
@@ -548,11 +1611,88 @@ pub mod gpio {
         type Module: super::consts::Unsigned;
         /// The offset; `U13` for `GPIO5_IO13`
         type Offset: super::consts::Unsigned;
+        /// The pin's SION requirement
+        ///
+        /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+        const SION: super::SionRequirement = super::SionRequirement::DontCare;
+        /// The pin's interrupt configuration register; `0` for `ICR1`, `1` for `ICR2`
+        ///
+        /// See [`icr()`] for more information.
+        const ICR_INDEX: usize = icr(<Self::Offset as super::consts::Unsigned>::U32).0;
+        /// The shift of the pin's two-bit field in its interrupt configuration register
+        ///
+        /// See [`icr()`] for more information.
+        const ICR_SHIFT: u32 = icr(<Self::Offset as super::consts::Unsigned>::U32).1;
+    }
+
+    module_aliases!("GPIO":
+        1 => Gpio1 = U1,
+        2 => Gpio2 = U2,
+        3 => Gpio3 = U3,
+        4 => Gpio4 = U4,
+        5 => Gpio5 = U5,
+        6 => Gpio6 = U6,
+        7 => Gpio7 = U7,
+        8 => Gpio8 = U8,
+        9 => Gpio9 = U9,
+    );
+
+    /// Returns the interrupt configuration register index and field shift for a
+    /// GPIO offset
+    ///
+    /// Each GPIO module has two interrupt configuration registers, `ICR1` and `ICR2`.
+    /// Each register has a two-bit field for sixteen GPIO offsets. The result is
+    /// `(index, shift)`, where `index` is `0` for `ICR1` and `1` for `ICR2`. Use this
+    /// when you only know the offset at run time. For strongly-typed pins, prefer
+    /// [`Pin::ICR_INDEX`] and [`Pin::ICR_SHIFT`]. To start from a pad, use the chip's
+    /// `gpio_icr()`, like `imxrt1060::gpio_icr()`.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::gpio;
+    ///
+    /// // GPIO1_IO03 is in ICR1, bits 7:6
+    /// assert_eq!(gpio::icr(3), (0, 6));
+    /// // GPIO1_IO16 is in ICR2, bits 1:0
+    /// assert_eq!(gpio::icr(16), (1, 0));
+    /// ```
+    pub const fn icr(offset: u32) -> (usize, u32) {
+        ((offset / 16) as usize, (offset % 16) * 2)
     }
 
     /// Prepare a pad to be used as a GPIO pin
     pub fn prepare<P: Pin>(pin: &mut P) {
-        super::alternate(pin, P::ALT);
+        trace!("gpio::prepare mux={:#010x}", pin.mux() as usize);
+        super::prepare_pin(pin, P::ALT, P::SION, None);
+    }
+
+    /// Like [`prepare()`], but skip the write if the mux register already holds
+    /// the GPIO alternate
+    ///
+    /// Returns `true` if it wrote the register. See the
+    /// [crate documentation](crate#idempotent-prepare) for more information.
+    pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+        trace!("gpio::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+        super::prepare_pin_idempotent(pin, P::ALT, P::SION, None)
+    }
+
+    /// Returns the [`Function`](crate::Function) of a GPIO pin
+    pub const fn function<P: Pin>() -> super::Function {
+        use super::consts::Unsigned;
+        super::Function::Gpio {
+            module: P::Module::U8,
+            offset: P::Offset::U8,
+        }
+    }
+
+    /// Compute the register writes that prepare a GPIO pin
+    ///
+    /// The result describes the same writes as [`prepare()`], along with the pad
+    /// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+    /// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+    pub const fn prepared<P: Pin + super::ConstIomuxc>(
+        config: super::Config,
+    ) -> super::PreparedWrites {
+        super::PreparedWrites::new::<P>(P::ALT, P::SION, config, None)
     }
 }
 
@@ -567,11 +1707,11 @@ mod tests {
     unsafe impl crate::Base for TestBase {
         fn mux_base() -> *mut u32 {
             static mut MEM: u32 = 0;
-            unsafe { &mut MEM as *mut u32 }
+            ptr::addr_of_mut!(MEM)
         }
         fn pad_base() -> *mut u32 {
             static mut MEM: u32 = 0;
-            unsafe { &mut MEM as *mut u32 }
+            ptr::addr_of_mut!(MEM)
         }
     }
 
@@ -586,6 +1726,85 @@ mod tests {
         TestPad::try_from(erased).expect("This is the test pad");
     }
 
+    #[test]
+    #[cfg(not(feature = "min-size"))]
+    fn pad_debug_addresses() {
+        extern crate std;
+        use std::format;
+
+        #[derive(Debug)]
+        struct DebugBase;
+
+        unsafe impl crate::Base for DebugBase {
+            fn mux_base() -> *mut u32 {
+                0x5000 as *mut u32
+            }
+            fn pad_base() -> *mut u32 {
+                0x5200 as *mut u32
+            }
+        }
+
+        let pad = unsafe { Pad::<DebugBase, U1>::new() };
+        assert_eq!(
+            format!("{:?}", pad),
+            "Pad { mux: 0x00005004, pad: 0x00005204 }"
+        );
+        assert_eq!(
+            format!("{:#?}", pad),
+            "Pad {\n    mux: 0x00005004,\n    pad: 0x00005204,\n}"
+        );
+    }
+
+    #[test]
+    fn pad_ref_reads_owned_pad() {
+        struct RefBase;
+
+        static mut REF_MUX: [u32; 2] = [0; 2];
+        static mut REF_PAD: [u32; 2] = [0; 2];
+
+        unsafe impl crate::Base for RefBase {
+            fn mux_base() -> *mut u32 {
+                ptr::addr_of_mut!(REF_MUX) as *mut u32
+            }
+            fn pad_base() -> *mut u32 {
+                ptr::addr_of_mut!(REF_PAD) as *mut u32
+            }
+        }
+
+        let mut pad = unsafe { Pad::<RefBase, U1>::new() };
+        let view = PadRef::from(&pad);
+
+        // The owner keeps configuring the pad, and the copy sees each change
+        let config = Config::zero().set_speed(Speed::Fast);
+        prepare_pin_configured(&mut pad, config, 3, SionRequirement::Set, None);
+        assert_eq!(view.mux_value(), 3 | SION_BIT);
+        assert_eq!(view.pad_value(), config.value());
+        assert_eq!(view.read_config(), config);
+        assert_eq!(view.read_config().pull_keeper(), Some(None));
+
+        let mut writes = PreparedWrites {
+            mux_addr: view.id().mux_addr(),
+            mux_val: 3 | SION_BIT,
+            sion: SionRequirement::Set,
+            pad_addr: view.pad_addr(),
+            pad_val: config.value(),
+            daisy: None,
+            peripheral: None,
+        };
+        assert!(view.verify(&writes));
+        writes.sion = SionRequirement::DontCare;
+        writes.mux_val = 3;
+        assert!(view.verify(&writes));
+        writes.pad_val = 0;
+        assert!(!view.verify(&writes));
+
+        // Erasing the pad doesn't change the view
+        let erased = pad.erase();
+        assert_eq!(erased.pad_ref(), view);
+        assert_eq!(erased.id(), view.id());
+        assert!(!PadRef::from(&unsafe { Pad::<RefBase, U0>::new() }).verify(&writes));
+    }
+
     #[test]
     fn erased_pad_convert_fail() {
         let pad = unsafe { TestPad::new() };
@@ -595,6 +1814,281 @@ mod tests {
         type OtherPad = Pad<TestBase, U1>;
         OtherPad::try_from(erased).expect_err("This is a different pad");
     }
+
+    #[test]
+    fn gpio_icr() {
+        for offset in 0..32 {
+            let (index, shift) = gpio::icr(offset);
+            assert_eq!(index as u32 * 16 + shift / 2, offset);
+            assert!(shift <= 30);
+        }
+    }
+
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn imxrt1060_gpio_icr() {
+        use crate::gpio::Pin;
+        use crate::imxrt1060::{gpio_ad_b0::GPIO_AD_B0_03, gpio_ad_b1::GPIO_AD_B1_00};
+        use crate::imxrt1060::{gpio_b0::GPIO_B0_15, gpio_b1::GPIO_B1_15};
+
+        // GPIO1_IO03
+        assert_eq!((GPIO_AD_B0_03::ICR_INDEX, GPIO_AD_B0_03::ICR_SHIFT), (0, 6));
+        // GPIO1_IO16
+        assert_eq!((GPIO_AD_B1_00::ICR_INDEX, GPIO_AD_B1_00::ICR_SHIFT), (1, 0));
+        // GPIO2_IO15
+        assert_eq!((GPIO_B0_15::ICR_INDEX, GPIO_B0_15::ICR_SHIFT), (0, 30));
+        // GPIO2_IO31
+        assert_eq!((GPIO_B1_15::ICR_INDEX, GPIO_B1_15::ICR_SHIFT), (1, 30));
+    }
+
+    /// Check the daisy table in a chip's peripheral module source
+    ///
+    /// No two daisy constants may select the same value in the same register, and
+    /// each pin must use the daisy constant named after its pad. If one pin of a
+    /// signal has a daisy, every pin of that signal must have a daisy.
+    #[cfg(any(feature = "imxrt1010", feature = "imxrt1060"))]
+    pub(crate) fn check_daisy_table(source: &str) {
+        extern crate std;
+        use std::vec::Vec;
+
+        fn ident(s: &str) -> &str {
+            let end = s
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(s.len());
+            &s[..end]
+        }
+
+        let mut selections: Vec<(&str, usize, u32)> = Vec::new();
+        // The signal of each pin, and the pin's pad and daisy
+        let mut pins: Vec<(Vec<&str>, &str, Option<&str>)> = Vec::new();
+        for line in source.lines() {
+            if let Some((decl, args)) = line.split_once("Daisy::new(") {
+                let name = ident(decl.trim().trim_start_matches("pub const "));
+                let (reg, value) = args.trim_end_matches(");").split_once(", ").unwrap();
+                let reg = usize::from_str_radix(reg.trim_start_matches("0x"), 16).unwrap();
+                let value: u32 = value.parse().unwrap();
+                if let Some((other, _, _)) =
+                    selections.iter().find(|&&(_, r, v)| (r, v) == (reg, value))
+                {
+                    panic!("{} and {} select the same input", other, name);
+                }
+                selections.push((name, reg, value));
+            }
+            if let (Some((_, pad)), Some((_, daisy))) =
+                (line.split_once("pad: "), line.split_once("DAISY_"))
+            {
+                let (pad, daisy) = (ident(pad), ident(daisy));
+                assert!(
+                    daisy.ends_with(pad) && daisy[..daisy.len() - pad.len()].ends_with('_'),
+                    "{} uses DAISY_{}",
+                    pad,
+                    daisy
+                );
+            }
+            if let (Some((_, pad)), Some((_, daisy))) =
+                (line.split_once("pad: "), line.split_once("daisy: "))
+            {
+                let signal = line
+                    .split(", ")
+                    .filter(|arg| {
+                        !["pad: ", "alt: ", "daisy: "]
+                            .iter()
+                            .any(|k| arg.contains(k))
+                    })
+                    .map(|arg| arg.rsplit(['(', '{']).next().unwrap().trim())
+                    .collect();
+                let daisy = daisy.split_once("DAISY_").map(|(_, daisy)| ident(daisy));
+                pins.push((signal, ident(pad), daisy));
+            }
+        }
+        assert!(!selections.is_empty(), "no daisy table");
+        assert!(!pins.is_empty(), "no pins");
+
+        for (signal, pad, daisy) in &pins {
+            if daisy.is_none() {
+                let other = pins.iter().find(|(s, _, d)| s == signal && d.is_some());
+                assert!(
+                    other.is_none(),
+                    "{} has no daisy, but {} of the same signal does",
+                    pad,
+                    other.unwrap().1
+                );
+            }
+        }
+    }
+
+    /// Cross-check the generated GPIO pins against a chip's pin tables
+    ///
+    /// `generated` is the chip's build script output. Every pad must have exactly one
+    /// GPIO pin on `gpio_alt`, and no two pads may share a GPIO module and offset. No peripheral pin
+    /// in `sources` may select its pad's GPIO alternate; if one does, either the table
+    /// or the GPIO alternate is wrong.
+    #[cfg(any(feature = "imxrt1010", feature = "imxrt1060"))]
+    pub(crate) fn check_gpio_alts(generated: &str, pads: usize, gpio_alt: u8, sources: &[&str]) {
+        extern crate std;
+        use std::{string::String, vec::Vec};
+
+        fn number(s: &str, prefix: &str) -> u32 {
+            let (_, rest) = s.split_once(prefix).unwrap();
+            let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap();
+            rest[..end].parse().unwrap()
+        }
+
+        fn ident(s: &str) -> &str {
+            let end = s
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(s.len());
+            &s[..end]
+        }
+
+        // The generated tokens may or may not be formatted
+        let generated: String = generated.split_whitespace().collect();
+        // Each GPIO pin's pad, alternate, module, and offset
+        let mut gpios: Vec<(&str, u32, u32, u32)> = Vec::new();
+        for block in generated.split("implcrate::gpio::Pinfor").skip(1) {
+            let (path, body) = block.split_once('{').unwrap();
+            let pad = path.rsplit("::").next().unwrap();
+            let gpio = (
+                pad,
+                number(body, "constALT:u32="),
+                number(body, "typeModule=U"),
+                number(body, "typeOffset=U"),
+            );
+            assert_eq!(gpio.1, u32::from(gpio_alt), "{} GPIO alternate", pad);
+            if let Some(other) = gpios.iter().find(|other| other.0 == pad) {
+                panic!("{} has two GPIO pins: {:?} and {:?}", pad, other, gpio);
+            }
+            if let Some(other) = gpios
+                .iter()
+                .find(|other| (other.2, other.3) == (gpio.2, gpio.3))
+            {
+                panic!(
+                    "{} and {} are both GPIO{}_IO{:02}",
+                    other.0, pad, gpio.2, gpio.3
+                );
+            }
+            gpios.push(gpio);
+        }
+        assert_eq!(gpios.len(), pads, "not every pad has a GPIO pin");
+
+        let mut pins = 0;
+        for source in sources {
+            for line in source.lines() {
+                if let (Some((_, alt)), Some((_, pad))) =
+                    (line.split_once("alt: "), line.split_once("pad: "))
+                {
+                    let (alt, pad) = (number(alt, ""), ident(pad));
+                    let gpio = gpios
+                        .iter()
+                        .find(|gpio| gpio.0 == pad)
+                        .unwrap_or_else(|| panic!("{} has no GPIO pin", pad));
+                    assert_ne!(
+                        alt, gpio.1,
+                        "{} selects ALT{}, which is GPIO{}_IO{:02}",
+                        pad, alt, gpio.2, gpio.3
+                    );
+                    pins += 1;
+                }
+            }
+        }
+        assert!(pins > 0, "no pins");
+    }
+
+    #[test]
+    fn prepare_writes_mux_last() {
+        // When two writes share a register, the register keeps the later write.
+        // The daisy shares the mux register, so the mux write must come last.
+        let mut reg = [0x7; 1];
+        let mut erased = ErasedPad {
+            mux_base: reg.as_mut_ptr(),
+            pad_base: reg.as_mut_ptr(),
+            offset: 0,
+        };
+        let daisy = Some(Daisy::new(reg.as_mut_ptr() as usize, 1));
+        prepare_pin(&mut erased, 3, SionRequirement::Set, daisy);
+        assert_eq!(reg, [SION_BIT | 3]);
+
+        // The daisy shares the pad register, so it must follow the configuration.
+        let (mut mux, mut pad) = ([0x7; 1], [0; 1]);
+        let mut erased = ErasedPad {
+            mux_base: mux.as_mut_ptr(),
+            pad_base: pad.as_mut_ptr(),
+            offset: 0,
+        };
+        let daisy = Some(Daisy::new(pad.as_mut_ptr() as usize, 1));
+        let config = Config::zero().set_pull_keeper(Some(PullKeeper::Keeper));
+        prepare_pin_configured(&mut erased, config, 2, SionRequirement::Clear, daisy);
+        assert_eq!((mux, pad), ([2], [1]));
+
+        // The pad shares the mux register, so the mux must follow the configuration.
+        // Config::zero() clears bit 0, and ALT1 sets it.
+        let mut reg = [0x7; 1];
+        let mut erased = ErasedPad {
+            mux_base: reg.as_mut_ptr(),
+            pad_base: reg.as_mut_ptr(),
+            offset: 0,
+        };
+        prepare_pin_configured(&mut erased, config, 1, SionRequirement::Clear, None);
+        assert_eq!(reg, [PullKeeper::Keeper as u32 | 1]);
+    }
+
+    #[test]
+    fn prepare_idempotent_skips_matching_writes() {
+        let (mut mux, mut pad, mut select) = ([0x7; 1], [0; 1], [0; 1]);
+        let mut erased = ErasedPad {
+            mux_base: mux.as_mut_ptr(),
+            pad_base: pad.as_mut_ptr(),
+            offset: 0,
+        };
+        let daisy = Some(Daisy::new(select.as_mut_ptr() as usize, 1));
+        let config = Config::zero().set_pull_keeper(Some(PullKeeper::Keeper));
+
+        assert!(prepare_pin_configured_idempotent(
+            &mut erased,
+            config,
+            3,
+            SionRequirement::Set,
+            daisy
+        ));
+        assert_eq!(
+            (mux, pad, select),
+            ([SION_BIT | 3], [PullKeeper::Keeper as u32], [1])
+        );
+
+        // Everything matches, so nothing is written
+        assert!(!prepare_pin_configured_idempotent(
+            &mut erased,
+            config,
+            3,
+            SionRequirement::Set,
+            daisy
+        ));
+        assert!(!prepare_pin_idempotent(
+            &mut erased,
+            3,
+            SionRequirement::DontCare,
+            daisy
+        ));
+
+        // Only the registers that changed are written
+        mux[0] = 3;
+        assert!(prepare_pin_idempotent(
+            &mut erased,
+            3,
+            SionRequirement::Set,
+            daisy
+        ));
+        assert_eq!((mux, select), ([SION_BIT | 3], [1]));
+
+        select[0] = 0;
+        assert!(prepare_pin_idempotent(
+            &mut erased,
+            3,
+            SionRequirement::Set,
+            daisy
+        ));
+        assert_eq!((mux, select), ([SION_BIT | 3], [1]));
+    }
 }
 
 /// ```
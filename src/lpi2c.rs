@@ -1,4 +1,28 @@
 //! I2C pad configuration
+//!
+//! # Bus recovery
+//!
+//! A peripheral that resets in the middle of a transfer can leave the I2C bus
+//! stuck, with SDA held low. To recover the bus, a driver clocks SCL as a GPIO
+//! until the peripheral releases SDA. [`begin_recovery()`] switches an I2C pin
+//! to an open-drain GPIO, and returns a [`RecoveryGuard`]. When the guard drops,
+//! it restores the pin's I2C configuration.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{imxrt1060::gpio_ad_b1::GPIO_AD_B1_00, lpi2c};
+//!
+//! let mut scl = unsafe { GPIO_AD_B1_00::new() };
+//! lpi2c::prepare(&mut scl);
+//!
+//! // The bus is stuck...
+//! let recovery = lpi2c::begin_recovery(&mut scl);
+//! assert_eq!((recovery.gpio_module(), recovery.gpio_offset()), (1, 16));
+//! // Toggle GPIO1_IO16 with your GPIO driver, then:
+//! recovery.finish();
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
 
 /// Tag that indicates the SCL signal
 pub enum Scl {}
@@ -12,9 +36,17 @@ impl Signal for Scl {}
 impl Signal for Sda {}
 
 mod private {
-    pub trait Sealed {}
-    impl Sealed for super::Scl {}
-    impl Sealed for super::Sda {}
+    use crate::function::I2cSignal;
+
+    pub trait Sealed {
+        const SIGNAL: I2cSignal;
+    }
+    impl Sealed for super::Scl {
+        const SIGNAL: I2cSignal = I2cSignal::Scl;
+    }
+    impl Sealed for super::Sda {
+        const SIGNAL: I2cSignal = I2cSignal::Sda;
+    }
 }
 
 /// An I2C pin
@@ -23,20 +55,202 @@ pub trait Pin: super::Iomuxc {
     const ALT: u32;
     /// Daisy register
     const DAISY: super::Daisy;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Set`](crate::SionRequirement::Set).
+    const SION: super::SionRequirement = super::SionRequirement::Set;
     /// I2C Signal
     type Signal: Signal;
     /// I2C module; `U2` for `I2C2`
     type Module: super::consts::Unsigned;
 }
 
+module_aliases!("LPI2C":
+    1 => Lpi2c1 = U1,
+    2 => Lpi2c2 = U2,
+    3 => Lpi2c3 = U3,
+    4 => Lpi2c4 = U4,
+);
+
 /// Prepare an I2C pin
 ///
 /// If you do not call `prepare()` on your I2C pin, it might not work as a I2C
 /// pin.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::set_sion(pin);
-    unsafe { P::DAISY.write() };
+    trace!("lpi2c::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, Some(P::DAISY));
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!("lpi2c::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, Some(P::DAISY))
+}
+
+prepared_pin!("I2C");
+
+/// An I2C pin for I2C module `M`; `PinFor<U2>` for an `I2C2` pin
+///
+/// A pad implements [`Pin`] for one I2C module. When the reference manual routes
+/// more than one I2C module to the same pad, the pad implements `PinFor` for every
+/// module that it supports, including the [`Pin::Module`].
+pub trait PinFor<M: super::consts::Unsigned>: super::Iomuxc {
+    /// Alternate value for this pin
+    const ALT: u32;
+    /// Daisy register
+    const DAISY: super::Daisy;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Set`](crate::SionRequirement::Set).
+    const SION: super::SionRequirement = super::SionRequirement::Set;
+    /// I2C signal
+    type Signal: Signal;
+}
+
+/// Prepare an I2C pin for I2C module `M`
+///
+/// Like [`prepare()`], but selects the routing to module `M`.
+pub fn prepare_for<M: super::consts::Unsigned, P: PinFor<M>>(pin: &mut P) {
+    trace!("lpi2c::prepare_for mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, Some(P::DAISY));
+}
+
+/// Compute the register writes that prepare an I2C pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, Some(P::DAISY))
+}
+
+/// Compute the register writes that prepare an I2C pin for I2C module `M`
+///
+/// Like [`prepared()`], but selects the routing to module `M`.
+pub const fn prepared_for<M, P>(config: super::Config) -> super::PreparedWrites
+where
+    M: super::consts::Unsigned,
+    P: PinFor<M> + super::ConstIomuxc,
+{
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, Some(P::DAISY))
+}
+
+/// An I2C pin that's temporarily muxed as a GPIO, for bus recovery
+///
+/// Created by [`begin_recovery()`]. When the guard drops, it restores the pin's
+/// pad configuration, daisy register, alternate, and SION bit. Use
+/// [`finish()`](RecoveryGuard::finish) to restore the pin at a specific point.
+#[must_use = "dropping the guard immediately restores the I2C configuration"]
+pub struct RecoveryGuard<'a, P>
+where
+    P: Pin + super::gpio::Pin,
+{
+    pin: &'a mut P,
+    mux: u32,
+    pad: u32,
+    daisy: super::Daisy,
+}
+
+/// Mux an I2C pin as an open-drain GPIO, for bus recovery
+///
+/// `begin_recovery()` saves the pin's mux and pad configuration registers, enables
+/// open drain, and selects the GPIO alternate. The SION bit is unchanged, so an
+/// I2C pin keeps its input path; your driver can read back the line while it
+/// toggles the GPIO.
+///
+/// The returned guard restores the I2C configuration when it drops. See the
+/// [module-level documentation](crate::lpi2c#bus-recovery) for an example.
+pub fn begin_recovery<P>(pin: &mut P) -> RecoveryGuard<'_, P>
+where
+    P: Pin + super::gpio::Pin,
+{
+    // Safety: same justification as set_sion.
+    let (mux, pad) = unsafe {
+        (
+            core::ptr::read_volatile(pin.mux()),
+            core::ptr::read_volatile(pin.pad()),
+        )
+    };
+    trace!(
+        "lpi2c::begin_recovery mux={:#010x} value={:#010x} pad={:#010x} value={:#010x}",
+        pin.mux() as usize,
+        mux,
+        pin.pad() as usize,
+        pad
+    );
+    super::prepare_pin_configured(
+        pin,
+        RECOVERY_CONFIG,
+        <P as super::gpio::Pin>::ALT,
+        <P as super::gpio::Pin>::SION,
+        None,
+    );
+    RecoveryGuard {
+        pin,
+        mux,
+        pad,
+        daisy: <P as Pin>::DAISY,
+    }
+}
+
+/// The pad configuration for a recovering pin
+const RECOVERY_CONFIG: super::Config =
+    super::Config::modify().set_open_drain(super::OpenDrain::Enabled);
+
+impl<P> RecoveryGuard<'_, P>
+where
+    P: Pin + super::gpio::Pin,
+{
+    /// Returns the GPIO module; `1` for `GPIO1`
+    pub fn gpio_module(&self) -> u32 {
+        <<P as super::gpio::Pin>::Module as super::consts::Unsigned>::U32
+    }
+
+    /// Returns the GPIO offset; `16` for `GPIO1_IO16`
+    pub fn gpio_offset(&self) -> u32 {
+        <<P as super::gpio::Pin>::Offset as super::consts::Unsigned>::U32
+    }
+
+    /// Restore the I2C configuration
+    ///
+    /// This is the same as dropping the guard.
+    pub fn finish(self) {}
+}
+
+impl<P> Drop for RecoveryGuard<'_, P>
+where
+    P: Pin + super::gpio::Pin,
+{
+    fn drop(&mut self) {
+        trace!(
+            "lpi2c::finish_recovery mux={:#010x} value={:#010x} pad={:#010x} value={:#010x}",
+            self.pin.mux() as usize,
+            self.mux,
+            self.pin.pad() as usize,
+            self.pad
+        );
+        let sion = if self.mux & super::SION_BIT != 0 {
+            super::SionRequirement::Set
+        } else {
+            super::SionRequirement::Clear
+        };
+        // Safety: same justification as set_sion. The saved value came from this
+        // register, so it's restored as-is, without validation.
+        unsafe { super::config::configure_raw(self.pin.pad(), !0, self.pad) };
+        super::prepare_pin(self.pin, self.mux & super::ALT_MASK, sion, Some(self.daisy));
+    }
+}
+
+/// Returns the [`Function`](crate::Function) of an I2C pin
+pub const fn function<P: Pin>() -> super::Function {
+    use super::consts::Unsigned;
+    super::Function::Lpi2c {
+        module: P::Module::U8,
+        signal: <P::Signal as private::Sealed>::SIGNAL,
+    }
 }
 
 #[allow(unused)] // Used in chip-specific modules...
@@ -48,5 +262,102 @@ macro_rules! i2c {
             type Signal = $signal;
             type Module = $module;
         }
+        i2c!(for module: $module, alt: $alt, pad: $pad, signal: $signal, daisy: $daisy);
     };
+    // A routing to another module, for a pad that already implements Pin
+    (for module: $module:ty, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        impl crate::lpi2c::PinFor<$module> for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Daisy = $daisy;
+            type Signal = $signal;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{begin_recovery, Pin, RecoveryGuard, Scl};
+    use crate::{consts::*, Daisy, Iomuxc};
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    impl Pin for FakePad {
+        const ALT: u32 = 3;
+        const DAISY: Daisy = Daisy::new(0x5000, 1);
+        type Signal = Scl;
+        type Module = U1;
+    }
+
+    impl crate::gpio::Pin for FakePad {
+        const ALT: u32 = 5;
+        type Module = U1;
+        type Offset = U16;
+    }
+
+    /// Begin recovery, restoring the daisy into `select` instead of the fake's register
+    fn begin<'a>(pin: &'a mut FakePad, select: &mut [u32; 1]) -> RecoveryGuard<'a, FakePad> {
+        let mut recovery = begin_recovery(pin);
+        recovery.daisy = Daisy::new(select.as_mut_ptr() as usize, 1);
+        recovery
+    }
+
+    #[test]
+    fn recovery_restores_i2c() {
+        // Typical I2C pad configuration
+        let mut pin = FakePad {
+            mux: 1 << 4 | 3,
+            pad: 0xF8B0,
+        };
+        let mut select = [0];
+
+        let recovery = begin(&mut pin, &mut select);
+        assert_eq!((recovery.gpio_module(), recovery.gpio_offset()), (1, 16));
+        recovery.finish();
+
+        assert_eq!(pin.mux, 1 << 4 | 3);
+        assert_eq!(pin.pad, 0xF8B0);
+        assert_eq!(select, [1]);
+    }
+
+    #[test]
+    fn recovery_muxes_open_drain_gpio() {
+        let mut pin = FakePad {
+            mux: 1 << 4 | 3,
+            pad: 0x10B0,
+        };
+        let mut select = [0];
+        {
+            let recovery = begin(&mut pin, &mut select);
+            // SION is unchanged, so the GPIO can read back the line
+            assert_eq!(recovery.pin.mux, 1 << 4 | 5);
+            assert_eq!(recovery.pin.pad, 0x10B0 | 1 << 11);
+        }
+        assert_eq!((pin.mux, pin.pad), (1 << 4 | 3, 0x10B0));
+    }
+
+    #[test]
+    fn recovery_restores_cleared_sion() {
+        let mut pin = FakePad {
+            mux: 1 << 4 | 5,
+            pad: 0,
+        };
+        crate::alternate(&mut pin, 3);
+        crate::clear_sion(&mut pin);
+        drop(begin(&mut pin, &mut [0]));
+        assert_eq!(pin.mux, 3);
+    }
 }
@@ -1,9 +1,20 @@
 //! SPI pad configurations
+//!
+//! # SCK loopback
+//!
+//! At high SPI clock rates, the LPSPI samples the data input using the SCK signal that's
+//! looped back through the SCK pad's input path. The loopback requires the SION bit on
+//! the SCK pad. So, by default, [`prepare()`] sets SION on the SCK pin, and leaves SION
+//! alone on the data and chip select pins. If you configure SPI pads yourself, set SION
+//! on the SCK pad before running the bus faster than a few MHz.
 
 /// A SPI signal
 pub trait Signal: private::Sealed {}
 
 /// A tag that indicates a SPI clock pad
+///
+/// The SCK pin's default SION requirement is [`SionRequirement::Set`](crate::SionRequirement::Set),
+/// which enables the SCK loopback. See the [module-level documentation](crate::lpspi) for more information.
 pub enum Sck {}
 /// A tag that indicates a SPI data out pad
 pub enum Sdo {}
@@ -18,11 +29,27 @@ impl Signal for Sdi {}
 impl Signal for Pcs0 {}
 
 mod private {
-    pub trait Sealed {}
-    impl Sealed for super::Sck {}
-    impl Sealed for super::Sdo {}
-    impl Sealed for super::Sdi {}
-    impl Sealed for super::Pcs0 {}
+    use crate::{function::SpiSignal, SionRequirement};
+
+    pub trait Sealed {
+        /// The signal's default SION requirement
+        const SION: SionRequirement = SionRequirement::DontCare;
+        /// The signal's run-time value
+        const SIGNAL: SpiSignal;
+    }
+    impl Sealed for super::Sck {
+        const SION: SionRequirement = SionRequirement::Set;
+        const SIGNAL: SpiSignal = SpiSignal::Sck;
+    }
+    impl Sealed for super::Sdo {
+        const SIGNAL: SpiSignal = SpiSignal::Sdo;
+    }
+    impl Sealed for super::Sdi {
+        const SIGNAL: SpiSignal = SpiSignal::Sdi;
+    }
+    impl Sealed for super::Pcs0 {
+        const SIGNAL: SpiSignal = SpiSignal::Pcs0;
+    }
 }
 
 /// A SPI pin
@@ -31,12 +58,24 @@ pub trait Pin: super::Iomuxc {
     const ALT: u32;
     /// Daisy register
     const DAISY: super::Daisy;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Set`](crate::SionRequirement::Set) for an SCK pin,
+    /// and [`SionRequirement::DontCare`](crate::SionRequirement::DontCare) for all other pins.
+    const SION: super::SionRequirement = <Self::Signal as private::Sealed>::SION;
     /// SPI signal
     type Signal: Signal;
     /// SPI module; `U3` for `SPI3`
     type Module: super::consts::Unsigned;
 }
 
+module_aliases!("LPSPI":
+    1 => Lpspi1 = U1,
+    2 => Lpspi2 = U2,
+    3 => Lpspi3 = U3,
+    4 => Lpspi4 = U4,
+);
+
 /// Prepare a SPI pin
 ///
 /// If you do not call `prepare()` on your SPI pin, it might work as
@@ -46,9 +85,75 @@ pub trait Pin: super::Iomuxc {
 ///
 /// `prepare()` inherits all the unsafety that comes from the `IOMUX` supertrait.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::set_sion(pin);
-    unsafe { P::DAISY.write() };
+    trace!("lpspi::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, Some(P::DAISY));
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!("lpspi::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, Some(P::DAISY))
+}
+
+prepared_pin!("SPI");
+
+/// A SPI pin for SPI module `M`; `PinFor<U3>` for a `SPI3` pin
+///
+/// A pad implements [`Pin`] for one SPI module. When the reference manual routes
+/// more than one SPI module to the same pad, the pad implements `PinFor` for every
+/// module that it supports, including the [`Pin::Module`].
+pub trait PinFor<M: super::consts::Unsigned>: super::Iomuxc {
+    /// Alternate value for this pin
+    const ALT: u32;
+    /// Daisy register
+    const DAISY: super::Daisy;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Set`](crate::SionRequirement::Set) for an SCK pin,
+    /// and [`SionRequirement::DontCare`](crate::SionRequirement::DontCare) for all other pins.
+    const SION: super::SionRequirement = <Self::Signal as private::Sealed>::SION;
+    /// SPI signal
+    type Signal: Signal;
+}
+
+/// Prepare a SPI pin for SPI module `M`
+///
+/// Like [`prepare()`], but selects the routing to module `M`.
+pub fn prepare_for<M: super::consts::Unsigned, P: PinFor<M>>(pin: &mut P) {
+    trace!("lpspi::prepare_for mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, Some(P::DAISY));
+}
+
+/// Compute the register writes that prepare a SPI pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, Some(P::DAISY))
+}
+
+/// Compute the register writes that prepare a SPI pin for SPI module `M`
+///
+/// Like [`prepared()`], but selects the routing to module `M`.
+pub const fn prepared_for<M, P>(config: super::Config) -> super::PreparedWrites
+where
+    M: super::consts::Unsigned,
+    P: PinFor<M> + super::ConstIomuxc,
+{
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, Some(P::DAISY))
+}
+
+/// Returns the [`Function`](crate::Function) of a SPI pin
+pub const fn function<P: Pin>() -> super::Function {
+    use super::consts::Unsigned;
+    super::Function::Lpspi {
+        module: P::Module::U8,
+        signal: <P::Signal as private::Sealed>::SIGNAL,
+    }
 }
 
 #[allow(unused)] // Used in chip-specific modules...
@@ -60,5 +165,67 @@ macro_rules! spi {
             type Signal = $signal;
             type Module = $module;
         }
+        spi!(for module: $module, alt: $alt, pad: $pad, signal: $signal, daisy: $daisy);
     };
+    // A routing to another module, for a pad that already implements Pin
+    (for module: $module:ty, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        impl crate::lpspi::PinFor<$module> for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Daisy = $daisy;
+            type Signal = $signal;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prepared, Pcs0, Pin, Sck, Sdi, Sdo, Signal};
+    use crate::{consts::U1, Config, ConstIomuxc, Daisy, Iomuxc, PreparedWrites};
+    use core::marker::PhantomData;
+
+    struct FakePad<S> {
+        mux: u32,
+        pad: u32,
+        _signal: PhantomData<S>,
+    }
+
+    impl<S> crate::private::Sealed for FakePad<S> {}
+
+    unsafe impl<S> Iomuxc for FakePad<S> {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// Only used to compute descriptors; nothing writes these addresses
+    unsafe impl<S> ConstIomuxc for FakePad<S> {
+        const MUX: usize = 0x401f_8000;
+        const PAD: usize = 0x401f_8200;
+    }
+
+    impl<S: Signal> Pin for FakePad<S> {
+        const ALT: u32 = 3;
+        const DAISY: Daisy = Daisy::new(0x401f84f0, 0);
+        type Signal = S;
+        type Module = U1;
+    }
+
+    fn writes<S: Signal>() -> PreparedWrites {
+        prepared::<FakePad<S>>(Config::zero())
+    }
+
+    #[test]
+    fn sion_only_on_sck() {
+        assert_eq!(writes::<Sck>().mux_val, 1 << 4 | 3);
+        let muxes = [
+            writes::<Sdo>().mux_val,
+            writes::<Sdi>().mux_val,
+            writes::<Pcs0>().mux_val,
+        ];
+        assert_eq!(muxes, [3; 3]);
+        assert_eq!(writes::<Sck>().daisy, Some((0x401f84f0, 0)));
+    }
 }
@@ -1,20 +1,148 @@
 //! UART pad configuration
+//!
+//! Use [`prepare()`] to prepare a UART pin in place. Or, use [`prepare_owned()`]
+//! to prepare the pin, and get a [`PreparedPin`] token. A driver that requires
+//! the token cannot receive a pin that wasn't prepared.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::*, lpuart};
+//!
+//! // Prepare in place
+//! let mut tx = unsafe { GPIO_AD_B0_12::new() };
+//! lpuart::prepare(&mut tx);
+//!
+//! // Prepare, and take the token
+//! # struct Uart;
+//! fn uart_new<T, R>(tx: lpuart::PreparedPin<T>, rx: lpuart::PreparedPin<R>) -> Uart
+//! where
+//!     T: lpuart::Pin<Direction = lpuart::Tx>,
+//!     R: lpuart::Pin<Direction = lpuart::Rx, Module = <T as lpuart::Pin>::Module>,
+//! {
+//!     // Configure the UART...
+//!     # Uart
+//! }
+//!
+//! let rx = unsafe { GPIO_AD_B0_13::new() };
+//! let uart = uart_new(lpuart::prepare_owned(tx), lpuart::prepare_owned(rx));
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! A bare pin is not a prepared pin:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::*, lpuart};
+//!
+//! fn uart_new<T: lpuart::Pin, R: lpuart::Pin>(tx: lpuart::PreparedPin<T>, rx: lpuart::PreparedPin<R>) {}
+//!
+//! let tx = unsafe { GPIO_AD_B0_12::new() };
+//! let rx = unsafe { GPIO_AD_B0_13::new() };
+//! uart_new(tx, rx);
+//! ```
+//!
+//! # Glitch-free TX
+//!
+//! When a pad switches from its reset state to UART TX, the line may briefly go
+//! low. The attached device could see that as a start bit, and report a framing error.
+//! [`prepare_tx_glitch_free()`] pulls the pad up *before* it switches the pad to
+//! UART TX. After the UART drives its idle level, call [`remove_tx_pull()`] to
+//! remove the pull. This crate does not wait between the two calls; your driver
+//! decides how long to wait.
+//!
+//! The pull-up only holds the line while nothing drives it. If the UART transmitter
+//! is disabled when the mux switches, the UART may still drive the line low. The
+//! complete fix is to enable the UART before you prepare the TX pin.
+//!
+//! # Single-wire
+//!
+//! In single-wire (half-duplex) mode, the UART transmits and receives on its TX
+//! pad. [`prepare_single_wire()`] prepares the TX pad for that mode: it sets the SION
+//! bit, so that the UART receives from the pad, and it enables open drain with a
+//! 22KOhm pull-up, so that either side may pull the line low. The RX pad isn't
+//! used; leave it unconnected, and don't prepare it.
+//!
+//! # Swapped TX and RX
+//!
+//! The LPUARTs of the supported processors can't exchange their TXD and RXD
+//! signals. A board that swaps the two lines can only recover if its TX pad can
+//! also be the module's RX pin, and its RX pad the module's TX pin, on some other
+//! alternate. [`TableEntry::swapped()`] finds the other role of a pin in a chip's
+//! [`PinTable`], and [`suggest_swap()`] checks a [`Plan`] for a swap that the
+//! chip supports.
+//!
+//! On the i.MX RT 1010 and 1060, no pad is both a TX and an RX pin of the same
+//! UART module, so `suggest_swap()` returns `None` for every plan.
+
+use crate::{PadId, PadLookup, Plan, RoutingError};
 
 /// Type tag for the transfer pin
 pub enum Tx {}
 /// Type tag for the receive pin
 pub enum Rx {}
 
+/// Type tag for the data set ready (DSR) modem input
+pub enum Dsr {}
+/// Type tag for the data terminal ready (DTR) modem output
+pub enum Dtr {}
+/// Type tag for the data carrier detect (DCD) modem input
+pub enum Dcd {}
+/// Type tag for the ring indicator (RI) modem input
+pub enum Ri {}
+
 /// A pin direction, either transfer or receive
+///
+/// `Dsr`, `Dtr`, `Dcd`, and `Ri` describe the modem control signals of a 9-pin
+/// serial port. They're available so that a serial HAL may type these signals.
+/// However, no pads implement a `Pin` with these directions. In the i.MX RT 1060
+/// and 1010 reference manuals, the LPUART chapter's external signals table lists
+/// only `TXD`, `RXD`, `CTS_B`, and `RTS_B`, and the IOMUXC chapter's mux tables
+/// have no `LPUARTn_DSR`, `LPUARTn_DTR`, `LPUARTn_DCD`, or `LPUARTn_RI`
+/// alternates. Drive these signals with GPIOs.
 pub trait Direction: private::Sealed {}
 
 impl Direction for Tx {}
 impl Direction for Rx {}
+impl Direction for Dsr {}
+impl Direction for Dtr {}
+impl Direction for Dcd {}
+impl Direction for Ri {}
 
 mod private {
-    pub trait Sealed {}
-    impl Sealed for super::Tx {}
-    impl Sealed for super::Rx {}
+    use crate::function::UartDirection;
+
+    /// A direction that's known at run time
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Kind {
+        Tx,
+        Rx,
+        Modem,
+    }
+
+    pub trait Sealed {
+        const KIND: Kind = Kind::Modem;
+        const DIRECTION: UartDirection;
+    }
+    impl Sealed for super::Tx {
+        const KIND: Kind = Kind::Tx;
+        const DIRECTION: UartDirection = UartDirection::Tx;
+    }
+    impl Sealed for super::Rx {
+        const KIND: Kind = Kind::Rx;
+        const DIRECTION: UartDirection = UartDirection::Rx;
+    }
+    impl Sealed for super::Dsr {
+        const DIRECTION: UartDirection = UartDirection::Dsr;
+    }
+    impl Sealed for super::Dtr {
+        const DIRECTION: UartDirection = UartDirection::Dtr;
+    }
+    impl Sealed for super::Dcd {
+        const DIRECTION: UartDirection = UartDirection::Dcd;
+    }
+    impl Sealed for super::Ri {
+        const DIRECTION: UartDirection = UartDirection::Ri;
+    }
 }
 
 /// A UART pin
@@ -23,12 +151,27 @@ pub trait Pin: super::Iomuxc {
     const ALT: u32;
     /// The daisy register which will select the pad
     const DAISY: Option<super::Daisy>;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Clear`](crate::SionRequirement::Clear).
+    const SION: super::SionRequirement = super::SionRequirement::Clear;
     /// Pin direction
     type Direction: Direction;
     /// UART module; `U3` for `UART3`
     type Module: super::consts::Unsigned;
 }
 
+module_aliases!("LPUART":
+    1 => Lpuart1 = U1,
+    2 => Lpuart2 = U2,
+    3 => Lpuart3 = U3,
+    4 => Lpuart4 = U4,
+    5 => Lpuart5 = U5,
+    6 => Lpuart6 = U6,
+    7 => Lpuart7 = U7,
+    8 => Lpuart8 = U8,
+);
+
 /// Prepare a UART pin
 ///
 /// If you do not call `prepare()` on your UART pin, it might not work as a UART
@@ -40,10 +183,300 @@ pub trait Pin: super::Iomuxc {
 /// In particular, we cannot be sure that the implementation's pointers are correct.
 /// It may also write a daisy configuration that's incorrect.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::clear_sion(pin);
-    if let Some(daisy) = P::DAISY {
-        unsafe { daisy.write() };
+    trace!("lpuart::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, P::DAISY);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!(
+        "lpuart::prepare_idempotent mux={:#010x}",
+        pin.mux() as usize
+    );
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, P::DAISY)
+}
+
+prepared_pin!("UART");
+
+/// Prepare a UART TX pin, pulling the pad up before the mux switch
+///
+/// `prepare_tx_glitch_free()` enables the 22KOhm pull-up on the pad, then does the
+/// work of [`prepare()`]. The call only modifies the pad's pull / keeper fields. Use
+/// [`remove_tx_pull()`] to remove the pull-up. See [Glitch-free TX](crate::lpuart#glitch-free-tx)
+/// for the limitations.
+pub fn prepare_tx_glitch_free<P: Pin<Direction = Tx>>(pin: &mut P) {
+    trace!(
+        "lpuart::prepare_tx_glitch_free mux={:#010x}",
+        pin.mux() as usize
+    );
+    let config = super::Config::modify().set_pull_keeper(Some(super::PullKeeper::Pullup22k));
+    super::prepare_pin_configured(pin, config, P::ALT, P::SION, P::DAISY);
+}
+
+/// Remove the pull-up applied by [`prepare_tx_glitch_free()`]
+///
+/// Disables the pad's pull / keeper. The call only modifies the pull / keeper
+/// fields, and it doesn't touch the mux register.
+pub fn remove_tx_pull<P: Pin<Direction = Tx>>(pin: &mut P) {
+    super::configure(pin, super::Config::modify().set_pull_keeper(None));
+}
+
+/// Prepare a UART TX pin for single-wire mode
+///
+/// `prepare_single_wire()` enables open drain and the 22KOhm pull-up on the pad, then
+/// does the work of [`prepare()`], and sets the SION bit regardless of the pin's
+/// SION requirement. The call only modifies the pad's open drain and pull / keeper
+/// fields. See [Single-wire](crate::lpuart#single-wire) for more information.
+///
+/// With debug assertions, `prepare_single_wire()` panics if the pad doesn't
+/// support open drain.
+pub fn prepare_single_wire<P: Pin<Direction = Tx>>(pin: &mut P) {
+    trace!(
+        "lpuart::prepare_single_wire mux={:#010x}",
+        pin.mux() as usize
+    );
+    let config = super::Config::modify()
+        .set_open_drain(super::OpenDrain::Enabled)
+        .set_pull_keeper(Some(super::PullKeeper::Pullup22k));
+    super::prepare_pin_configured(pin, config, P::ALT, super::SionRequirement::Set, P::DAISY);
+}
+
+/// A UART pin for UART module `M`; `PinFor<U3>` for a `UART3` pin
+///
+/// A pad implements [`Pin`] for one UART module. But the reference manual may route
+/// more than one UART module to the same pad, each on a different alternate. A pad
+/// implements `PinFor` for every UART module that it supports, including the
+/// [`Pin::Module`]. Bound your driver on `PinFor` to accept any of those routings:
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{consts::*, imxrt1060::gpio_ad_b0::*, lpuart};
+///
+/// # struct Uart<M>(core::marker::PhantomData<M>);
+/// fn uart_new<M, T, R>(mut tx: T, mut rx: R) -> Uart<M>
+/// where
+///     M: Unsigned,
+///     T: lpuart::PinFor<M, Direction = lpuart::Tx>,
+///     R: lpuart::PinFor<M, Direction = lpuart::Rx>,
+/// {
+///     lpuart::prepare_for::<M, _>(&mut tx);
+///     lpuart::prepare_for::<M, _>(&mut rx);
+///     // Configure the UART...
+///     # Uart(core::marker::PhantomData)
+/// }
+///
+/// let uart1: Uart<U1> = uart_new(unsafe { GPIO_AD_B0_12::new() }, unsafe { GPIO_AD_B0_13::new() });
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+pub trait PinFor<M: super::consts::Unsigned>: super::Iomuxc {
+    /// The alternate value for the UART pin
+    const ALT: u32;
+    /// The daisy register which will select the pad
+    const DAISY: Option<super::Daisy>;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Clear`](crate::SionRequirement::Clear).
+    const SION: super::SionRequirement = super::SionRequirement::Clear;
+    /// Pin direction
+    type Direction: Direction;
+}
+
+/// Prepare a UART pin for UART module `M`
+///
+/// Like [`prepare()`], but selects the routing to module `M`.
+pub fn prepare_for<M: super::consts::Unsigned, P: PinFor<M>>(pin: &mut P) {
+    trace!("lpuart::prepare_for mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, P::DAISY);
+}
+
+/// Compute the register writes that prepare a UART pin for UART module `M`
+///
+/// Like [`prepared()`], but selects the routing to module `M`.
+pub const fn prepared_for<M, P>(config: super::Config) -> super::PreparedWrites
+where
+    M: super::consts::Unsigned,
+    P: PinFor<M> + super::ConstIomuxc,
+{
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, P::DAISY)
+}
+
+/// Compute the register writes that prepare a UART pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, P::DAISY)
+}
+
+/// A UART pin in a chip's table of UART pins
+///
+/// Use [`new()`](TableEntry::new) to describe a pin. See [`PinTable`] for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableEntry {
+    pad: PadId,
+    module: u8,
+    kind: private::Kind,
+    alt: u32,
+    sion: super::SionRequirement,
+    daisy: Option<super::Daisy>,
+}
+
+impl TableEntry {
+    /// Describe pad `P` as a UART pin for UART module `M`
+    pub const fn new<M, P>() -> Self
+    where
+        M: super::consts::Unsigned,
+        P: PinFor<M> + super::ConstIomuxc,
+    {
+        TableEntry {
+            pad: PadId::of::<P>(),
+            module: M::U8,
+            kind: <P::Direction as private::Sealed>::KIND,
+            alt: P::ALT,
+            sion: P::SION,
+            daisy: P::DAISY,
+        }
+    }
+}
+
+impl TableEntry {
+    /// Returns the pin's pad
+    pub const fn pad(&self) -> PadId {
+        self.pad
+    }
+
+    /// Returns the pin's UART module; `3` for `UART3`
+    pub const fn module(&self) -> u8 {
+        self.module
+    }
+
+    /// Returns the pin for the same pad and UART module, in the other direction
+    ///
+    /// For a TX pin, that's the RX pin on the same pad, if `pins` has one. Returns
+    /// `None` if `pins` doesn't have the other pin, or if this is not a TX or RX pin.
+    pub fn swapped(&self, pins: &[TableEntry]) -> Option<TableEntry> {
+        let kind = match self.kind {
+            private::Kind::Tx => private::Kind::Rx,
+            private::Kind::Rx => private::Kind::Tx,
+            private::Kind::Modem => return None,
+        };
+        pins.iter()
+            .find(|entry| {
+                entry.pad == self.pad && entry.module == self.module && entry.kind == kind
+            })
+            .copied()
+    }
+}
+
+/// The TX and RX pads of a UART module
+///
+/// Returned from [`suggest_swap()`]. Prepare the pads with [`prepare_erased()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Swap {
+    /// The UART module; `3` for `UART3`
+    pub module: u8,
+    /// The TX pad, which the plan routes as RX
+    pub tx: PadId,
+    /// The RX pad, which the plan routes as TX
+    pub rx: PadId,
+}
+
+/// Suggest a routing of UART `module` that swaps the TX and RX pads in `plan`
+///
+/// `suggest_swap()` finds the TX and RX pins of `module` in `plan`, using the chip's
+/// table of UART pins. If the TX pad can also be the module's RX pin, and the RX
+/// pad its TX pin, `suggest_swap()` returns those pads. Returns `None` if `plan`
+/// doesn't route both pins, or if the chip can't swap them. See [Swapped TX and RX](crate::lpuart#swapped-tx-and-rx)
+/// for more information.
+pub fn suggest_swap<T>(pads: &T, plan: &Plan<'_>, module: u8) -> Option<Swap>
+where
+    T: PinTable + ?Sized,
+{
+    let pins = pads.pins();
+    let find = |kind| {
+        plan.routings().iter().find_map(|routing| {
+            pins.iter().find(|entry| {
+                entry.module == module
+                    && entry.kind == kind
+                    && entry.pad == routing.pad
+                    && entry.alt == routing.alt as u32
+            })
+        })
+    };
+    let (tx, rx) = (find(private::Kind::Tx)?, find(private::Kind::Rx)?);
+    Some(Swap {
+        module,
+        tx: rx.swapped(pins)?.pad,
+        rx: tx.swapped(pins)?.pad,
+    })
+}
+
+/// Erased pads that know their UART pins
+///
+/// Each chip's `ErasedPads` implements `PinTable`, listing all of the chip's UART pins.
+/// Use it with [`prepare_erased()`].
+pub trait PinTable: PadLookup {
+    /// Returns all of the UART pins
+    fn pins(&self) -> &[TableEntry];
+}
+
+/// Prepare the TX and RX pins of UART `module`, using type-erased pads
+///
+/// `prepare_erased()` finds the `tx` and `rx` pads in `pads`, and makes sure that
+/// they're the TX and RX pins of `module`. Then, it performs the same writes as
+/// [`prepare()`] on both pins. If either pin is invalid, nothing is written.
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::{gpio_ad_b0::*, Pads}, lpuart, PadId};
+///
+/// let mut pads = unsafe { Pads::new() }.erase();
+/// lpuart::prepare_erased(
+///     &mut pads,
+///     1,
+///     PadId::of::<GPIO_AD_B0_12>(),
+///     PadId::of::<GPIO_AD_B0_13>(),
+/// ).unwrap();
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+pub fn prepare_erased<T>(pads: &mut T, module: u8, tx: PadId, rx: PadId) -> Result<(), RoutingError>
+where
+    T: PinTable + ?Sized,
+{
+    for &id in &[tx, rx] {
+        pads.find_mut(id).ok_or(RoutingError::UnknownPad(id))?;
+    }
+    let find = |kind, id| {
+        pads.pins()
+            .iter()
+            .find(|entry| entry.module == module && entry.kind == kind && entry.pad == id)
+            .copied()
+            .ok_or(RoutingError::UnsupportedPad(id))
+    };
+    let entries = [find(private::Kind::Tx, tx)?, find(private::Kind::Rx, rx)?];
+
+    for entry in &entries {
+        if let Some(pad) = pads.find_mut(entry.pad) {
+            trace!("lpuart::prepare_erased mux={:#010x}", entry.pad.mux_addr());
+            super::prepare_pin(pad, entry.alt, entry.sion, entry.daisy);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the [`Function`](crate::Function) of a UART pin
+pub const fn function<P: Pin>() -> super::Function {
+    use super::consts::Unsigned;
+    super::Function::Lpuart {
+        module: P::Module::U8,
+        direction: <P::Direction as private::Sealed>::DIRECTION,
     }
 }
 
@@ -56,5 +489,447 @@ macro_rules! uart {
             type Direction = $direction;
             type Module = $module;
         }
+        uart!(for module: $module, alt: $alt, pad: $pad, direction: $direction, daisy: $daisy);
     };
+    // A routing to another module, for a pad that already implements Pin
+    (for module: $module:ty, alt: $alt:expr, pad: $pad:ty, direction: $direction:ty, daisy: $daisy:expr) => {
+        impl crate::lpuart::PinFor<$module> for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
+            type Direction = $direction;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{prepare, prepare_erased, prepare_for, prepare_idempotent, private::Kind};
+    use super::{
+        prepare_single_wire, prepare_tx_glitch_free, remove_tx_pull, PinTable, TableEntry,
+    };
+    use super::{suggest_swap, Pin, PinFor, Rx, Swap, Tx};
+    use super::{Dcd, Direction, Dsr, Dtr, Ri};
+    use crate::{consts::*, ConstIomuxc, Daisy, ErasedPad, Iomuxc, PadId, PadLookup};
+    use crate::{Config, PullKeeper, Routing, RoutingError, SionRequirement};
+
+    struct FakePad<const SION: bool> {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl<const SION: bool> crate::private::Sealed for FakePad<SION> {}
+
+    unsafe impl<const SION: bool> Iomuxc for FakePad<SION> {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// Uses the default SION requirement
+    impl Pin for FakePad<false> {
+        const ALT: u32 = 3;
+        const DAISY: Option<Daisy> = None;
+        type Direction = Rx;
+        type Module = U1;
+    }
+
+    /// Overrides the default SION requirement
+    impl Pin for FakePad<true> {
+        const ALT: u32 = 2;
+        const DAISY: Option<Daisy> = None;
+        const SION: SionRequirement = SionRequirement::Set;
+        type Direction = Tx;
+        type Module = U1;
+    }
+
+    fn modem_function<D: Direction>(name: &str) {
+        use crate::Function;
+        use std::string::ToString;
+
+        let function = Function::Lpuart {
+            module: 1,
+            direction: D::DIRECTION,
+        };
+        assert_eq!(D::KIND, Kind::Modem);
+        assert_eq!(function.to_string(), name);
+        let bits = function.to_bits().unwrap();
+        assert_eq!(Function::from_bits(bits), Some(function));
+    }
+
+    #[test]
+    fn modem_signals() {
+        modem_function::<Dsr>("LPUART1_DSR");
+        modem_function::<Dtr>("LPUART1_DTR");
+        modem_function::<Dcd>("LPUART1_DCD");
+        modem_function::<Ri>("LPUART1_RI");
+    }
+
+    #[test]
+    fn sion_clear() {
+        let mut pad = FakePad::<false> {
+            mux: 1 << 4,
+            pad: 0,
+        };
+        assert_eq!(<FakePad<false> as Pin>::SION, SionRequirement::Clear);
+        prepare(&mut pad);
+        assert_eq!(pad.mux & (1 << 4), 0);
+    }
+
+    #[test]
+    fn prepare_owned_release() {
+        let pad = FakePad::<true> { mux: 0, pad: 0 };
+        let prepared = super::prepare_owned(pad);
+        let pad = prepared.release();
+        assert_eq!(pad.mux, 1 << 4 | 2);
+    }
+
+    #[test]
+    fn adopt_configured_pad() {
+        let pad = FakePad::<true> {
+            mux: 1 << 4 | 2,
+            pad: 0x10B0,
+        };
+        let prepared: super::PreparedPin<_> = unsafe { crate::ConfiguredPad::assume(pad) }.into();
+        let pad = prepared.release();
+        assert_eq!((pad.mux, pad.pad), (1 << 4 | 2, 0x10B0));
+    }
+
+    #[test]
+    fn idempotent_prepare() {
+        let mut pad = FakePad::<true> { mux: 2, pad: 0 };
+        assert!(prepare_idempotent(&mut pad));
+        assert_eq!(pad.mux, 1 << 4 | 2);
+
+        assert!(!prepare_idempotent(&mut pad));
+        assert_eq!(pad.mux, 1 << 4 | 2);
+    }
+
+    #[test]
+    fn sion_set() {
+        let mut pad = FakePad::<true> { mux: 0, pad: 0 };
+        prepare(&mut pad);
+        assert_eq!(pad.mux, 1 << 4 | 2);
+    }
+
+    /// One pad, routed to UART2 and UART3 on different alternates
+    struct TwoModules {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for TwoModules {}
+
+    unsafe impl Iomuxc for TwoModules {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// Only used to compute descriptors; nothing writes these addresses
+    unsafe impl ConstIomuxc for TwoModules {
+        const MUX: usize = 0x401f_8000;
+        const PAD: usize = 0x401f_8200;
+    }
+
+    uart!(module: U2, alt: 2, pad: TwoModules, direction: Tx, daisy: None);
+    uart!(for module: U3, alt: 6, pad: TwoModules, direction: Rx, daisy: Some(Daisy::new(0x401f8540, 1)));
+
+    fn module_of<M: Unsigned, P: PinFor<M>>(pin: &mut P) -> u32 {
+        prepare_for::<M, P>(pin);
+        M::U32
+    }
+
+    #[test]
+    fn pin_for_two_modules() {
+        let mut pad = TwoModules { mux: 0, pad: 0 };
+        assert_eq!(module_of::<U2, _>(&mut pad), 2);
+        assert_eq!(pad.mux, 2);
+
+        // The UART3 routing has a daisy register, so check its writes
+        // without committing them.
+        let uart2 = super::prepared_for::<U2, TwoModules>(Config::zero());
+        assert_eq!((uart2.mux_val, uart2.daisy), (2, None));
+        let uart3 = super::prepared_for::<U3, TwoModules>(Config::zero());
+        assert_eq!((uart3.mux_val, uart3.daisy), (6, Some((0x401f8540, 1))));
+    }
+
+    fn is_tx<M: Unsigned, P: PinFor<M, Direction = Tx>>(_: &P) -> bool {
+        true
+    }
+
+    fn is_rx<M: Unsigned, P: PinFor<M, Direction = Rx>>(_: &P) -> bool {
+        true
+    }
+
+    #[test]
+    fn pin_for_direction() {
+        let pad = TwoModules { mux: 0, pad: 0 };
+        assert!(is_tx::<U2, _>(&pad));
+        assert!(is_rx::<U3, _>(&pad));
+    }
+
+    /// Three erased pads: a UART2 TX pin, a UART2 RX pin, and a UART3 RX pin
+    ///
+    /// The UART2 pins select themselves in the two `select` daisy registers.
+    struct FakePads {
+        pads: [ErasedPad; 3],
+        pins: [TableEntry; 3],
+    }
+
+    impl FakePads {
+        fn new(mux: &mut [u32; 3], pad: &mut [u32; 3], select: &mut [u32; 2]) -> Self {
+            let mut erased = |offset| ErasedPad {
+                mux_base: mux.as_mut_ptr(),
+                pad_base: pad.as_mut_ptr(),
+                offset,
+            };
+            let pads = [erased(0), erased(1), erased(2)];
+            let select = select.as_mut_ptr() as usize;
+            let entry = |pad: &ErasedPad, module, kind, alt, daisy| TableEntry {
+                pad: pad.id(),
+                module,
+                kind,
+                alt,
+                sion: SionRequirement::Clear,
+                daisy,
+            };
+            let pins = [
+                entry(&pads[0], 2, Kind::Tx, 2, Some(Daisy::new(select, 1))),
+                entry(&pads[1], 2, Kind::Rx, 2, Some(Daisy::new(select + 4, 0))),
+                entry(&pads[2], 3, Kind::Rx, 6, None),
+            ];
+            FakePads { pads, pins }
+        }
+    }
+
+    impl PadLookup for FakePads {
+        fn find_mut(&mut self, id: PadId) -> Option<&mut ErasedPad> {
+            self.pads.find_mut(id)
+        }
+    }
+
+    impl PinTable for FakePads {
+        fn pins(&self) -> &[TableEntry] {
+            &self.pins
+        }
+    }
+
+    #[test]
+    fn prepare_erased_valid() {
+        let (mut mux, mut pad, mut select) = ([1 << 4 | 5; 3], [0; 3], [5; 2]);
+        let mut pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        let (tx, rx) = (pads.pads[0].id(), pads.pads[1].id());
+        assert_eq!(prepare_erased(&mut pads, 2, tx, rx), Ok(()));
+
+        assert_eq!(mux, [2, 2, 1 << 4 | 5]);
+        assert_eq!(pad, [0; 3]);
+        assert_eq!(select, [1, 0]);
+    }
+
+    #[test]
+    fn prepare_erased_unknown_pad() {
+        let (mut mux, mut pad, mut select) = ([1 << 4 | 5; 3], [0; 3], [5; 2]);
+        let mut pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        let tx = pads.pads[0].id();
+        assert_eq!(
+            prepare_erased(&mut pads, 2, tx, PadId(0x1234)),
+            Err(RoutingError::UnknownPad(PadId(0x1234)))
+        );
+
+        assert_eq!(mux, [1 << 4 | 5; 3]);
+        assert_eq!(select, [5; 2]);
+    }
+
+    #[test]
+    fn prepare_erased_unsupported_pad() {
+        let (mut mux, mut pad, mut select) = ([1 << 4 | 5; 3], [0; 3], [5; 2]);
+        let mut pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        let ids = [pads.pads[0].id(), pads.pads[1].id(), pads.pads[2].id()];
+
+        // The UART3 RX pin isn't a UART2 pin
+        assert_eq!(
+            prepare_erased(&mut pads, 2, ids[0], ids[2]),
+            Err(RoutingError::UnsupportedPad(ids[2]))
+        );
+        // The UART2 pins aren't UART3 pins
+        assert_eq!(
+            prepare_erased(&mut pads, 3, ids[0], ids[1]),
+            Err(RoutingError::UnsupportedPad(ids[0]))
+        );
+        // The UART2 RX pin isn't a TX pin
+        assert_eq!(
+            prepare_erased(&mut pads, 2, ids[1], ids[0]),
+            Err(RoutingError::UnsupportedPad(ids[1]))
+        );
+
+        assert_eq!(mux, [1 << 4 | 5; 3]);
+        assert_eq!(select, [5; 2]);
+    }
+
+    /// Pads 0 and 1 are UART2's TX and RX pins on alternate 2, and
+    /// its RX and TX pins on alternate 4
+    struct SwapTable {
+        pins: [TableEntry; 4],
+    }
+
+    impl SwapTable {
+        fn new() -> Self {
+            let entry = |offset, kind, alt| TableEntry {
+                pad: PadId(offset),
+                module: 2,
+                kind,
+                alt,
+                sion: SionRequirement::Clear,
+                daisy: None,
+            };
+            SwapTable {
+                pins: [
+                    entry(0, Kind::Tx, 2),
+                    entry(4, Kind::Rx, 2),
+                    entry(0, Kind::Rx, 4),
+                    entry(4, Kind::Tx, 4),
+                ],
+            }
+        }
+    }
+
+    impl PadLookup for SwapTable {
+        fn find_mut(&mut self, _: PadId) -> Option<&mut ErasedPad> {
+            None
+        }
+    }
+
+    impl PinTable for SwapTable {
+        fn pins(&self) -> &[TableEntry] {
+            &self.pins
+        }
+    }
+
+    fn routing(pad: PadId, alt: u8) -> Routing {
+        Routing {
+            pad,
+            alt,
+            sion: SionRequirement::Clear,
+            daisy: None,
+            config: Config::zero(),
+            peripheral: None,
+            function: None,
+        }
+    }
+
+    #[test]
+    fn swapped_entry() {
+        let table = SwapTable::new();
+        let pins = table.pins();
+        assert_eq!(pins[0].swapped(pins), Some(pins[2]));
+        assert_eq!(pins[3].swapped(pins), Some(pins[1]));
+        assert_eq!(pins[0].swapped(&pins[..2]), None);
+
+        let (mut mux, mut pad, mut select) = ([0; 3], [0; 3], [0; 2]);
+        let pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        assert!(pads
+            .pins()
+            .iter()
+            .all(|pin| pin.swapped(pads.pins()).is_none()));
+    }
+
+    #[test]
+    fn suggest_swap_pads() {
+        let table = SwapTable::new();
+        let routings = [routing(PadId(0), 2), routing(PadId(4), 2)];
+        let plan = crate::plan(&routings).unwrap();
+        assert_eq!(
+            suggest_swap(&table, &plan, 2),
+            Some(Swap {
+                module: 2,
+                tx: PadId(4),
+                rx: PadId(0),
+            })
+        );
+        // No UART3 pins in the plan
+        assert_eq!(suggest_swap(&table, &plan, 3), None);
+
+        // Only the TX pin is in the plan
+        let plan = crate::plan(&routings[..1]).unwrap();
+        assert_eq!(suggest_swap(&table, &plan, 2), None);
+
+        // Pads on a different alternate aren't UART pins
+        let routings = [routing(PadId(0), 3), routing(PadId(4), 3)];
+        let plan = crate::plan(&routings).unwrap();
+        assert_eq!(suggest_swap(&table, &plan, 2), None);
+    }
+
+    #[test]
+    fn suggest_swap_unsupported() {
+        let (mut mux, mut pad, mut select) = ([0; 3], [0; 3], [0; 2]);
+        let pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        let routings = [routing(pads.pads[0].id(), 2), routing(pads.pads[1].id(), 2)];
+        let plan = crate::plan(&routings).unwrap();
+        assert_eq!(suggest_swap(&pads, &plan, 2), None);
+    }
+
+    #[test]
+    fn tx_pulled_up_before_mux() {
+        // Keeper enabled, open drain
+        let mut pad = FakePad::<true> {
+            mux: 5,
+            pad: PullKeeper::Keeper as u32 | 1 << 11,
+        };
+
+        // prepare_pin_configured() writes the pad before the mux; see
+        // prepare_writes_mux_last.
+        prepare_tx_glitch_free(&mut pad);
+        assert_eq!(pad.mux, 1 << 4 | 2);
+        assert_eq!(pad.pad, PullKeeper::Pullup22k as u32 | 1 << 11);
+
+        remove_tx_pull(&mut pad);
+        assert_eq!(pad.mux, 1 << 4 | 2);
+        assert_eq!(pad.pad, 1 << 11);
+    }
+
+    /// A TX pin with the default SION requirement
+    struct TxPad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for TxPad {}
+
+    unsafe impl Iomuxc for TxPad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    impl Pin for TxPad {
+        const ALT: u32 = 2;
+        const DAISY: Option<Daisy> = None;
+        type Direction = Tx;
+        type Module = U1;
+    }
+
+    #[test]
+    fn single_wire() {
+        // Keeper enabled, fast slew rate
+        let mut pad = TxPad {
+            mux: 5,
+            pad: PullKeeper::Keeper as u32 | 1,
+        };
+        assert_eq!(<TxPad as Pin>::SION, SionRequirement::Clear);
+
+        prepare_single_wire(&mut pad);
+        assert_eq!(pad.mux, 1 << 4 | 2);
+        assert_eq!(pad.pad, PullKeeper::Pullup22k as u32 | 1 << 11 | 1);
+    }
 }
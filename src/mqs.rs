@@ -0,0 +1,119 @@
+//! Medium quality sound (MQS) pad configuration
+//!
+//! MQS generates a PWM audio signal on its left and right pins. MQS doesn't have
+//! its own audio interface; SAI3 drives it internally. While you use MQS, SAI3
+//! isn't available for other pins. [`BORROWS`] records that relationship, and
+//! [`prepared()`] records [`Peripheral::Mqs`](crate::Peripheral::Mqs), so that
+//! [`plan()`](crate::plan) rejects a set of routings with both MQS and SAI3 pins.
+//!
+//! ```
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{imxrt1060::{gpio_b0::*, gpio_emc::*}, consts::U3};
+//! use imxrt_iomuxc::{mqs, plan, sai, Config, Peripheral, PlanError, Routing};
+//!
+//! const CONFIG: Config = Config::zero();
+//! let routings = [
+//!     Routing::from_prepared(&mqs::prepared::<GPIO_B0_00>(CONFIG)),
+//!     Routing::from_prepared(&sai::prepared::<U3, GPIO_EMC_36>(CONFIG)), // SAI3 TX_DATA
+//! ];
+//! assert_eq!(
+//!     plan(&routings),
+//!     Err(PlanError::BorrowConflict {
+//!         peripheral: Peripheral::Sai(3),
+//!         borrower: 0,
+//!         user: 1,
+//!     })
+//! );
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+
+use crate::Peripheral;
+
+/// The peripheral that drives MQS
+///
+/// Reserve this peripheral in your HAL while you use MQS.
+pub const BORROWS: Option<Peripheral> = Some(Peripheral::Sai(3));
+
+/// Tag that indicates the left channel
+pub enum Left {}
+/// Tag that indicates the right channel
+pub enum Right {}
+
+/// An MQS signal; one of `Left` or `Right`
+pub trait Signal: private::Sealed {}
+
+impl Signal for Left {}
+impl Signal for Right {}
+
+mod private {
+    use crate::function::MqsSignal;
+
+    pub trait Sealed {
+        const SIGNAL: MqsSignal;
+    }
+    impl Sealed for super::Left {
+        const SIGNAL: MqsSignal = MqsSignal::Left;
+    }
+    impl Sealed for super::Right {
+        const SIGNAL: MqsSignal = MqsSignal::Right;
+    }
+}
+
+/// An MQS pin
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the MQS pin
+    const ALT: u32;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+    const SION: super::SionRequirement = super::SionRequirement::DontCare;
+    /// The MQS signal
+    type Signal: Signal;
+}
+
+/// Prepare an MQS pin
+///
+/// MQS borrows [`BORROWS`]. Don't prepare any pins for that peripheral while you
+/// use MQS.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    trace!("mqs::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, None);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!("mqs::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, None)
+}
+
+prepared_pin!("MQS");
+
+/// Compute the register writes that prepare an MQS pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+///
+/// The result records [`Peripheral::Mqs`](crate::Peripheral::Mqs) as its peripheral.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, None).with_peripheral(Peripheral::Mqs)
+}
+
+/// Returns the [`Function`](crate::Function) of an MQS pin
+pub const fn function<P: Pin>() -> super::Function {
+    super::Function::Mqs(<P::Signal as private::Sealed>::SIGNAL)
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! mqs {
+    (alt: $alt:expr, pad: $pad:ty, signal: $signal:ty) => {
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            type Signal = $signal;
+        }
+    };
+}
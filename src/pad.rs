@@ -0,0 +1,345 @@
+//! Fixed-capacity collections keyed by pad
+//!
+//! A driver that manages many pads, like a keypad scanner or an LED matrix, often
+//! tracks state for each pad. [`Map`] associates a value with each [`PadId`], and
+//! [`Set`] collects `PadId`s. Both hold up to `N` pads without allocating, and both
+//! iterate in insertion order.
+//!
+//! ```
+//! use imxrt_iomuxc::{consts::*, pad::Map, Pad};
+//! # #[derive(Debug)] struct GPIO_B0;
+//! # unsafe impl imxrt_iomuxc::Base for GPIO_B0 {
+//! #     fn mux_base() -> *mut u32 { 0x401F_814C as *mut u32 }
+//! #     fn pad_base() -> *mut u32 { 0x401F_833C as *mut u32 }
+//! # }
+//!
+//! let row = unsafe { Pad::<GPIO_B0, U2>::new() }.erase();
+//! let col = unsafe { Pad::<GPIO_B0, U3>::new() }.erase();
+//!
+//! let mut pressed: Map<bool, 8> = Map::new();
+//! pressed.insert(row.id(), false).unwrap();
+//! pressed.insert(col.id(), true).unwrap();
+//!
+//! assert_eq!(pressed.get(col.id()), Some(&true));
+//! assert_eq!(pressed.remove(row.id()), Some(false));
+//! assert_eq!(pressed.len(), 1);
+//! ```
+
+use crate::PadId;
+
+/// A map from [`PadId`] to `V`, holding up to `N` pads
+///
+/// Iteration visits pads in the order they were inserted. Replacing a pad's
+/// value does not change the pad's position.
+#[derive(Debug, Clone)]
+pub struct Map<V, const N: usize> {
+    /// Entries `0..len` are occupied
+    entries: [Option<(PadId, V)>; N],
+    len: usize,
+}
+
+impl<V, const N: usize> Map<V, N> {
+    /// Create an empty map
+    pub fn new() -> Self {
+        Map {
+            entries: core::array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of pads in the map
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map is empty
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of pads in the map
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Insert `value` for the pad `id`
+    ///
+    /// If the map already has the pad, `insert()` replaces the value, and returns
+    /// the previous value. If the map is full, `insert()` returns the pad and the
+    /// value as an error.
+    pub fn insert(&mut self, id: PadId, value: V) -> Result<Option<V>, (PadId, V)> {
+        if let Some(old) = self.get_mut(id) {
+            return Ok(Some(core::mem::replace(old, value)));
+        }
+        match self.entries.get_mut(self.len) {
+            Some(slot) => {
+                *slot = Some((id, value));
+                self.len += 1;
+                Ok(None)
+            }
+            None => Err((id, value)),
+        }
+    }
+
+    /// Returns a reference to the value of pad `id`
+    pub fn get(&self, id: PadId) -> Option<&V> {
+        self.iter()
+            .find(|(key, _)| *key == id)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the value of pad `id`
+    pub fn get_mut(&mut self, id: PadId) -> Option<&mut V> {
+        self.iter_mut()
+            .find(|(key, _)| *key == id)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns `true` if the map has the pad `id`
+    pub fn contains(&self, id: PadId) -> bool {
+        self.position(id).is_some()
+    }
+
+    /// Remove the pad `id`, and return its value
+    ///
+    /// The other pads keep their insertion order.
+    pub fn remove(&mut self, id: PadId) -> Option<V> {
+        let index = self.position(id)?;
+        let mut tail = self.occupied_mut().get_mut(index..)?;
+        let (_, value) = tail.first_mut()?.take()?;
+        // Move the empty entry to the end, one swap at a time
+        while let Some((entry, rest)) = core::mem::take(&mut tail).split_first_mut() {
+            if let Some(next) = rest.first_mut() {
+                core::mem::swap(entry, next);
+            }
+            tail = rest;
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Remove all pads
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|entry| *entry = None);
+        self.len = 0;
+    }
+
+    /// Iterate over the pads and their values, in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (PadId, &V)> {
+        self.occupied()
+            .iter()
+            .flatten()
+            .map(|(id, value)| (*id, value))
+    }
+
+    /// Iterate over the pads and mutable references to their values, in insertion order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (PadId, &mut V)> {
+        self.occupied_mut()
+            .iter_mut()
+            .flatten()
+            .map(|(id, value)| (*id, value))
+    }
+
+    /// Iterate over the pads, in insertion order
+    pub fn keys(&self) -> impl Iterator<Item = PadId> + '_ {
+        self.iter().map(|(id, _)| id)
+    }
+
+    fn position(&self, id: PadId) -> Option<usize> {
+        self.occupied()
+            .iter()
+            .position(|entry| matches!(entry, Some((key, _)) if *key == id))
+    }
+
+    /// Entries `0..len`
+    fn occupied(&self) -> &[Option<(PadId, V)>] {
+        self.entries.get(..self.len).unwrap_or(&[])
+    }
+
+    /// Entries `0..len`
+    fn occupied_mut(&mut self) -> &mut [Option<(PadId, V)>] {
+        match self.entries.get_mut(..self.len) {
+            Some(occupied) => occupied,
+            None => &mut [],
+        }
+    }
+}
+
+impl<V, const N: usize> Default for Map<V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A set of [`PadId`]s, holding up to `N` pads
+///
+/// Iteration visits pads in the order they were inserted.
+#[derive(Debug, Clone, Default)]
+pub struct Set<const N: usize> {
+    map: Map<(), N>,
+}
+
+impl<const N: usize> Set<N> {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Set { map: Map::new() }
+    }
+
+    /// Returns the number of pads in the set
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set is empty
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the maximum number of pads in the set
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Insert the pad `id`
+    ///
+    /// Returns `true` if the pad is new, or `false` if the set already had the pad.
+    /// If the set is full, `insert()` returns the pad as an error.
+    pub fn insert(&mut self, id: PadId) -> Result<bool, PadId> {
+        match self.map.insert(id, ()) {
+            Ok(old) => Ok(old.is_none()),
+            Err((id, ())) => Err(id),
+        }
+    }
+
+    /// Returns `true` if the set has the pad `id`
+    pub fn contains(&self, id: PadId) -> bool {
+        self.map.contains(id)
+    }
+
+    /// Remove the pad `id`
+    ///
+    /// Returns `true` if the set had the pad.
+    pub fn remove(&mut self, id: PadId) -> bool {
+        self.map.remove(id).is_some()
+    }
+
+    /// Remove all pads
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Iterate over the pads, in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = PadId> + '_ {
+        self.map.keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Map, Set};
+    use crate::{consts::*, Pad, PadId};
+
+    #[derive(Debug)]
+    struct MapBase;
+
+    unsafe impl crate::Base for MapBase {
+        fn mux_base() -> *mut u32 {
+            0x5000 as *mut u32
+        }
+        fn pad_base() -> *mut u32 {
+            0x5200 as *mut u32
+        }
+    }
+
+    const A: PadId = PadId(0x5000);
+    const B: PadId = PadId(0x5004);
+    const C: PadId = PadId(0x5008);
+    const D: PadId = PadId(0x500C);
+
+    #[test]
+    fn capacity() {
+        let mut map: Map<u32, 3> = Map::new();
+        assert_eq!(map.capacity(), 3);
+        assert!(map.is_empty());
+
+        assert_eq!(map.insert(A, 1), Ok(None));
+        assert_eq!(map.insert(B, 2), Ok(None));
+        assert_eq!(map.insert(C, 3), Ok(None));
+        assert_eq!(map.insert(D, 4), Err((D, 4)));
+        assert_eq!(map.len(), 3);
+        assert!(!map.contains(D));
+
+        // A full map still replaces values
+        assert_eq!(map.insert(B, 20), Ok(Some(2)));
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut map: Map<u32, 4> = Map::new();
+        map.insert(A, 1).unwrap();
+        map.insert(B, 2).unwrap();
+        assert_eq!(map.insert(A, 10), Ok(Some(1)));
+
+        // Replacing keeps the insertion order
+        let entries: [(PadId, u32); 2] = [(A, 10), (B, 2)];
+        assert!(map.iter().map(|(id, value)| (id, *value)).eq(entries));
+
+        *map.get_mut(B).unwrap() += 1;
+        assert_eq!(map.get(B), Some(&3));
+        assert_eq!(map.get(C), None);
+    }
+
+    #[test]
+    fn removal() {
+        let mut map: Map<u32, 4> = Map::new();
+        for (value, id) in [A, B, C, D].iter().enumerate() {
+            map.insert(*id, value as u32).unwrap();
+        }
+
+        assert_eq!(map.remove(B), Some(1));
+        assert_eq!(map.remove(B), None);
+        assert!(map.keys().eq([A, C, D]));
+
+        // The freed slot is available
+        map.insert(B, 5).unwrap();
+        assert!(map.keys().eq([A, C, D, B]));
+
+        assert_eq!(map.remove(B), Some(5));
+        assert_eq!(map.remove(A), Some(0));
+        assert!(map.keys().eq([C, D]));
+
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.get(C), None);
+    }
+
+    #[test]
+    fn erased_pad_ids() {
+        let mut map: Map<&str, 2> = Map::new();
+        let pad = unsafe { Pad::<MapBase, U1>::new() }.erase();
+        map.insert(pad.id(), "row").unwrap();
+
+        // Another handle for the same pad has the same ID
+        let same = unsafe { Pad::<MapBase, U1>::new() }.erase();
+        assert_eq!(map.insert(same.id(), "col"), Ok(Some("row")));
+        assert_eq!(map.get(B), Some(&"col"));
+    }
+
+    #[test]
+    fn set() {
+        let mut set: Set<2> = Set::new();
+        assert_eq!(set.insert(C), Ok(true));
+        assert_eq!(set.insert(C), Ok(false));
+        assert_eq!(set.insert(A), Ok(true));
+        assert_eq!(set.insert(B), Err(B));
+        assert!(set.iter().eq([C, A]));
+
+        assert!(set.remove(C));
+        assert!(!set.remove(C));
+        assert!(!set.contains(C));
+        assert_eq!(set.insert(B), Ok(true));
+        assert!(set.iter().eq([A, B]));
+        assert_eq!(set.len(), 2);
+    }
+}
@@ -0,0 +1,160 @@
+//! Declare a board's pins, and check them while compiling
+
+use crate::{plan, Plan, PlanError, Routing, RoutingError};
+
+/// Declare a board's pinout
+///
+/// `pinout!` declares a struct with one strongly-typed pad for each pin. Each pin
+/// names its peripheral module, its pad, and its pad configuration:
+///
+/// ```text
+/// <field>: <module> <pad> = <config>,
+/// ```
+///
+/// The module is one of this crate's peripheral modules with a `prepare()` and a
+/// `prepared()` function, like `lpuart` or `gpio`. The configuration must be a
+/// `const` [`Config`](crate::Config) created with [`Config::zero()`](crate::Config::zero).
+///
+/// The struct has
+///
+/// - `ROUTINGS`, a [`Routing`] for each pin, in declaration order.
+/// - `PLAN`, the [`plan()`] of the routings.
+/// - `unsafe fn new()`, which creates the pads.
+/// - `fn prepare()`, which configures and prepares each pin, in declaration order.
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] mod board {
+/// use imxrt_iomuxc::imxrt1060::{gpio_ad_b0::*, gpio_b0::*};
+/// use imxrt_iomuxc::{Config, DriveStrength, PullKeeper};
+///
+/// const UART: Config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup100k));
+/// const LED: Config = Config::zero().set_drive_strength(DriveStrength::R0_6);
+///
+/// imxrt_iomuxc::pinout! {
+///     /// The board's pins
+///     pub struct Pinout {
+///         uart_tx: lpuart GPIO_AD_B0_12 = UART,
+///         uart_rx: lpuart GPIO_AD_B0_13 = UART,
+///         led: gpio GPIO_B0_03 = LED,
+///     }
+/// }
+///
+/// # pub fn main() {
+/// let mut pins = unsafe { Pinout::new() };
+/// pins.prepare();
+/// assert_eq!(Pinout::PLAN.routings().len(), 3);
+///
+/// // The fields are strongly typed
+/// let tx: GPIO_AD_B0_12 = pins.uart_tx;
+/// # }
+/// # }
+/// # #[cfg(feature = "imxrt1060")] fn main() { board::main() }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// # Compile-time checks
+///
+/// A pad that doesn't support the module's function is a compile error that points
+/// at the pad:
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::{imxrt1060::gpio_b0::*, Config};
+///
+/// const CONFIG: Config = Config::zero();
+/// imxrt_iomuxc::pinout! {
+///     struct Pinout {
+///         uart_tx: lpuart GPIO_B0_03 = CONFIG, // Not a UART pin
+///     }
+/// }
+/// ```
+///
+/// The pinout must also pass [`plan()`]. Using a pad twice, selecting two pads for
+/// the same peripheral input, or using a pad configuration that doesn't make sense, is
+/// a compile error:
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::*, Config};
+///
+/// const CONFIG: Config = Config::zero();
+/// imxrt_iomuxc::pinout! {
+///     struct Pinout {
+///         uart_tx: lpuart GPIO_AD_B0_12 = CONFIG,
+///         led: gpio GPIO_AD_B0_12 = CONFIG, // Same pad
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! pinout {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($pin:ident: $module:ident $pad:ty = $config:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                #[doc = concat!("The `", stringify!($pad), "` pad, a `", stringify!($module), "` pin")]
+                pub $pin: $pad,
+            )+
+        }
+
+        impl $name {
+            /// The routing of each pin, in declaration order
+            pub const ROUTINGS: &'static [$crate::Routing] = &[
+                $($crate::Routing::from_prepared(&$crate::$module::prepared::<$pad>($config)),)+
+            ];
+
+            /// The planned routings
+            pub const PLAN: $crate::Plan<'static> = $crate::__pinout_plan(Self::ROUTINGS);
+
+            /// Create the pads
+            ///
+            /// # Safety
+            ///
+            /// Creates pads that may exist elsewhere, like in the chip's `Pads`. Make
+            /// sure that nothing else uses these pads.
+            pub unsafe fn new() -> Self {
+                $name {
+                    $($pin: <$pad>::new(),)+
+                }
+            }
+
+            /// Configure and prepare each pin, in declaration order
+            pub fn prepare(&mut self) {
+                $(
+                    $crate::configure(&mut self.$pin, $config);
+                    $crate::$module::prepare(&mut self.$pin);
+                )+
+            }
+        }
+
+        // Check the plan, even if nothing uses it
+        const _: $crate::Plan<'static> = $name::PLAN;
+    };
+}
+
+#[doc(hidden)] // Used in pinout!
+pub const fn __pinout_plan(routings: &'static [Routing]) -> Plan<'static> {
+    match plan(routings) {
+        Ok(plan) => plan,
+        Err(PlanError::Routing {
+            error: RoutingError::Config(_),
+            ..
+        }) => panic!("a pinout pin has an invalid pad configuration"),
+        Err(PlanError::Routing { .. }) => panic!("a pinout pin has an invalid routing"),
+        Err(PlanError::DuplicatePad { .. }) => panic!("two pinout pins use the same pad"),
+        Err(PlanError::DaisyConflict(_)) => {
+            panic!("two pinout pins select different pads for the same peripheral input")
+        }
+        Err(PlanError::BorrowConflict { .. }) => {
+            panic!("a pinout pin uses a peripheral that another pin's peripheral borrows")
+        }
+        Err(PlanError::ReservedPad { .. }) => {
+            panic!("a pinout pin uses a pad that another pin's peripheral reserves")
+        }
+        Err(PlanError::ImpossibleAssignment { .. } | PlanError::TooManyAssignments(_)) => {
+            panic!("plan() doesn't resolve assignments")
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! Compile-time pin descriptors
+
+use crate::{Config, ConstIomuxc, Daisy, Peripheral, SionRequirement};
+
+/// The register writes that prepare a pin
+///
+/// A `PreparedWrites` describes the final mux register value, pad configuration register
+/// value, and optional daisy selection for a strongly-typed pin. Each peripheral module
+/// provides a `const fn prepared()` that computes a `PreparedWrites` at compile time.
+///
+/// Use `PreparedWrites` when you want to configure pins without any trait machinery at
+/// run time. For example, a bootloader can store all of its pin configurations in a static
+/// table, and apply them with a simple loop:
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{lpi2c, lpuart, Config, PreparedWrites, PullKeeper};
+/// use imxrt_iomuxc::imxrt1060::{gpio_ad_b0::*, gpio_ad_b1::*};
+///
+/// const UART: Config = Config::zero();
+/// const I2C: Config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup22k));
+///
+/// static BOOT_PINMUX: [PreparedWrites; 4] = [
+///     lpuart::prepared::<GPIO_AD_B0_12>(UART),
+///     lpuart::prepared::<GPIO_AD_B0_13>(UART),
+///     lpi2c::prepared::<GPIO_AD_B1_00>(I2C),
+///     lpi2c::prepared::<GPIO_AD_B1_01>(I2C),
+/// ];
+///
+/// for writes in BOOT_PINMUX.iter() {
+///     unsafe {
+///         core::ptr::write_volatile(writes.pad_addr as *mut u32, writes.pad_val);
+///         if let Some((reg, value)) = writes.daisy {
+///             core::ptr::write_volatile(reg as *mut u32, value);
+///         }
+///         core::ptr::write_volatile(writes.mux_addr as *mut u32, writes.mux_val);
+///     }
+/// }
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// [`init_table!`](crate::init_table) declares a table like this, and the loop that
+/// applies it.
+///
+/// Since they write entire registers, `PreparedWrites` require a pad configuration that was
+/// created with [`Config::zero()`](crate::Config::zero). Using a configuration from
+/// [`Config::modify()`](crate::Config::modify) is a compile-time error when evaluated
+/// in a `const` context.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PreparedWrites {
+    /// Address of the pad's multiplex register
+    pub mux_addr: usize,
+    /// The value for the multiplex register
+    pub mux_val: u32,
+    /// The SION requirement that's reflected in `mux_val`
+    pub sion: SionRequirement,
+    /// Address of the pad's configuration register
+    pub pad_addr: usize,
+    /// The value for the pad configuration register
+    pub pad_val: u32,
+    /// The daisy register address and value, if the pin requires one
+    pub daisy: Option<(usize, u32)>,
+    /// The peripheral that the pin connects to, if the pin's module records it
+    ///
+    /// See [`Peripheral`] for more information.
+    pub peripheral: Option<Peripheral>,
+}
+
+impl PreparedWrites {
+    /// Describe the writes for pad `P`
+    pub(crate) const fn new<P: ConstIomuxc>(
+        alt: u32,
+        sion: SionRequirement,
+        config: Config,
+        daisy: Option<Daisy>,
+    ) -> Self {
+        assert!(
+            config.is_zero(),
+            "PreparedWrites require a Config created with Config::zero()"
+        );
+        PreparedWrites {
+            mux_addr: P::MUX,
+            mux_val: sion.mux_value(alt),
+            sion,
+            pad_addr: P::PAD,
+            pad_val: config.value(),
+            daisy: match daisy {
+                Some(daisy) => Some((daisy.reg(), daisy.value())),
+                None => None,
+            },
+            peripheral: None,
+        }
+    }
+
+    /// Record the peripheral that the pin connects to
+    pub(crate) const fn with_peripheral(mut self, peripheral: Peripheral) -> Self {
+        self.peripheral = Some(peripheral);
+        self
+    }
+}
+
+/// Two prepared pins that select different pads for the same peripheral input
+///
+/// Returned from [`detect_daisy_conflicts()`]. The indices refer to the slice that
+/// you analyzed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DaisyConflict {
+    /// Address of the shared daisy register
+    pub reg: usize,
+    /// Index of the earlier pin
+    pub earlier: usize,
+    /// The earlier pin's daisy value, which is overwritten
+    pub earlier_value: u32,
+    /// Index of the later pin
+    pub later: usize,
+    /// The later pin's daisy value, which wins
+    pub later_value: u32,
+}
+
+/// Find prepared pins whose daisy selections overwrite each other
+///
+/// When two pins select different pads for the same peripheral input, the
+/// later daisy write wins, and the earlier pin no longer reaches the peripheral.
+/// `detect_daisy_conflicts()` reports each pair of pins that target the same
+/// daisy register with different values, assuming that you apply `prepared` in
+/// order. Pins that write the same value do not conflict.
+///
+/// The analysis does not touch any registers, so you may run it on a host, or in
+/// a test.
+///
+/// ```
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{detect_daisy_conflicts, lpi2c, Config};
+/// use imxrt_iomuxc::imxrt1060::{gpio_ad_b1::*, gpio_sd_b1::*};
+///
+/// const I2C: Config = Config::zero();
+/// let prepared = [
+///     lpi2c::prepared::<GPIO_AD_B1_00>(I2C), // LPI2C1 SCL
+///     lpi2c::prepared::<GPIO_AD_B1_01>(I2C), // LPI2C1 SDA
+///     lpi2c::prepared::<GPIO_SD_B1_04>(I2C), // LPI2C1 SCL, again
+/// ];
+///
+/// let mut conflicts = detect_daisy_conflicts(&prepared);
+/// let conflict = conflicts.next().unwrap();
+/// assert_eq!((conflict.earlier, conflict.later), (0, 2));
+/// assert!(conflicts.next().is_none());
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+pub fn detect_daisy_conflicts(
+    prepared: &[PreparedWrites],
+) -> impl Iterator<Item = DaisyConflict> + '_ {
+    (0..prepared.len()).flat_map(move |later| {
+        (0..later).filter_map(move |earlier| {
+            match (prepared[earlier].daisy, prepared[later].daisy) {
+                (Some((reg, earlier_value)), Some((later_reg, later_value)))
+                    if reg == later_reg && earlier_value != later_value =>
+                {
+                    Some(DaisyConflict {
+                        reg,
+                        earlier,
+                        earlier_value,
+                        later,
+                        later_value,
+                    })
+                }
+                _ => None,
+            }
+        })
+    })
+}
+
+/// Declare a table of prepared pins, and a function that applies it
+///
+/// `init_table!` declares a `const` array of [`PreparedWrites`], and an `unsafe fn`
+/// that writes every entry to the hardware. The function has no arguments, no
+/// generics, and no state. It's a loop over the array, so you can audit the entire
+/// pinmux in one place. Each pin uses the syntax of [`pinout!`](crate::pinout):
+///
+/// ```text
+/// <module> <pad> = <config>,
+/// ```
+///
+/// The module's `prepared()` resolves the alternate, SION, and daisy of the pad
+/// while compiling. The table must also pass [`plan()`](crate::plan), so a pad
+/// that doesn't support the module's function, a pad used twice, or a daisy
+/// conflict is a compile error.
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] mod boot {
+/// use imxrt_iomuxc::imxrt1060::{gpio_ad_b0::*, gpio_ad_b1::*};
+/// use imxrt_iomuxc::{Config, PullKeeper};
+///
+/// const UART: Config = Config::zero();
+/// const I2C: Config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup22k));
+///
+/// imxrt_iomuxc::init_table! {
+///     /// The bootloader's pins
+///     pub const BOOT_PINMUX = [
+///         lpuart GPIO_AD_B0_12 = UART,
+///         lpuart GPIO_AD_B0_13 = UART,
+///         lpi2c GPIO_AD_B1_00 = I2C,
+///         lpi2c GPIO_AD_B1_01 = I2C,
+///     ];
+///     /// Apply the bootloader's pins
+///     pub unsafe fn apply_boot_pinmux();
+/// }
+///
+/// # pub fn main() {
+/// assert_eq!(BOOT_PINMUX.len(), 4);
+/// unsafe { apply_boot_pinmux() };
+/// # }
+/// # }
+/// # #[cfg(feature = "imxrt1060")] fn main() { boot::main() }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// The function writes each pin's pad configuration, daisy, and multiplex register,
+/// in that order. See [Write Ordering](crate#write-ordering). It takes no locks, so
+/// call it before enabling interrupts that use the pads.
+///
+/// # Replaying the table
+///
+/// By default, the function uses [`core::ptr::write_volatile`]. Add `with <path>` to
+/// call a different `unsafe fn(*mut u32, u32)` for each write. For example, a
+/// test can replay the table against fake memory:
+///
+/// ```
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::imxrt1060::gpio_ad_b1::*;
+/// use imxrt_iomuxc::{lpuart, Config};
+/// use std::sync::Mutex;
+///
+/// static MEMORY: Mutex<Vec<(usize, u32)>> = Mutex::new(Vec::new());
+///
+/// unsafe fn fake_write(addr: *mut u32, value: u32) {
+///     MEMORY.lock().unwrap().push((addr as usize, value));
+/// }
+///
+/// const UART: Config = Config::zero();
+/// imxrt_iomuxc::init_table! {
+///     const BOOT_PINMUX = [
+///         lpuart GPIO_AD_B1_02 = UART,
+///     ];
+///     unsafe fn replay_boot_pinmux() with fake_write;
+/// }
+///
+/// unsafe { replay_boot_pinmux() };
+///
+/// // LPUART2 TX selects its pad with a daisy
+/// let tx = lpuart::prepared::<GPIO_AD_B1_02>(UART);
+/// assert_eq!(
+///     *MEMORY.lock().unwrap(),
+///     [
+///         (tx.pad_addr, tx.pad_val),
+///         tx.daisy.unwrap(),
+///         (tx.mux_addr, tx.mux_val),
+///     ]
+/// );
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// # Compile-time checks
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::*, Config};
+///
+/// const CONFIG: Config = Config::zero();
+/// imxrt_iomuxc::init_table! {
+///     const BOOT_PINMUX = [
+///         lpuart GPIO_AD_B0_12 = CONFIG,
+///         gpio GPIO_AD_B0_12 = CONFIG, // Same pad
+///     ];
+///     unsafe fn apply_boot_pinmux();
+/// }
+/// ```
+#[macro_export]
+macro_rules! init_table {
+    (
+        $(#[$table_attr:meta])*
+        $table_vis:vis const $table:ident = [
+            $($module:ident $pad:ty = $config:expr),+ $(,)?
+        ];
+        $(#[$fn_attr:meta])*
+        $fn_vis:vis unsafe fn $apply:ident() with $write:path;
+    ) => {
+        $(#[$table_attr])*
+        $table_vis const $table: [$crate::PreparedWrites; [$(stringify!($pad)),+].len()] = [
+            $($crate::$module::prepared::<$pad>($config),)+
+        ];
+
+        // Check the plan, even if nothing uses it
+        const _: $crate::Plan<'static> = {
+            const ROUTINGS: &[$crate::Routing] = &[
+                $($crate::Routing::from_prepared(&$crate::$module::prepared::<$pad>($config)),)+
+            ];
+            $crate::__pinout_plan(ROUTINGS)
+        };
+
+        $(#[$fn_attr])*
+        ///
+        /// # Safety
+        ///
+        #[doc = concat!("Writes the IOMUXC registers of every pin in `", stringify!($table), "`.")]
+        /// Make sure that nothing else is using the pads, or their daisy registers.
+        $fn_vis unsafe fn $apply() {
+            for writes in $table.iter() {
+                $write(writes.pad_addr as *mut u32, writes.pad_val);
+                if let Some((reg, value)) = writes.daisy {
+                    $write(reg as *mut u32, value);
+                }
+                $write(writes.mux_addr as *mut u32, writes.mux_val);
+            }
+        }
+    };
+    (
+        $(#[$table_attr:meta])*
+        $table_vis:vis const $table:ident = [
+            $($module:ident $pad:ty = $config:expr),+ $(,)?
+        ];
+        $(#[$fn_attr:meta])*
+        $fn_vis:vis unsafe fn $apply:ident();
+    ) => {
+        $crate::init_table! {
+            $(#[$table_attr])*
+            $table_vis const $table = [
+                $($module $pad = $config),+
+            ];
+            $(#[$fn_attr])*
+            $fn_vis unsafe fn $apply() with ::core::ptr::write_volatile;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_daisy_conflicts, DaisyConflict};
+    use crate::{consts::*, Config, Daisy, DriveStrength, Pad, PreparedWrites, SionRequirement};
+
+    struct TestBase;
+
+    unsafe impl crate::Base for TestBase {
+        fn mux_base() -> *mut u32 {
+            0x4000_0000 as *mut u32
+        }
+        fn pad_base() -> *mut u32 {
+            0x4000_1000 as *mut u32
+        }
+    }
+
+    unsafe impl crate::ConstBase for TestBase {
+        const MUX_BASE: usize = 0x4000_0000;
+        const PAD_BASE: usize = 0x4000_1000;
+    }
+
+    type TestPad = Pad<TestBase, U3>;
+
+    const CONFIG: Config = Config::zero().set_drive_strength(DriveStrength::R0_6);
+
+    #[test]
+    fn addresses() {
+        const WRITES: PreparedWrites =
+            PreparedWrites::new::<TestPad>(7, SionRequirement::Clear, CONFIG, None);
+        assert_eq!(WRITES.mux_addr, 0x4000_000C);
+        assert_eq!(WRITES.pad_addr, 0x4000_100C);
+        assert_eq!(WRITES.mux_val, 7);
+        assert_eq!(WRITES.pad_val, DriveStrength::R0_6 as u32);
+        assert_eq!(WRITES.daisy, None);
+    }
+
+    #[test]
+    fn daisy() {
+        const WRITES: PreparedWrites = PreparedWrites::new::<TestPad>(
+            2,
+            SionRequirement::Set,
+            CONFIG,
+            Some(Daisy::new(0x4000_2000, 3)),
+        );
+        assert_eq!(WRITES.mux_val, 1 << 4 | 2);
+        assert_eq!(WRITES.daisy, Some((0x4000_2000, 3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn modify_config() {
+        PreparedWrites::new::<TestPad>(2, SionRequirement::DontCare, Config::modify(), None);
+    }
+
+    #[test]
+    fn daisy_conflicts() {
+        type OtherPad = Pad<TestBase, U4>;
+        type ThirdPad = Pad<TestBase, U5>;
+
+        let prepared = [
+            PreparedWrites::new::<TestPad>(
+                2,
+                SionRequirement::Set,
+                Config::zero(),
+                Some(Daisy::new(0x4000_2000, 0)),
+            ),
+            PreparedWrites::new::<OtherPad>(
+                2,
+                SionRequirement::Set,
+                Config::zero(),
+                Some(Daisy::new(0x4000_2000, 0)),
+            ),
+            PreparedWrites::new::<ThirdPad>(
+                3,
+                SionRequirement::Set,
+                Config::zero(),
+                Some(Daisy::new(0x4000_2000, 1)),
+            ),
+        ];
+
+        let mut conflicts = detect_daisy_conflicts(&prepared);
+        assert_eq!(
+            conflicts.next(),
+            Some(DaisyConflict {
+                reg: 0x4000_2000,
+                earlier: 0,
+                earlier_value: 0,
+                later: 2,
+                later_value: 1,
+            })
+        );
+        assert_eq!(
+            conflicts.next(),
+            Some(DaisyConflict {
+                reg: 0x4000_2000,
+                earlier: 1,
+                earlier_value: 0,
+                later: 2,
+                later_value: 1,
+            })
+        );
+        assert_eq!(conflicts.next(), None);
+    }
+}
@@ -0,0 +1,187 @@
+//! Quad timer (QTIMER) pad configuration
+//!
+//! A QTIMER pin is the input / output of one of a module's four timer channels.
+//! Use [`prepare()`] to prepare any single timer pin.
+//!
+//! # Quadrature decoding
+//!
+//! A quad timer channel may decode a quadrature signal using a primary and a
+//! secondary counter input. Both inputs must come from the same QTIMER module. If
+//! the pads route through daisy registers, both daisy registers must be committed,
+//! otherwise the decoder never sees one of the phases. Use [`prepare_quadrature()`]
+//! to prepare both inputs together:
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{imxrt1060::gpio_ad_b1::*, qtimer};
+//!
+//! let mut phase_a = unsafe { GPIO_AD_B1_00::new() };
+//! let mut phase_b = unsafe { GPIO_AD_B1_01::new() };
+//! qtimer::prepare_quadrature(&mut phase_a, &mut phase_b);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! The two inputs must be on the same QTIMER module:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{imxrt1060::{gpio_ad_b1::*, gpio_b0::*}, qtimer};
+//!
+//! let mut phase_a = unsafe { GPIO_AD_B1_00::new() }; // QTIMER3
+//! let mut phase_b = unsafe { GPIO_B0_10::new() }; // QTIMER4
+//! qtimer::prepare_quadrature(&mut phase_a, &mut phase_b);
+//! ```
+
+/// A QTIMER pin
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the QTIMER pin
+    const ALT: u32;
+    /// The daisy register which will select the pad
+    const DAISY: Option<super::Daisy>;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Clear`](crate::SionRequirement::Clear).
+    const SION: super::SionRequirement = super::SionRequirement::Clear;
+    /// QTIMER module; `U3` for `QTIMER3`
+    type Module: super::consts::Unsigned;
+    /// Timer channel; `U1` for `TIMER1`
+    type Timer: super::consts::Unsigned;
+}
+
+module_aliases!("QTIMER":
+    1 => Qtimer1 = U1,
+    2 => Qtimer2 = U2,
+    3 => Qtimer3 = U3,
+    4 => Qtimer4 = U4,
+);
+
+/// Prepare a QTIMER pin
+///
+/// If you do not call `prepare()` on your QTIMER pin, it might not work as a QTIMER
+/// pin.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    trace!("qtimer::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, P::DAISY);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!(
+        "qtimer::prepare_idempotent mux={:#010x}",
+        pin.mux() as usize
+    );
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, P::DAISY)
+}
+
+prepared_pin!("QTIMER");
+
+/// Prepare two QTIMER pins as quadrature decoder inputs
+///
+/// `primary` and `secondary` must be pins for the same QTIMER module. The call
+/// prepares both pins, and commits both of their daisy registers. Prefer two
+/// different timer channels, since a single pad cannot supply both phases.
+pub fn prepare_quadrature<A, B>(primary: &mut A, secondary: &mut B)
+where
+    A: Pin,
+    B: Pin<Module = <A as Pin>::Module>,
+{
+    prepare(primary);
+    prepare(secondary);
+}
+
+/// Compute the register writes that prepare a QTIMER pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, P::DAISY)
+}
+
+/// Returns the [`Function`](crate::Function) of a QTimer pin
+pub const fn function<P: Pin>() -> super::Function {
+    use super::consts::Unsigned;
+    super::Function::QTimer {
+        module: P::Module::U8,
+        timer: P::Timer::U8,
+    }
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! qtimer {
+    (module: $module:ty, timer: $timer:ty, alt: $alt:expr, pad: $pad:ty, daisy: $daisy:expr) => {
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
+            type Module = $module;
+            type Timer = $timer;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prepare_quadrature, Pin};
+    use crate::{consts::*, Daisy, Iomuxc};
+
+    struct FakePad<const TIMER: usize> {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl<const TIMER: usize> crate::private::Sealed for FakePad<TIMER> {}
+
+    unsafe impl<const TIMER: usize> Iomuxc for FakePad<TIMER> {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// The fakes don't have daisy registers; prepare_pin() commits the daisy
+    /// of every pad that it prepares.
+    impl Pin for FakePad<0> {
+        const ALT: u32 = 1;
+        const DAISY: Option<Daisy> = None;
+        type Module = U3;
+        type Timer = U0;
+    }
+
+    impl Pin for FakePad<1> {
+        const ALT: u32 = 1;
+        const DAISY: Option<Daisy> = None;
+        type Module = U3;
+        type Timer = U1;
+    }
+
+    #[test]
+    fn quadrature_prepares_both_pads() {
+        let mut primary = FakePad::<0> {
+            mux: 1 << 4,
+            pad: 0,
+        };
+        let mut secondary = FakePad::<1> { mux: 5, pad: 0 };
+
+        prepare_quadrature(&mut primary, &mut secondary);
+
+        assert_eq!(primary.mux, 1);
+        assert_eq!(secondary.mux, 1);
+    }
+
+    /// The documented QTIMER3 select inputs for the GPIO_AD_B1_00 / GPIO_AD_B1_01 pair
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn imxrt1060_quadrature_daisies() {
+        use crate::imxrt1060::gpio_ad_b1::{GPIO_AD_B1_00, GPIO_AD_B1_01};
+
+        let a = <GPIO_AD_B1_00 as Pin>::DAISY.unwrap();
+        let b = <GPIO_AD_B1_01 as Pin>::DAISY.unwrap();
+        assert_eq!((a.reg(), a.value()), (0x401F_857C, 1));
+        assert_eq!((b.reg(), b.value()), (0x401F_8580, 1));
+    }
+}
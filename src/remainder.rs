@@ -0,0 +1,203 @@
+//! Take some pads from a group, and keep the rest
+
+use crate::{consts::Unsigned, ErasedPad, Pad};
+use core::{convert::TryFrom, marker::PhantomData};
+
+/// Move pads out of a group, and return the rest of the group
+///
+/// `take!(group, pad, ...)` evaluates to a tuple. The first element is a tuple of the
+/// named pads, in the order you name them. The second element is a [`Remainder`] with
+/// the group's other pads. Name pads by their member in the group's `Pads` struct, like
+/// `p12`. See [`Remainder`] for an example.
+#[macro_export]
+macro_rules! take {
+    ($group:expr, $($pad:ident),+ $(,)?) => {{
+        let group = $group;
+        $(let $pad = $crate::__pad_type(&group.$pad);)+
+        let mut remainder = $crate::Remainder::new(group.erase());
+        let taken = ($($crate::__take_pad(&mut remainder, $pad),)+);
+        (taken, remainder)
+    }};
+}
+
+/// The pads that remain in a group
+///
+/// A driver usually needs a few pads from a group. Moving those pads out of a
+/// group's `Pads` struct leaves the struct partially moved, so you can no longer
+/// erase or iterate the rest of the group. [`take!`](crate::take) moves the pads
+/// you name out of a group, and returns the rest of the group as a [`Remainder`].
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::{park_all, Pads}, lpuart, take, ParkStrategy};
+///
+/// let pads = unsafe { Pads::new() };
+/// let ((tx, rx), mut gpio_ad_b0) = take!(pads.gpio_ad_b0, p12, p13);
+///
+/// let tx = lpuart::prepare_owned(tx);
+/// let rx = lpuart::prepare_owned(rx);
+///
+/// // The other 14 pads of GPIO_AD_B0 are unused
+/// park_all(gpio_ad_b0.iter_mut(), ParkStrategy::DigitalKeeper);
+///
+/// // The other groups are still available
+/// let gpio_b0_03 = pads.gpio_b0.p03;
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// A `Remainder` holds the group's erased pads, except for the pads that you took.
+/// Use [`iter_mut()`](Remainder::iter_mut) or [`erased_iter()`](Remainder::erased_iter)
+/// to access the remaining pads, or [`take()`](Remainder::take) to take more pads.
+pub struct Remainder<const N: usize> {
+    pads: [Option<ErasedPad>; N],
+}
+
+impl<const N: usize> Remainder<N> {
+    /// Hold all of the group's erased `pads`
+    pub fn new(pads: [ErasedPad; N]) -> Self {
+        Remainder {
+            pads: pads.map(Some),
+        }
+    }
+
+    /// Take the strongly-typed pad from the remainder
+    ///
+    /// Returns `None` if the pad was already taken, or if the pad isn't in
+    /// this group.
+    pub fn take<Base, Offset>(&mut self) -> Option<Pad<Base, Offset>>
+    where
+        Base: crate::Base,
+        Offset: Unsigned,
+    {
+        let slot = self.pads.iter_mut().find(|slot| match slot {
+            Some(pad) => pad.offset() == Offset::USIZE,
+            None => false,
+        })?;
+        match Pad::try_from(slot.take()?) {
+            Ok(pad) => Some(pad),
+            Err(err) => {
+                *slot = Some(err.0);
+                None
+            }
+        }
+    }
+
+    /// Returns the number of remaining pads
+    pub fn len(&self) -> usize {
+        self.pads.iter().flatten().count()
+    }
+
+    /// Returns `true` if there are no remaining pads
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the remaining pads
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut ErasedPad> {
+        self.pads.iter_mut().flatten()
+    }
+
+    /// Iterate over the remaining pads, taking each pad
+    pub fn erased_iter(self) -> impl Iterator<Item = ErasedPad> {
+        core::iter::IntoIterator::into_iter(self.pads).flatten()
+    }
+}
+
+#[doc(hidden)] // Used in take!
+pub fn __pad_type<Base, Offset>(_: &Pad<Base, Offset>) -> PhantomData<Pad<Base, Offset>> {
+    PhantomData
+}
+
+#[doc(hidden)] // Used in take!
+pub fn __take_pad<Base, Offset, const N: usize>(
+    remainder: &mut Remainder<N>,
+    _: PhantomData<Pad<Base, Offset>>,
+) -> Pad<Base, Offset>
+where
+    Base: crate::Base,
+    Offset: Unsigned,
+{
+    remainder
+        .take()
+        .expect("take! names each pad of the group once")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{consts::*, ErasedPad, Pad};
+
+    #[derive(Debug)]
+    struct Foo;
+
+    unsafe impl crate::Base for Foo {
+        fn mux_base() -> *mut u32 {
+            0x5000 as *mut u32
+        }
+        fn pad_base() -> *mut u32 {
+            0x5200 as *mut u32
+        }
+    }
+
+    #[derive(Debug)]
+    struct Bar;
+
+    unsafe impl crate::Base for Bar {
+        fn mux_base() -> *mut u32 {
+            0x6000 as *mut u32
+        }
+        fn pad_base() -> *mut u32 {
+            0x6200 as *mut u32
+        }
+    }
+
+    /// A group of pads, like the generated groups
+    struct Pads {
+        p02: Pad<Foo, U2>,
+        p03: Pad<Foo, U3>,
+        p04: Pad<Foo, U4>,
+    }
+
+    impl Pads {
+        fn new() -> Self {
+            unsafe {
+                Pads {
+                    p02: Pad::new(),
+                    p03: Pad::new(),
+                    p04: Pad::new(),
+                }
+            }
+        }
+
+        fn erase(self) -> [ErasedPad; 3] {
+            [self.p02.erase(), self.p03.erase(), self.p04.erase()]
+        }
+    }
+
+    #[test]
+    fn take_two_pads() {
+        let ((p04, p02), mut remainder) = crate::take!(Pads::new(), p04, p02);
+        assert_eq!(p04.erase().offset(), 4);
+        assert_eq!(p02.erase().offset(), 2);
+
+        assert_eq!(remainder.len(), 1);
+        let offsets: [usize; 1] = [remainder.iter_mut().next().unwrap().offset()];
+        assert_eq!(offsets, [3]);
+    }
+
+    #[test]
+    fn take_from_remainder() {
+        let ((_,), mut remainder) = crate::take!(Pads::new(), p03);
+
+        // Already taken
+        assert!(remainder.take::<Foo, U3>().is_none());
+        // Same offset, but a different group
+        assert!(remainder.take::<Bar, U4>().is_none());
+        assert_eq!(remainder.len(), 2);
+
+        assert!(remainder.take::<Foo, U4>().is_some());
+        let mut pads = remainder.erased_iter();
+        assert_eq!(pads.next().map(|pad| pad.offset()), Some(2));
+        assert!(pads.next().is_none());
+    }
+}
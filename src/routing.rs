@@ -0,0 +1,1195 @@
+//! Run-time pad routing
+//!
+//! A [`Routing`] describes how to route one pad at run time: the pad, its alternate,
+//! its SION requirement, an optional daisy selection, and its pad configuration.
+//! Use `Routing`s when one firmware image supports boards that wire a peripheral to
+//! different pads. Build one `const` table of `Routing`s for each board, then use
+//! [`apply_routing()`] to apply the table that matches the board.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{
+//!     imxrt1060::{gpio_ad_b1::*, gpio_b1::*, Pads},
+//!     lpuart, Config, PullKeeper, Routing,
+//! };
+//!
+//! const UART: Config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup100k));
+//!
+//! /// Revision A wires the debug UART to LPUART2
+//! const REV_A: [Routing; 2] = [
+//!     Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B1_02>(UART)),
+//!     Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B1_03>(UART)),
+//! ];
+//!
+//! /// Revision B wires the debug UART to LPUART4
+//! const REV_B: [Routing; 2] = [
+//!     Routing::from_prepared(&lpuart::prepared::<GPIO_B1_00>(UART)),
+//!     Routing::from_prepared(&lpuart::prepared::<GPIO_B1_01>(UART)),
+//! ];
+//!
+//! # fn board_is_rev_b() -> bool { false }
+//! let mut pads = unsafe { Pads::new() }.erase();
+//! let table = if board_is_rev_b() { &REV_B } else { &REV_A };
+//! for routing in table {
+//!     unsafe { imxrt_iomuxc::apply_routing(&mut pads, routing) }.unwrap();
+//! }
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+
+use crate::{Config, ConfigError, Daisy, DaisyConflict, ErasedPad, PadId, PreparedWrites};
+use crate::{Function, Peripheral, SionRequirement, ALT_MASK, SION_BIT};
+
+/// A collection of erased pads that you can search by [`PadId`]
+///
+/// Implemented for each chip's `ErasedPads`, and for arrays and slices of
+/// erased pads.
+pub trait PadLookup {
+    /// Returns the pad identified by `id`, if it's in the collection
+    fn find_mut(&mut self, id: PadId) -> Option<&mut ErasedPad>;
+
+    /// Returns `true` if the pad `id` implements the alternate `alt`
+    ///
+    /// The chips' `ErasedPads` check their pads' alternates. The default implementation
+    /// accepts every alternate.
+    fn supports_alternate(&self, id: PadId, alt: u8) -> bool {
+        let _ = (id, alt);
+        true
+    }
+
+    /// Returns the function that the pad `id` carries on the alternate `alt`, if it's known
+    ///
+    /// [`validate()`] uses it to name the function that a mismatched pad is muxed to.
+    /// The chips' `ErasedPads` check their pin tables. The default implementation
+    /// returns `None`.
+    fn function_at(&self, id: PadId, alt: u8) -> Option<Function> {
+        let _ = (id, alt);
+        None
+    }
+}
+
+impl PadLookup for [ErasedPad] {
+    fn find_mut(&mut self, id: PadId) -> Option<&mut ErasedPad> {
+        self.iter_mut().find(|pad| pad.id() == id)
+    }
+}
+
+impl<const N: usize> PadLookup for [ErasedPad; N] {
+    fn find_mut(&mut self, id: PadId) -> Option<&mut ErasedPad> {
+        self[..].find_mut(id)
+    }
+}
+
+/// How to route a pad at run time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Routing {
+    /// The pad to route
+    pub pad: PadId,
+    /// The pad's alternate
+    pub alt: u8,
+    /// The pad's SION requirement
+    pub sion: SionRequirement,
+    /// The daisy selection, if the function requires one
+    pub daisy: Option<Daisy>,
+    /// The pad configuration
+    pub config: Config,
+    /// The peripheral that the pad connects to, if it's known
+    ///
+    /// See [`Peripheral`] for more information.
+    pub peripheral: Option<Peripheral>,
+    /// The function that the pad carries, if it's known
+    ///
+    /// The routing doesn't need it. It names the pin in a stored [`Plan`].
+    pub function: Option<Function>,
+}
+
+impl Routing {
+    /// Describe the routing for a pin's prepared writes
+    ///
+    /// The routing uses the pad configuration from `writes`.
+    pub const fn from_prepared(writes: &PreparedWrites) -> Self {
+        Routing {
+            pad: PadId(writes.mux_addr),
+            alt: (writes.mux_val & ALT_MASK) as u8,
+            sion: writes.sion,
+            daisy: match writes.daisy {
+                Some((reg, value)) => Some(Daisy::new(reg, value)),
+                None => None,
+            },
+            config: Config::from_register(writes.pad_val),
+            peripheral: writes.peripheral,
+            function: None,
+        }
+    }
+
+    /// Name the function that the pad carries
+    ///
+    /// ```
+    /// # #[cfg(feature = "imxrt1060")] fn main() {
+    /// use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::GPIO_AD_B0_12, lpuart, Config, Routing};
+    ///
+    /// const TX: Routing = Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B0_12>(Config::zero()))
+    ///     .with_function(lpuart::function::<GPIO_AD_B0_12>());
+    /// assert_eq!(TX.function, Some(lpuart::function::<GPIO_AD_B0_12>()));
+    /// # }
+    /// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+    /// ```
+    pub const fn with_function(mut self, function: Function) -> Self {
+        self.function = Some(function);
+        self
+    }
+
+    /// Returns the peripheral that this routing borrows, if any
+    const fn borrows(&self) -> Option<Peripheral> {
+        match self.peripheral {
+            Some(peripheral) => peripheral.borrows(),
+            None => None,
+        }
+    }
+
+    /// Check the alternate and the pad configuration
+    const fn validate(&self) -> Result<(), RoutingError> {
+        if self.alt as u32 & !ALT_MASK != 0 {
+            return Err(RoutingError::InvalidAlternate(self.alt));
+        }
+        match self.config.validate() {
+            Ok(()) => Ok(()),
+            Err(err) => Err(RoutingError::Config(err)),
+        }
+    }
+}
+
+/// An error when applying a [`Routing`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RoutingError {
+    /// The pad isn't in the collection of pads
+    UnknownPad(PadId),
+    /// The alternate doesn't fit in the mux register's alternate field
+    InvalidAlternate(u8),
+    /// The pad configuration is invalid
+    Config(ConfigError),
+    /// The pad doesn't support the requested function or alternate
+    UnsupportedPad(PadId),
+}
+
+/// Apply a routing to one of the `pads`
+///
+/// `apply_routing()` finds the routing's pad in `pads`, configures the pad, then
+/// performs the same writes as a peripheral's `prepare()`. See
+/// [Write Ordering](crate#write-ordering).
+/// It checks the routing before writing any register; if the routing is invalid,
+/// or if the pad doesn't implement the routing's alternate, nothing is written.
+///
+/// # Safety
+///
+/// The routing's daisy selection is written as-is. The caller must make sure
+/// that the routing came from the pad's chip, like a routing created with
+/// [`Routing::from_prepared()`].
+pub unsafe fn apply_routing<L>(pads: &mut L, routing: &Routing) -> Result<(), RoutingError>
+where
+    L: PadLookup + ?Sized,
+{
+    routing.validate()?;
+    check_pad(pads, routing)?;
+    if let Some(pad) = pads.find_mut(routing.pad) {
+        crate::prepare_pin_configured(
+            pad,
+            routing.config,
+            routing.alt.into(),
+            routing.sion,
+            routing.daisy,
+        );
+    }
+    Ok(())
+}
+
+/// Check that `pads` has the routing's pad, and that the pad implements the alternate
+fn check_pad<L>(pads: &mut L, routing: &Routing) -> Result<(), RoutingError>
+where
+    L: PadLookup + ?Sized,
+{
+    if pads.find_mut(routing.pad).is_none() {
+        Err(RoutingError::UnknownPad(routing.pad))
+    } else if !pads.supports_alternate(routing.pad, routing.alt) {
+        Err(RoutingError::UnsupportedPad(routing.pad))
+    } else {
+        Ok(())
+    }
+}
+
+/// A set of routings that don't interfere with each other
+///
+/// Use [`plan()`] to create a `Plan`.
+///
+/// # Encoding
+///
+/// Devices that read their pinout from a configuration sector can store a plan as
+/// bytes. [`encode()`](Plan::encode) writes the plan into a buffer, and
+/// [`decode()`](Plan::decode) reads it back into storage that you provide. Neither
+/// allocates.
+///
+/// ```
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::*, lpuart, plan, Config, Plan, Routing};
+///
+/// const ROUTINGS: [Routing; 2] = [
+///     Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B0_12>(Config::zero())),
+///     Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B0_13>(Config::zero())),
+/// ];
+///
+/// let plan = plan(&ROUTINGS).unwrap();
+/// let mut sector = [0u8; 128];
+/// let len = plan.encode(&mut sector).unwrap();
+/// assert_eq!(len, Plan::encoded_len(2));
+///
+/// let mut storage = [ROUTINGS[0]; 4];
+/// let decoded = Plan::decode(&sector[..len], &mut storage).unwrap();
+/// assert_eq!(decoded.routings(), &ROUTINGS);
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// All values are little endian. The encoding starts with an eight byte header:
+///
+/// | Offset | Size | Value                                    |
+/// | ------ | ---- | ---------------------------------------- |
+/// | 0      | 4    | [`MAGIC`](Plan::MAGIC), `"IMXR"`         |
+/// | 4      | 1    | [`VERSION`](Plan::VERSION)               |
+/// | 5      | 1    | Reserved, zero                           |
+/// | 6      | 2    | The number of routings                   |
+///
+/// Each routing follows in [`Routing::ENCODED_LEN`] bytes:
+///
+/// | Offset | Size | Value                                                       |
+/// | ------ | ---- | ----------------------------------------------------------- |
+/// | 0      | 4    | The pad's mux register address                              |
+/// | 4      | 1    | The alternate                                               |
+/// | 5      | 1    | The SION requirement: set, clear, or don't care; 0, 1, or 2 |
+/// | 6      | 1    | The peripheral: none, SAI, MQS, CSI, or eLCDIF; 0 through 4 |
+/// | 7      | 1    | The SAI module, or zero                                     |
+/// | 8      | 4    | The daisy register address, or zero                         |
+/// | 12     | 1    | The daisy value, or zero                                    |
+/// | 13     | 1    | Whether the routing names a function; 0 or 1                |
+/// | 14     | 2    | The function's [`to_bits()`](Function::to_bits), or zero    |
+/// | 16     | 4    | The pad configuration's value                               |
+/// | 20     | 4    | The pad configuration's mask                                |
+///
+/// A CRC-32 (IEEE) of the header and the routings ends the encoding.
+///
+/// A decoder rejects a version, a SION requirement, a peripheral, or a function that
+/// it doesn't know, and a reserved byte that isn't zero. A later version may use them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plan<'a> {
+    routings: &'a [Routing],
+}
+
+/// An error when planning a set of [`Routing`]s
+///
+/// Indices refer to the slice of routings that you planned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PlanError {
+    /// A routing is invalid
+    Routing {
+        /// Index of the routing
+        index: usize,
+        /// Why the routing is invalid
+        error: RoutingError,
+    },
+    /// Two routings use the same pad
+    DuplicatePad {
+        /// The pad
+        pad: PadId,
+        /// Index of the earlier routing
+        earlier: usize,
+        /// Index of the later routing
+        later: usize,
+    },
+    /// Two routings select different pads for the same peripheral input
+    DaisyConflict(DaisyConflict),
+    /// One routing's peripheral borrows a peripheral that another routing uses
+    ///
+    /// See [`Peripheral::borrows()`].
+    BorrowConflict {
+        /// The borrowed peripheral
+        peripheral: Peripheral,
+        /// Index of the routing that borrows the peripheral
+        borrower: usize,
+        /// Index of the routing that uses the peripheral
+        user: usize,
+    },
+    /// A routing uses a pad that another routing's peripheral reserves
+    ///
+    /// See [`Plan::check_reservations()`].
+    ReservedPad {
+        /// The reserved pad
+        pad: PadId,
+        /// The peripheral that reserves the pad
+        peripheral: Peripheral,
+        /// Index of the routing that uses the pad
+        index: usize,
+        /// Index of the routing that uses the peripheral
+        user: usize,
+    },
+    /// An assignment names a function that its pad can't carry
+    ///
+    /// Only returned when planning `(PadId, Function)` assignments, like
+    /// `imxrt1060::plan_assignments()`.
+    ImpossibleAssignment {
+        /// Index of the assignment
+        index: usize,
+        /// The assignment's pad
+        pad: PadId,
+        /// The assignment's function
+        function: Function,
+    },
+    /// The storage can't hold a routing for each assignment
+    ///
+    /// Holds the number of assignments.
+    TooManyAssignments(usize),
+}
+
+/// Pads that a peripheral reserves
+///
+/// A parallel bus, like the CSI or the eLCDIF, needs a fixed set of pads. Once a
+/// plan uses the peripheral, no other function may use those pads, even if the
+/// plan doesn't route them yet. Chip modules list their reservations, like
+/// `imxrt1060::RESERVATIONS`. Check a [`Plan`] against them with
+/// [`check_reservations()`](Plan::check_reservations).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reservation {
+    /// The peripheral
+    pub peripheral: Peripheral,
+    /// The pads that the peripheral reserves
+    pub pads: &'static [PadId],
+}
+
+/// Check that `routings` can be applied together
+///
+/// `plan()` makes sure that
+///
+/// - each routing has a valid alternate and pad configuration.
+/// - no two routings use the same pad.
+/// - no two routings select different pads for the same peripheral input. See
+///   [`detect_daisy_conflicts()`](crate::detect_daisy_conflicts) for more information.
+/// - no routing uses a peripheral that another routing's peripheral borrows, like
+///   an SAI3 pin and an MQS pin. See [`Peripheral::borrows()`].
+///
+/// A routing created with [`Routing::from_prepared()`] is always a function that its pad
+/// supports, since the peripheral's `prepared()` only accepts pins of that peripheral.
+/// The check does not touch any registers, so you may run it on a host, or in a test.
+/// `plan()` is a `const fn`, so a `const` can check routings while compiling. See
+/// [`pinout!`](crate::pinout).
+///
+/// ```
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::{gpio_ad_b1::*, gpio_sd_b1::*}, lpi2c, plan, Config};
+/// use imxrt_iomuxc::{PlanError, Routing};
+///
+/// const I2C: Config = Config::zero();
+/// let routings = [
+///     Routing::from_prepared(&lpi2c::prepared::<GPIO_AD_B1_00>(I2C)), // LPI2C1 SCL
+///     Routing::from_prepared(&lpi2c::prepared::<GPIO_AD_B1_01>(I2C)), // LPI2C1 SDA
+/// ];
+/// assert!(plan(&routings).is_ok());
+///
+/// let routings = [
+///     Routing::from_prepared(&lpi2c::prepared::<GPIO_AD_B1_00>(I2C)), // LPI2C1 SCL
+///     Routing::from_prepared(&lpi2c::prepared::<GPIO_SD_B1_04>(I2C)), // LPI2C1 SCL, again
+/// ];
+/// assert!(matches!(plan(&routings), Err(PlanError::DaisyConflict(_))));
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+pub const fn plan(routings: &[Routing]) -> Result<Plan<'_>, PlanError> {
+    let mut index = 0;
+    while index < routings.len() {
+        if let Err(error) = routings[index].validate() {
+            return Err(PlanError::Routing { index, error });
+        }
+        index += 1;
+    }
+    let mut later = 0;
+    while later < routings.len() {
+        let routing = &routings[later];
+        let mut earlier = 0;
+        while earlier < later {
+            let other = &routings[earlier];
+            if other.pad.0 == routing.pad.0 {
+                return Err(PlanError::DuplicatePad {
+                    pad: routing.pad,
+                    earlier,
+                    later,
+                });
+            }
+            if let (Some(earlier_daisy), Some(later_daisy)) = (other.daisy, routing.daisy) {
+                if earlier_daisy.reg() == later_daisy.reg()
+                    && earlier_daisy.value() != later_daisy.value()
+                {
+                    return Err(PlanError::DaisyConflict(DaisyConflict {
+                        reg: later_daisy.reg(),
+                        earlier,
+                        earlier_value: earlier_daisy.value(),
+                        later,
+                        later_value: later_daisy.value(),
+                    }));
+                }
+            }
+            if let Some(peripheral) = borrowed(other, routing) {
+                return Err(PlanError::BorrowConflict {
+                    peripheral,
+                    borrower: earlier,
+                    user: later,
+                });
+            }
+            if let Some(peripheral) = borrowed(routing, other) {
+                return Err(PlanError::BorrowConflict {
+                    peripheral,
+                    borrower: later,
+                    user: earlier,
+                });
+            }
+            earlier += 1;
+        }
+        later += 1;
+    }
+    Ok(Plan { routings })
+}
+
+/// Resolve each assignment with `resolve`, then [`plan()`] the routings
+///
+/// The chips' `plan_assignments()` resolve through their pin tables. The routings
+/// go in the front of `storage`, in assignment order, so indices in the error
+/// refer to `assignments`.
+#[cfg_attr(not(any(feature = "imxrt1010", feature = "imxrt1060")), allow(unused))]
+pub(crate) fn plan_assignments<'a>(
+    assignments: &[(PadId, Function)],
+    storage: &'a mut [Routing],
+    resolve: fn(PadId, Function) -> Option<Routing>,
+) -> Result<Plan<'a>, PlanError> {
+    if assignments.len() > storage.len() {
+        return Err(PlanError::TooManyAssignments(assignments.len()));
+    }
+    for (index, (&(pad, function), slot)) in assignments.iter().zip(storage.iter_mut()).enumerate()
+    {
+        *slot = resolve(pad, function).ok_or(PlanError::ImpossibleAssignment {
+            index,
+            pad,
+            function,
+        })?;
+    }
+    let storage: &'a [Routing] = storage;
+    plan(&storage[..assignments.len()])
+}
+
+/// Returns the peripheral that `borrower` borrows from `user`, if any
+const fn borrowed(borrower: &Routing, user: &Routing) -> Option<Peripheral> {
+    match (borrower.borrows(), user.peripheral) {
+        (Some(Peripheral::Sai(borrowed)), Some(Peripheral::Sai(used))) if borrowed == used => {
+            Some(Peripheral::Sai(borrowed))
+        }
+        (Some(Peripheral::Mqs), Some(Peripheral::Mqs)) => Some(Peripheral::Mqs),
+        (Some(Peripheral::Csi), Some(Peripheral::Csi)) => Some(Peripheral::Csi),
+        (Some(Peripheral::Lcdif), Some(Peripheral::Lcdif)) => Some(Peripheral::Lcdif),
+        _ => None,
+    }
+}
+
+impl<'a> Plan<'a> {
+    /// Returns the planned routings
+    pub const fn routings(&self) -> &'a [Routing] {
+        self.routings
+    }
+
+    /// Apply all of the routings to the `pads`
+    ///
+    /// If a routing's pad isn't in `pads`, or if the pad doesn't implement the
+    /// routing's alternate, nothing is written.
+    ///
+    /// # Safety
+    ///
+    /// See [`apply_routing()`].
+    pub unsafe fn apply<L>(&self, pads: &mut L) -> Result<(), RoutingError>
+    where
+        L: PadLookup + ?Sized,
+    {
+        for routing in self.routings {
+            check_pad(pads, routing)?;
+        }
+        for routing in self.routings {
+            apply_routing(pads, routing)?;
+        }
+        Ok(())
+    }
+
+    /// Check that no routing uses a pad that another routing's peripheral reserves
+    ///
+    /// A reservation applies once any routing uses its peripheral. Then, each
+    /// routing on a reserved pad must use the same peripheral. Returns the plan,
+    /// so that you can chain the check after [`plan()`].
+    ///
+    /// ```
+    /// # #[cfg(feature = "imxrt1060")] fn main() {
+    /// use imxrt_iomuxc::{imxrt1060::{gpio_ad_b1::*, RESERVATIONS}, csi, lpuart, plan, Config};
+    /// use imxrt_iomuxc::{PlanError, Routing};
+    ///
+    /// const CONFIG: Config = Config::zero();
+    /// let routings = [
+    ///     Routing::from_prepared(&csi::prepared::<GPIO_AD_B1_04>(CONFIG)), // CSI PIXCLK
+    ///     Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B1_06>(CONFIG)), // LPUART3 TX
+    /// ];
+    /// let plan = plan(&routings).unwrap();
+    /// assert!(matches!(
+    ///     plan.check_reservations(&RESERVATIONS),
+    ///     Err(PlanError::ReservedPad { index: 1, user: 0, .. })
+    /// ));
+    /// # }
+    /// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+    /// ```
+    pub fn check_reservations(self, reservations: &[Reservation]) -> Result<Self, PlanError> {
+        for reservation in reservations {
+            let peripheral = Some(reservation.peripheral);
+            let user = match self
+                .routings
+                .iter()
+                .position(|routing| routing.peripheral == peripheral)
+            {
+                Some(user) => user,
+                None => continue,
+            };
+            for (index, routing) in self.routings.iter().enumerate() {
+                if routing.peripheral != peripheral && reservation.pads.contains(&routing.pad) {
+                    return Err(PlanError::ReservedPad {
+                        pad: routing.pad,
+                        peripheral: reservation.peripheral,
+                        index,
+                        user,
+                    });
+                }
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// A difference between a planned [`Routing`] and the pad's registers
+///
+/// Indices refer to the plan's [`routings()`](Plan::routings).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Mismatch {
+    /// The pad isn't in the collection of pads
+    UnknownPad {
+        /// Index of the routing
+        index: usize,
+        /// The pad
+        pad: PadId,
+    },
+    /// The mux register selects another alternate, or has the wrong SION bit
+    Mux {
+        /// Index of the routing
+        index: usize,
+        /// The pad
+        pad: PadId,
+        /// The mux register value
+        actual: u32,
+        /// The function that the mux register's alternate selects
+        ///
+        /// `None` if the pad lookup doesn't know the function, like a GPIO, or a
+        /// function that the crate doesn't implement.
+        muxed: Option<Function>,
+    },
+    /// The pad configuration register doesn't hold the planned configuration
+    Config {
+        /// Index of the routing
+        index: usize,
+        /// The pad
+        pad: PadId,
+        /// The pad configuration register value
+        actual: u32,
+    },
+    /// The select input register selects another pad
+    Daisy {
+        /// Index of the routing
+        index: usize,
+        /// Address of the select input register
+        reg: usize,
+        /// The planned select input value
+        expected: u32,
+        /// The select input register value
+        actual: u32,
+    },
+}
+
+/// Check the pads against a plan, and return every mismatch
+///
+/// `validate()` reads back each routing's mux register, pad configuration
+/// register, and select input register. A SION requirement of
+/// [`DontCare`](SionRequirement::DontCare) ignores the SION bit, and a
+/// configuration created with [`Config::modify()`] only checks the fields that it
+/// specifies. The iterator yields the mismatches in routing order, and
+/// it doesn't stop at the first mismatch; if it yields nothing, the pads hold the plan.
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::{gpio_ad_b0::*, Pads}, lpuart, plan, validate};
+/// use imxrt_iomuxc::{Config, Routing};
+///
+/// const UART: Config = Config::zero();
+/// const BOARD: [Routing; 2] = [
+///     Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B0_12>(UART)),
+///     Routing::from_prepared(&lpuart::prepared::<GPIO_AD_B0_13>(UART)),
+/// ];
+///
+/// let mut pads = unsafe { Pads::new() }.erase();
+/// let plan = plan(&BOARD).unwrap();
+/// unsafe { plan.apply(&mut pads) }.unwrap();
+/// for mismatch in unsafe { validate(&plan, &mut pads) } {
+///     // Report the mismatch...
+/// #   let _ = mismatch;
+/// }
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// # Safety
+///
+/// `validate()` reads the routings' select input registers. See
+/// [`apply_routing()`].
+pub unsafe fn validate<'a, L>(
+    plan: &Plan<'a>,
+    pads: &'a mut L,
+) -> impl Iterator<Item = Mismatch> + 'a
+where
+    L: PadLookup + ?Sized,
+{
+    plan.routings
+        .iter()
+        .enumerate()
+        .flat_map(move |(index, routing)| {
+            IntoIterator::into_iter(mismatches(pads, index, routing)).flatten()
+        })
+}
+
+/// Returns the mismatches between one routing and its pad
+unsafe fn mismatches<L>(pads: &mut L, index: usize, routing: &Routing) -> [Option<Mismatch>; 3]
+where
+    L: PadLookup + ?Sized,
+{
+    let pad = routing.pad;
+    let pad_ref = match pads.find_mut(pad) {
+        Some(erased) => erased.pad_ref(),
+        None => return [Some(Mismatch::UnknownPad { index, pad }), None, None],
+    };
+
+    let mux = pad_ref.mux_value();
+    let sion_ok = match routing.sion {
+        SionRequirement::Set => mux & SION_BIT != 0,
+        SionRequirement::Clear => mux & SION_BIT == 0,
+        SionRequirement::DontCare => true,
+    };
+    let mux_mismatch = if mux & ALT_MASK != u32::from(routing.alt) || !sion_ok {
+        Some(Mismatch::Mux {
+            index,
+            pad,
+            actual: mux,
+            muxed: pads.function_at(pad, (mux & ALT_MASK) as u8),
+        })
+    } else {
+        None
+    };
+
+    let config = pad_ref.pad_value();
+    let config_mismatch = if !routing.config.matches(config) {
+        Some(Mismatch::Config {
+            index,
+            pad,
+            actual: config,
+        })
+    } else {
+        None
+    };
+
+    let daisy_mismatch = routing.daisy.and_then(|daisy| {
+        let actual = daisy.read();
+        if actual != daisy.value() {
+            Some(Mismatch::Daisy {
+                index,
+                reg: daisy.reg(),
+                expected: daisy.value(),
+                actual,
+            })
+        } else {
+            None
+        }
+    });
+
+    [mux_mismatch, config_mismatch, daisy_mismatch]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoutingError;
+    use super::{apply_routing, plan, validate, Mismatch, PadLookup, PlanError, Routing};
+    use crate::PullKeeper;
+    use crate::{function::UartDirection, Function, Peripheral, PreparedWrites, SionRequirement};
+    use crate::{Config, ConfigError, Daisy, DaisyConflict, ErasedPad, OpenDrain, PadId};
+
+    // Each pad is backed by a mux and a pad register. The routings select
+    // their inputs in the two daisy registers.
+    struct Registers {
+        mux: [u32; 4],
+        pad: [u32; 4],
+        select: [u32; 2],
+    }
+
+    impl Registers {
+        fn erase(&mut self) -> [ErasedPad; 4] {
+            let mut pad = |offset| ErasedPad {
+                mux_base: self.mux.as_mut_ptr(),
+                pad_base: self.pad.as_mut_ptr(),
+                offset,
+            };
+            [pad(0), pad(1), pad(2), pad(3)]
+        }
+
+        /// The address of the first daisy register
+        fn select(&mut self) -> usize {
+            self.select.as_mut_ptr() as usize
+        }
+    }
+
+    fn routing(pads: &[ErasedPad], offset: usize, select: usize) -> Routing {
+        Routing::from_prepared(&PreparedWrites {
+            mux_addr: pads[offset].id().mux_addr(),
+            mux_val: 0x13,
+            sion: SionRequirement::Set,
+            pad_addr: 0,
+            pad_val: 0x10B0,
+            daisy: Some((select, 1)),
+            peripheral: None,
+        })
+    }
+
+    #[test]
+    fn from_prepared() {
+        let mut registers = Registers {
+            mux: [0; 4],
+            pad: [0; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let pads = registers.erase();
+        let routing = routing(&pads, 2, select);
+        assert_eq!(routing.pad, pads[2].id());
+        assert_eq!(routing.alt, 3);
+        assert_eq!(routing.sion, SionRequirement::Set);
+        assert_eq!(routing.daisy, Some(Daisy::new(select, 1)));
+        assert!(routing.config.is_zero());
+        assert_eq!(routing.config.value(), 0x10B0);
+    }
+
+    #[test]
+    fn apply() {
+        let mut registers = Registers {
+            mux: [0x7; 4],
+            pad: [0xFFFF; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let mut pads = registers.erase();
+        let routing = routing(&pads, 1, select);
+        unsafe { apply_routing(&mut pads, &routing) }.unwrap();
+
+        assert_eq!(registers.mux, [0x7, 0x13, 0x7, 0x7]);
+        assert_eq!(registers.pad, [0xFFFF, 0x10B0, 0xFFFF, 0xFFFF]);
+        assert_eq!(registers.select, [1, 0]);
+    }
+
+    #[test]
+    fn invalid_routing_writes_nothing() {
+        let mut registers = Registers {
+            mux: [0x7; 4],
+            pad: [0xFFFF; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let mut pads = registers.erase();
+
+        let mut routing = routing(&pads, 0, select);
+        routing.pad = PadId(0x1234);
+        assert_eq!(
+            unsafe { apply_routing(&mut pads, &routing) },
+            Err(RoutingError::UnknownPad(PadId(0x1234)))
+        );
+
+        routing.pad = pads[0].id();
+        routing.alt = 16;
+        assert_eq!(
+            unsafe { apply_routing(&mut pads, &routing) },
+            Err(RoutingError::InvalidAlternate(16))
+        );
+
+        routing.alt = 2;
+        routing.config = Config::zero()
+            .set_open_drain(OpenDrain::Enabled)
+            .set_pull_keeper(Some(PullKeeper::Pulldown100k));
+        assert_eq!(
+            unsafe { apply_routing(&mut pads[..], &routing) },
+            Err(RoutingError::Config(ConfigError::OpenDrainPulldown))
+        );
+        assert!(pads[..].find_mut(routing.pad).is_some());
+
+        assert_eq!(registers.mux, [0x7; 4]);
+        assert_eq!(registers.pad, [0xFFFF; 4]);
+        assert_eq!(registers.select, [0; 2]);
+    }
+
+    #[test]
+    fn plan_and_apply() {
+        let mut registers = Registers {
+            mux: [0x7; 4],
+            pad: [0xFFFF; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let mut pads = registers.erase();
+        // Same daisy value, so there's no conflict
+        let routings = [routing(&pads, 3, select), routing(&pads, 1, select)];
+        let plan = plan(&routings).unwrap();
+        assert_eq!(plan.routings(), &routings);
+        unsafe { plan.apply(&mut pads) }.unwrap();
+
+        assert_eq!(registers.mux, [0x7, 0x13, 0x7, 0x13]);
+        assert_eq!(registers.pad, [0xFFFF, 0x10B0, 0xFFFF, 0x10B0]);
+        assert_eq!(registers.select, [1, 0]);
+    }
+
+    #[test]
+    fn plan_errors() {
+        let mut registers = Registers {
+            mux: [0; 4],
+            pad: [0; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let pads = registers.erase();
+        let mut routings = [
+            routing(&pads, 0, select),
+            routing(&pads, 1, select),
+            routing(&pads, 2, select),
+        ];
+
+        routings[2].pad = pads[0].id();
+        assert_eq!(
+            plan(&routings),
+            Err(PlanError::DuplicatePad {
+                pad: pads[0].id(),
+                earlier: 0,
+                later: 2
+            })
+        );
+
+        routings[2].pad = pads[2].id();
+        routings[2].daisy = Some(Daisy::new(select, 0));
+        assert_eq!(
+            plan(&routings),
+            Err(PlanError::DaisyConflict(DaisyConflict {
+                reg: select,
+                earlier: 0,
+                earlier_value: 1,
+                later: 2,
+                later_value: 0,
+            }))
+        );
+
+        routings[1].alt = 16;
+        assert_eq!(
+            plan(&routings),
+            Err(PlanError::Routing {
+                index: 1,
+                error: RoutingError::InvalidAlternate(16)
+            })
+        );
+
+        routings[1].alt = 3;
+        routings[2].daisy = None;
+        routings[0].config = Config::zero()
+            .set_open_drain(OpenDrain::Enabled)
+            .set_pull_keeper(Some(PullKeeper::Keeper));
+        assert_eq!(
+            plan(&routings),
+            Err(PlanError::Routing {
+                index: 0,
+                error: RoutingError::Config(ConfigError::OpenDrainKeeper)
+            })
+        );
+    }
+
+    #[test]
+    fn plan_borrow_conflict() {
+        let mut registers = Registers {
+            mux: [0; 4],
+            pad: [0; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let pads = registers.erase();
+        let mut routings = [
+            routing(&pads, 0, select),
+            routing(&pads, 1, select),
+            routing(&pads, 2, select),
+        ];
+        for routing in routings.iter_mut() {
+            routing.daisy = None;
+        }
+        routings[0].peripheral = Some(Peripheral::Sai(3));
+        routings[1].peripheral = Some(Peripheral::Sai(1));
+        routings[2].peripheral = Some(Peripheral::Mqs);
+        assert_eq!(
+            plan(&routings),
+            Err(PlanError::BorrowConflict {
+                peripheral: Peripheral::Sai(3),
+                borrower: 2,
+                user: 0,
+            })
+        );
+
+        // Borrowing works in either order
+        routings.swap(0, 2);
+        assert_eq!(
+            plan(&routings),
+            Err(PlanError::BorrowConflict {
+                peripheral: Peripheral::Sai(3),
+                borrower: 0,
+                user: 2,
+            })
+        );
+
+        routings[2].peripheral = Some(Peripheral::Sai(2));
+        assert!(plan(&routings).is_ok());
+    }
+
+    /// Pads that only implement ALT3, like a chip's `ErasedPads` for its pin tables
+    ///
+    /// ALT3 is `LPUART1_TX` on every pad.
+    struct Alt3Only([ErasedPad; 4]);
+
+    const ALT3: Function = Function::Lpuart {
+        module: 1,
+        direction: UartDirection::Tx,
+    };
+
+    impl PadLookup for Alt3Only {
+        fn find_mut(&mut self, id: PadId) -> Option<&mut ErasedPad> {
+            self.0.find_mut(id)
+        }
+        fn supports_alternate(&self, _: PadId, alt: u8) -> bool {
+            alt == 3
+        }
+        fn function_at(&self, _: PadId, alt: u8) -> Option<Function> {
+            Some(ALT3).filter(|_| alt == 3)
+        }
+    }
+
+    #[test]
+    fn unsupported_alternate_writes_nothing() {
+        let mut registers = Registers {
+            mux: [0x7; 4],
+            pad: [0xFFFF; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let mut pads = Alt3Only(registers.erase());
+        // In range, but the pad doesn't implement it
+        let mut routings = [routing(&pads.0, 0, select), routing(&pads.0, 1, select)];
+        routings[1].alt = 2;
+        assert_eq!(
+            unsafe { apply_routing(&mut pads, &routings[1]) },
+            Err(RoutingError::UnsupportedPad(routings[1].pad))
+        );
+
+        let plan = plan(&routings).unwrap();
+        assert_eq!(
+            unsafe { plan.apply(&mut pads) },
+            Err(RoutingError::UnsupportedPad(routings[1].pad))
+        );
+
+        assert_eq!(registers.mux, [0x7; 4]);
+        assert_eq!(registers.pad, [0xFFFF; 4]);
+        assert_eq!(registers.select, [0; 2]);
+    }
+
+    #[test]
+    fn validate_names_the_muxed_function() {
+        let mut registers = Registers {
+            mux: [0x13; 4],
+            pad: [0x10B0; 4],
+            select: [1; 2],
+        };
+        let select = registers.select();
+        let mut pads = Alt3Only(registers.erase());
+        let mut routings = [routing(&pads.0, 0, select), routing(&pads.0, 1, select)];
+        routings[0].alt = 2;
+        routings[1].sion = SionRequirement::Clear;
+        let plan = plan(&routings).unwrap();
+
+        let mismatches: [_; 2] = {
+            let mut mismatches = unsafe { validate(&plan, &mut pads) };
+            [mismatches.next(), mismatches.next()]
+        };
+        assert_eq!(
+            mismatches,
+            [
+                Some(Mismatch::Mux {
+                    index: 0,
+                    pad: routings[0].pad,
+                    actual: 0x13,
+                    muxed: Some(ALT3),
+                }),
+                Some(Mismatch::Mux {
+                    index: 1,
+                    pad: routings[1].pad,
+                    actual: 0x13,
+                    muxed: Some(ALT3),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_apply_unknown_pad_writes_nothing() {
+        let mut registers = Registers {
+            mux: [0x7; 4],
+            pad: [0xFFFF; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let mut pads = registers.erase();
+        let mut routings = [routing(&pads, 0, select), routing(&pads, 1, select)];
+        routings[1].pad = PadId(0x1234);
+        let plan = plan(&routings).unwrap();
+        assert_eq!(
+            unsafe { plan.apply(&mut pads[..2]) },
+            Err(RoutingError::UnknownPad(PadId(0x1234)))
+        );
+
+        assert_eq!(registers.mux, [0x7; 4]);
+        assert_eq!(registers.select, [0; 2]);
+    }
+
+    #[test]
+    fn validate_reports_every_mismatch() {
+        let mut registers = Registers {
+            mux: [0x7; 4],
+            pad: [0xFFFF; 4],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let mut pads = registers.erase();
+        let mut routings = [
+            routing(&pads, 0, select),
+            routing(&pads, 1, select),
+            routing(&pads, 2, select),
+        ];
+        routings[2].daisy = Some(Daisy::new(select + 4, 2));
+        let plan = plan(&routings).unwrap();
+        unsafe { plan.apply(&mut pads) }.unwrap();
+        assert_eq!(unsafe { validate(&plan, &mut pads) }.count(), 0);
+
+        // Clear SION on the first pad, change the second pad's drive strength,
+        // and select another pad for the third pad's input.
+        registers.mux[0] = 0x03;
+        registers.pad[1] = 0x10B8;
+        registers.select[1] = 0;
+
+        let mut pads = registers.erase();
+        let mut mismatches = unsafe { validate(&plan, &mut pads) };
+        assert_eq!(
+            mismatches.next(),
+            Some(Mismatch::Mux {
+                index: 0,
+                pad: routings[0].pad,
+                actual: 0x03,
+                muxed: None,
+            })
+        );
+        assert_eq!(
+            mismatches.next(),
+            Some(Mismatch::Config {
+                index: 1,
+                pad: routings[1].pad,
+                actual: 0x10B8
+            })
+        );
+        assert_eq!(
+            mismatches.next(),
+            Some(Mismatch::Daisy {
+                index: 2,
+                reg: select + 4,
+                expected: 2,
+                actual: 0
+            })
+        );
+        assert_eq!(mismatches.next(), None);
+    }
+
+    #[test]
+    fn validate_ignores_unspecified_fields() {
+        let mut registers = Registers {
+            mux: [0x13, 0x03, 0x7, 0x7],
+            pad: [0x10B0, 0x10B8, 0xFFFF, 0xFFFF],
+            select: [0; 2],
+        };
+        let select = registers.select();
+        let mut pads = registers.erase();
+        let mut routings = [
+            routing(&pads, 0, select),
+            routing(&pads, 1, select),
+            routing(&pads, 3, select),
+        ];
+        routings[0].daisy = None;
+        routings[1].daisy = None;
+        routings[1].sion = SionRequirement::DontCare;
+        routings[1].config = Config::keeper();
+        routings[2].daisy = None;
+        routings[2].pad = PadId(0x1234);
+        let plan = plan(&routings).unwrap();
+
+        let mut mismatches = unsafe { validate(&plan, &mut pads[..]) };
+        assert_eq!(
+            mismatches.next(),
+            Some(Mismatch::UnknownPad {
+                index: 2,
+                pad: PadId(0x1234)
+            })
+        );
+        assert_eq!(mismatches.next(), None);
+    }
+
+    #[test]
+    fn plan_in_const() {
+        const ROUTING: Routing = Routing {
+            pad: PadId(0x401f_80bc),
+            alt: 2,
+            sion: SionRequirement::Clear,
+            daisy: None,
+            config: Config::zero(),
+            peripheral: None,
+            function: None,
+        };
+        const VALID: Result<super::Plan<'static>, PlanError> = plan(&[ROUTING]);
+        const DUPLICATE: Result<super::Plan<'static>, PlanError> = plan(&[ROUTING, ROUTING]);
+
+        assert!(VALID.is_ok());
+        assert_eq!(
+            DUPLICATE,
+            Err(PlanError::DuplicatePad {
+                pad: ROUTING.pad,
+                earlier: 0,
+                later: 1,
+            })
+        );
+    }
+}
@@ -6,6 +6,7 @@
 //! the inverse operation.
 //!
 //! ```
+//! # #[cfg(feature = "imxrt1060")] fn main() {
 //! use imxrt_iomuxc::sai::{Pin, TxDataSignal};
 //! use imxrt_iomuxc::consts::{U1, Unsigned};
 //!
@@ -35,10 +36,22 @@
 //!
 //!
 //! sai1.add_tx_pin(gpio_sd_b1_02);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
 //! ```
+//!
+//! # Clock direction
+//!
+//! The BCLK and SYNC pins are outputs when the SAI is the clock master, and inputs
+//! when it's the clock slave. Only an input needs its daisy register. [`prepare()`]
+//! treats the clock pins as inputs. Use [`prepare_clock()`] to choose the
+//! [`ClockDirection`], so that a clock master skips the daisy write.
+
+use crate::{consts::Unsigned, function::SaiSignal};
+use core::marker::PhantomData;
 
 /// An SAI pin signal
-pub trait Signal: Sealed {}
+pub trait Signal: Sealed + private::Code {}
 /// An SAI TX data signal
 pub trait TxDataSignal: Signal {
     /// Data pin index; the `3` in `TX_DATA03`
@@ -52,6 +65,11 @@ pub trait RxDataSignal: Signal {
 
 pub(crate) mod private {
     pub trait Sealed {}
+
+    /// A signal that's known at run time
+    pub trait Code {
+        const SIGNAL: crate::function::SaiSignal;
+    }
 }
 use private::Sealed;
 
@@ -86,6 +104,36 @@ impl RxDataSignal for RxData {
     type Index = super::consts::U0;
 }
 
+/// An SAI bit clock or frame sync signal
+pub trait ClockSignal: Signal {}
+
+impl ClockSignal for TxBclk {}
+impl ClockSignal for TxSync {}
+impl ClockSignal for RxBclk {}
+impl ClockSignal for RxSync {}
+
+impl private::Code for TxBclk {
+    const SIGNAL: SaiSignal = SaiSignal::TxBclk;
+}
+impl private::Code for TxSync {
+    const SIGNAL: SaiSignal = SaiSignal::TxSync;
+}
+impl private::Code for RxBclk {
+    const SIGNAL: SaiSignal = SaiSignal::RxBclk;
+}
+impl private::Code for RxSync {
+    const SIGNAL: SaiSignal = SaiSignal::RxSync;
+}
+impl private::Code for Mclk {
+    const SIGNAL: SaiSignal = SaiSignal::Mclk;
+}
+impl private::Code for TxData {
+    const SIGNAL: SaiSignal = SaiSignal::TxData(<Self as TxDataSignal>::Index::U8);
+}
+impl private::Code for RxData {
+    const SIGNAL: SaiSignal = SaiSignal::RxData(<Self as RxDataSignal>::Index::U8);
+}
+
 impl Sealed for TxBclk {}
 impl Sealed for TxSync {}
 impl Sealed for RxBclk {}
@@ -102,16 +150,114 @@ pub trait Pin<SAIx: crate::consts::Unsigned>: super::Iomuxc {
     const ALT: u32;
     /// The daisy register which will select the pad
     const DAISY: Option<super::Daisy>;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Set`](crate::SionRequirement::Set).
+    const SION: super::SionRequirement = super::SionRequirement::Set;
     /// The SAI signal
     type Signal: Signal;
 }
 
+module_aliases!("SAI":
+    1 => Sai1 = U1,
+    2 => Sai2 = U2,
+    3 => Sai3 = U3,
+);
+
 /// Prepare a pad to be used as a SAI pin
 pub fn prepare<SAIx: crate::consts::Unsigned, P: Pin<SAIx>>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::set_sion(pin);
-    if let Some(daisy) = P::DAISY {
-        unsafe { daisy.write() };
+    trace!("sai::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, P::DAISY);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<SAIx: crate::consts::Unsigned, P: Pin<SAIx>>(pin: &mut P) -> bool {
+    trace!("sai::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, P::DAISY)
+}
+
+/// The direction of an SAI bit clock or frame sync pin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockDirection {
+    /// The SAI is the clock master, and drives the pin
+    ///
+    /// Preparing the pin does not write its daisy register.
+    Output,
+    /// The SAI is the clock slave, and receives the clock from the pin
+    ///
+    /// Preparing the pin writes its daisy register, which selects the pad.
+    Input,
+}
+
+/// Prepare a SAI bit clock or frame sync pin for the clock `direction`
+///
+/// For an [`Input`](ClockDirection::Input), this is the same as [`prepare()`]. For an
+/// [`Output`](ClockDirection::Output), this skips the daisy write.
+pub fn prepare_clock<SAIx, P>(pin: &mut P, direction: ClockDirection)
+where
+    SAIx: crate::consts::Unsigned,
+    P: Pin<SAIx>,
+    <P as Pin<SAIx>>::Signal: ClockSignal,
+{
+    trace!(
+        "sai::prepare_clock mux={:#010x} direction={:?}",
+        pin.mux() as usize,
+        direction
+    );
+    super::prepare_pin(pin, P::ALT, P::SION, clock_daisy(P::DAISY, direction));
+}
+
+/// The daisy selection for a clock pin in `direction`
+const fn clock_daisy(
+    daisy: Option<super::Daisy>,
+    direction: ClockDirection,
+) -> Option<super::Daisy> {
+    match direction {
+        ClockDirection::Output => None,
+        ClockDirection::Input => daisy,
+    }
+}
+
+/// Compute the register writes that prepare a SAI pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+///
+/// The result records the SAI module as its [`Peripheral`](crate::Peripheral).
+pub const fn prepared<SAIx: crate::consts::Unsigned, P: Pin<SAIx> + super::ConstIomuxc>(
+    config: super::Config,
+) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, P::DAISY)
+        .with_peripheral(super::Peripheral::Sai(SAIx::U32))
+}
+
+/// Compute the register writes that prepare a SAI bit clock or frame sync pin
+///
+/// The result describes the same writes as [`prepare_clock()`]. For an
+/// [`Output`](ClockDirection::Output), the result has no daisy write. See
+/// [`prepared()`] for more information.
+pub const fn prepared_clock<SAIx, P>(
+    config: super::Config,
+    direction: ClockDirection,
+) -> super::PreparedWrites
+where
+    SAIx: crate::consts::Unsigned,
+    P: Pin<SAIx> + super::ConstIomuxc,
+    <P as Pin<SAIx>>::Signal: ClockSignal,
+{
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, clock_daisy(P::DAISY, direction))
+        .with_peripheral(super::Peripheral::Sai(SAIx::U32))
+}
+
+/// Returns the [`Function`](crate::Function) of a SAI pin
+pub const fn function<SAIx: crate::consts::Unsigned, P: Pin<SAIx>>() -> super::Function {
+    super::Function::Sai {
+        module: SAIx::U8,
+        signal: <P::Signal as private::Code>::SIGNAL,
     }
 }
 
@@ -126,3 +272,272 @@ macro_rules! sai {
         }
     };
 }
+
+/// Describes where the SAI receiver finds its bit clock and frame sync
+///
+/// See [`Pins`] for more information.
+pub trait Mode: Sealed {}
+
+/// The receiver and transmitter use their own BCLK and SYNC pads
+pub enum AsyncMode {}
+/// The receiver runs synchronous to the transmitter
+///
+/// The receiver shares the transmitter's BCLK and SYNC pads, so there are
+/// no RX clock pads.
+pub enum SyncMode {}
+
+impl Mode for AsyncMode {}
+impl Mode for SyncMode {}
+impl Sealed for AsyncMode {}
+impl Sealed for SyncMode {}
+
+/// A bundle of SAI pins for one SAI module
+///
+/// `SAIx` is the SAI module, and `M` is the [`Mode`]. Every pin must be a pin
+/// for the same `SAIx`, and must carry the signal of its field. The bundle
+/// checks both when you construct it.
+///
+/// In [`SyncMode`], the receiver runs from the transmitter's clocks. There are no RX
+/// BCLK and RX SYNC pads; those fields are `()`. Use [`SyncPins`] to name the type,
+/// and [`SyncPins::new`] to construct it. In [`AsyncMode`], the receiver has its
+/// own clock pads. Use [`AsyncPins`] to name the type, and [`AsyncPins::new`] to
+/// construct it.
+///
+/// # Example
+///
+/// A WM8960 codec, wired like the Teensy audio shield, with the receiver synchronous
+/// to the transmitter:
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::imxrt1060::{gpio_ad_b1::*, gpio_b1::*};
+/// use imxrt_iomuxc::{consts::U1, sai};
+///
+/// let mut pins = sai::SyncPins::<U1, _, _, _, _, _>::new(
+///     unsafe { GPIO_AD_B1_09::new() }, // MCLK
+///     unsafe { GPIO_AD_B1_14::new() }, // TX BCLK
+///     unsafe { GPIO_AD_B1_15::new() }, // TX SYNC
+///     unsafe { GPIO_B1_01::new() },    // TX DATA0
+///     unsafe { GPIO_B1_00::new() },    // RX DATA0
+/// );
+/// pins.prepare();
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// A full, asynchronous set of SAI1 pins:
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::imxrt1060::{gpio_ad_b1::*, gpio_b1::*};
+/// use imxrt_iomuxc::{consts::U1, sai};
+///
+/// let mut pins = sai::AsyncPins::<U1, _, _, _, _, _, _, _>::new(
+///     unsafe { GPIO_AD_B1_09::new() }, // MCLK
+///     unsafe { GPIO_AD_B1_14::new() }, // TX BCLK
+///     unsafe { GPIO_AD_B1_15::new() }, // TX SYNC
+///     unsafe { GPIO_B1_01::new() },    // TX DATA0
+///     unsafe { GPIO_AD_B1_11::new() }, // RX BCLK
+///     unsafe { GPIO_AD_B1_10::new() }, // RX SYNC
+///     unsafe { GPIO_B1_00::new() },    // RX DATA0
+/// );
+/// pins.prepare();
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+///
+/// All pins must belong to the same SAI module. `GPIO_EMC_04` is a SAI2 TX data pin,
+/// so it cannot join a SAI1 bundle:
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::imxrt1060::{gpio_ad_b1::*, gpio_b1::*, gpio_emc::*};
+/// use imxrt_iomuxc::{consts::U1, sai};
+///
+/// let pins = sai::SyncPins::<U1, _, _, _, _, _>::new(
+///     unsafe { GPIO_AD_B1_09::new() },
+///     unsafe { GPIO_AD_B1_14::new() },
+///     unsafe { GPIO_AD_B1_15::new() },
+///     unsafe { GPIO_EMC_04::new() }, // SAI2 TX DATA0
+///     unsafe { GPIO_B1_00::new() },
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Pins<SAIx, M, MC, TB, TS, TD, RB, RS, RD> {
+    /// The MCLK pin
+    pub mclk: MC,
+    /// The TX bit clock pin
+    pub tx_bclk: TB,
+    /// The TX frame sync pin
+    pub tx_sync: TS,
+    /// The TX data pin
+    pub tx_data: TD,
+    /// The RX bit clock pin; `()` in [`SyncMode`]
+    pub rx_bclk: RB,
+    /// The RX frame sync pin; `()` in [`SyncMode`]
+    pub rx_sync: RS,
+    /// The RX data pin
+    pub rx_data: RD,
+    _marker: PhantomData<(SAIx, M)>,
+}
+
+/// SAI pins for a receiver that's synchronous to the transmitter
+pub type SyncPins<SAIx, MC, TB, TS, TD, RD> = Pins<SAIx, SyncMode, MC, TB, TS, TD, (), (), RD>;
+
+/// SAI pins for a receiver and transmitter with their own clocks
+pub type AsyncPins<SAIx, MC, TB, TS, TD, RB, RS, RD> =
+    Pins<SAIx, AsyncMode, MC, TB, TS, TD, RB, RS, RD>;
+
+impl<SAIx, MC, TB, TS, TD, RD> SyncPins<SAIx, MC, TB, TS, TD, RD>
+where
+    SAIx: crate::consts::Unsigned,
+    MC: Pin<SAIx, Signal = Mclk>,
+    TB: Pin<SAIx, Signal = TxBclk>,
+    TS: Pin<SAIx, Signal = TxSync>,
+    TD: Pin<SAIx>,
+    <TD as Pin<SAIx>>::Signal: TxDataSignal,
+    RD: Pin<SAIx>,
+    <RD as Pin<SAIx>>::Signal: RxDataSignal,
+{
+    /// Bundle the pins for a receiver that's synchronous to the transmitter
+    pub fn new(mclk: MC, tx_bclk: TB, tx_sync: TS, tx_data: TD, rx_data: RD) -> Self {
+        Pins {
+            mclk,
+            tx_bclk,
+            tx_sync,
+            tx_data,
+            rx_bclk: (),
+            rx_sync: (),
+            rx_data,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Prepare all of the pins, with the clock pins as inputs
+    pub fn prepare(&mut self) {
+        self.prepare_clocks(ClockDirection::Input);
+    }
+
+    /// Prepare all of the pins, with the TX BCLK and SYNC pins in `direction`
+    pub fn prepare_clocks(&mut self, direction: ClockDirection) {
+        prepare(&mut self.mclk);
+        prepare_clock(&mut self.tx_bclk, direction);
+        prepare_clock(&mut self.tx_sync, direction);
+        prepare(&mut self.tx_data);
+        prepare(&mut self.rx_data);
+    }
+}
+
+impl<SAIx, MC, TB, TS, TD, RB, RS, RD> AsyncPins<SAIx, MC, TB, TS, TD, RB, RS, RD>
+where
+    SAIx: crate::consts::Unsigned,
+    MC: Pin<SAIx, Signal = Mclk>,
+    TB: Pin<SAIx, Signal = TxBclk>,
+    TS: Pin<SAIx, Signal = TxSync>,
+    TD: Pin<SAIx>,
+    <TD as Pin<SAIx>>::Signal: TxDataSignal,
+    RB: Pin<SAIx, Signal = RxBclk>,
+    RS: Pin<SAIx, Signal = RxSync>,
+    RD: Pin<SAIx>,
+    <RD as Pin<SAIx>>::Signal: RxDataSignal,
+{
+    /// Bundle the pins for a receiver and transmitter with their own clocks
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mclk: MC,
+        tx_bclk: TB,
+        tx_sync: TS,
+        tx_data: TD,
+        rx_bclk: RB,
+        rx_sync: RS,
+        rx_data: RD,
+    ) -> Self {
+        Pins {
+            mclk,
+            tx_bclk,
+            tx_sync,
+            tx_data,
+            rx_bclk,
+            rx_sync,
+            rx_data,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Prepare all of the pins, with the clock pins as inputs
+    pub fn prepare(&mut self) {
+        self.prepare_clocks(ClockDirection::Input);
+    }
+
+    /// Prepare all of the pins, with the BCLK and SYNC pins in `direction`
+    pub fn prepare_clocks(&mut self, direction: ClockDirection) {
+        prepare(&mut self.mclk);
+        prepare_clock(&mut self.tx_bclk, direction);
+        prepare_clock(&mut self.tx_sync, direction);
+        prepare(&mut self.tx_data);
+        prepare_clock(&mut self.rx_bclk, direction);
+        prepare_clock(&mut self.rx_sync, direction);
+        prepare(&mut self.rx_data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clock_daisy, prepare, prepare_clock, ClockDirection, Pin, TxBclk};
+    use crate::{consts::U1, Daisy, Iomuxc};
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// The fake doesn't have a daisy register; see clock_daisy_by_direction
+    impl Pin<U1> for FakePad {
+        const ALT: u32 = 3;
+        const DAISY: Option<Daisy> = None;
+        type Signal = TxBclk;
+    }
+
+    #[test]
+    fn clock_prepares_either_direction() {
+        for direction in [ClockDirection::Output, ClockDirection::Input] {
+            let mut pad = FakePad { mux: 0, pad: 0 };
+            prepare_clock::<U1, _>(&mut pad, direction);
+            assert_eq!(pad.mux, 1 << 4 | 3);
+        }
+
+        let mut pad = FakePad { mux: 0, pad: 0 };
+        prepare::<U1, _>(&mut pad);
+        assert_eq!(pad.mux, 1 << 4 | 3);
+    }
+
+    #[test]
+    fn clock_daisy_by_direction() {
+        let daisy = Some(Daisy::new(0x401f85d0, 1));
+        assert_eq!(clock_daisy(daisy, ClockDirection::Output), None);
+        assert_eq!(clock_daisy(daisy, ClockDirection::Input), daisy);
+    }
+
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn prepared_clock_direction() {
+        use super::prepared_clock;
+        use crate::{imxrt1060::gpio_ad_b1::GPIO_AD_B1_14, Config};
+
+        let output = prepared_clock::<U1, GPIO_AD_B1_14>(Config::zero(), ClockDirection::Output);
+        assert_eq!(output.daisy, None);
+        let input = prepared_clock::<U1, GPIO_AD_B1_14>(Config::zero(), ClockDirection::Input);
+        assert_eq!(input, super::prepared::<U1, GPIO_AD_B1_14>(Config::zero()));
+        assert!(input.daisy.is_some());
+    }
+}
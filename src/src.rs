@@ -0,0 +1,204 @@
+//! System reset controller (SRC) and NMI pad functions
+//!
+//! The boot ROM samples the SRC boot mode and boot configuration pads when the
+//! processor resets. Boards may reuse those pads after boot, but a strapped pad
+//! needs its reset configuration back before you issue a software reset;
+//! otherwise, the boot ROM may sample the wrong level. Use [`restore_strap()`]
+//! to restore each reused pad before the reset.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{gpio, imxrt1060::gpio_b0::*, src};
+//!
+//! let mut boot_cfg4 = unsafe { GPIO_B0_08::new() };
+//! let mut boot_cfg5 = unsafe { GPIO_B0_09::new() };
+//! gpio::prepare(&mut boot_cfg4);
+//! gpio::prepare(&mut boot_cfg5);
+//! // Use the pads as GPIOs...
+//!
+//! // Before the software reset:
+//! src::restore_strap(&mut boot_cfg4);
+//! src::restore_strap(&mut boot_cfg5);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+
+use crate::consts::Unsigned;
+use core::marker::PhantomData;
+
+/// An SRC or NMI pad signal
+pub trait Signal: private::Sealed {}
+
+/// A tag that indicates a boot mode pad; `BootMode<U1>` for `SRC_BOOT_MODE01`
+pub struct BootMode<N> {
+    _n: PhantomData<N>,
+}
+/// A tag that indicates a boot configuration pad; `BootCfg<U4>` for `SRC_BOOT_CFG04`
+pub struct BootCfg<N> {
+    _n: PhantomData<N>,
+}
+/// A tag that indicates the non-maskable interrupt input
+pub enum Nmi {}
+
+impl<N: Unsigned> Signal for BootMode<N> {}
+impl<N: Unsigned> Signal for BootCfg<N> {}
+impl Signal for Nmi {}
+
+mod private {
+    use crate::{consts::Unsigned, function::SrcSignal};
+
+    pub trait Sealed {
+        const SIGNAL: SrcSignal;
+    }
+    impl<N: Unsigned> Sealed for super::BootMode<N> {
+        const SIGNAL: SrcSignal = SrcSignal::BootMode(N::U8);
+    }
+    impl<N: Unsigned> Sealed for super::BootCfg<N> {
+        const SIGNAL: SrcSignal = SrcSignal::BootCfg(N::U8);
+    }
+    impl Sealed for super::Nmi {
+        const SIGNAL: SrcSignal = SrcSignal::Nmi;
+    }
+}
+
+/// An SRC or NMI pin
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the pin
+    const ALT: u32;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::DontCare`](crate::SionRequirement::DontCare).
+    const SION: super::SionRequirement = super::SionRequirement::DontCare;
+    /// The pin's signal
+    type Signal: Signal;
+}
+
+/// A pin that the boot ROM samples on reset
+pub trait Strap: Pin {
+    /// The mux register value after reset
+    const RESET_MUX: u32;
+    /// The pad configuration register value after reset
+    const RESET_PAD: u32;
+}
+
+/// Prepare an SRC or NMI pin
+pub fn prepare<P: Pin>(pin: &mut P) {
+    trace!("src::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, None);
+}
+
+/// Like [`prepare()`], but only write the registers that don't already prepare the pin
+///
+/// Returns `true` if it wrote a register. See the [crate documentation](crate#idempotent-prepare)
+/// for more information.
+pub fn prepare_idempotent<P: Pin>(pin: &mut P) -> bool {
+    trace!("src::prepare_idempotent mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_idempotent(pin, P::ALT, P::SION, None)
+}
+
+prepared_pin!("SRC or NMI");
+
+/// Compute the register writes that prepare an SRC or NMI pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P: Pin + super::ConstIomuxc>(config: super::Config) -> super::PreparedWrites {
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, None)
+}
+
+/// Restore a strap pin to its reset configuration
+///
+/// Call this before a software reset, so that the boot ROM samples the strap
+/// as it would after a power-on reset. The call overwrites both the mux and pad
+/// configuration registers.
+pub fn restore_strap<P: Strap>(pin: &mut P) {
+    trace!(
+        "src::restore_strap mux={:#010x} value={:#010x} pad={:#010x} value={:#010x}",
+        pin.mux() as usize,
+        P::RESET_MUX,
+        pin.pad() as usize,
+        P::RESET_PAD
+    );
+    unsafe {
+        core::ptr::write_volatile(pin.mux(), P::RESET_MUX);
+        core::ptr::write_volatile(pin.pad(), P::RESET_PAD);
+    }
+}
+
+/// Returns the [`Function`](crate::Function) of an SRC or NMI pin
+pub const fn function<P: Pin>() -> super::Function {
+    super::Function::Src(<P::Signal as private::Sealed>::SIGNAL)
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! src {
+    (alt: $alt:expr, pad: $pad:ty, signal: $signal:ty) => {
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            type Signal = $signal;
+        }
+    };
+    (alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, reset_mux: $mux:expr, reset_pad: $pad_ctl:expr) => {
+        src!(alt: $alt, pad: $pad, signal: $signal);
+        impl Strap for $pad {
+            const RESET_MUX: u32 = $mux;
+            const RESET_PAD: u32 = $pad_ctl;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{restore_strap, BootCfg, Pin, Strap};
+    use crate::{consts::U4, Iomuxc};
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    impl Pin for FakePad {
+        const ALT: u32 = 6;
+        type Signal = BootCfg<U4>;
+    }
+
+    impl Strap for FakePad {
+        const RESET_MUX: u32 = 5;
+        const RESET_PAD: u32 = 0x10B0;
+    }
+
+    #[test]
+    fn restore_overwrites_mux_and_pad() {
+        let mut pad = FakePad {
+            mux: 1 << 4 | 3,
+            pad: 0xF0F9,
+        };
+        restore_strap(&mut pad);
+        assert_eq!(pad.mux, 5);
+        assert_eq!(pad.pad, 0x10B0);
+    }
+
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn imxrt1060_restore_values() {
+        use crate::imxrt1060::{gpio_ad_b0::GPIO_AD_B0_04, gpio_b0::GPIO_B0_15};
+
+        assert_eq!(
+            (GPIO_AD_B0_04::RESET_MUX, GPIO_AD_B0_04::RESET_PAD),
+            (0, 0x10B0)
+        );
+        assert_eq!((GPIO_B0_15::RESET_MUX, GPIO_B0_15::RESET_PAD), (5, 0x10B0));
+    }
+}
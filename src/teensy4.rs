@@ -0,0 +1,702 @@
+//! Teensy 4.0 and 4.1 pin mapping
+//!
+//! The Teensy 4 boards label their pins with numbers. This module maps those numbers
+//! to i.MX RT 1060 pads. Pins 0 through 33 are the same on both boards, and they're
+//! available in this module. The boards differ from pin 34 on, so the [`t40`] and [`t41`]
+//! modules provide the complete set of pins for each board.
+//!
+//! Each board module provides
+//!
+//! - type aliases, `P0` through `Pxx`, for the strongly-typed pads.
+//! - a [`PadId`](crate::PadId) table, `PADS`, that maps a pin number to a pad at run time.
+//! - a `Pins` struct, which takes the board's pins from the [`Pads`](crate::imxrt1060::Pads).
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{imxrt1060::Pads, teensy4::t40};
+//!
+//! let pads = unsafe { Pads::new() };
+//! let mut pins = t40::Pins::new(pads);
+//!
+//! // Pin 13 is the LED
+//! imxrt_iomuxc::gpio::prepare(&mut pins.p13);
+//!
+//! assert_eq!(t40::PADS[13], t40::P13::ID);
+//! ```
+//!
+//! The mapping follows the schematics published by PJRC.
+
+use crate::imxrt1060::{gpio_ad_b0::*, gpio_ad_b1::*, gpio_b0::*, gpio_b1::*, gpio_emc::*};
+
+/// Pin 0
+pub type P0 = GPIO_AD_B0_03;
+/// Pin 1
+pub type P1 = GPIO_AD_B0_02;
+/// Pin 2
+pub type P2 = GPIO_EMC_04;
+/// Pin 3
+pub type P3 = GPIO_EMC_05;
+/// Pin 4
+pub type P4 = GPIO_EMC_06;
+/// Pin 5
+pub type P5 = GPIO_EMC_08;
+/// Pin 6
+pub type P6 = GPIO_B0_10;
+/// Pin 7
+pub type P7 = GPIO_B1_01;
+/// Pin 8
+pub type P8 = GPIO_B1_00;
+/// Pin 9
+pub type P9 = GPIO_B0_11;
+/// Pin 10
+pub type P10 = GPIO_B0_00;
+/// Pin 11
+pub type P11 = GPIO_B0_02;
+/// Pin 12
+pub type P12 = GPIO_B0_01;
+/// Pin 13
+pub type P13 = GPIO_B0_03;
+/// Pin 14
+pub type P14 = GPIO_AD_B1_02;
+/// Pin 15
+pub type P15 = GPIO_AD_B1_03;
+/// Pin 16
+pub type P16 = GPIO_AD_B1_07;
+/// Pin 17
+pub type P17 = GPIO_AD_B1_06;
+/// Pin 18
+pub type P18 = GPIO_AD_B1_01;
+/// Pin 19
+pub type P19 = GPIO_AD_B1_00;
+/// Pin 20
+pub type P20 = GPIO_AD_B1_10;
+/// Pin 21
+pub type P21 = GPIO_AD_B1_11;
+/// Pin 22
+pub type P22 = GPIO_AD_B1_08;
+/// Pin 23
+pub type P23 = GPIO_AD_B1_09;
+/// Pin 24
+pub type P24 = GPIO_AD_B0_12;
+/// Pin 25
+pub type P25 = GPIO_AD_B0_13;
+/// Pin 26
+pub type P26 = GPIO_AD_B1_14;
+/// Pin 27
+pub type P27 = GPIO_AD_B1_15;
+/// Pin 28
+pub type P28 = GPIO_EMC_32;
+/// Pin 29
+pub type P29 = GPIO_EMC_31;
+/// Pin 30
+pub type P30 = GPIO_EMC_37;
+/// Pin 31
+pub type P31 = GPIO_EMC_36;
+/// Pin 32
+pub type P32 = GPIO_B0_12;
+/// Pin 33
+pub type P33 = GPIO_EMC_07;
+
+/// Teensy 4.0 pins
+pub mod t40 {
+    pub use super::{
+        P0, P1, P10, P11, P12, P13, P14, P15, P16, P17, P18, P19, P2, P20, P21, P22, P23, P24, P25,
+        P26, P27, P28, P29, P3, P30, P31, P32, P33, P4, P5, P6, P7, P8, P9,
+    };
+    use crate::imxrt1060::gpio_sd_b0::*;
+    use crate::{imxrt1060::Pads, PadId};
+
+    /// Pin 34
+    pub type P34 = GPIO_SD_B0_03;
+    /// Pin 35
+    pub type P35 = GPIO_SD_B0_02;
+    /// Pin 36
+    pub type P36 = GPIO_SD_B0_01;
+    /// Pin 37
+    pub type P37 = GPIO_SD_B0_00;
+    /// Pin 38
+    pub type P38 = GPIO_SD_B0_05;
+    /// Pin 39
+    pub type P39 = GPIO_SD_B0_04;
+
+    /// The number of pins
+    pub const PIN_COUNT: usize = 40;
+
+    /// Maps a pin number to its pad
+    pub const PADS: [PadId; PIN_COUNT] = [
+        P0::ID,
+        P1::ID,
+        P2::ID,
+        P3::ID,
+        P4::ID,
+        P5::ID,
+        P6::ID,
+        P7::ID,
+        P8::ID,
+        P9::ID,
+        P10::ID,
+        P11::ID,
+        P12::ID,
+        P13::ID,
+        P14::ID,
+        P15::ID,
+        P16::ID,
+        P17::ID,
+        P18::ID,
+        P19::ID,
+        P20::ID,
+        P21::ID,
+        P22::ID,
+        P23::ID,
+        P24::ID,
+        P25::ID,
+        P26::ID,
+        P27::ID,
+        P28::ID,
+        P29::ID,
+        P30::ID,
+        P31::ID,
+        P32::ID,
+        P33::ID,
+        P34::ID,
+        P35::ID,
+        P36::ID,
+        P37::ID,
+        P38::ID,
+        P39::ID,
+    ];
+
+    /// All of the Teensy 4.0 pins
+    ///
+    /// Use [`new()`](Pins::new) to take the pins from the processor pads.
+    pub struct Pins {
+        /// Pin 0
+        pub p0: P0,
+        /// Pin 1
+        pub p1: P1,
+        /// Pin 2
+        pub p2: P2,
+        /// Pin 3
+        pub p3: P3,
+        /// Pin 4
+        pub p4: P4,
+        /// Pin 5
+        pub p5: P5,
+        /// Pin 6
+        pub p6: P6,
+        /// Pin 7
+        pub p7: P7,
+        /// Pin 8
+        pub p8: P8,
+        /// Pin 9
+        pub p9: P9,
+        /// Pin 10
+        pub p10: P10,
+        /// Pin 11
+        pub p11: P11,
+        /// Pin 12
+        pub p12: P12,
+        /// Pin 13
+        pub p13: P13,
+        /// Pin 14
+        pub p14: P14,
+        /// Pin 15
+        pub p15: P15,
+        /// Pin 16
+        pub p16: P16,
+        /// Pin 17
+        pub p17: P17,
+        /// Pin 18
+        pub p18: P18,
+        /// Pin 19
+        pub p19: P19,
+        /// Pin 20
+        pub p20: P20,
+        /// Pin 21
+        pub p21: P21,
+        /// Pin 22
+        pub p22: P22,
+        /// Pin 23
+        pub p23: P23,
+        /// Pin 24
+        pub p24: P24,
+        /// Pin 25
+        pub p25: P25,
+        /// Pin 26
+        pub p26: P26,
+        /// Pin 27
+        pub p27: P27,
+        /// Pin 28
+        pub p28: P28,
+        /// Pin 29
+        pub p29: P29,
+        /// Pin 30
+        pub p30: P30,
+        /// Pin 31
+        pub p31: P31,
+        /// Pin 32
+        pub p32: P32,
+        /// Pin 33
+        pub p33: P33,
+        /// Pin 34
+        pub p34: P34,
+        /// Pin 35
+        pub p35: P35,
+        /// Pin 36
+        pub p36: P36,
+        /// Pin 37
+        pub p37: P37,
+        /// Pin 38
+        pub p38: P38,
+        /// Pin 39
+        pub p39: P39,
+    }
+
+    impl Pins {
+        /// Take the Teensy 4.0 pins from the processor pads
+        ///
+        /// Pads that are not connected to a numbered pin are dropped.
+        pub fn new(pads: Pads) -> Self {
+            Pins {
+                p0: pads.gpio_ad_b0.p03,
+                p1: pads.gpio_ad_b0.p02,
+                p2: pads.gpio_emc.p04,
+                p3: pads.gpio_emc.p05,
+                p4: pads.gpio_emc.p06,
+                p5: pads.gpio_emc.p08,
+                p6: pads.gpio_b0.p10,
+                p7: pads.gpio_b1.p01,
+                p8: pads.gpio_b1.p00,
+                p9: pads.gpio_b0.p11,
+                p10: pads.gpio_b0.p00,
+                p11: pads.gpio_b0.p02,
+                p12: pads.gpio_b0.p01,
+                p13: pads.gpio_b0.p03,
+                p14: pads.gpio_ad_b1.p02,
+                p15: pads.gpio_ad_b1.p03,
+                p16: pads.gpio_ad_b1.p07,
+                p17: pads.gpio_ad_b1.p06,
+                p18: pads.gpio_ad_b1.p01,
+                p19: pads.gpio_ad_b1.p00,
+                p20: pads.gpio_ad_b1.p10,
+                p21: pads.gpio_ad_b1.p11,
+                p22: pads.gpio_ad_b1.p08,
+                p23: pads.gpio_ad_b1.p09,
+                p24: pads.gpio_ad_b0.p12,
+                p25: pads.gpio_ad_b0.p13,
+                p26: pads.gpio_ad_b1.p14,
+                p27: pads.gpio_ad_b1.p15,
+                p28: pads.gpio_emc.p32,
+                p29: pads.gpio_emc.p31,
+                p30: pads.gpio_emc.p37,
+                p31: pads.gpio_emc.p36,
+                p32: pads.gpio_b0.p12,
+                p33: pads.gpio_emc.p07,
+                p34: pads.gpio_sd_b0.p03,
+                p35: pads.gpio_sd_b0.p02,
+                p36: pads.gpio_sd_b0.p01,
+                p37: pads.gpio_sd_b0.p00,
+                p38: pads.gpio_sd_b0.p05,
+                p39: pads.gpio_sd_b0.p04,
+            }
+        }
+    }
+}
+
+/// Teensy 4.1 pins
+pub mod t41 {
+    pub use super::{
+        P0, P1, P10, P11, P12, P13, P14, P15, P16, P17, P18, P19, P2, P20, P21, P22, P23, P24, P25,
+        P26, P27, P28, P29, P3, P30, P31, P32, P33, P4, P5, P6, P7, P8, P9,
+    };
+    use crate::imxrt1060::{gpio_ad_b1::*, gpio_b1::*, gpio_emc::*, gpio_sd_b0::*};
+    use crate::{imxrt1060::Pads, PadId};
+
+    /// Pin 34
+    pub type P34 = GPIO_B1_13;
+    /// Pin 35
+    pub type P35 = GPIO_B1_12;
+    /// Pin 36
+    pub type P36 = GPIO_B1_02;
+    /// Pin 37
+    pub type P37 = GPIO_B1_03;
+    /// Pin 38
+    pub type P38 = GPIO_AD_B1_12;
+    /// Pin 39
+    pub type P39 = GPIO_AD_B1_13;
+    /// Pin 40
+    pub type P40 = GPIO_AD_B1_04;
+    /// Pin 41
+    pub type P41 = GPIO_AD_B1_05;
+    /// Pin 42
+    pub type P42 = GPIO_SD_B0_03;
+    /// Pin 43
+    pub type P43 = GPIO_SD_B0_02;
+    /// Pin 44
+    pub type P44 = GPIO_SD_B0_01;
+    /// Pin 45
+    pub type P45 = GPIO_SD_B0_00;
+    /// Pin 46
+    pub type P46 = GPIO_SD_B0_05;
+    /// Pin 47
+    pub type P47 = GPIO_SD_B0_04;
+    /// Pin 48
+    pub type P48 = GPIO_EMC_24;
+    /// Pin 49
+    pub type P49 = GPIO_EMC_27;
+    /// Pin 50
+    pub type P50 = GPIO_EMC_28;
+    /// Pin 51
+    pub type P51 = GPIO_EMC_22;
+    /// Pin 52
+    pub type P52 = GPIO_EMC_26;
+    /// Pin 53
+    pub type P53 = GPIO_EMC_25;
+    /// Pin 54
+    pub type P54 = GPIO_EMC_29;
+
+    /// The number of pins
+    pub const PIN_COUNT: usize = 55;
+
+    /// Maps a pin number to its pad
+    pub const PADS: [PadId; PIN_COUNT] = [
+        P0::ID,
+        P1::ID,
+        P2::ID,
+        P3::ID,
+        P4::ID,
+        P5::ID,
+        P6::ID,
+        P7::ID,
+        P8::ID,
+        P9::ID,
+        P10::ID,
+        P11::ID,
+        P12::ID,
+        P13::ID,
+        P14::ID,
+        P15::ID,
+        P16::ID,
+        P17::ID,
+        P18::ID,
+        P19::ID,
+        P20::ID,
+        P21::ID,
+        P22::ID,
+        P23::ID,
+        P24::ID,
+        P25::ID,
+        P26::ID,
+        P27::ID,
+        P28::ID,
+        P29::ID,
+        P30::ID,
+        P31::ID,
+        P32::ID,
+        P33::ID,
+        P34::ID,
+        P35::ID,
+        P36::ID,
+        P37::ID,
+        P38::ID,
+        P39::ID,
+        P40::ID,
+        P41::ID,
+        P42::ID,
+        P43::ID,
+        P44::ID,
+        P45::ID,
+        P46::ID,
+        P47::ID,
+        P48::ID,
+        P49::ID,
+        P50::ID,
+        P51::ID,
+        P52::ID,
+        P53::ID,
+        P54::ID,
+    ];
+
+    /// All of the Teensy 4.1 pins
+    ///
+    /// Use [`new()`](Pins::new) to take the pins from the processor pads.
+    pub struct Pins {
+        /// Pin 0
+        pub p0: P0,
+        /// Pin 1
+        pub p1: P1,
+        /// Pin 2
+        pub p2: P2,
+        /// Pin 3
+        pub p3: P3,
+        /// Pin 4
+        pub p4: P4,
+        /// Pin 5
+        pub p5: P5,
+        /// Pin 6
+        pub p6: P6,
+        /// Pin 7
+        pub p7: P7,
+        /// Pin 8
+        pub p8: P8,
+        /// Pin 9
+        pub p9: P9,
+        /// Pin 10
+        pub p10: P10,
+        /// Pin 11
+        pub p11: P11,
+        /// Pin 12
+        pub p12: P12,
+        /// Pin 13
+        pub p13: P13,
+        /// Pin 14
+        pub p14: P14,
+        /// Pin 15
+        pub p15: P15,
+        /// Pin 16
+        pub p16: P16,
+        /// Pin 17
+        pub p17: P17,
+        /// Pin 18
+        pub p18: P18,
+        /// Pin 19
+        pub p19: P19,
+        /// Pin 20
+        pub p20: P20,
+        /// Pin 21
+        pub p21: P21,
+        /// Pin 22
+        pub p22: P22,
+        /// Pin 23
+        pub p23: P23,
+        /// Pin 24
+        pub p24: P24,
+        /// Pin 25
+        pub p25: P25,
+        /// Pin 26
+        pub p26: P26,
+        /// Pin 27
+        pub p27: P27,
+        /// Pin 28
+        pub p28: P28,
+        /// Pin 29
+        pub p29: P29,
+        /// Pin 30
+        pub p30: P30,
+        /// Pin 31
+        pub p31: P31,
+        /// Pin 32
+        pub p32: P32,
+        /// Pin 33
+        pub p33: P33,
+        /// Pin 34
+        pub p34: P34,
+        /// Pin 35
+        pub p35: P35,
+        /// Pin 36
+        pub p36: P36,
+        /// Pin 37
+        pub p37: P37,
+        /// Pin 38
+        pub p38: P38,
+        /// Pin 39
+        pub p39: P39,
+        /// Pin 40
+        pub p40: P40,
+        /// Pin 41
+        pub p41: P41,
+        /// Pin 42
+        pub p42: P42,
+        /// Pin 43
+        pub p43: P43,
+        /// Pin 44
+        pub p44: P44,
+        /// Pin 45
+        pub p45: P45,
+        /// Pin 46
+        pub p46: P46,
+        /// Pin 47
+        pub p47: P47,
+        /// Pin 48
+        pub p48: P48,
+        /// Pin 49
+        pub p49: P49,
+        /// Pin 50
+        pub p50: P50,
+        /// Pin 51
+        pub p51: P51,
+        /// Pin 52
+        pub p52: P52,
+        /// Pin 53
+        pub p53: P53,
+        /// Pin 54
+        pub p54: P54,
+    }
+
+    impl Pins {
+        /// Take the Teensy 4.1 pins from the processor pads
+        ///
+        /// Pads that are not connected to a numbered pin are dropped.
+        pub fn new(pads: Pads) -> Self {
+            Pins {
+                p0: pads.gpio_ad_b0.p03,
+                p1: pads.gpio_ad_b0.p02,
+                p2: pads.gpio_emc.p04,
+                p3: pads.gpio_emc.p05,
+                p4: pads.gpio_emc.p06,
+                p5: pads.gpio_emc.p08,
+                p6: pads.gpio_b0.p10,
+                p7: pads.gpio_b1.p01,
+                p8: pads.gpio_b1.p00,
+                p9: pads.gpio_b0.p11,
+                p10: pads.gpio_b0.p00,
+                p11: pads.gpio_b0.p02,
+                p12: pads.gpio_b0.p01,
+                p13: pads.gpio_b0.p03,
+                p14: pads.gpio_ad_b1.p02,
+                p15: pads.gpio_ad_b1.p03,
+                p16: pads.gpio_ad_b1.p07,
+                p17: pads.gpio_ad_b1.p06,
+                p18: pads.gpio_ad_b1.p01,
+                p19: pads.gpio_ad_b1.p00,
+                p20: pads.gpio_ad_b1.p10,
+                p21: pads.gpio_ad_b1.p11,
+                p22: pads.gpio_ad_b1.p08,
+                p23: pads.gpio_ad_b1.p09,
+                p24: pads.gpio_ad_b0.p12,
+                p25: pads.gpio_ad_b0.p13,
+                p26: pads.gpio_ad_b1.p14,
+                p27: pads.gpio_ad_b1.p15,
+                p28: pads.gpio_emc.p32,
+                p29: pads.gpio_emc.p31,
+                p30: pads.gpio_emc.p37,
+                p31: pads.gpio_emc.p36,
+                p32: pads.gpio_b0.p12,
+                p33: pads.gpio_emc.p07,
+                p34: pads.gpio_b1.p13,
+                p35: pads.gpio_b1.p12,
+                p36: pads.gpio_b1.p02,
+                p37: pads.gpio_b1.p03,
+                p38: pads.gpio_ad_b1.p12,
+                p39: pads.gpio_ad_b1.p13,
+                p40: pads.gpio_ad_b1.p04,
+                p41: pads.gpio_ad_b1.p05,
+                p42: pads.gpio_sd_b0.p03,
+                p43: pads.gpio_sd_b0.p02,
+                p44: pads.gpio_sd_b0.p01,
+                p45: pads.gpio_sd_b0.p00,
+                p46: pads.gpio_sd_b0.p05,
+                p47: pads.gpio_sd_b0.p04,
+                p48: pads.gpio_emc.p24,
+                p49: pads.gpio_emc.p27,
+                p50: pads.gpio_emc.p28,
+                p51: pads.gpio_emc.p22,
+                p52: pads.gpio_emc.p26,
+                p53: pads.gpio_emc.p25,
+                p54: pads.gpio_emc.p29,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{t40, t41};
+    use crate::PadId;
+
+    /// The pad names from the PJRC schematics, indexed by pin number
+    const COMMON: [&str; 34] = [
+        "GPIO_AD_B0_03",
+        "GPIO_AD_B0_02",
+        "GPIO_EMC_04",
+        "GPIO_EMC_05",
+        "GPIO_EMC_06",
+        "GPIO_EMC_08",
+        "GPIO_B0_10",
+        "GPIO_B1_01",
+        "GPIO_B1_00",
+        "GPIO_B0_11",
+        "GPIO_B0_00",
+        "GPIO_B0_02",
+        "GPIO_B0_01",
+        "GPIO_B0_03",
+        "GPIO_AD_B1_02",
+        "GPIO_AD_B1_03",
+        "GPIO_AD_B1_07",
+        "GPIO_AD_B1_06",
+        "GPIO_AD_B1_01",
+        "GPIO_AD_B1_00",
+        "GPIO_AD_B1_10",
+        "GPIO_AD_B1_11",
+        "GPIO_AD_B1_08",
+        "GPIO_AD_B1_09",
+        "GPIO_AD_B0_12",
+        "GPIO_AD_B0_13",
+        "GPIO_AD_B1_14",
+        "GPIO_AD_B1_15",
+        "GPIO_EMC_32",
+        "GPIO_EMC_31",
+        "GPIO_EMC_37",
+        "GPIO_EMC_36",
+        "GPIO_B0_12",
+        "GPIO_EMC_07",
+    ];
+    const T40: [&str; 6] = [
+        "GPIO_SD_B0_03",
+        "GPIO_SD_B0_02",
+        "GPIO_SD_B0_01",
+        "GPIO_SD_B0_00",
+        "GPIO_SD_B0_05",
+        "GPIO_SD_B0_04",
+    ];
+    const T41: [&str; 21] = [
+        "GPIO_B1_13",
+        "GPIO_B1_12",
+        "GPIO_B1_02",
+        "GPIO_B1_03",
+        "GPIO_AD_B1_12",
+        "GPIO_AD_B1_13",
+        "GPIO_AD_B1_04",
+        "GPIO_AD_B1_05",
+        "GPIO_SD_B0_03",
+        "GPIO_SD_B0_02",
+        "GPIO_SD_B0_01",
+        "GPIO_SD_B0_00",
+        "GPIO_SD_B0_05",
+        "GPIO_SD_B0_04",
+        "GPIO_EMC_24",
+        "GPIO_EMC_27",
+        "GPIO_EMC_28",
+        "GPIO_EMC_22",
+        "GPIO_EMC_26",
+        "GPIO_EMC_25",
+        "GPIO_EMC_29",
+    ];
+
+    /// Compute a pad identifier from the pad's name
+    fn pad_id(name: &str) -> PadId {
+        let (group, offset) = name.split_at(name.rfind('_').unwrap());
+        let offset: usize = offset[1..].parse().unwrap();
+        let mux_base = match group {
+            "GPIO_EMC" => 0x401F_8014,
+            "GPIO_AD_B0" => 0x401F_80BC,
+            "GPIO_AD_B1" => 0x401F_80FC,
+            "GPIO_B0" => 0x401F_813C,
+            "GPIO_B1" => 0x401F_817C,
+            "GPIO_SD_B0" => 0x401F_81BC,
+            _ => panic!("Unexpected pad group {}", group),
+        };
+        PadId(mux_base + 4 * offset)
+    }
+
+    #[test]
+    fn t40_mapping() {
+        let expected = COMMON.iter().chain(T40.iter()).map(|name| pad_id(name));
+        assert!(expected.eq(t40::PADS.iter().copied()));
+    }
+
+    #[test]
+    fn t41_mapping() {
+        let expected = COMMON.iter().chain(T41.iter()).map(|name| pad_id(name));
+        assert!(expected.eq(t41::PADS.iter().copied()));
+    }
+}
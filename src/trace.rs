@@ -0,0 +1,123 @@
+//! Optional tracing of IOMUXC register writes
+//!
+//! When the `log` feature is enabled, `trace!` forwards to `log::trace!`, using
+//! the `"imxrt_iomuxc"` target. Otherwise, `trace!` expands to nothing, and its
+//! arguments are never evaluated.
+//!
+//! Every message starts with the operation, followed by `key=value` pairs. Addresses
+//! and register values are formatted as 32-bit hex numbers.
+
+#[cfg(feature = "log")]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        ::log::trace!(target: "imxrt_iomuxc", $($arg)+)
+    };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! trace {
+    ($($arg:tt)+) => {};
+}
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    extern crate std;
+
+    use crate::{lpuart, Daisy, Iomuxc};
+    use std::{cell::RefCell, format, string::String, sync::Once, vec::Vec};
+
+    std::thread_local! {
+        static RECORDS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Captures records on the calling thread, so that tests may run in parallel
+    struct Capture;
+
+    impl log::Log for Capture {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == "imxrt_iomuxc"
+        }
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                RECORDS.with(|records| records.borrow_mut().push(format!("{}", record.args())));
+            }
+        }
+        fn flush(&self) {}
+    }
+
+    fn capture<F: FnOnce()>(f: F) -> Vec<String> {
+        static CAPTURE: Capture = Capture;
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            log::set_logger(&CAPTURE).unwrap();
+            log::set_max_level(log::LevelFilter::Trace);
+        });
+        RECORDS.with(|records| records.borrow_mut().clear());
+        f();
+        RECORDS.with(|records| records.borrow_mut().split_off(0))
+    }
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    impl lpuart::Pin for FakePad {
+        const ALT: u32 = 2;
+        const DAISY: Option<Daisy> = None;
+        type Direction = lpuart::Tx;
+        type Module = crate::consts::U1;
+    }
+
+    #[test]
+    fn lpuart_prepare() {
+        let mut pad = FakePad {
+            mux: 1 << 4 | 5,
+            pad: 0,
+        };
+        let mux = pad.mux() as usize;
+
+        let records = capture(|| lpuart::prepare(&mut pad));
+        assert_eq!(
+            records,
+            [
+                format!("lpuart::prepare mux={:#010x}", mux),
+                format!("prepare mux={:#010x} alt=2 value=0x00000002", mux),
+            ]
+        );
+    }
+
+    #[test]
+    fn daisy_configure() {
+        let mut pad = FakePad { mux: 0, pad: 0 };
+        let mut select_input = 0u32;
+        let reg = &mut select_input as *mut u32 as usize;
+        let addr = pad.pad() as usize;
+
+        let records = capture(|| {
+            unsafe { Daisy::new(reg, 3).write() };
+            crate::configure(
+                &mut pad,
+                crate::Config::zero().set_open_drain(crate::OpenDrain::Enabled),
+            );
+        });
+        assert_eq!(
+            records,
+            [
+                format!("daisy reg={:#010x} value=0x00000003", reg),
+                format!("configure pad={:#010x} value=0x00000800", addr),
+            ]
+        );
+    }
+}
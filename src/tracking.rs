@@ -0,0 +1,153 @@
+//! Debug tracking for dropped pads
+//!
+//! Enabled with the `debug-pad-tracking` feature. When an [`ErasedPad`](crate::ErasedPad)
+//! drops, the crate records its [`PadId`] in a static registry. Call [`assert_live()`]
+//! wherever your code expects to own a pad, like a HAL driver's constructor; it panics
+//! if the pad was previously dropped. This catches code that drops a configured pad,
+//! then creates a new handle for the same pad and reconfigures it.
+//!
+//! Strongly-typed [`Pad`](crate::Pad)s are not tracked, since they may be created in
+//! `const` contexts. Converting an erased pad back into a strongly-typed pad does not
+//! count as a drop.
+//!
+//! The registry holds up to [`CAPACITY`] pads, enough for every pad on the supported
+//! processors. Additional drops are ignored. If you intentionally drop a pad and create
+//! it again, call [`revive()`] to remove the pad from the registry.
+//!
+//! ```
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::GPIO_AD_B0_03, tracking};
+//!
+//! let pad = unsafe { GPIO_AD_B0_03::new() }.erase();
+//! let id = pad.id();
+//! drop(pad);
+//! assert!(tracking::is_dropped(id));
+//!
+//! // Intentionally recreate the pad
+//! tracking::revive(id);
+//! let pad = unsafe { GPIO_AD_B0_03::new() };
+//! tracking::assert_live(id);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+
+use crate::PadId;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The number of dropped pads that the registry can hold
+pub const CAPACITY: usize = 256;
+
+/// An empty registry slot
+const EMPTY: usize = 0;
+
+#[allow(clippy::declare_interior_mutable_const)] // Only used to initialize DROPPED
+const SLOT: AtomicUsize = AtomicUsize::new(EMPTY);
+static DROPPED: [AtomicUsize; CAPACITY] = [SLOT; CAPACITY];
+
+/// Record that the pad `id` was dropped
+///
+/// Called when an `ErasedPad` drops. Recording the same pad more than once
+/// has no additional effect.
+pub(crate) fn record_drop(id: PadId) {
+    let addr = id.mux_addr();
+    if is_dropped(id) {
+        return;
+    }
+    for slot in DROPPED.iter() {
+        if slot
+            .compare_exchange(EMPTY, addr, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            return;
+        }
+    }
+}
+
+/// Returns `true` if the pad `id` was dropped, and not revived
+pub fn is_dropped(id: PadId) -> bool {
+    let addr = id.mux_addr();
+    DROPPED
+        .iter()
+        .any(|slot| slot.load(Ordering::Acquire) == addr)
+}
+
+/// Remove the pad `id` from the registry
+///
+/// Use this when you intentionally drop a pad, and then create it again.
+pub fn revive(id: PadId) {
+    let addr = id.mux_addr();
+    for slot in DROPPED.iter() {
+        let _ = slot.compare_exchange(addr, EMPTY, Ordering::AcqRel, Ordering::Acquire);
+    }
+}
+
+/// Assert that the pad `id` was not dropped
+///
+/// # Panics
+///
+/// Panics if the pad was dropped, and not revived.
+pub fn assert_live(id: PadId) {
+    assert!(
+        !is_dropped(id),
+        "pad {:#010x} was dropped, then used again",
+        id.mux_addr()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_live, is_dropped, revive};
+    use crate::ErasedPad;
+
+    // Each test uses its own fake mux address, since tests share the registry.
+    fn erased(mux_base: usize) -> ErasedPad {
+        ErasedPad {
+            mux_base: mux_base as *mut u32,
+            pad_base: (mux_base + 0x1F0) as *mut u32,
+            offset: 0,
+        }
+    }
+
+    #[test]
+    fn drop_is_recorded() {
+        let pad = erased(0x1000);
+        let id = pad.id();
+        assert_live(id);
+        drop(pad);
+        assert!(is_dropped(id));
+        revive(id);
+        assert_live(id);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dropped_pad_is_not_live() {
+        let pad = erased(0x2000);
+        let id = pad.id();
+        drop(pad);
+        assert_live(id);
+    }
+
+    #[test]
+    fn convert_is_not_a_drop() {
+        use crate::{consts::U0, Pad};
+        use core::convert::TryFrom;
+
+        struct TrackedBase;
+        unsafe impl crate::Base for TrackedBase {
+            fn mux_base() -> *mut u32 {
+                0x3000 as *mut u32
+            }
+            fn pad_base() -> *mut u32 {
+                0x31F0 as *mut u32
+            }
+        }
+
+        let pad = erased(0x3000);
+        let id = pad.id();
+        let pad = Pad::<TrackedBase, U0>::try_from(pad).unwrap();
+        assert_live(id);
+        let _ = pad.erase();
+        assert!(is_dropped(id));
+    }
+}
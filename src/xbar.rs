@@ -0,0 +1,548 @@
+//! Crossbar switch (XBAR) pad configuration
+//!
+//! An XBAR pin connects a pad to one of the crossbar's IOMUX lines. Once the pad
+//! is prepared, the crossbar may route the line to a peripheral input, like the
+//! quadrature decoder (ENC). Some lines are bidirectional; the crossbar may also
+//! drive the line with an internal signal, like a PWM trigger, so that you can
+//! observe the signal on the pad. Programming the crossbar is outside the scope of
+//! this crate.
+//!
+//! A bidirectional line's direction is selected outside of the pad registers. On
+//! the i.MX RT 1060, that's the `IOMUXC_GPR_GPR6` register. Your HAL is responsible
+//! for selecting the direction.
+//!
+//! # Observing internal signals
+//!
+//! To debug internal signal routing, drive a crossbar output onto a test point.
+//! [`prepare_output()`] selects the XBAR function, and configures the pad as a fast,
+//! push-pull output. Any bidirectional ([`InOut`]) line can be an output. The example
+//! prepares a test point for a FlexPWM submodule's sync signal. Your HAL then
+//!
+//! - selects the output direction for `XBAR1_INOUT14` in `IOMUXC_GPR_GPR6`, and
+//! - routes `PWM1_OUT_TRIG0` to crossbar output 14.
+//!
+//! ```no_run
+//! # #[cfg(feature = "imxrt1060")] fn main() {
+//! use imxrt_iomuxc::{imxrt1060::gpio_ad_b0::*, xbar, DriveStrength};
+//!
+//! let mut test_point = unsafe { GPIO_AD_B0_00::new() }; // XBAR1_INOUT14
+//! let sync_output = xbar::prepare_output(&mut test_point, DriveStrength::R0_6);
+//!
+//! // Route the PWM sync signal to this crossbar output
+//! assert_eq!(sync_output.index(), 14);
+//! # }
+//! # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+//! ```
+//!
+//! An input-only line cannot be an output:
+//!
+//! ```compile_fail
+//! use imxrt_iomuxc::{imxrt1060::gpio_emc::*, xbar, DriveStrength};
+//!
+//! let mut pad = unsafe { GPIO_EMC_00::new() }; // XBAR1_XBAR_IN02
+//! xbar::prepare_output(&mut pad, DriveStrength::R0_6);
+//! ```
+//!
+//! # Lines
+//!
+//! Only some crossbar inputs are IOMUX lines. The crossbar drives the others
+//! internally, like its logic-high input, so no pad can be their input. An IOMUX
+//! line that more than one pad can drive has a select input register. Each pad
+//! that drives the line has its own daisy value.
+//!
+//! A strongly-typed [`Pin`] always names an IOMUX line. When you only know the line
+//! and the pad at run time, use [`prepare_erased()`]. It returns an error if the
+//! line is hardwired, or if the pad can't drive the line. Query the lines with
+//! [`PinTable::line()`] and [`PinTable::line_has_pad_input()`].
+
+use crate::{function::XbarDirection, PadId, PadLookup, RoutingError};
+use core::marker::PhantomData;
+
+/// An XBAR line direction
+pub trait Direction: private::Sealed {}
+/// A direction that can receive a crossbar input
+pub trait InputDirection: Direction {}
+/// A direction that can drive a crossbar output
+pub trait OutputDirection: Direction {}
+
+/// A tag that indicates an input-only line, like `XBAR1_XBAR_IN02`
+pub enum In {}
+/// A tag that indicates an output-only line
+pub enum Out {}
+/// A tag that indicates a bidirectional line, like `XBAR1_INOUT14`
+pub enum InOut {}
+
+impl Direction for In {}
+impl Direction for Out {}
+impl Direction for InOut {}
+impl InputDirection for In {}
+impl InputDirection for InOut {}
+impl OutputDirection for Out {}
+impl OutputDirection for InOut {}
+
+mod private {
+    use crate::function::XbarDirection;
+
+    pub trait Sealed {
+        const DIRECTION: XbarDirection;
+    }
+    impl Sealed for super::In {
+        const DIRECTION: XbarDirection = XbarDirection::In;
+    }
+    impl Sealed for super::Out {
+        const DIRECTION: XbarDirection = XbarDirection::Out;
+    }
+    impl Sealed for super::InOut {
+        const DIRECTION: XbarDirection = XbarDirection::InOut;
+    }
+}
+
+/// An XBAR pin
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the XBAR pin
+    const ALT: u32;
+    /// The daisy register which will select the pad
+    const DAISY: Option<super::Daisy>;
+    /// The pin's SION requirement
+    ///
+    /// Defaults to [`SionRequirement::Set`](crate::SionRequirement::Set).
+    const SION: super::SionRequirement = super::SionRequirement::Set;
+    /// The XBAR IOMUX line; `U14` for `XBAR1_INOUT14`
+    ///
+    /// The line is also the crossbar's input or output number.
+    type Index: super::consts::Unsigned;
+    /// The line direction; one of [`In`], [`Out`], or [`InOut`]
+    type Direction: Direction;
+}
+
+/// Prepare an XBAR pin as a crossbar input
+///
+/// If you do not call `prepare()` on your XBAR pin, it might not work as an XBAR
+/// input.
+pub fn prepare<P>(pin: &mut P)
+where
+    P: Pin,
+    P::Direction: InputDirection,
+{
+    trace!("xbar::prepare mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin(pin, P::ALT, P::SION, P::DAISY);
+}
+
+/// Prepare an XBAR pin as a crossbar output
+///
+/// Returns the crossbar output that drives the pad. The call configures the pad
+/// with [`output_config()`], using the drive strength `drive`. Unlike [`prepare()`],
+/// this does not commit the pin's daisy register, since the daisy only selects an
+/// input. For a bidirectional line, you must also select the output direction.
+pub fn prepare_output<P>(pin: &mut P, drive: super::DriveStrength) -> Output<P::Index>
+where
+    P: Pin,
+    P::Direction: OutputDirection,
+{
+    trace!("xbar::prepare_output mux={:#010x}", pin.mux() as usize);
+    super::prepare_pin_configured(pin, output_config(drive), P::ALT, P::SION, None);
+    Output::new()
+}
+
+/// The pad configuration for a crossbar output
+///
+/// The pad is a fast, push-pull output with the drive strength `drive`. The
+/// configuration modifies only those fields.
+pub const fn output_config(drive: super::DriveStrength) -> super::Config {
+    super::Config::modify()
+        .set_open_drain(super::OpenDrain::Disabled)
+        .set_speed(super::Speed::Fast)
+        .set_slew_rate(super::SlewRate::Fast)
+        .set_drive_strength(drive)
+}
+
+/// Compute the register writes that prepare an XBAR input pin
+///
+/// The result describes the same writes as [`prepare()`], along with the pad
+/// configuration `config`. `config` must be created with [`Config::zero()`](crate::Config::zero).
+/// See [`PreparedWrites`](crate::PreparedWrites) for more information.
+pub const fn prepared<P>(config: super::Config) -> super::PreparedWrites
+where
+    P: Pin + super::ConstIomuxc,
+    P::Direction: InputDirection,
+{
+    super::PreparedWrites::new::<P>(P::ALT, P::SION, config, P::DAISY)
+}
+
+/// A crossbar input that is driven by a prepared pad
+///
+/// `Index` is the crossbar input number. Use [`index()`](Input::index) to
+/// access the number when you program the crossbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<Index> {
+    _index: PhantomData<Index>,
+}
+
+impl<Index: super::consts::Unsigned> Input<Index> {
+    /// The crossbar input number
+    pub const INDEX: u32 = Index::U32;
+
+    pub(crate) const fn new() -> Self {
+        Input {
+            _index: PhantomData,
+        }
+    }
+
+    /// Returns the crossbar input number
+    pub const fn index(self) -> u32 {
+        Self::INDEX
+    }
+}
+
+/// A crossbar output that drives a prepared pad
+///
+/// `Index` is the crossbar output number. Use [`index()`](Output::index) to
+/// access the number when you program the crossbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Output<Index> {
+    _index: PhantomData<Index>,
+}
+
+impl<Index: super::consts::Unsigned> Output<Index> {
+    /// The crossbar output number
+    pub const INDEX: u32 = Index::U32;
+
+    const fn new() -> Self {
+        Output {
+            _index: PhantomData,
+        }
+    }
+
+    /// Returns the crossbar output number
+    pub const fn index(self) -> u32 {
+        Self::INDEX
+    }
+}
+
+/// How a crossbar input connects to the pads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Line {
+    /// The crossbar drives the input internally; no pad is an input
+    Hardwired,
+    /// One pad drives the line, without a select input register
+    Pad,
+    /// Pads drive the line through a select input register
+    Selectable,
+}
+
+/// An error when preparing an XBAR pin at run time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LineError {
+    /// The chip's crossbar doesn't have the input
+    UnknownLine(u8),
+    /// The crossbar drives the input internally, so no pad can drive it
+    HardwiredLine(u8),
+    /// The pad isn't in the collection, or it can't drive the line
+    Routing(RoutingError),
+}
+
+impl From<RoutingError> for LineError {
+    fn from(err: RoutingError) -> Self {
+        LineError::Routing(err)
+    }
+}
+
+/// An XBAR pin in a chip's table of XBAR pins
+///
+/// Use [`new()`](TableEntry::new) to describe a pin. See [`PinTable`] for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableEntry {
+    pad: PadId,
+    index: u8,
+    direction: XbarDirection,
+    alt: u32,
+    sion: super::SionRequirement,
+    daisy: Option<super::Daisy>,
+}
+
+impl TableEntry {
+    /// Describe pad `P` as an XBAR pin
+    pub const fn new<P: Pin + super::ConstIomuxc>() -> Self {
+        use super::consts::Unsigned;
+        TableEntry {
+            pad: PadId::of::<P>(),
+            index: P::Index::U8,
+            direction: <P::Direction as private::Sealed>::DIRECTION,
+            alt: P::ALT,
+            sion: P::SION,
+            daisy: P::DAISY,
+        }
+    }
+
+    /// Returns the pin's pad
+    pub const fn pad(&self) -> PadId {
+        self.pad
+    }
+
+    /// Returns the pin's IOMUX line
+    pub const fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the pin's daisy selection, if the line has a select input register
+    pub const fn daisy(&self) -> Option<super::Daisy> {
+        self.daisy
+    }
+
+    /// Returns `true` if the pin can be a crossbar input
+    const fn is_input(&self) -> bool {
+        matches!(self.direction, XbarDirection::In | XbarDirection::InOut)
+    }
+}
+
+/// Erased pads that know their XBAR lines and pins
+///
+/// Each chip with a crossbar implements `PinTable` for its `ErasedPads`. Use it with
+/// [`prepare_erased()`].
+pub trait PinTable: PadLookup {
+    /// Returns all of the XBAR pins
+    fn pins(&self) -> &[TableEntry];
+    /// Returns how crossbar input `line` connects to the pads
+    ///
+    /// Returns `None` if the crossbar doesn't have the input.
+    fn line(&self, line: u8) -> Option<Line>;
+    /// Returns `true` if a pad can drive crossbar input `line`
+    fn line_has_pad_input(&self, line: u8) -> bool {
+        matches!(self.line(line), Some(Line::Pad) | Some(Line::Selectable))
+    }
+}
+
+/// Prepare `pad` as the input of crossbar input `line`, using type-erased pads
+///
+/// `prepare_erased()` makes sure that a pad can drive `line`, and that `pad` is
+/// one of those pads. Then, it performs the same writes as [`prepare()`]. If the
+/// line or the pad is invalid, nothing is written.
+///
+/// ```no_run
+/// # #[cfg(feature = "imxrt1060")] fn main() {
+/// use imxrt_iomuxc::{imxrt1060::{gpio_b1::*, Pads}, xbar, PadId};
+///
+/// let mut pads = unsafe { Pads::new() }.erase();
+/// xbar::prepare_erased(&mut pads, 14, PadId::of::<GPIO_B1_00>()).unwrap();
+///
+/// // The crossbar's logic-high input isn't an IOMUX line
+/// assert_eq!(
+///     xbar::prepare_erased(&mut pads, 1, PadId::of::<GPIO_B1_00>()),
+///     Err(xbar::LineError::HardwiredLine(1))
+/// );
+/// # }
+/// # #[cfg(not(feature = "imxrt1060"))] fn main() {}
+/// ```
+pub fn prepare_erased<T>(pads: &mut T, line: u8, pad: PadId) -> Result<(), LineError>
+where
+    T: PinTable + ?Sized,
+{
+    match pads.line(line) {
+        None => return Err(LineError::UnknownLine(line)),
+        Some(Line::Hardwired) => return Err(LineError::HardwiredLine(line)),
+        Some(Line::Pad) | Some(Line::Selectable) => {}
+    }
+    pads.find_mut(pad).ok_or(RoutingError::UnknownPad(pad))?;
+    let entry = pads
+        .pins()
+        .iter()
+        .find(|entry| entry.index == line && entry.is_input() && entry.pad == pad)
+        .copied()
+        .ok_or(RoutingError::UnsupportedPad(pad))?;
+
+    if let Some(erased) = pads.find_mut(entry.pad) {
+        trace!("xbar::prepare_erased mux={:#010x}", entry.pad.mux_addr());
+        super::prepare_pin(erased, entry.alt, entry.sion, entry.daisy);
+    }
+    Ok(())
+}
+
+/// Returns the [`Function`](crate::Function) of an XBAR pin
+pub const fn function<P: Pin>() -> super::Function {
+    use super::consts::Unsigned;
+    super::Function::Xbar {
+        index: P::Index::U8,
+        direction: <P::Direction as private::Sealed>::DIRECTION,
+    }
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! xbar {
+    (index: $index:ty, direction: $direction:ty, alt: $alt:expr, pad: $pad:ty, daisy: $daisy:expr) => {
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
+            type Index = $index;
+            type Direction = $direction;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prepare_erased, prepare_output, InOut, Line, LineError, Pin, PinTable};
+    use super::{TableEntry, XbarDirection};
+    use crate::{consts::U14, Daisy, DriveStrength, Iomuxc};
+    use crate::{ErasedPad, PadId, PadLookup, RoutingError, SionRequirement};
+
+    struct FakePad {
+        mux: u32,
+        pad: u32,
+    }
+
+    impl crate::private::Sealed for FakePad {}
+
+    unsafe impl Iomuxc for FakePad {
+        fn mux(&mut self) -> *mut u32 {
+            &mut self.mux as *mut _
+        }
+        fn pad(&mut self) -> *mut u32 {
+            &mut self.pad as *mut _
+        }
+    }
+
+    /// prepare_output() never commits the daisy, so the fake can keep the
+    /// hardware address
+    impl Pin for FakePad {
+        const ALT: u32 = 1;
+        const DAISY: Option<Daisy> = Some(Daisy::new(0x401F_8644, 0));
+        type Index = U14;
+        type Direction = InOut;
+    }
+
+    #[test]
+    fn output_is_fast_push_pull() {
+        // Open drain enabled, slow slew, low speed, keeper
+        let mut pad = FakePad {
+            mux: 5,
+            pad: 1 << 11 | 0b001 << 3 | 1 << 12,
+        };
+
+        let output = prepare_output(&mut pad, DriveStrength::R0_6);
+
+        assert_eq!(output.index(), 14);
+        assert_eq!(pad.mux, 1 << 4 | 1);
+        assert_eq!(pad.pad, 1 << 12 | 0b10 << 6 | 0b110 << 3 | 1);
+    }
+
+    /// Two erased pads: an input on line 2, and an output on line 3
+    ///
+    /// Lines 0 and 1 are hardwired, and lines 2 through 9 are selectable. Both
+    /// pads share the `select` daisy register.
+    struct FakePads {
+        pads: [ErasedPad; 2],
+        pins: [TableEntry; 2],
+    }
+
+    impl FakePads {
+        fn new(mux: &mut [u32; 2], pad: &mut [u32; 2], select: &mut [u32; 1]) -> Self {
+            let mut erased = |offset| ErasedPad {
+                mux_base: mux.as_mut_ptr(),
+                pad_base: pad.as_mut_ptr(),
+                offset,
+            };
+            let pads = [erased(0), erased(1)];
+            let select = select.as_mut_ptr() as usize;
+            let entry = |pad: &ErasedPad, index, direction| TableEntry {
+                pad: pad.id(),
+                index,
+                direction,
+                alt: 3,
+                sion: SionRequirement::Set,
+                daisy: Some(Daisy::new(select, 1)),
+            };
+            let pins = [
+                entry(&pads[0], 2, XbarDirection::In),
+                entry(&pads[1], 3, XbarDirection::Out),
+            ];
+            FakePads { pads, pins }
+        }
+    }
+
+    impl PadLookup for FakePads {
+        fn find_mut(&mut self, id: PadId) -> Option<&mut ErasedPad> {
+            self.pads.find_mut(id)
+        }
+    }
+
+    impl PinTable for FakePads {
+        fn pins(&self) -> &[TableEntry] {
+            &self.pins
+        }
+        fn line(&self, line: u8) -> Option<Line> {
+            match line {
+                0..=1 => Some(Line::Hardwired),
+                2..=9 => Some(Line::Selectable),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn prepare_erased_selectable_line() {
+        let (mut mux, mut pad, mut select) = ([5; 2], [0; 2], [0]);
+        let mut pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        let id = pads.pads[0].id();
+        assert!(pads.line_has_pad_input(2));
+        assert_eq!(prepare_erased(&mut pads, 2, id), Ok(()));
+
+        assert_eq!(mux, [1 << 4 | 3, 5]);
+        assert_eq!(select, [1]);
+    }
+
+    #[test]
+    fn prepare_erased_hardwired_line() {
+        let (mut mux, mut pad, mut select) = ([5; 2], [0; 2], [0]);
+        let mut pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        let id = pads.pads[0].id();
+        assert!(!pads.line_has_pad_input(1));
+        assert_eq!(
+            prepare_erased(&mut pads, 1, id),
+            Err(LineError::HardwiredLine(1))
+        );
+
+        assert_eq!(mux, [5; 2]);
+        assert_eq!(select, [0]);
+    }
+
+    #[test]
+    fn prepare_erased_unknown_line() {
+        let (mut mux, mut pad, mut select) = ([5; 2], [0; 2], [0]);
+        let mut pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        let id = pads.pads[0].id();
+        assert!(!pads.line_has_pad_input(42));
+        assert_eq!(
+            prepare_erased(&mut pads, 42, id),
+            Err(LineError::UnknownLine(42))
+        );
+
+        assert_eq!(mux, [5; 2]);
+        assert_eq!(select, [0]);
+    }
+
+    #[test]
+    fn prepare_erased_unsupported_pad() {
+        let (mut mux, mut pad, mut select) = ([5; 2], [0; 2], [0]);
+        let mut pads = FakePads::new(&mut mux, &mut pad, &mut select);
+        let ids = [pads.pads[0].id(), pads.pads[1].id()];
+        // The line 2 pad can't drive line 4
+        assert_eq!(
+            prepare_erased(&mut pads, 4, ids[0]),
+            Err(LineError::Routing(RoutingError::UnsupportedPad(ids[0])))
+        );
+        // The line 3 pad is an output
+        assert_eq!(
+            prepare_erased(&mut pads, 3, ids[1]),
+            Err(LineError::Routing(RoutingError::UnsupportedPad(ids[1])))
+        );
+        assert_eq!(
+            prepare_erased(&mut pads, 2, PadId(0x1234)),
+            Err(LineError::Routing(RoutingError::UnknownPad(PadId(0x1234))))
+        );
+
+        assert_eq!(mux, [5; 2]);
+        assert_eq!(select, [0]);
+    }
+}
@@ -3,6 +3,7 @@
 //! If these tests do not compile, consider the API broken.
 
 #![allow(unused)]
+#![deny(deprecated)]
 
 mod iomuxc {
     #[cfg(feature = "imxrt1060")]
@@ -10,6 +11,10 @@ mod iomuxc {
     pub use imxrt_iomuxc::prelude::*;
 }
 
+mod legacy {
+    pub use imxrt_iomuxc::prelude::legacy::*;
+}
+
 /// Ensure that prelude modules are re-exported as expected
 #[test]
 fn use_prelude() {
@@ -18,6 +23,25 @@ fn use_prelude() {
     };
 }
 
+/// Ensure that newer items are re-exported, without deprecation warnings
+#[test]
+fn use_prelude_additions() {
+    use iomuxc::{
+        apply_routing, init_table, modify_field, modify_fields, pinout, plan, take, validate,
+        Config, ConfiguredPad, EncodingError, Field, Mismatch, PadId, PadLookup, PadRef,
+        Peripheral, Plan, PullKeeper, Remainder, Reservation, Routing, SionRequirement,
+    };
+    let config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup22k));
+}
+
+/// Ensure that the legacy prelude has the prelude, and the deprecated items
+#[test]
+#[allow(deprecated)]
+fn use_legacy() {
+    use legacy::{lpuart, Config, PadId, PullKeep, PullKeepSelect, PullUpDown};
+    let config = Config::modify().set_pull_keep(PullKeep::Enabled);
+}
+
 /// Ensure that the imxrt1060 modules are re-exported
 #[cfg(feature = "imxrt1060")]
 #[test]